@@ -0,0 +1,34 @@
+//! Stable, curated facade over the chaos engineering framework's internal
+//! crates (`chaos_core`, `chaos_scenarios`, `chaos_metrics`).
+//!
+//! Applications embedding the framework programmatically should depend on
+//! this crate rather than the internal ones directly - which module a type
+//! lives in, or which crate owns it, may still move around as the
+//! framework grows, but its re-export here won't. Most callers only need
+//! `use chaos::prelude::*;`.
+
+pub use chaos_core::{
+    self, Capability, ChaosError, DynInjector, Executor, InjectionHandle, Injector,
+    InjectorRegistry, Result, Target,
+};
+pub use chaos_metrics::{self, AggregatedMetrics, Metric, MetricType, MetricsAggregator, MetricsCollector};
+pub use chaos_scenarios::{
+    self, parse_scenario_from_file, parse_scenario_from_str, run_scenario, serialize_scenario,
+    HostFingerprint, ImpactEstimate, PackageManifest, Phase, Scenario, ScenarioConfig,
+    ScenarioPackage, ScenarioPlan, ScenarioRunner, Scheduler, SchedulingMode,
+};
+
+/// The common set of types most embedding applications need: the executor,
+/// the injector trait and its built-in implementations, and the scenario
+/// runner and its configuration types.
+pub mod prelude {
+    pub use crate::{
+        ChaosError, Executor, Injector, MetricsCollector, Result, Scenario, ScenarioRunner, Target,
+    };
+
+    pub use chaos_core::{
+        CpuStarvationConfig, CpuStarvationInjector, MemoryLeakConfig, MemoryLeakInjector,
+        MemoryPressureConfig, MemoryPressureInjector, NetworkLatencyConfig, NetworkLatencyInjector,
+        PacketLossConfig, PacketLossInjector, ProcessKillConfig, ProcessKillInjector,
+    };
+}
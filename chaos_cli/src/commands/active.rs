@@ -0,0 +1,33 @@
+use anyhow::Result;
+use chaos_core::StateFile;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Lists the injections recorded in `state_file`, so an operator who ran
+/// `chaos attach` without `--duration` has something to feed `chaos stop
+/// <injection-id>` with later, instead of having to recall the ID printed
+/// at attach time.
+pub async fn execute(state_file: PathBuf) -> Result<()> {
+    println!("{}", "=== Active Injections ===".bold().cyan());
+
+    let state = StateFile::load(&state_file).await?;
+
+    if state.injections.is_empty() {
+        println!("\n{}", "No active injections recorded.".green());
+        return Ok(());
+    }
+
+    println!("\nFound {} active injection(s):", state.injections.len());
+
+    for handle in state.injections.values() {
+        println!(
+            "  {} - {} on {} (running {})",
+            handle.id,
+            handle.injector_name,
+            handle.target.description(),
+            humantime::format_duration(handle.duration().to_std().unwrap_or_default())
+        );
+    }
+
+    Ok(())
+}
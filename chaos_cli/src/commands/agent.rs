@@ -0,0 +1,67 @@
+use anyhow::Result;
+use chaos_core::{AgentServerConfig, Executor, InjectorRegistry};
+use colored::Colorize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Runs `chaos agent`: a long-lived `chaos_core::agent::serve` exposing this
+/// host's `Executor` over HTTP, so a scenario on another box can target it
+/// through `Target::Agent` instead of a bare `chaos run`. Active faults are
+/// persisted to `state_file` the same as `chaos attach`/`chaos monkey`, so a
+/// `chaos recover` on this host can clean up after an agent that's killed
+/// mid-injection.
+pub async fn execute(bind_addr: SocketAddr, token: String, state_file: PathBuf) -> Result<()> {
+    println!("{}", "=== Chaos Agent ===".bold().cyan());
+
+    if token.is_empty() {
+        anyhow::bail!("Refusing to start an agent with an empty bearer token");
+    }
+
+    let executor = Executor::with_persistence(InjectorRegistry::with_defaults(), state_file);
+    let cleanup_executor = executor.clone();
+    let cancel = CancellationToken::new();
+
+    let signal_cancel = cancel.clone();
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        {
+            let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("failed to install SIGTERM handler");
+            tokio::select! {
+                result = tokio::signal::ctrl_c() => {
+                    if result.is_ok() {
+                        warn!("Received interrupt (SIGINT), stopping chaos agent...");
+                    }
+                }
+                _ = sigterm.recv() => {
+                    warn!("Received SIGTERM, stopping chaos agent...");
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                warn!("Received interrupt, stopping chaos agent...");
+            }
+        }
+        signal_cancel.cancel();
+    });
+
+    println!("Listening on {}", bind_addr.to_string().green());
+    println!(
+        "\n{}",
+        "Running indefinitely - Ctrl-C or SIGTERM to stop...".bold().yellow()
+    );
+
+    chaos_core::serve_with_cancellation(executor, AgentServerConfig::new(bind_addr, token), cancel).await?;
+
+    println!("\n{}", "Cleaning up any faults still active...".bold().yellow());
+    if let Err(e) = cleanup_executor.remove_all().await {
+        warn!("Failed to remove all injections during shutdown cleanup: {}", e);
+    }
+
+    println!("{}", "=== Chaos Agent Stopped ===".bold().green());
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use chaos_core::{Executor, Target};
+use chaos_core::{Executor, InjectorRegistry, StateFile, Target};
 use colored::Colorize;
 use std::path::PathBuf;
 
@@ -9,6 +9,7 @@ pub async fn execute(
     injection: String,
     duration: Option<String>,
     _config: Option<PathBuf>,
+    export: Option<PathBuf>,
 ) -> Result<()> {
     println!("{}", "=== Attach Mode ===".bold().cyan());
 
@@ -36,8 +37,10 @@ pub async fn execute(
         println!("Duration: {}", dur);
     }
 
-    // Create executor
-    let executor = Executor::with_defaults();
+    // Create executor. Persist to the state file so an unattended injection
+    // (especially one left active with no duration) can still be found and
+    // torn down by `chaos recover` if this process is killed.
+    let executor = Executor::with_persistence(InjectorRegistry::with_defaults(), StateFile::default_path());
 
     println!("\n{}", "Applying injection...".yellow());
 
@@ -47,6 +50,16 @@ pub async fn execute(
     println!("{}", "✓ Injection applied successfully!".green().bold());
     println!("Injection ID: {}", handle.id);
 
+    if let Some(export_path) = &export {
+        let handle_json = serde_json::to_string_pretty(&handle)?;
+        tokio::fs::write(export_path, handle_json).await?;
+        println!(
+            "Exported handle to {} (use `chaos stop --handle {}` to remove it from another session)",
+            export_path.display(),
+            export_path.display()
+        );
+    }
+
     // Wait for duration if specified
     if let Some(dur_str) = duration {
         let duration = humantime::parse_duration(&dur_str)?;
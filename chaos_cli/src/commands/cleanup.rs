@@ -0,0 +1,55 @@
+use anyhow::Result;
+use chaos_core::{RealSystemBackend, SystemBackend};
+use colored::Colorize;
+
+/// Scans the host for artifacts `chaos` injectors could have left behind -
+/// netem/loss tc qdiscs, marked iptables rules, `chaos_*` cgroups,
+/// `/tmp/chaos_*` files - independent of any saved [`chaos_core::StateFile`],
+/// and removes whatever it finds. This is the thing to run after a killed
+/// run whose executor never got to write (or finish writing) its state, the
+/// case [`super::recover`] can't help with.
+pub async fn execute(dry_run: bool) -> Result<()> {
+    println!("{}", "=== Chaos Cleanup ===".bold().cyan());
+
+    let backend: &dyn SystemBackend = &RealSystemBackend;
+    let orphans = chaos_core::cleanup::scan(backend).await;
+
+    if orphans.is_empty() {
+        println!("\n{}", "Nothing to clean up - no orphaned artifacts found.".green());
+        return Ok(());
+    }
+
+    println!("\nFound {} orphaned artifact(s):", orphans.len());
+
+    if dry_run {
+        for orphan in &orphans {
+            println!("  {} {}", "would remove".yellow(), orphan.description());
+        }
+        return Ok(());
+    }
+
+    let mut removed = 0;
+    let mut failed = 0;
+
+    for orphan in &orphans {
+        print!("  {}... ", orphan.description());
+        match orphan.remove(backend).await {
+            Ok(()) => {
+                println!("{}", "removed".green());
+                removed += 1;
+            }
+            Err(e) => {
+                println!("{} ({})", "failed".red(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "\n{} removed, {} failed",
+        removed.to_string().green(),
+        failed.to_string().red()
+    );
+
+    Ok(())
+}
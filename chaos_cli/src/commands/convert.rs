@@ -0,0 +1,33 @@
+use anyhow::Result;
+use chaos_scenarios::{parse_scenario_from_file, serialize_scenario};
+use colored::Colorize;
+use std::path::PathBuf;
+
+pub async fn execute(scenario_file: PathBuf, to: String, output: Option<PathBuf>) -> Result<()> {
+    println!("{}", "=== Converting Scenario ===".bold().cyan());
+    println!("Source: {}", scenario_file.display());
+    println!("Target format: {}", to);
+
+    let scenario = parse_scenario_from_file(&scenario_file).await?;
+    let converted = serialize_scenario(&scenario, &to)?;
+
+    let output_path = output.unwrap_or_else(|| scenario_file.with_extension(normalized_extension(&to)));
+
+    tokio::fs::write(&output_path, converted).await?;
+
+    println!(
+        "\n{} Wrote {} as {}",
+        "✓".green().bold(),
+        output_path.display(),
+        to
+    );
+
+    Ok(())
+}
+
+fn normalized_extension(format: &str) -> String {
+    match format.to_lowercase().as_str() {
+        "yml" => "yaml".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use chaos_core::{InjectorRegistry, Preflight};
+use colored::Colorize;
+
+/// Runs [`Preflight::run`] against the default injector registry and prints
+/// a per-check readiness report, so an operator can tell whether a host
+/// supports real fault injection before pointing a scenario at it - rather
+/// than discovering a missing `tc` binary or `CAP_NET_ADMIN` mid-run.
+pub async fn execute() -> Result<()> {
+    println!("{}", "=== Chaos Doctor ===".bold().cyan());
+
+    let registry = InjectorRegistry::with_defaults();
+    let report = Preflight::run(&registry).await;
+
+    println!("\n{}", "Privilege:".bold());
+    if report.privileged {
+        println!("  {} running as root", "✓".green());
+    } else {
+        println!(
+            "  {} running unprivileged - injectors needing a capability this process doesn't hold will report not ready below",
+            "i".yellow()
+        );
+    }
+
+    println!("\n{}", "Required binaries:".bold());
+    for binary in &report.binaries {
+        let label = match &binary.path {
+            Some(path) => format!("{} ({})", binary.name, path.display()),
+            None => binary.name.to_string(),
+        };
+        print_check(binary.found, &label);
+    }
+
+    println!("\n{}", "Kernel modules:".bold());
+    if report.kernel_modules.is_empty() {
+        println!("  (not checked on this platform)");
+    }
+    for module in &report.kernel_modules {
+        print_check(module.loaded, module.name);
+    }
+
+    println!("\n{}", "Capabilities:".bold());
+    if report.capabilities.is_empty() {
+        println!("  (not checked on this platform)");
+    }
+    for capability in &report.capabilities {
+        print_check(capability.held, capability.name);
+    }
+
+    println!("\n{}", "Cgroup version:".bold());
+    match report.cgroup_version {
+        Some(version) => println!("  cgroup v{}", version),
+        None => println!("  {}", "could not be determined".yellow()),
+    }
+
+    println!("\n{}", "Injector readiness:".bold());
+    for injector in &report.injectors {
+        let label = match &injector.detail {
+            Some(detail) => format!("{} - {}", injector.name, detail),
+            None => injector.name.clone(),
+        };
+        print_check(injector.ready, &label);
+    }
+
+    if report.all_passed() {
+        println!("\n{}", "Host is ready for real fault injection.".green().bold());
+        Ok(())
+    } else {
+        println!(
+            "\n{}",
+            "Some preflight checks failed - review the items above.".yellow().bold()
+        );
+        anyhow::bail!("chaos doctor found one or more preflight issues");
+    }
+}
+
+fn print_check(passed: bool, label: &str) {
+    let marker = if passed { "✓".green() } else { "✗".red() };
+    println!("  {} {}", marker, label);
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use chaos_metrics::{HistoryStore, TrendSeries};
+use colored::Colorize;
+use std::path::PathBuf;
+
+pub async fn compact(history_dir: PathBuf) -> Result<()> {
+    println!("{}", "=== Compacting Run History ===".bold().cyan());
+    println!("History directory: {}", history_dir.display());
+
+    let store = HistoryStore::new(&history_dir);
+    let summary = store.compact().await?;
+
+    println!(
+        "\n{} {} raw entries compacted into {} scenario aggregates ({} stale aggregates pruned)",
+        "✓".green().bold(),
+        summary.raw_entries_compacted,
+        summary.scenarios_aggregated,
+        summary.aggregates_pruned
+    );
+
+    Ok(())
+}
+
+/// Regression detection window and threshold used by `chaos history trend`.
+/// A 20% drift over the trailing 5 runs is flagged; tune per-scenario
+/// thresholds aren't supported yet.
+const REGRESSION_WINDOW: usize = 5;
+const REGRESSION_THRESHOLD: f64 = 0.2;
+
+pub async fn trend(history_dir: PathBuf, scenario: String, metric: String, format: String) -> Result<()> {
+    let store = HistoryStore::new(&history_dir);
+    let series = store.trend(&scenario, &metric).await?;
+
+    if series.points.is_empty() {
+        println!(
+            "No stored runs found for scenario '{}' in {}",
+            scenario,
+            history_dir.display()
+        );
+        return Ok(());
+    }
+
+    match format.as_str() {
+        "json" => println!("{}", serde_json::to_string_pretty(&series)?),
+        "csv" => print_csv_trend(&series),
+        _ => print_ascii_trend(&series),
+    }
+
+    if let Some(alert) = series.detect_regression(REGRESSION_WINDOW, REGRESSION_THRESHOLD) {
+        println!(
+            "\n{} '{}' drifted {:.1}% against its trailing baseline ({:.3} -> {:.3})",
+            "⚠".yellow().bold(),
+            series.metric,
+            alert.deviation * 100.0,
+            alert.baseline,
+            alert.latest
+        );
+    }
+
+    Ok(())
+}
+
+fn print_csv_trend(series: &TrendSeries) {
+    println!("recorded_at,{}", series.metric);
+    for point in &series.points {
+        println!("{},{}", point.recorded_at.to_rfc3339(), point.value);
+    }
+}
+
+fn print_ascii_trend(series: &TrendSeries) {
+    let max = series.points.iter().map(|p| p.value).fold(f64::MIN, f64::max);
+    let min = series.points.iter().map(|p| p.value).fold(f64::MAX, f64::min);
+    let range = (max - min).max(f64::EPSILON);
+
+    println!(
+        "{} trend for '{}' ({} runs)\n",
+        series.metric.bold(),
+        series.scenario_name.cyan(),
+        series.points.len()
+    );
+
+    for point in &series.points {
+        let filled = (((point.value - min) / range) * 40.0).round().max(1.0) as usize;
+        let bar = "#".repeat(filled);
+        println!(
+            "{:<20} {:>12.3} {}",
+            point.recorded_at.format("%Y-%m-%d %H:%M"),
+            point.value,
+            bar.green()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use chaos_scenarios::{lint::LintSeverity, parse_scenario_from_file};
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Runs `chaos_scenarios::lint` against a scenario file and prints what it
+/// found. Unlike `chaos validate`, nothing here is fatal - a lint finding
+/// describes a scenario that's technically fine but worth a second look, so
+/// this only exits non-zero to make "any findings" easy to script around,
+/// not because the scenario is broken.
+pub async fn execute(scenario_file: PathBuf) -> Result<()> {
+    println!("{}", "=== Linting Scenario ===".bold().cyan());
+    println!("File: {}", scenario_file.display());
+
+    let scenario = parse_scenario_from_file(&scenario_file).await?;
+    let findings = chaos_scenarios::lint(&scenario);
+
+    if findings.is_empty() {
+        println!("\n{}", "No issues found.".green().bold());
+        return Ok(());
+    }
+
+    println!("\n{} finding(s):", findings.len());
+    for finding in &findings {
+        let marker = match finding.severity {
+            LintSeverity::Warning => "⚠".yellow(),
+            LintSeverity::Info => "i".cyan(),
+        };
+        println!("  {} [{}] {}", marker, finding.phase, finding.message);
+    }
+
+    anyhow::bail!(
+        "chaos lint found {} issue(s) - review the items above",
+        findings.len()
+    );
+}
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use chaos_core::Executor;
+use chaos_core::{Capability, Executor};
 use colored::Colorize;
 
 pub async fn execute() -> Result<()> {
@@ -16,5 +16,19 @@ pub async fn execute() -> Result<()> {
 
     println!("\n{}", "Use 'chaos attach' to apply an injector to a target".yellow());
 
+    println!("\n{}", "Optional integrations:".bold());
+    for capability in Capability::ALL {
+        if capability.is_compiled() {
+            println!("  {} {} (compiled in)", "✓".green(), capability);
+        } else {
+            println!(
+                "  {} {} (rebuild with `--features {}` to enable)",
+                "✗".red(),
+                capability,
+                capability.feature_flag()
+            );
+        }
+    }
+
     Ok(())
 }
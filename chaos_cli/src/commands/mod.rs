@@ -1,5 +1,24 @@
-pub mod run;
-pub mod attach;
-pub mod report;
-pub mod validate;
-pub mod list;
+pub mod run;
+pub mod active;
+pub mod agent;
+pub mod attach;
+pub mod cleanup;
+pub mod convert;
+pub mod doctor;
+pub mod history;
+pub mod lint;
+pub mod monkey;
+pub mod package;
+pub mod plan;
+pub mod recover;
+pub mod replay;
+pub mod report;
+pub mod schedule;
+pub mod scenarios;
+pub mod self_test;
+pub mod stop;
+pub mod suite;
+pub mod targets;
+pub mod top;
+pub mod validate;
+pub mod list;
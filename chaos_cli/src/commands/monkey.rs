@@ -0,0 +1,102 @@
+use anyhow::Result;
+use chaos_core::{Executor, InjectorRegistry, SafetyPolicy, StateFile};
+use chaos_scenarios::{MonkeyConfig, MonkeyRunner};
+use colored::Colorize;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Runs a chaos monkey indefinitely from `config_file`: a random (seeded)
+/// allowed injector against a random allowed target, on a random interval,
+/// until interrupted. Every action goes through the same tracing setup
+/// `chaos run` uses, so the per-run JSON log under `--log-dir` is today's
+/// audit trail; there's no separate daemon/status API yet (see
+/// `chaos_scenarios::MonkeyRunner::status`).
+pub async fn execute(config_file: PathBuf, policy_file: Option<PathBuf>) -> Result<()> {
+    println!("{}", "=== Chaos Monkey ===".bold().cyan());
+    println!("Loading monkey config: {}", config_file.display());
+
+    let config = MonkeyConfig::load(&config_file).await?;
+
+    println!("\n{}", "Monkey Details:".bold());
+    println!("  Name: {}", config.name.green());
+    println!("  Targets: {}", config.targets.len());
+    println!("  Allowed injectors: {}", config.allowed_injectors.join(", "));
+    println!(
+        "  Interval: {:?} - {:?}",
+        config.min_interval, config.max_interval
+    );
+    println!("  Injection TTL: {:?}", config.injection_ttl);
+    println!("  Max concurrent faults: {}", config.max_concurrent);
+    if let Some(duration) = &config.duration {
+        println!("  Fault duration: {:?} - {:?}", duration.min, duration.max);
+    }
+    if let Some(intensity) = &config.intensity {
+        println!(
+            "  Intensity ({}): {} - {}",
+            intensity.parameter, intensity.min, intensity.max
+        );
+    }
+
+    // Active faults are mirrored to the state file as they're applied, the
+    // same as `chaos run`, so `chaos recover` can find anything left behind
+    // by a monkey that was killed uncleanly.
+    let mut executor = Executor::with_persistence(
+        InjectorRegistry::with_defaults(),
+        StateFile::default_path(),
+    );
+
+    // A monkey picks its own targets at random, so a safety policy is the
+    // main thing standing between an unlucky roll and production - unlike
+    // `chaos run`, where the scenario author already chose every target.
+    if let Some(policy_file) = &policy_file {
+        println!("Loading safety policy: {}", policy_file.display());
+        executor = executor.with_policy(SafetyPolicy::load(policy_file).await?);
+    }
+    let cleanup_executor = executor.clone();
+    let mut runner = MonkeyRunner::new(config, executor);
+    let cancel = CancellationToken::new();
+
+    let signal_cancel = cancel.clone();
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        {
+            let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("failed to install SIGTERM handler");
+            tokio::select! {
+                result = tokio::signal::ctrl_c() => {
+                    if result.is_ok() {
+                        warn!("Received interrupt (SIGINT), stopping chaos monkey...");
+                    }
+                }
+                _ = sigterm.recv() => {
+                    warn!("Received SIGTERM, stopping chaos monkey...");
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                warn!("Received interrupt, stopping chaos monkey...");
+            }
+        }
+        signal_cancel.cancel();
+    });
+
+    println!(
+        "\n{}",
+        "Running indefinitely - Ctrl-C or SIGTERM to stop...".bold().yellow()
+    );
+    runner.run(cancel).await;
+
+    println!("\n{}", "Cleaning up any faults still active...".bold().yellow());
+    if let Err(e) = cleanup_executor.remove_all().await {
+        warn!("Failed to remove all injections during shutdown cleanup: {}", e);
+    }
+
+    let status = runner.status();
+    println!("\n{}", "=== Chaos Monkey Stopped ===".bold().green());
+    println!("Total actions taken: {}", status.actions_taken);
+
+    Ok(())
+}
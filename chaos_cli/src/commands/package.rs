@@ -0,0 +1,46 @@
+use anyhow::Result;
+use chaos_scenarios::ScenarioPackage;
+use colored::Colorize;
+use std::path::PathBuf;
+
+pub async fn validate(path: PathBuf) -> Result<()> {
+    println!("{}", "=== Validating Scenario Package ===".bold().cyan());
+    println!("Package: {}", path.display());
+
+    let package = ScenarioPackage::load(&path).await?;
+
+    println!("\n{}", "✓ Package is valid!".green().bold());
+    println!("  Name: {}", package.manifest.name);
+    println!("  Version: {}", package.manifest.version);
+    println!("  Scenario: {}", package.scenario.name);
+    println!("  Probes: {}", package.manifest.probes.len());
+    println!(
+        "  Policy exceptions: {}",
+        package.manifest.policy_exceptions.len()
+    );
+
+    if let Some(summary) = &package.manifest.docs.summary {
+        println!("  Summary: {}", summary);
+    }
+
+    Ok(())
+}
+
+pub async fn install(path: PathBuf, library_dir: PathBuf) -> Result<()> {
+    println!("{}", "=== Installing Scenario Package ===".bold().cyan());
+    println!("Package: {}", path.display());
+    println!("Library: {}", library_dir.display());
+
+    let package = ScenarioPackage::load(&path).await?;
+    let dest = package.install(&library_dir).await?;
+
+    println!(
+        "\n{} Installed {} v{} to {}",
+        "✓".green().bold(),
+        package.manifest.name,
+        package.manifest.version,
+        dest.display()
+    );
+
+    Ok(())
+}
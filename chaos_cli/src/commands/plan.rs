@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chaos_scenarios::{parse_scenario_from_file, HostFingerprint, ImpactEstimate, ScenarioPlan};
+use colored::Colorize;
+use std::path::PathBuf;
+
+pub async fn execute(scenario_file: PathBuf) -> Result<()> {
+    println!("{}", "=== Scenario Plan (dry run) ===".bold().cyan());
+    println!("File: {}", scenario_file.display());
+
+    let scenario = parse_scenario_from_file(&scenario_file).await?;
+    let host = HostFingerprint::capture();
+    let plan = ScenarioPlan::build(&scenario, &host);
+
+    println!("\n{}", "Host:".bold());
+    println!(
+        "  OS: {} (kernel {})",
+        plan.host.os,
+        plan.host.kernel_version.as_deref().unwrap_or("unknown")
+    );
+    println!(
+        "  CPU: {} ({} cores)",
+        plan.host.cpu_model.as_deref().unwrap_or("unknown"),
+        plan.host.cpu_cores
+    );
+    println!("  Memory: {} bytes", plan.host.total_memory_bytes);
+    println!(
+        "  cgroup: {}",
+        plan.host.cgroup_version.as_deref().unwrap_or("none detected")
+    );
+
+    if !plan.background.is_empty() {
+        println!("\n{}", "Background load:".bold());
+        for estimate in &plan.background {
+            print_estimate(estimate, "  ");
+        }
+    }
+
+    println!("\n{}", "Phases:".bold());
+    for (i, phase) in plan.phases.iter().enumerate() {
+        println!("\n  Phase {}: {} ({:?})", i + 1, phase.name, phase.duration);
+        for estimate in &phase.estimates {
+            print_estimate(estimate, "    ");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_estimate(estimate: &ImpactEstimate, indent: &str) {
+    println!(
+        "{}- {} -> {}",
+        indent, estimate.injection_type, estimate.target
+    );
+
+    if let Some(ms) = estimate.added_latency_ms {
+        println!("{}    added latency: ~{:.1}ms", indent, ms);
+    }
+    if let Some(pct) = estimate.traffic_affected_pct {
+        println!("{}    traffic affected: ~{:.1}%", indent, pct);
+    }
+    if let Some(n) = estimate.processes_affected {
+        println!("{}    processes affected: ~{}", indent, n);
+    }
+    if let Some(bytes) = estimate.disk_bytes_to_fill {
+        println!("{}    disk to fill: ~{} bytes", indent, bytes);
+    }
+    for note in &estimate.notes {
+        println!("{}    {} {}", indent, "note:".dimmed(), note);
+    }
+}
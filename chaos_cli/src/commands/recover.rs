@@ -0,0 +1,68 @@
+use anyhow::Result;
+use chaos_core::{InjectorRegistry, StateFile};
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Reads `state_file` left behind by a crashed (or otherwise un-cleaned-up)
+/// `Executor` and removes whatever injections it still lists, so a crash
+/// doesn't leave the host degraded with orphaned tc/iptables/cgroup
+/// artifacts and no record of what caused them.
+pub async fn execute(state_file: PathBuf) -> Result<()> {
+    println!("{}", "=== Chaos Recovery ===".bold().cyan());
+    println!("State file: {}", state_file.display());
+
+    let state = StateFile::load(&state_file).await?;
+
+    if state.injections.is_empty() {
+        println!("\n{}", "Nothing to recover - no active injections recorded.".green());
+        return Ok(());
+    }
+
+    println!(
+        "\nFound {} recorded injection(s) to clean up:",
+        state.injections.len()
+    );
+
+    let registry = InjectorRegistry::with_defaults();
+    let mut recovered = 0;
+    let mut failed = 0;
+
+    for handle in state.injections.values() {
+        print!(
+            "  {} ({}) on {}... ",
+            handle.injector_name,
+            handle.id,
+            handle.target.description()
+        );
+
+        match registry.get(&handle.injector_name) {
+            Some(injector) => match injector.remove(handle.clone()).await {
+                Ok(()) => {
+                    println!("{}", "removed".green());
+                    recovered += 1;
+                }
+                Err(e) => {
+                    println!("{} ({})", "failed".red(), e);
+                    failed += 1;
+                }
+            },
+            None => {
+                println!("{}", "unknown injector type, skipped".yellow());
+                failed += 1;
+            }
+        }
+    }
+
+    // The artifacts have been (best-effort) torn down, so the state file no
+    // longer reflects reality - clear it rather than leaving stale entries
+    // that the next recovery run would try to remove again.
+    StateFile::default().save(&state_file).await?;
+
+    println!(
+        "\n{} recovered, {} failed",
+        recovered.to_string().green(),
+        failed.to_string().red()
+    );
+
+    Ok(())
+}
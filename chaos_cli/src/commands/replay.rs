@@ -0,0 +1,46 @@
+use anyhow::Result;
+use chaos_metrics::HistoryStore;
+use chaos_scenarios::runner::ScenarioRunner;
+use chaos_scenarios::ExecutionRecord;
+use colored::Colorize;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+/// Reruns the scenario recorded in `artifact` - written by `chaos run
+/// --record-execution` (see `commands::run`) - via
+/// [`ExecutionRecord::replay_scenario`], so a run that used randomness or
+/// live discovery reproduces the same phase order, targets, and parameters
+/// instead of re-rolling them.
+pub async fn execute(artifact: PathBuf, history_dir: PathBuf) -> Result<()> {
+    println!("{}", "=== Chaos Replay ===".bold().cyan());
+    println!("Loading execution record: {}", artifact.display());
+
+    let record = ExecutionRecord::load(&artifact).await?;
+    let scenario = record.replay_scenario();
+
+    println!("\n{}", "Original Run:".bold());
+    println!("  Scenario: {}", record.scenario.name.green());
+    println!("  Recorded At: {}", record.recorded_at);
+    if let Some(seed) = record.result.resolved_seed {
+        println!("  Seed: {} (reproducible)", seed);
+    }
+    println!("  Success Rate: {:.2}%", record.result.success_rate() * 100.0);
+
+    println!("\n{}", "Replaying...".bold().yellow());
+    let result = ScenarioRunner::with_defaults().run(&scenario).await?;
+
+    println!("\n{}", "=== Replay Results ===".bold().green());
+    println!("Scenario: {}", result.scenario_name.cyan());
+    println!("Total Duration: {:?}", result.total_duration);
+    println!("Total Injections: {}", result.total_injections);
+    println!("Success Rate: {:.2}%", result.success_rate() * 100.0);
+
+    let history = HistoryStore::new(&history_dir);
+    match history.record(&result).await {
+        Ok(path) => info!("Recorded replay run history to {}", path.display()),
+        Err(e) => warn!("Failed to record replay run history: {}", e),
+    }
+
+    println!("\n{}", "✓ Replay completed!".bold().green());
+    Ok(())
+}
@@ -1,100 +1,299 @@
-use anyhow::Result;
-use colored::Colorize;
-use std::path::PathBuf;
-
-pub async fn execute(
-    metrics_file: PathBuf,
-    format: String,
-    output: Option<PathBuf>,
-    compare: Vec<PathBuf>,
-) -> Result<()> {
-    println!("{}", "=== Generate Report ===".bold().cyan());
-    println!("Metrics file: {}", metrics_file.display());
-    println!("Format: {}", format);
-
-    // Load metrics
-    let contents = tokio::fs::read_to_string(&metrics_file).await?;
-    let result: chaos_scenarios::runner::ScenarioResult = serde_json::from_str(&contents)?;
-
-    match format.as_str() {
-        "cli" => {
-            print_cli_report(&result);
-        }
-        "json" => {
-            let json = serde_json::to_string_pretty(&result)?;
-            if let Some(output_path) = output {
-                tokio::fs::write(output_path, json).await?;
-            } else {
-                println!("{}", json);
-            }
-        }
-        "markdown" => {
-            let md = generate_markdown_report(&result);
-            if let Some(output_path) = output {
-                tokio::fs::write(output_path, md).await?;
-            } else {
-                println!("{}", md);
-            }
-        }
-        "html" => {
-            println!("{}", "HTML report generation not yet implemented".yellow());
-        }
-        _ => {
-            anyhow::bail!("Unknown format: {}", format);
-        }
-    }
-
-    if !compare.is_empty() {
-        println!("\n{}", "Comparison mode not yet implemented".yellow());
-    }
-
-    Ok(())
-}
-
-fn print_cli_report(result: &chaos_scenarios::runner::ScenarioResult) {
-    println!("\n{}", "=== Scenario Report ===".bold().green());
-    println!("Scenario: {}", result.scenario_name.cyan());
-    println!("Total Duration: {:?}", result.total_duration);
-    println!("Total Injections: {}", result.total_injections);
-    println!("Success Rate: {:.2}%", result.success_rate() * 100.0);
-
-    println!("\n{}", "Phase Results:".bold());
-    for phase in &result.phase_results {
-        println!("  {} - Duration: {:?}, Injections: {}",
-            phase.name.yellow(),
-            phase.duration,
-            phase.injection_count
-        );
-    }
-}
-
-fn generate_markdown_report(result: &chaos_scenarios::runner::ScenarioResult) -> String {
-    format!(
-        r#"# Chaos Test Report: {}
-
-## Summary
-
-- **Total Duration**: {:?}
-- **Total Injections**: {}
-- **Success Rate**: {:.2}%
-
-## Phase Results
-
-{}
-
-## Conclusion
-
-Test completed successfully.
-"#,
-        result.scenario_name,
-        result.total_duration,
-        result.total_injections,
-        result.success_rate() * 100.0,
-        result
-            .phase_results
-            .iter()
-            .map(|p| format!("- **{}**: {:?} ({} injections)", p.name, p.duration, p.injection_count))
-            .collect::<Vec<_>>()
-            .join("\n")
-    )
-}
+use crate::redact::redact_text;
+use anyhow::Result;
+use chaos_metrics::TrendMetric;
+use chaos_scenarios::runner::ScenarioResult;
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+/// Metrics compared against `--baseline` - the closest proxies
+/// `ScenarioResult` has today for "latency percentiles and error rates"
+/// (see [`chaos_metrics::TrendMetric`]'s own doc comment on why percentiles
+/// aren't available yet). Duration and injection-count deltas aren't
+/// checked here: a run taking longer or injecting more faults isn't
+/// necessarily a regression the way a lower success rate or a slower phase
+/// is.
+const BASELINE_METRICS: [TrendMetric; 2] = [TrendMetric::SuccessRate, TrendMetric::AvgPhaseDurationSecs];
+
+pub async fn execute(
+    metrics_file: PathBuf,
+    format: String,
+    output: Option<PathBuf>,
+    compare: Vec<PathBuf>,
+    baseline: Option<PathBuf>,
+    regression_threshold: f64,
+    redact: bool,
+) -> Result<()> {
+    println!("{}", "=== Generate Report ===".bold().cyan());
+    println!("Metrics file: {}", metrics_file.display());
+    println!("Format: {}", format);
+    if redact {
+        println!("{}", "Redaction: enabled".yellow());
+    }
+
+    // Load metrics
+    let contents = tokio::fs::read_to_string(&metrics_file).await?;
+    let result: chaos_scenarios::runner::ScenarioResult = serde_json::from_str(&contents)?;
+
+    match format.as_str() {
+        "cli" => {
+            print_cli_report(&result);
+        }
+        "json" => {
+            let json = serde_json::to_string_pretty(&result)?;
+            let json = if redact { redact_text(&json) } else { json };
+            if let Some(output_path) = output {
+                tokio::fs::write(output_path, json).await?;
+            } else {
+                println!("{}", json);
+            }
+        }
+        "markdown" => {
+            let md = generate_markdown_report(&result);
+            let md = if redact { redact_text(&md) } else { md };
+            if let Some(output_path) = output {
+                tokio::fs::write(output_path, md).await?;
+            } else {
+                println!("{}", md);
+            }
+        }
+        "html" => {
+            println!("{}", "HTML report generation not yet implemented".yellow());
+        }
+        _ => {
+            anyhow::bail!("Unknown format: {}", format);
+        }
+    }
+
+    if !compare.is_empty() {
+        let mut runs = vec![(label_for(&metrics_file), result.clone())];
+        for path in &compare {
+            let contents = tokio::fs::read_to_string(path).await?;
+            let other: ScenarioResult = serde_json::from_str(&contents)?;
+            runs.push((label_for(path), other));
+        }
+
+        let comparison = render_comparison(&runs, &format)?;
+        let comparison = if redact { redact_text(&comparison) } else { comparison };
+        println!("\n{}", comparison);
+    }
+
+    if let Some(baseline_file) = baseline {
+        check_baseline(&result, &baseline_file, regression_threshold).await?;
+    }
+
+    Ok(())
+}
+
+fn label_for(path: &Path) -> String {
+    path.file_stem().and_then(|s| s.to_str()).unwrap_or("run").to_string()
+}
+
+#[derive(Tabled)]
+struct RunSummaryRow {
+    #[tabled(rename = "Run")]
+    run: String,
+    #[tabled(rename = "Total Duration")]
+    total_duration: String,
+    #[tabled(rename = "Total Injections")]
+    total_injections: usize,
+    #[tabled(rename = "Success Rate")]
+    success_rate: String,
+    #[tabled(rename = "Avg Phase Duration")]
+    avg_phase_duration: String,
+}
+
+#[derive(Tabled)]
+struct PhaseRow {
+    #[tabled(rename = "Run")]
+    run: String,
+    #[tabled(rename = "Phase")]
+    phase: String,
+    #[tabled(rename = "Duration")]
+    duration: String,
+    #[tabled(rename = "Injections")]
+    injections: usize,
+}
+
+/// Builds per-run and per-phase comparison tables across `runs`, the first
+/// of which is the report's primary `metrics_file`. Latency deltas aren't
+/// included: like [`BASELINE_METRICS`], `ScenarioResult` doesn't carry
+/// per-run latency data yet, so average phase duration stands in as the
+/// closest available proxy.
+fn render_comparison(runs: &[(String, ScenarioResult)], format: &str) -> Result<String> {
+    let summary_rows: Vec<RunSummaryRow> = runs
+        .iter()
+        .map(|(label, result)| RunSummaryRow {
+            run: label.clone(),
+            total_duration: format!("{:?}", result.total_duration),
+            total_injections: result.total_injections,
+            success_rate: format!("{:.2}%", result.success_rate() * 100.0),
+            avg_phase_duration: format!("{:?}", result.average_phase_duration()),
+        })
+        .collect();
+
+    let phase_rows: Vec<PhaseRow> = runs
+        .iter()
+        .flat_map(|(label, result)| {
+            result.phase_results.iter().map(move |phase| PhaseRow {
+                run: label.clone(),
+                phase: phase.name.clone(),
+                duration: format!("{:?}", phase.duration),
+                injections: phase.injection_count,
+            })
+        })
+        .collect();
+
+    match format {
+        "markdown" => Ok(format!(
+            "## Run Comparison\n\n{}\n\n### Phase Breakdown\n\n{}",
+            Table::new(summary_rows).with(Style::markdown()),
+            Table::new(phase_rows).with(Style::markdown()),
+        )),
+        "html" => Ok(render_comparison_html(&summary_rows, &phase_rows)),
+        "json" => Ok(serde_json::to_string_pretty(&summary_rows_json(runs))?),
+        _ => Ok(format!(
+            "Run Comparison:\n{}\n\nPhase Breakdown:\n{}",
+            Table::new(summary_rows),
+            Table::new(phase_rows),
+        )),
+    }
+}
+
+fn summary_rows_json(runs: &[(String, ScenarioResult)]) -> serde_json::Value {
+    serde_json::json!(runs
+        .iter()
+        .map(|(label, result)| serde_json::json!({
+            "run": label,
+            "total_duration": result.total_duration,
+            "total_injections": result.total_injections,
+            "success_rate": result.success_rate(),
+            "avg_phase_duration": result.average_phase_duration(),
+        }))
+        .collect::<Vec<_>>())
+}
+
+fn render_comparison_html(summary_rows: &[RunSummaryRow], phase_rows: &[PhaseRow]) -> String {
+    let summary_body: String = summary_rows
+        .iter()
+        .map(|r| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                r.run, r.total_duration, r.total_injections, r.success_rate, r.avg_phase_duration
+            )
+        })
+        .collect();
+
+    let phase_body: String = phase_rows
+        .iter()
+        .map(|r| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                r.run, r.phase, r.duration, r.injections
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<h2>Run Comparison</h2>
+<table>
+<tr><th>Run</th><th>Total Duration</th><th>Total Injections</th><th>Success Rate</th><th>Avg Phase Duration</th></tr>
+{summary_body}
+</table>
+
+<h2>Phase Breakdown</h2>
+<table>
+<tr><th>Run</th><th>Phase</th><th>Duration</th><th>Injections</th></tr>
+{phase_body}
+</table>"#
+    )
+}
+
+/// Compares `result` against the run stored at `baseline_file`, printing a
+/// pass/fail verdict and returning an error (so the process exits non-zero)
+/// if any tracked metric regressed beyond `threshold` - the hook CI uses to
+/// gate a deploy on `chaos report --baseline`.
+async fn check_baseline(result: &ScenarioResult, baseline_file: &PathBuf, threshold: f64) -> Result<()> {
+    println!("\n{}", "=== Baseline Comparison ===".bold().cyan());
+    println!("Baseline: {}", baseline_file.display());
+
+    let contents = tokio::fs::read_to_string(baseline_file).await?;
+    let baseline: ScenarioResult = serde_json::from_str(&contents)?;
+
+    let alerts: Vec<_> = BASELINE_METRICS
+        .iter()
+        .filter_map(|metric| metric.compare(&baseline, result, threshold).map(|alert| (metric, alert)))
+        .collect();
+
+    if alerts.is_empty() {
+        println!("{} no regression beyond {:.0}% detected", "✓".green().bold(), threshold * 100.0);
+        return Ok(());
+    }
+
+    println!("{}", "✗ regression detected:".red().bold());
+    for (metric, alert) in &alerts {
+        println!(
+            "  {:?}: baseline {:.3} -> current {:.3} ({:+.1}%)",
+            metric,
+            alert.baseline,
+            alert.latest,
+            alert.deviation * 100.0
+        );
+    }
+
+    anyhow::bail!(
+        "{} metric(s) regressed beyond the {:.0}% threshold against baseline {}",
+        alerts.len(),
+        threshold * 100.0,
+        baseline_file.display()
+    );
+}
+
+fn print_cli_report(result: &chaos_scenarios::runner::ScenarioResult) {
+    println!("\n{}", "=== Scenario Report ===".bold().green());
+    println!("Scenario: {}", result.scenario_name.cyan());
+    println!("Total Duration: {:?}", result.total_duration);
+    println!("Total Injections: {}", result.total_injections);
+    println!("Success Rate: {:.2}%", result.success_rate() * 100.0);
+
+    println!("\n{}", "Phase Results:".bold());
+    for phase in &result.phase_results {
+        println!("  {} - Duration: {:?}, Injections: {}",
+            phase.name.yellow(),
+            phase.duration,
+            phase.injection_count
+        );
+    }
+}
+
+fn generate_markdown_report(result: &chaos_scenarios::runner::ScenarioResult) -> String {
+    format!(
+        r#"# Chaos Test Report: {}
+
+## Summary
+
+- **Total Duration**: {:?}
+- **Total Injections**: {}
+- **Success Rate**: {:.2}%
+
+## Phase Results
+
+{}
+
+## Conclusion
+
+Test completed successfully.
+"#,
+        result.scenario_name,
+        result.total_duration,
+        result.total_injections,
+        result.success_rate() * 100.0,
+        result
+            .phase_results
+            .iter()
+            .map(|p| format!("- **{}**: {:?} ({} injections)", p.name, p.duration, p.injection_count))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
@@ -1,9 +1,16 @@
 use anyhow::Result;
-use chaos_scenarios::{parse_scenario_from_file, ScenarioRunner};
+use chaos_core::{Executor, ExecutorEvent, SafetyPolicy, StateFile};
+use chaos_metrics::exporters::otlp::{OtlpConfig, OtlpExporter};
+use chaos_metrics::{HistoryStore, MetricsCollector, StreamingAggregator, Window};
+use chaos_scenarios::config::BASELINE_LABEL;
+use chaos_scenarios::ScenarioRunner;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::net::SocketAddr;
 use std::path::PathBuf;
-use tracing::info;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
 
 pub async fn execute(
     scenario_file: PathBuf,
@@ -12,12 +19,55 @@ pub async fn execute(
     output_markdown: Option<PathBuf>,
     prometheus_port: Option<u16>,
     seed: Option<u64>,
+    history_dir: PathBuf,
+    baseline: bool,
+    dry_run: bool,
+    policy_file: Option<PathBuf>,
+    otlp_endpoint: Option<String>,
+    slo_target: Option<f64>,
+    slo_window: Option<String>,
+    fail_on: Vec<String>,
+    output_junit: Option<PathBuf>,
+    workload_ws: Option<String>,
+    set: Vec<(String, String)>,
+    record_execution: Option<PathBuf>,
+    tui: bool,
 ) -> Result<()> {
     println!("{}", "=== Chaos Framework ===".bold().cyan());
+    if dry_run {
+        println!(
+            "{}",
+            "DRY RUN - no injections will actually be applied".bold().yellow()
+        );
+    }
     println!("Loading scenario: {}", scenario_file.display());
 
     // Parse scenario
-    let mut scenario = parse_scenario_from_file(&scenario_file).await?;
+    let vars: std::collections::HashMap<String, String> = set.into_iter().collect();
+
+    // A `matrix:` section expands the file into more than one combination -
+    // run those as a sweep instead of the single-scenario pipeline below,
+    // the same way `chaos suite` runs a battery of scenario files. A
+    // `builtin:<name>` argument names an embedded scenario (see
+    // `chaos_scenarios::builtin`) instead of a path on disk, and has no
+    // matrix of its own to expand.
+    let combinations = match scenario_file.to_str().and_then(|s| s.strip_prefix(chaos_scenarios::builtin::PREFIX)) {
+        Some(name) => {
+            let builtin = chaos_scenarios::builtin::find(name).ok_or_else(|| {
+                anyhow::anyhow!("Unknown built-in scenario '{}' - see `chaos scenarios list`", name)
+            })?;
+            vec![(std::collections::HashMap::new(), builtin.load(&vars)?)]
+        }
+        None => chaos_scenarios::load_scenario_matrix(&scenario_file, &vars).await?,
+    };
+    if combinations.len() > 1 {
+        return run_matrix_sweep(combinations, output_json).await;
+    }
+    let mut scenario = combinations
+        .into_iter()
+        .next()
+        .map(|(_, scenario)| scenario)
+        .ok_or_else(|| anyhow::anyhow!("Scenario file expanded to zero combinations"))?;
 
     // Override seed if provided
     if let Some(seed) = seed {
@@ -25,6 +75,11 @@ pub async fn execute(
         info!("Overriding scenario seed: {}", seed);
     }
 
+    if baseline {
+        scenario.labels.insert(BASELINE_LABEL.to_string(), "true".to_string());
+        info!("Recording this run as the baseline for '{}'", scenario.name);
+    }
+
     println!("\n{}", "Scenario Details:".bold());
     println!("  Name: {}", scenario.name.green());
     if let Some(desc) = &scenario.description {
@@ -36,39 +91,413 @@ pub async fn execute(
         println!("  Seed: {} (reproducible)", seed);
     }
 
-    // Create progress bar
-    let pb = ProgressBar::new(scenario.duration.as_secs());
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len}s ({msg})")
-            .unwrap()
-            .progress_chars("=>-"),
-    );
+    // `--tui` replaces this plain progress bar with the interactive
+    // `tui::run` console below, so there's nothing for `indicatif` to do.
+    let pb = (!tui).then(|| {
+        let pb = ProgressBar::new(scenario.duration.as_secs());
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len}s ({msg})")
+                .unwrap()
+                .progress_chars("=>-"),
+        );
+        pb
+    });
 
     println!("\n{}", "Starting chaos test...".bold().yellow());
 
-    // Run scenario
-    let runner = ScenarioRunner::with_defaults();
-    
-    // Spawn progress updater
-    let pb_clone = pb.clone();
-    let duration = scenario.duration;
+    // Run scenario. Active injections are mirrored to the state file as
+    // they're applied, so a crash mid-run still leaves `chaos recover`
+    // something to clean up from. A dry run never touches the host, so it
+    // has nothing worth persisting for `chaos recover` to find.
+    let mut executor = if dry_run {
+        Executor::dry_run(chaos_core::InjectorRegistry::with_defaults())
+    } else {
+        Executor::with_persistence(
+            chaos_core::InjectorRegistry::with_defaults(),
+            StateFile::default_path(),
+        )
+    };
+
+    if let Some(policy_file) = &policy_file {
+        info!("Loading safety policy: {}", policy_file.display());
+        executor = executor.with_policy(SafetyPolicy::load(policy_file).await?);
+    }
+
+    let cleanup_executor = executor.clone();
+    let cancel = CancellationToken::new();
+
+    // Subscribe before `executor` is moved into the runner below - events
+    // broadcast during the run would otherwise have no listener to reach.
+    // The collector and exporter(s) only exist when `--prometheus-port`/
+    // `--otlp-endpoint`/`--output-html`/`--workload-ws`/a scenario
+    // `workloads:` or `probes:` entry are set, so a run with no interest in metrics
+    // doesn't pay for any of them.
+    let metrics_collector = (prometheus_port.is_some()
+        || otlp_endpoint.is_some()
+        || output_html.is_some()
+        || workload_ws.is_some()
+        || !scenario.workloads.is_empty()
+        || !scenario.probes.is_empty())
+    .then(|| Arc::new(MetricsCollector::new()));
+    let otlp_exporter = otlp_endpoint
+        .as_ref()
+        .map(|endpoint| OtlpExporter::new(OtlpConfig::new(endpoint.clone(), format!("chaos-{}", scenario.name))))
+        .transpose()?
+        .map(Arc::new);
+
+    // Rolling 1s/10s/1m windows, kept alongside `metrics_collector`'s
+    // whole-run history - the live progress bar, the Prometheus endpoint,
+    // and the local abort check below all want "recent" rather than
+    // "everything so far". Only built when something actually reads it.
+    let local_abort_thresholds = scenario
+        .abort_conditions
+        .as_ref()
+        .map(|c| c.max_error_rate.is_some() || c.max_p99_latency.is_some())
+        .unwrap_or(false);
+    let streaming_aggregator = (prometheus_port.is_some() || local_abort_thresholds || tui)
+        .then(|| Arc::new(StreamingAggregator::new()));
+
+    if metrics_collector.is_some() || otlp_exporter.is_some() || streaming_aggregator.is_some() {
+        let mut events = executor.subscribe();
+        let feed_collector = metrics_collector.clone();
+        let feed_otlp = otlp_exporter.clone();
+        let feed_streaming = streaming_aggregator.clone();
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                match event {
+                    ExecutorEvent::InjectionApplied { handle, .. } => {
+                        if let Some(collector) = &feed_collector {
+                            collector.record_success().await;
+                        }
+                        if let Some(streaming) = &feed_streaming {
+                            streaming.record(chaos_metrics::Metric {
+                                metric_type: chaos_metrics::MetricType::Success,
+                                timestamp: chrono::Utc::now(),
+                                labels: Default::default(),
+                            }).await;
+                        }
+                        if let Some(otlp) = &feed_otlp {
+                            otlp.record_injection_applied(&handle);
+                        }
+                    }
+                    ExecutorEvent::InjectionRemoved { handle, at } => {
+                        if let Ok(latency) = (at - handle.started_at).to_std() {
+                            if let Some(collector) = &feed_collector {
+                                collector.record_latency(latency).await;
+                            }
+                            if let Some(streaming) = &feed_streaming {
+                                streaming.record(chaos_metrics::Metric {
+                                    metric_type: chaos_metrics::MetricType::Latency(latency),
+                                    timestamp: chrono::Utc::now(),
+                                    labels: Default::default(),
+                                }).await;
+                            }
+                        }
+                        if let Some(otlp) = &feed_otlp {
+                            otlp.record_injection_removed(&handle);
+                        }
+                    }
+                    ExecutorEvent::InjectionFailed { error, .. } => {
+                        if let Some(collector) = &feed_collector {
+                            collector.record_error(error.clone()).await;
+                        }
+                        if let Some(streaming) = &feed_streaming {
+                            streaming.record(chaos_metrics::Metric {
+                                metric_type: chaos_metrics::MetricType::Error { error_type: error },
+                                timestamp: chrono::Utc::now(),
+                                labels: Default::default(),
+                            }).await;
+                        }
+                    }
+                    ExecutorEvent::CleanupFailed { handle, error, .. } => {
+                        if let Some(collector) = &feed_collector {
+                            collector.record_error(error.clone()).await;
+                        }
+                        if let Some(otlp) = &feed_otlp {
+                            otlp.record_cleanup_failed(&handle, &error);
+                        }
+                    }
+                    ExecutorEvent::DriftDetected { .. } => {}
+                }
+            }
+        });
+    }
+
+    if let (Some(collector), Some(port)) = (metrics_collector.clone(), prometheus_port) {
+        let bind_addr = SocketAddr::from(([0, 0, 0, 0], port));
+        let serve_cancel = cancel.clone();
+        let serve_streaming = streaming_aggregator.clone();
+        tokio::spawn(async move {
+            if let Err(e) =
+                chaos_metrics::exporters::prometheus::serve(collector, serve_streaming, bind_addr, serve_cancel)
+                    .await
+            {
+                warn!("Prometheus exporter stopped: {}", e);
+            }
+        });
+    }
+
+    // Local counterpart to `ScenarioRunner`'s PromQL-backed abort checks:
+    // evaluates the same `max_error_rate`/`max_p99_latency` thresholds
+    // against this process's own rolling 1-minute window, so a scenario
+    // with no external metrics source configured still gets an abort
+    // check from the injections it just applied itself.
+    if let (Some(streaming), Some(conditions)) = (streaming_aggregator.clone(), scenario.abort_conditions.clone()) {
+        let abort_cancel = cancel.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = abort_cancel.cancelled() => break,
+                    _ = tokio::time::sleep(std::time::Duration::from_secs(1)) => {}
+                }
+
+                let windowed = streaming.aggregate(Window::OneMinute).await;
+                if let Some(max_error_rate) = conditions.max_error_rate {
+                    if windowed.total_requests > 0 && windowed.error_rate > max_error_rate {
+                        warn!(
+                            "Local error rate {:.1}% exceeds maximum {:.1}%, aborting scenario...",
+                            windowed.error_rate * 100.0,
+                            max_error_rate * 100.0
+                        );
+                        abort_cancel.cancel();
+                        break;
+                    }
+                }
+                if let Some(max_p99) = conditions.max_p99_latency {
+                    if windowed.latency_p99 > max_p99 {
+                        warn!(
+                            "Local p99 latency {:?} exceeds maximum {:?}, aborting scenario...",
+                            windowed.latency_p99, max_p99
+                        );
+                        abort_cancel.cancel();
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    // Drives real traffic for the run's duration, independent of the
+    // scenario's own injections, so a target's actual behavior under fault
+    // injection - not just whether the injections applied cleanly - shows
+    // up in this run's metrics. `--workload-ws` covers the common
+    // single-feed case from the CLI; `scenario.workloads` covers everything
+    // else (multiple drivers, or drivers like gRPC with more knobs than fit
+    // comfortably as flags). Both share one stop signal, sent once the
+    // scenario returns, successfully or not.
+    let (workload_stop_tx, workload_stop_rx) = tokio::sync::watch::channel(false);
+    let mut workload_handles = Vec::new();
+    if let Some(url) = &workload_ws {
+        println!("Driving workload feed: {}", url);
+        let driver = chaos_metrics::WebSocketWorkloadDriver::new(url.clone());
+        let collector = metrics_collector
+            .clone()
+            .expect("metrics_collector is always Some when workload_ws is set");
+        let stop_rx = workload_stop_rx.clone();
+        workload_handles.push(tokio::spawn(async move { driver.run(collector, stop_rx).await }));
+    }
+    for workload in &scenario.workloads {
+        let collector = metrics_collector
+            .clone()
+            .expect("metrics_collector is always Some when scenario.workloads is non-empty");
+        let endpoint = workload
+            .parameters
+            .get("url")
+            .or_else(|| workload.parameters.get("address"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("?");
+        println!("Driving configured workload: {} ({})", workload.r#type, endpoint);
+        workload_handles.push(spawn_configured_workload(workload, collector, workload_stop_rx.clone())?);
+    }
+
+    // Runs each scenario-defined probe on its own interval, recording its
+    // result as a custom metric and aborting the scenario if it crosses
+    // `abort_below`/`abort_above` - the same "local check fed by this
+    // run's own signal, no external metrics system required" shape as the
+    // `local_abort_thresholds` check above, just fed by a script instead
+    // of `StreamingAggregator`. Stopped by the same signal as the workload
+    // drivers.
+    for probe in scenario.probes.clone() {
+        let collector = metrics_collector
+            .clone()
+            .expect("metrics_collector is always Some when scenario.probes is non-empty");
+        let probe_cancel = cancel.clone();
+        let mut probe_stop = workload_stop_rx.clone();
+        println!("Running probe: {} ({})", probe.name, probe.command);
+        workload_handles.push(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(probe.interval);
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = probe_stop.changed() => {
+                        if *probe_stop.borrow() {
+                            return;
+                        }
+                    }
+                    _ = ticker.tick() => {
+                        run_probe_once(&probe, &collector, &probe_cancel).await;
+                    }
+                }
+            }
+        }));
+    }
+
+    let runner = ScenarioRunner::new(executor);
+
+    // Ctrl-C and SIGTERM should interrupt the scenario within about a
+    // second rather than waiting for the current phase to finish, so an
+    // operator killing `chaos run` doesn't need to separately remember to
+    // clean up whatever was active.
+    let signal_cancel = cancel.clone();
     tokio::spawn(async move {
-        let start = tokio::time::Instant::now();
-        loop {
-            let elapsed = start.elapsed();
-            if elapsed >= duration {
-                break;
+        #[cfg(unix)]
+        {
+            let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("failed to install SIGTERM handler");
+            tokio::select! {
+                result = tokio::signal::ctrl_c() => {
+                    if result.is_ok() {
+                        warn!("Received interrupt (SIGINT), aborting scenario...");
+                    }
+                }
+                _ = sigterm.recv() => {
+                    warn!("Received SIGTERM, aborting scenario...");
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                warn!("Received interrupt, aborting scenario...");
             }
-            pb_clone.set_position(elapsed.as_secs());
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
         }
-        pb_clone.finish_with_message("Complete");
+        signal_cancel.cancel();
     });
 
-    let result = runner.run(&scenario).await?;
+    // SIGUSR1/SIGUSR2 pause/resume the scenario without aborting it, so an
+    // operator investigating an unexpected production signal can freeze
+    // the experiment rather than having to kill and re-run it from
+    // scratch. Lifts active injections while paused, since the point of
+    // pausing is usually to ask "is this still happening without the
+    // fault?" Unix-only: there's no equivalent signal on Windows.
+    #[cfg(unix)]
+    {
+        let pause_runner = runner.clone();
+        tokio::spawn(async move {
+            let mut sigusr1 = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined1())
+                .expect("failed to install SIGUSR1 handler");
+            let mut sigusr2 = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined2())
+                .expect("failed to install SIGUSR2 handler");
+            loop {
+                tokio::select! {
+                    _ = sigusr1.recv() => {
+                        warn!("Received SIGUSR1, pausing scenario (send SIGUSR2 to resume)...");
+                        pause_runner.pause(true);
+                    }
+                    _ = sigusr2.recv() => {
+                        warn!("Received SIGUSR2, resuming scenario...");
+                        pause_runner.resume();
+                    }
+                }
+            }
+        });
+    }
+
+    // Spawn the progress updater - the plain bar, or the `--tui` console in
+    // its place. `render_cancel` is separate from the scenario's own
+    // `cancel` so stopping the renderer once the run completes normally
+    // doesn't also trip the post-run "was this aborted?" cleanup check
+    // below, which reads `cancel.is_cancelled()`.
+    let render_cancel = CancellationToken::new();
+    let tui_handle = if tui {
+        let tui_scenario = scenario.clone();
+        let tui_runner = runner.clone();
+        let tui_executor = cleanup_executor.clone();
+        let tui_streaming = streaming_aggregator.clone();
+        let tui_cancel = cancel.clone();
+        let tui_render_cancel = render_cancel.clone();
+        Some(tokio::spawn(async move {
+            let stop = tui_cancel.child_token();
+            tokio::spawn({
+                let stop = stop.clone();
+                async move {
+                    tui_render_cancel.cancelled().await;
+                    stop.cancel();
+                }
+            });
+            if let Err(e) = crate::tui::run(&tui_scenario, tui_runner, tui_executor, tui_streaming, slo_target, stop).await {
+                warn!("TUI console exited with an error: {}", e);
+            }
+        }))
+    } else {
+        let pb_clone = pb.clone().expect("pb is always Some when --tui is not set");
+        let duration = scenario.duration;
+        let progress_cancel = cancel.clone();
+        let progress_render_cancel = render_cancel.clone();
+        let progress_streaming = streaming_aggregator.clone();
+        Some(tokio::spawn(async move {
+            let start = tokio::time::Instant::now();
+            loop {
+                let elapsed = start.elapsed();
+                if elapsed >= duration || progress_cancel.is_cancelled() || progress_render_cancel.is_cancelled() {
+                    break;
+                }
+                pb_clone.set_position(elapsed.as_secs());
+                if let Some(streaming) = &progress_streaming {
+                    let last_second = streaming.aggregate(Window::OneSecond).await;
+                    pb_clone.set_message(format!(
+                        "p50 {:?}, err {:.1}%",
+                        last_second.latency_p50,
+                        last_second.error_rate * 100.0
+                    ));
+                }
+                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            }
+            pb_clone.finish_with_message("Complete");
+        }))
+    };
+
+    let run_outcome = runner.run_with_cancellation(&scenario, cancel.clone()).await;
+
+    let _ = workload_stop_tx.send(true);
+    for handle in workload_handles {
+        let _ = handle.await;
+    }
+
+    let result = run_outcome?;
 
-    pb.finish_and_clear();
+    // Stop whichever renderer is running and wait for it to hand the
+    // terminal back - the `--tui` console owns the alternate screen and
+    // must leave it before anything below writes to stdout.
+    render_cancel.cancel();
+    if let Some(handle) = tui_handle {
+        let _ = handle.await;
+    }
+
+    if let Some(pb) = &pb {
+        pb.finish_and_clear();
+    }
+
+    // The runner already tears down injections from the phase it was
+    // interrupted in, but sweep the executor once more so an operator
+    // killing `chaos run` can see, explicitly, that nothing was left
+    // behind - rather than trusting that the phase-level cleanup covered
+    // everything.
+    if cancel.is_cancelled() {
+        let leftover = cleanup_executor.list_active().await.len();
+        match cleanup_executor.remove_all().await {
+            Ok(()) if leftover > 0 => {
+                println!(
+                    "\n{}",
+                    format!("✓ Cleaned up {} injection(s) left active after abort", leftover)
+                        .yellow()
+                );
+            }
+            Ok(()) => {}
+            Err(e) => warn!("Failed to remove all injections during shutdown cleanup: {}", e),
+        }
+    }
 
     // Display results
     println!("\n{}", "=== Test Results ===".bold().green());
@@ -86,6 +515,74 @@ pub async fn execute(
         );
     }
 
+    // Only computed when both --slo-target and --slo-window are set (clap
+    // enforces they come together). Uses the same success-rate proxy as
+    // the rest of this summary, since `ScenarioResult` doesn't carry a
+    // per-run error rate of its own.
+    let error_budget_report = if let (Some(target), Some(window_str)) = (slo_target, &slo_window) {
+        let window = humantime::parse_duration(window_str)?;
+        let budget = chaos_metrics::ErrorBudget::new(target, window);
+        let report = budget.evaluate(1.0 - result.success_rate(), result.total_duration);
+        println!("\n{}", "Error Budget:".bold());
+        println!("  {}", report.summary());
+        Some(report)
+    } else {
+        None
+    };
+
+    // A dry run is not a real outcome, so it's never recorded to history
+    // and never compared against (or recorded as) a baseline.
+    if dry_run {
+        println!(
+            "\n{}",
+            "DRY RUN - no injections were actually applied".bold().yellow()
+        );
+    } else {
+        // Record this run in history so it can feed `history trend` and
+        // later `--baseline` comparisons.
+        let history = HistoryStore::new(&history_dir);
+        match history.record(&result).await {
+            Ok(path) => info!("Recorded run history to {}", path.display()),
+            Err(e) => warn!("Failed to record run history: {}", e),
+        }
+
+        if let Some(record_path) = &record_execution {
+            let record = chaos_scenarios::ExecutionRecord::new(scenario.clone(), result.clone());
+            match record.save(record_path).await {
+                Ok(()) => info!("Recorded execution artifact to {}", record_path.display()),
+                Err(e) => warn!("Failed to record execution artifact: {}", e),
+            }
+        }
+
+        if baseline {
+            println!(
+                "\n{}",
+                format!("✓ Recorded as the baseline for '{}'", result.scenario_name).bold().cyan()
+            );
+        } else {
+            match history.load_baseline(&result.scenario_name).await {
+                Ok(Some(baseline_entry)) => {
+                    let baseline_rate = baseline_entry.result.success_rate();
+                    let current_rate = result.success_rate();
+                    println!("\n{}", "Baseline Comparison:".bold());
+                    println!(
+                        "  Success Rate: {:.2}% (baseline: {:.2}%)",
+                        current_rate * 100.0,
+                        baseline_rate * 100.0
+                    );
+                    println!(
+                        "  Total Duration: {:?} (baseline: {:?})",
+                        result.total_duration, baseline_entry.result.total_duration
+                    );
+                }
+                Ok(None) => {
+                    info!("No recorded baseline for '{}' yet", result.scenario_name);
+                }
+                Err(e) => warn!("Failed to load baseline: {}", e),
+            }
+        }
+    }
+
     // Save outputs
     if let Some(json_path) = output_json {
         println!("\nSaving JSON report to: {}", json_path.display());
@@ -95,7 +592,11 @@ pub async fn execute(
 
     if let Some(html_path) = output_html {
         println!("Generating HTML report to: {}", html_path.display());
-        // HTML generation would be implemented here
+        let samples = match &metrics_collector {
+            Some(collector) => collector.get_metrics().await,
+            None => Vec::new(),
+        };
+        chaos_metrics::exporters::html::HtmlExporter::export(&samples, &result.phase_results, &html_path).await?;
     }
 
     if let Some(md_path) = output_markdown {
@@ -103,11 +604,258 @@ pub async fn execute(
         // Markdown generation would be implemented here
     }
 
+    if let Some(junit_path) = output_junit {
+        println!("Writing JUnit XML report to: {}", junit_path.display());
+        chaos_metrics::exporters::junit::JunitExporter::export(&result, &junit_path).await?;
+    }
+
     if let Some(port) = prometheus_port {
-        println!("Prometheus metrics would be available on port: {}", port);
+        println!(
+            "\nPrometheus metrics were served on port {} for the duration of this run.",
+            port
+        );
+    }
+
+    if let Some(otlp) = &otlp_exporter {
+        if let Some(collector) = &metrics_collector {
+            otlp.export_metrics(&collector.summary().await);
+        }
+        if let Err(e) = otlp.shutdown() {
+            warn!("Failed to flush OTLP exporter: {}", e);
+        } else {
+            println!(
+                "\nPushed run metrics and injection spans to OTLP collector at {}",
+                otlp_endpoint.as_deref().unwrap_or_default()
+            );
+        }
+    }
+
+    let gate_failures = evaluate_fail_on(&fail_on, &result, error_budget_report.as_ref())?;
+    if !gate_failures.is_empty() {
+        println!("\n{}", "✗ CI gate failed:".bold().red());
+        for reason in &gate_failures {
+            println!("  {}", reason);
+        }
+        anyhow::bail!(
+            "{} --fail-on condition(s) triggered: {}",
+            gate_failures.len(),
+            gate_failures.join("; ")
+        );
     }
 
     println!("\n{}", "✓ Chaos test completed successfully!".bold().green());
 
     Ok(())
 }
+
+/// Checks `result` against each `--fail-on` policy, returning one message
+/// per policy that was triggered (empty if the run should be treated as a
+/// pass). Kept separate from the printing/exit-code plumbing in `execute`
+/// so the gating logic itself can fail loudly on an unknown policy name.
+fn evaluate_fail_on(
+    fail_on: &[String],
+    result: &chaos_scenarios::runner::ScenarioResult,
+    error_budget_report: Option<&chaos_metrics::ErrorBudgetReport>,
+) -> Result<Vec<String>> {
+    let mut failures = Vec::new();
+
+    for policy in fail_on {
+        match policy.as_str() {
+            "slo-violation" => match error_budget_report {
+                Some(report) if report.burn_rate > 1.0 => failures.push(format!(
+                    "slo-violation: burn rate {:.2}x exceeds the sustainable rate",
+                    report.burn_rate
+                )),
+                Some(_) => {}
+                None => {
+                    warn!("--fail-on slo-violation has no effect without --slo-target and --slo-window");
+                }
+            },
+            "any-injection-failure" => {
+                if result.failed_injections > 0 {
+                    failures.push(format!(
+                        "any-injection-failure: {} injection(s) failed to apply",
+                        result.failed_injections
+                    ));
+                }
+            }
+            "abort" => {
+                if let Some(reason) = &result.aborted_reason {
+                    failures.push(format!("abort: {}", reason));
+                }
+            }
+            other => anyhow::bail!("Unknown --fail-on policy: {}", other),
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Runs one [`chaos_scenarios::config::ProbeConfig`] to completion,
+/// recording its result as a custom metric named after the probe and
+/// cancelling `cancel` if the parsed value crosses `abort_below`/
+/// `abort_above`.
+async fn run_probe_once(
+    probe: &chaos_scenarios::config::ProbeConfig,
+    collector: &MetricsCollector,
+    cancel: &CancellationToken,
+) {
+    use chaos_scenarios::config::ProbeParse;
+
+    let output = tokio::process::Command::new(&probe.command).args(&probe.args).output().await;
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            warn!("Probe '{}' failed to run: {}", probe.name, e);
+            collector.record_error(format!("probe_{}_exec_error", probe.name)).await;
+            return;
+        }
+    };
+
+    if !output.status.success() {
+        collector.record_error(format!("probe_{}_failed", probe.name)).await;
+    }
+
+    match probe.parse {
+        ProbeParse::ExitCode => {
+            collector.record_custom(probe.name.clone(), if output.status.success() { 1.0 } else { 0.0 }).await;
+        }
+        ProbeParse::StdoutNumeric => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            match stdout.trim().parse::<f64>() {
+                Ok(value) => {
+                    collector.record_custom(probe.name.clone(), value).await;
+                    if probe.abort_below.is_some_and(|min| value < min) || probe.abort_above.is_some_and(|max| value > max) {
+                        warn!("Probe '{}' value {} crossed its abort threshold, aborting scenario...", probe.name, value);
+                        cancel.cancel();
+                    }
+                }
+                Err(e) => {
+                    warn!("Probe '{}' stdout '{}' is not numeric: {}", probe.name, stdout.trim(), e);
+                    collector.record_error(format!("probe_{}_parse_error", probe.name)).await;
+                }
+            }
+        }
+    }
+}
+
+/// Builds and spawns the driver named by `workload.r#type`, translating its
+/// untyped `parameters` map into the concrete driver's config. Mirrors the
+/// `match ... { other => anyhow::bail!(...) }` shape used for CLI enum-like
+/// flags elsewhere in this crate - `WorkloadConfig::r#type` is no
+/// different, just sourced from scenario YAML instead of an `--flag`.
+fn spawn_configured_workload(
+    workload: &chaos_scenarios::config::WorkloadConfig,
+    collector: Arc<MetricsCollector>,
+    stop: tokio::sync::watch::Receiver<bool>,
+) -> Result<tokio::task::JoinHandle<()>> {
+    let param_str = |name: &str| -> Result<String> {
+        workload
+            .parameters
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("workload '{}' is missing required parameter '{}'", workload.r#type, name))
+    };
+    let param_duration = |name: &str, default: std::time::Duration| -> Result<std::time::Duration> {
+        match workload.parameters.get(name).and_then(|v| v.as_str()) {
+            Some(raw) => humantime::parse_duration(raw)
+                .map_err(|e| anyhow::anyhow!("workload '{}' has invalid '{}': {}", workload.r#type, name, e)),
+            None => Ok(default),
+        }
+    };
+
+    match workload.r#type.as_str() {
+        "websocket" => {
+            let url = param_str("url")?;
+            let driver = chaos_metrics::WebSocketWorkloadDriver::new(url)
+                .reconnect_backoff(param_duration("reconnect_backoff", std::time::Duration::from_secs(1))?)
+                .staleness_interval(param_duration("staleness_interval", std::time::Duration::from_secs(1))?);
+            Ok(tokio::spawn(async move { driver.run(collector, stop).await }))
+        }
+        "tcp" => {
+            let addr = param_str("address")?;
+            let mut driver = chaos_metrics::TcpWorkloadDriver::new(addr)
+                .interval(param_duration("interval", std::time::Duration::from_secs(1))?)
+                .reconnect_backoff(param_duration("reconnect_backoff", std::time::Duration::from_secs(1))?);
+            if let Some(payload) = workload.parameters.get("payload").and_then(|v| v.as_str()) {
+                driver = driver.payload(payload.as_bytes().to_vec());
+            }
+            Ok(tokio::spawn(async move { driver.run(collector, stop).await }))
+        }
+        "grpc" => {
+            let url = param_str("url")?;
+            let path = param_str("method")?;
+            let mode = match workload.parameters.get("mode").and_then(|v| v.as_str()) {
+                Some("server-streaming") => chaos_metrics::RpcMode::ServerStreaming,
+                Some("unary") | None => chaos_metrics::RpcMode::Unary,
+                Some(other) => anyhow::bail!("workload 'grpc' has invalid 'mode': {}", other),
+            };
+            let mut driver = chaos_metrics::GrpcWorkloadDriver::new(url, path)
+                .mode(mode)
+                .interval(param_duration("interval", std::time::Duration::from_secs(1))?);
+            if let Some(payload) = workload.parameters.get("payload").and_then(|v| v.as_str()) {
+                driver = driver.payload(payload.as_bytes().to_vec());
+            }
+            Ok(tokio::spawn(async move { driver.run(collector, stop).await }))
+        }
+        other => anyhow::bail!("Unknown workload type: {}", other),
+    }
+}
+
+/// Runs a `matrix:` expansion's combinations in order and prints one
+/// combined verdict, mirroring `chaos suite`'s output - each combination is
+/// a full scenario run, but the surrounding metrics/export/history
+/// machinery `execute` layers on for a single run is out of scope here,
+/// the same way it's out of scope for `chaos suite`.
+async fn run_matrix_sweep(
+    combinations: Vec<(std::collections::HashMap<String, String>, chaos_scenarios::config::Scenario)>,
+    output_json: Option<PathBuf>,
+) -> Result<()> {
+    println!(
+        "\n{}",
+        format!("=== Matrix Sweep: {} combinations ===", combinations.len()).bold().cyan()
+    );
+
+    let result = chaos_scenarios::suite::run_matrix(combinations).await;
+
+    println!("\n{}", "=== Sweep Results ===".bold().green());
+    for entry in &result.entries {
+        let status = if entry.passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        };
+
+        match &entry.result {
+            Ok(scenario_result) => println!(
+                "  [{}] {} - success rate {:.2}%",
+                status,
+                entry.name,
+                scenario_result.success_rate() * 100.0
+            ),
+            Err(e) => println!("  [{}] {} - {}", status, entry.name, e),
+        }
+    }
+
+    println!(
+        "\nOverall: {}",
+        if result.passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        }
+    );
+
+    if let Some(json_path) = output_json {
+        println!("\nSaving JSON report to: {}", json_path.display());
+        let json = serde_json::to_string_pretty(&result)?;
+        tokio::fs::write(&json_path, json).await?;
+    }
+
+    if !result.passed {
+        anyhow::bail!("Matrix sweep did not pass");
+    }
+
+    Ok(())
+}
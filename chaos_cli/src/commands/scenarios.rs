@@ -0,0 +1,22 @@
+use anyhow::Result;
+use chaos_scenarios::builtin::{BUILTIN_SCENARIOS, PREFIX};
+use colored::Colorize;
+
+/// Lists every scenario embedded in this binary, so a team can start chaos
+/// testing with `chaos run builtin:<name> --set target=...` before writing
+/// any YAML.
+pub async fn list() -> Result<()> {
+    println!("{}", "=== Built-in Scenarios ===".bold().cyan());
+
+    for scenario in BUILTIN_SCENARIOS {
+        println!("\n  {}{}", PREFIX, scenario.name.green().bold());
+        println!("    {}", scenario.summary);
+    }
+
+    println!(
+        "\nRun one with: {}",
+        "chaos run builtin:<name> --set target=<pattern>".dimmed()
+    );
+
+    Ok(())
+}
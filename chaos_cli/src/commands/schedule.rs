@@ -0,0 +1,157 @@
+use anyhow::Result;
+use chaos_metrics::HistoryStore;
+use chaos_scenarios::runner::ScenarioRunner;
+use chaos_scenarios::schedule::{ScheduleEntry, ScheduleFile};
+use colored::Colorize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Runs every entry in `schedule_file` forever, firing each one whenever its
+/// cron expression comes due and recording the result to `history_dir` via
+/// the same [`HistoryStore`] `chaos run --history-dir` writes to - so
+/// `chaos history` and `--baseline` comparisons see scheduled runs exactly
+/// like manual ones.
+///
+/// There's no separate daemon process or control API yet (see
+/// `commands::monkey`'s docs for the same caveat) - this command *is* the
+/// daemon, run in the foreground until interrupted.
+pub async fn execute(schedule_file: PathBuf, history_dir: PathBuf) -> Result<()> {
+    println!("{}", "=== Chaos Schedule ===".bold().cyan());
+    println!("Loading schedule: {}", schedule_file.display());
+
+    let file = ScheduleFile::load(&schedule_file).await?;
+    let base_dir = schedule_file
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    let mut entries = Vec::new();
+    for entry in file.schedules {
+        let name = entry.name.clone();
+        match entry.parsed_cron() {
+            Ok(cron) => entries.push((entry, cron)),
+            Err(e) => warn!("Skipping schedule '{}': {}", name, e),
+        }
+    }
+
+    println!("\n{}", "Schedule Details:".bold());
+    println!("  Name: {}", file.name.green());
+    for (entry, _) in &entries {
+        println!(
+            "  - {} [{}] cron: {}",
+            entry.name,
+            if entry.enabled { "enabled".green() } else { "disabled".dimmed() },
+            entry.cron
+        );
+    }
+
+    if entries.is_empty() {
+        println!("\nNo valid schedules to run.");
+        return Ok(());
+    }
+
+    // Guards against firing the same entry again while its previous run is
+    // still in flight - a scenario whose duration exceeds its own cron
+    // interval would otherwise stack up overlapping runs against the same
+    // target.
+    let running: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+
+    let cancel = CancellationToken::new();
+    let signal_cancel = cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            signal_cancel.cancel();
+        }
+    });
+
+    println!("\n{}", "Watching for due schedules (Ctrl+C to stop)...".bold().yellow());
+
+    let mut last_checked = chrono::Utc::now();
+    while !cancel.is_cancelled() {
+        let now = chrono::Utc::now();
+
+        for (entry, cron) in &entries {
+            if !entry.enabled {
+                continue;
+            }
+
+            let due = cron
+                .after(&last_checked)
+                .next()
+                .is_some_and(|next_fire| next_fire <= now);
+            if !due {
+                continue;
+            }
+
+            let already_running = {
+                let mut guard = running.lock().expect("running set lock poisoned");
+                !guard.insert(entry.name.clone())
+            };
+            if already_running {
+                warn!(
+                    "Skipping '{}': previous run still in progress (overlap prevention)",
+                    entry.name
+                );
+                continue;
+            }
+
+            info!("Firing schedule '{}'", entry.name);
+            println!("\n{} {}", "Firing:".bold().cyan(), entry.name);
+
+            let entry = entry.clone();
+            let base_dir = base_dir.clone();
+            let history_dir = history_dir.clone();
+            let running = running.clone();
+            tokio::spawn(async move {
+                run_entry(&entry, &base_dir, &history_dir).await;
+                running.lock().expect("running set lock poisoned").remove(&entry.name);
+            });
+        }
+
+        last_checked = now;
+        tokio::select! {
+            _ = tokio::time::sleep(std::time::Duration::from_secs(1)) => {}
+            _ = cancel.cancelled() => break,
+        }
+    }
+
+    println!("\n{}", "Schedule stopped.".bold());
+    Ok(())
+}
+
+/// Loads and runs a single [`ScheduleEntry`]'s scenario, then records the
+/// result to `history_dir` - logged and swallowed on failure at every step
+/// so one bad entry never brings down the rest of the schedule.
+async fn run_entry(entry: &ScheduleEntry, base_dir: &std::path::Path, history_dir: &std::path::Path) {
+    let scenario = match entry.load_scenario(base_dir).await {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            warn!("Schedule '{}' failed to load its scenario: {}", entry.name, e);
+            return;
+        }
+    };
+
+    let result = match ScenarioRunner::with_defaults().run(&scenario).await {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("Schedule '{}' failed to run: {}", entry.name, e);
+            return;
+        }
+    };
+
+    println!(
+        "{} '{}' completed: success rate {:.2}%",
+        "Schedule".bold(),
+        entry.name,
+        result.success_rate() * 100.0
+    );
+
+    let history = HistoryStore::new(history_dir);
+    match history.record(&result).await {
+        Ok(path) => info!("Recorded scheduled run history to {}", path.display()),
+        Err(e) => warn!("Failed to record scheduled run history for '{}': {}", entry.name, e),
+    }
+}
@@ -0,0 +1,197 @@
+use anyhow::{anyhow, Result};
+use chaos_core::{Executor, Injector, ProcessKillInjector, Signal, Target};
+use colored::Colorize;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::process::{Child, Command};
+
+/// Matches the fixed listen address baked into `chaos_targets::bin::tcp_echo_server`.
+const ECHO_SERVER_ADDR: &str = "127.0.0.1:9000";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const STARTUP_GRACE: Duration = Duration::from_millis(500);
+
+/// Spin up the bundled `tcp_echo_server`, exercise a couple of real
+/// injectors against it, and report whether the faults were actually
+/// observable. This is the fastest way for a user to confirm that their
+/// host and process permissions support real injection before trusting a
+/// full scenario run.
+pub async fn execute() -> Result<()> {
+    println!("{}", "=== Chaos Self-Test ===".bold().cyan());
+
+    let mut checks = Vec::new();
+
+    println!("\nStarting bundled tcp_echo_server...");
+    let binary = locate_tcp_echo_server()?;
+    let mut server = spawn_echo_server(&binary).await?;
+    let pid = server
+        .id()
+        .ok_or_else(|| anyhow!("echo server exited before it could be probed"))?;
+    println!("  Spawned PID {} ({})", pid, binary.display());
+
+    tokio::time::sleep(STARTUP_GRACE).await;
+
+    let addr: SocketAddr = ECHO_SERVER_ADDR.parse()?;
+    let executor = Executor::with_defaults();
+
+    let baseline = run_check("Connectivity", checks.len(), || async {
+        probe_echo(addr).await.map(|rtt| {
+            format!("echo round-trip {:?}", rtt)
+        })
+    })
+    .await;
+    checks.push(baseline);
+
+    let latency_check = run_check("Network latency injection", checks.len(), || async {
+        match executor.inject("network_latency", &Target::network(addr)).await {
+            Ok(handle) => {
+                let rtt = probe_echo(addr).await?;
+                executor.remove(handle).await.ok();
+                Ok(format!("injection applied; round-trip under fault {:?}", rtt))
+            }
+            Err(e) => Err(anyhow!(
+                "could not apply network_latency (likely missing NET_ADMIN / tc): {}",
+                e
+            )),
+        }
+    })
+    .await;
+    checks.push(latency_check);
+
+    let kill_check = run_check("Process kill injection", checks.len(), || async {
+        let target = Target::process(pid);
+        let injector = ProcessKillInjector::builder().signal(Signal::SIGKILL).build();
+        let handle = injector.inject(&target).await?;
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        if target.exists().await {
+            Err(anyhow!("process {} still running after SIGKILL", pid))
+        } else {
+            // The injector's own handle is never registered with `executor`
+            // here, so there's nothing left for it to clean up.
+            drop(handle);
+            Ok(format!("process {} no longer running", pid))
+        }
+    })
+    .await;
+    let killed = kill_check.passed;
+    checks.push(kill_check);
+
+    if !killed {
+        println!("\nCleaning up echo server...");
+        let _ = server.kill().await;
+    }
+    let _ = server.wait().await;
+
+    println!("\n{}", "=== Summary ===".bold());
+    let passed = checks.iter().filter(|c| c.passed).count();
+    for check in &checks {
+        let marker = if check.passed {
+            "✓".green().bold()
+        } else {
+            "✗".red().bold()
+        };
+        println!("  {} {}: {}", marker, check.name, check.detail);
+    }
+    println!("\n{}/{} checks passed", passed, checks.len());
+
+    if passed == checks.len() {
+        println!("{}", "Host supports real fault injection.".green().bold());
+        Ok(())
+    } else {
+        println!(
+            "{}",
+            "Some checks failed — review the permissions/capabilities above.".yellow().bold()
+        );
+        Err(anyhow!("{} of {} self-test checks failed", checks.len() - passed, checks.len()))
+    }
+}
+
+struct CheckResult {
+    name: &'static str,
+    passed: bool,
+    detail: String,
+}
+
+async fn run_check<F, Fut>(name: &'static str, index: usize, f: F) -> CheckResult
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<String>>,
+{
+    println!("\n[{}] {}...", index + 1, name);
+    match f().await {
+        Ok(detail) => {
+            println!("  {} {}", "✓".green(), detail);
+            CheckResult {
+                name,
+                passed: true,
+                detail,
+            }
+        }
+        Err(e) => {
+            println!("  {} {}", "✗".red(), e);
+            CheckResult {
+                name,
+                passed: false,
+                detail: e.to_string(),
+            }
+        }
+    }
+}
+
+async fn probe_echo(addr: SocketAddr) -> Result<Duration> {
+    let start = tokio::time::Instant::now();
+    let mut stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(addr)).await??;
+
+    let mut welcome = [0u8; 128];
+    tokio::time::timeout(CONNECT_TIMEOUT, stream.read(&mut welcome)).await??;
+
+    let payload = b"chaos-self-test\n";
+    stream.write_all(payload).await?;
+
+    let mut echoed = vec![0u8; payload.len()];
+    tokio::time::timeout(CONNECT_TIMEOUT, stream.read_exact(&mut echoed)).await??;
+
+    if echoed.as_slice() != &payload[..] {
+        return Err(anyhow!("echo server returned mismatched payload"));
+    }
+
+    Ok(start.elapsed())
+}
+
+/// The self-test relies on the `tcp_echo_server` binary built alongside the
+/// `chaos` CLI in the same workspace target directory, rather than shelling
+/// out to `cargo run`, so it works from an installed/released build too.
+fn locate_tcp_echo_server() -> Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    let dir = exe
+        .parent()
+        .ok_or_else(|| anyhow!("could not determine directory of the running chaos binary"))?;
+    let name = if cfg!(windows) {
+        "tcp_echo_server.exe"
+    } else {
+        "tcp_echo_server"
+    };
+    let candidate = dir.join(name);
+
+    if candidate.exists() {
+        Ok(candidate)
+    } else {
+        Err(anyhow!(
+            "bundled tcp_echo_server not found at {}; build the workspace with `cargo build --workspace` first",
+            candidate.display()
+        ))
+    }
+}
+
+async fn spawn_echo_server(binary: &Path) -> Result<Child> {
+    Command::new(binary)
+        .kill_on_drop(true)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow!("failed to spawn tcp_echo_server: {}", e))
+}
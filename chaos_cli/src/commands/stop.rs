@@ -0,0 +1,61 @@
+use anyhow::Result;
+use chaos_core::{Executor, InjectionHandle, StateFile};
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Removes an injection identified either by an exported handle file, or by
+/// the ID `chaos attach` printed and `chaos active` lists, looked up in
+/// `state_file`. Either way this process doesn't need to be the one that
+/// applied the injection - the handle alone is enough to `adopt` it and
+/// tear it down.
+pub async fn execute(
+    injection_id: Option<String>,
+    handle_file: Option<PathBuf>,
+    state_file: PathBuf,
+) -> Result<()> {
+    println!("{}", "=== Chaos Stop ===".bold().cyan());
+
+    let handle = if let Some(handle_file) = &handle_file {
+        println!("Handle file: {}", handle_file.display());
+        let contents = tokio::fs::read_to_string(handle_file).await?;
+        serde_json::from_str::<InjectionHandle>(&contents)?
+    } else if let Some(injection_id) = &injection_id {
+        println!("Injection ID: {}", injection_id);
+        let state = StateFile::load(&state_file).await?;
+        state.injections.get(injection_id).cloned().ok_or_else(|| {
+            anyhow::anyhow!(
+                "no active injection with ID '{}' in {}",
+                injection_id,
+                state_file.display()
+            )
+        })?
+    } else {
+        anyhow::bail!("must specify either an injection ID or --handle");
+    };
+
+    println!(
+        "\nRemoving {} ({}) on {}...",
+        handle.injector_name,
+        handle.id,
+        handle.target.description()
+    );
+
+    let executor = Executor::with_defaults();
+    executor.adopt(handle.clone()).await?;
+    executor.remove(handle.clone()).await?;
+
+    println!("{}", "✓ Injection removed".green().bold());
+
+    if let Some(handle_file) = &handle_file {
+        tokio::fs::remove_file(handle_file).await.ok();
+    } else {
+        // Only drop the record once removal actually succeeded - leaving it
+        // behind on failure means a retried `chaos stop` (or `chaos
+        // recover`, if this process dies too) can still find it.
+        let mut state = StateFile::load(&state_file).await?;
+        state.injections.remove(&handle.id);
+        state.save(&state_file).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chaos_scenarios::SuiteFile;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Runs every scenario in `suite_file` and prints a combined
+/// release-qualification verdict, so reviewers get one pass/fail for the
+/// whole battery rather than having to eyeball a series of separate
+/// `chaos run` invocations.
+pub async fn run(suite_file: PathBuf, output_json: Option<PathBuf>) -> Result<()> {
+    println!("{}", "=== Chaos Suite ===".bold().cyan());
+    println!("Loading suite: {}", suite_file.display());
+
+    let suite = SuiteFile::load(&suite_file).await?;
+    let base_dir = suite_file
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    println!("\n{}", "Suite Details:".bold());
+    println!("  Name: {}", suite.name.green());
+    if let Some(desc) = &suite.description {
+        println!("  Description: {}", desc);
+    }
+    println!("  Mode: {:?}", suite.mode);
+    println!("  Scenarios: {}", suite.scenarios.len());
+
+    println!("\n{}", "Running suite...".bold().yellow());
+    let result = suite.run(&base_dir).await;
+
+    println!("\n{}", "=== Suite Results ===".bold().green());
+    for entry in &result.entries {
+        let status = if entry.passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        };
+
+        match &entry.result {
+            Ok(scenario_result) => println!(
+                "  [{}] {} - success rate {:.2}%",
+                status,
+                entry.name,
+                scenario_result.success_rate() * 100.0
+            ),
+            Err(e) => println!("  [{}] {} - {}", status, entry.name, e),
+        }
+    }
+
+    println!(
+        "\nOverall: {}",
+        if result.passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        }
+    );
+
+    if let Some(json_path) = output_json {
+        println!("\nSaving JSON report to: {}", json_path.display());
+        let json = serde_json::to_string_pretty(&result)?;
+        tokio::fs::write(&json_path, json).await?;
+    }
+
+    if !result.passed {
+        anyhow::bail!("Suite '{}' did not pass", result.suite_name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use chaos_core::discovery;
+use colored::Colorize;
+
+/// Lists processes matching `pattern` (if given), running containers, and
+/// listening TCP sockets, so an operator can confirm a target actually
+/// exists before wiring it into a scenario or `chaos attach` call.
+pub async fn execute(pattern: Option<String>) -> Result<()> {
+    println!("{}", "=== Discoverable Targets ===".bold().cyan());
+
+    println!("\n{}", "Processes:".bold());
+    match &pattern {
+        Some(pattern) => {
+            let processes = discovery::discover_processes(pattern);
+            if processes.is_empty() {
+                println!("  (no process name contains '{}')", pattern);
+            }
+            for process in processes {
+                println!("  {} PID {} - {}", "•".green(), process.pid, process.name);
+            }
+        }
+        None => {
+            println!("  (pass --pattern to list matching processes)");
+        }
+    }
+
+    println!("\n{}", "Containers:".bold());
+    match discovery::discover_containers().await {
+        Ok(containers) => {
+            if containers.is_empty() {
+                println!("  (none running)");
+            }
+            for container in containers {
+                println!(
+                    "  {} {} ({}) - {}",
+                    "•".green(),
+                    container.name,
+                    container.id,
+                    container.image
+                );
+            }
+        }
+        Err(e) => println!("  {} {}", "✗".red(), e),
+    }
+
+    println!("\n{}", "Listening sockets:".bold());
+    match discovery::discover_listening_sockets().await {
+        Ok(sockets) => {
+            if sockets.is_empty() {
+                println!("  (none found)");
+            }
+            for socket in sockets {
+                let process = match (&socket.process_name, socket.pid) {
+                    (Some(name), Some(pid)) => format!(" ({} PID {})", name, pid),
+                    (Some(name), None) => format!(" ({})", name),
+                    _ => String::new(),
+                };
+                println!("  {} {}{}", "•".green(), socket.address, process);
+            }
+        }
+        Err(e) => println!("  {} {}", "✗".red(), e),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use chaos_core::StateFile;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+use ratatui::Terminal;
+use std::io::stdout;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Puts the terminal into raw mode + the alternate screen for the lifetime
+/// of the guard, and always restores both on drop - including when a
+/// fallible step after `enable_raw_mode` (entering the alternate screen,
+/// constructing the `Terminal`) fails, so a setup error can't leave the
+/// user's shell in raw mode with no way back short of running `reset`.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> Result<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = stdout().execute(EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(e.into());
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = stdout().execute(LeaveAlternateScreen);
+    }
+}
+
+/// Continuously re-reads `state_file` and shows what's currently injected
+/// on this host - the same source [`crate::commands::active`] prints once,
+/// refreshed on `interval` like `top`, so "is chaos currently applied to
+/// this box?" doesn't require re-running a command by hand. Reads the
+/// persisted state directly rather than talking to a daemon - there isn't
+/// one; see [`chaos_scenarios::monkey`]'s own admission of the same gap.
+pub async fn execute(state_file: PathBuf, interval: Duration) -> Result<()> {
+    let _guard = TerminalGuard::new()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+
+    run_loop(&state_file, interval, &mut terminal).await
+}
+
+async fn run_loop(
+    state_file: &PathBuf,
+    interval: Duration,
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+) -> Result<()> {
+    loop {
+        let mut last_error = None;
+        let state = match StateFile::load(state_file).await {
+            Ok(state) => state,
+            Err(e) => {
+                last_error = Some(e.to_string());
+                StateFile::default()
+            }
+        };
+
+        terminal.draw(|frame| draw(frame, state_file, &state, last_error.as_deref()))?;
+
+        if crossterm::event::poll(interval)? {
+            if let Event::Key(key) = crossterm::event::read()? {
+                if key.kind == KeyEventKind::Press && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+fn draw(
+    frame: &mut ratatui::Frame,
+    state_file: &std::path::Path,
+    state: &StateFile,
+    last_error: Option<&str>,
+) {
+    let rows = Layout::default()
+        .direction(ratatui::layout::Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.size());
+
+    let now = chrono::Utc::now();
+    let header = Row::new(vec!["ID", "INJECTOR", "TARGET", "AGE", "ARTIFACTS"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let mut handles: Vec<_> = state.injections.values().collect();
+    handles.sort_by_key(|h| h.started_at);
+
+    let body: Vec<Row> = handles
+        .iter()
+        .map(|handle| {
+            let age = (now - handle.started_at).to_std().unwrap_or_default();
+            Row::new(vec![
+                Cell::from(handle.id.chars().take(8).collect::<String>()),
+                Cell::from(handle.injector_name.clone()),
+                Cell::from(handle.target.description()),
+                Cell::from(humantime::format_duration(age).to_string()),
+                Cell::from(artifact_summary(handle)),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        body,
+        [
+            Constraint::Length(10),
+            Constraint::Length(18),
+            Constraint::Percentage(30),
+            Constraint::Length(12),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        "chaos top - {} ({} active)",
+        state_file.display(),
+        state.injections.len()
+    )));
+
+    frame.render_widget(table, rows[0]);
+
+    let footer = match last_error {
+        Some(e) => format!("failed to read state file: {} - q/Esc to quit", e),
+        None => "q/Esc: quit".to_string(),
+    };
+    frame.render_widget(
+        Paragraph::new(footer).style(Style::default().fg(Color::DarkGray)),
+        rows[1],
+    );
+}
+
+/// A one-line summary of what an injection left behind on the host, from
+/// its untyped `metadata` - just enough to answer "what would `chaos
+/// recover` clean up here?" without dumping the whole JSON blob.
+fn artifact_summary(handle: &chaos_core::InjectionHandle) -> String {
+    match &handle.metadata {
+        serde_json::Value::Object(map) if !map.is_empty() => map
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => "-".to_string(),
+    }
+}
@@ -1,9 +1,11 @@
 use anyhow::Result;
-use chaos_scenarios::parse_scenario_from_file;
+use chaos_core::{InjectorRegistry, Preflight};
+use chaos_scenarios::config::InjectionConfig;
+use chaos_scenarios::{parse_scenario_from_file, Scenario};
 use colored::Colorize;
 use std::path::PathBuf;
 
-pub async fn execute(scenario_file: PathBuf) -> Result<()> {
+pub async fn execute(scenario_file: PathBuf, deep: bool) -> Result<()> {
     println!("{}", "=== Validating Scenario ===".bold().cyan());
     println!("File: {}", scenario_file.display());
 
@@ -19,16 +21,33 @@ pub async fn execute(scenario_file: PathBuf) -> Result<()> {
                 println!("\n{}", "⚠ Warning: Scenario has no phases".yellow());
             }
 
+            let registry = InjectorRegistry::with_defaults();
+
             for (i, phase) in scenario.phases.iter().enumerate() {
                 println!("\n  Phase {}: {}", i + 1, phase.name);
                 println!("    Duration: {:?}", phase.duration);
                 println!("    Injections: {}", phase.injections.len());
 
                 for (j, injection) in phase.injections.iter().enumerate() {
-                    println!("      {}: {}", j + 1, injection.r#type);
+                    println!(
+                        "      {}: {}{}",
+                        j + 1,
+                        injection.r#type,
+                        describe_availability(&registry, &injection.r#type)
+                    );
                 }
             }
 
+            if let Err(e) = scenario.validate_against_registry(&registry) {
+                println!("\n{}", "✗ Scenario is invalid!".red().bold());
+                println!("\nError: {}", e);
+                return Err(anyhow::anyhow!(e));
+            }
+
+            if deep {
+                run_deep_checks(&scenario, &registry).await?;
+            }
+
             Ok(())
         }
         Err(e) => {
@@ -38,3 +57,84 @@ pub async fn execute(scenario_file: PathBuf) -> Result<()> {
         }
     }
 }
+
+/// Beyond `validate_against_registry`'s injector-type and parameter checks,
+/// resolves every injection's target the way a real run would and confirms
+/// it actually exists on this host, and checks the injector's binaries and
+/// capabilities via the same [`Preflight`] `chaos doctor` runs - so a
+/// scenario that would fail on a missing PID or a `CAP_NET_ADMIN` this
+/// process doesn't hold is caught here instead of mid-run.
+async fn run_deep_checks(scenario: &Scenario, registry: &InjectorRegistry) -> Result<()> {
+    println!("\n{}", "Deep validation:".bold());
+
+    let preflight = Preflight::run(registry).await;
+    let mut all_ready = true;
+
+    let injections: Vec<(&str, &InjectionConfig)> = scenario
+        .phases
+        .iter()
+        .flat_map(|phase| phase.injections.iter().map(move |i| (phase.name.as_str(), i)))
+        .chain(scenario.background.iter().map(|i| ("background", i)))
+        .collect();
+
+    for (phase_name, injection) in injections {
+        println!("\n  [{}] {}", phase_name, injection.r#type);
+
+        match preflight.injectors.iter().find(|r| r.name == injection.r#type) {
+            Some(readiness) => {
+                let marker = if readiness.ready { "✓".green() } else { "✗".red() };
+                let detail = readiness.detail.as_deref().unwrap_or("ready");
+                println!("    {} injector: {}", marker, detail);
+                all_ready &= readiness.ready;
+            }
+            None => {
+                println!("    {} injector: unknown type, not in registry", "✗".red());
+                all_ready = false;
+            }
+        }
+
+        match injection.target.to_target_with_seed(scenario.seed) {
+            Ok(target) => {
+                let exists = target.exists().await;
+                let marker = if exists { "✓".green() } else { "✗".red() };
+                println!("    {} target: {}", marker, target.description());
+                all_ready &= exists;
+            }
+            Err(e) => {
+                println!("    {} target: failed to resolve - {}", "✗".red(), e);
+                all_ready = false;
+            }
+        }
+    }
+
+    if all_ready {
+        println!("\n{}", "Host and targets are ready for this scenario.".green().bold());
+        Ok(())
+    } else {
+        println!(
+            "\n{}",
+            "Some deep validation checks failed - review the items above.".yellow().bold()
+        );
+        anyhow::bail!("chaos validate --deep found one or more readiness issues");
+    }
+}
+
+/// Annotates an injection type with whether it's actually usable in this
+/// build - missing entirely, or present but gated behind an optional
+/// integration feature this binary wasn't compiled with.
+fn describe_availability(registry: &InjectorRegistry, injection_type: &str) -> String {
+    match registry.get(injection_type) {
+        Some(injector) => match injector.required_feature() {
+            Some(capability) if !capability.is_compiled() => format!(
+                " {}",
+                format!(
+                    "(requires '{}' feature, not compiled in)",
+                    capability.feature_flag()
+                )
+                .yellow()
+            ),
+            _ => String::new(),
+        },
+        None => format!(" {}", "(unknown injector type)".red()),
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+use tracing::Level;
+use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+
+/// Initialize layered logging: a colored console layer plus a JSON log file
+/// per run under `log_dir`. Per-module levels can be overridden with a
+/// `RUST_LOG`-style filter (e.g. `chaos_core::injectors=debug,warn`); without
+/// one, `default_level` applies to the workspace's own crates and
+/// dependencies are held at `warn`.
+///
+/// The returned `WorkerGuard` must be kept alive for the duration of the
+/// process — dropping it flushes and detaches the file writer.
+pub fn init(default_level: Level, log_dir: &Path) -> anyhow::Result<tracing_appender::non_blocking::WorkerGuard> {
+    std::fs::create_dir_all(log_dir)?;
+
+    let log_file_name = format!("run-{}.jsonl", chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ"));
+    let log_file = std::fs::File::create(log_dir.join(&log_file_name))?;
+    let (non_blocking, guard) = tracing_appender::non_blocking(log_file);
+
+    let default_directive = format!(
+        "chaos_cli={level},chaos_core={level},chaos_scenarios={level},chaos_metrics={level},warn",
+        level = default_level
+    );
+
+    let console_layer = fmt::layer()
+        .with_target(false)
+        .with_filter(build_filter(&default_directive));
+
+    let file_layer = fmt::layer()
+        .json()
+        .with_writer(non_blocking)
+        .with_filter(build_filter(&default_directive));
+
+    tracing_subscriber::registry()
+        .with(console_layer)
+        .with(file_layer)
+        .init();
+
+    Ok(guard)
+}
+
+fn build_filter(default_directive: &str) -> EnvFilter {
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_directive))
+}
@@ -1,10 +1,20 @@
 mod commands;
+mod logging;
+mod redact;
+mod tui;
 mod ui;
 
 use clap::{Parser, Subcommand};
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use tracing::Level;
-use tracing_subscriber;
+
+/// Parses a `--set`-style `NAME=value` argument into its two halves.
+fn parse_key_val(raw: &str) -> Result<(String, String), String> {
+    raw.split_once('=')
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected NAME=value, got '{}'", raw))
+}
 
 #[derive(Parser)]
 #[command(name = "chaos")]
@@ -21,6 +31,10 @@ struct Cli {
     /// Enable quiet mode (errors only)
     #[arg(short, long, global = true)]
     quiet: bool,
+
+    /// Directory to write this run's JSON log file into
+    #[arg(long, global = true, default_value = "./chaos_logs")]
+    log_dir: PathBuf,
 }
 
 #[derive(Subcommand)]
@@ -49,6 +63,77 @@ enum Commands {
         /// Override scenario seed
         #[arg(long)]
         seed: Option<u64>,
+
+        /// Directory to record this run's result into, for later `history`
+        /// and `--baseline` lookups
+        #[arg(long, default_value = "./chaos_history")]
+        history_dir: PathBuf,
+
+        /// Record this run as the baseline for its scenario, rather than
+        /// comparing against the existing one
+        #[arg(long)]
+        baseline: bool,
+
+        /// Report what each injection would do without actually applying it
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Path to a safety policy file (YAML) to enforce blast-radius limits
+        #[arg(long)]
+        policy: Option<PathBuf>,
+
+        /// Push run metrics and per-injection spans to an OTLP collector at
+        /// this base URL (e.g. http://localhost:4318)
+        #[arg(long)]
+        otlp_endpoint: Option<String>,
+
+        /// SLO target success rate for error-budget reporting, e.g. 0.999
+        /// for "three nines" (requires --slo-window)
+        #[arg(long, requires = "slo_window")]
+        slo_target: Option<f64>,
+
+        /// Window the SLO target is measured over, e.g. "30days" (requires
+        /// --slo-target)
+        #[arg(long, requires = "slo_target")]
+        slo_window: Option<String>,
+
+        /// Make the process exit non-zero when the named condition holds.
+        /// Repeatable. One of: slo-violation (requires --slo-target /
+        /// --slo-window and a burn rate over 1x), any-injection-failure
+        /// (any injection, background or in-phase, failed to apply),
+        /// abort (the scenario stopped before its last scheduled phase)
+        #[arg(long = "fail-on")]
+        fail_on: Vec<String>,
+
+        /// Write a JUnit XML report to this path, so the run shows up as
+        /// a test result in Jenkins/GitLab CI
+        #[arg(long)]
+        output_junit: Option<PathBuf>,
+
+        /// Drive a WebSocket feed (e.g. chaos_targets' websocket_feed) for
+        /// the duration of the run, recording message gap, staleness and
+        /// reconnect metrics under fault injection
+        #[arg(long)]
+        workload_ws: Option<String>,
+
+        /// Override or supply a scenario `${NAME}` template variable, e.g.
+        /// `--set host=staging.internal`. Repeatable; takes precedence over
+        /// the same name in the scenario's `vars:` section
+        #[arg(long = "set", value_parser = parse_key_val)]
+        set: Vec<(String, String)>,
+
+        /// Record the resolved schedule, targets, and parameters this run
+        /// actually used to this path, so `chaos replay` can rerun exactly
+        /// this experiment even if the scenario used randomness or discovery
+        #[arg(long)]
+        record_execution: Option<PathBuf>,
+
+        /// Replace the plain progress bar with an interactive full-screen
+        /// console showing the phase timeline, active injections, and a
+        /// live latency/error sparkline, with keybindings to pause (p),
+        /// resume (r), and abort (q)
+        #[arg(long)]
+        tui: bool,
     },
 
     /// Attach to a running process and inject chaos
@@ -72,6 +157,47 @@ enum Commands {
         /// Config file for injection parameters
         #[arg(short, long)]
         config: Option<PathBuf>,
+
+        /// Write the applied injection's handle to this file, so another
+        /// process can remove it later via `chaos stop --handle`
+        #[arg(long)]
+        export: Option<PathBuf>,
+    },
+
+    /// List injections currently recorded as active in the persisted state
+    /// file
+    Active {
+        /// Path to the persisted injection state file
+        #[arg(long, default_value_os_t = chaos_core::StateFile::default_path())]
+        state_file: PathBuf,
+    },
+
+    /// Continuously display active injections on this host, refreshing
+    /// like `top`
+    Top {
+        /// Path to the persisted injection state file
+        #[arg(long, default_value_os_t = chaos_core::StateFile::default_path())]
+        state_file: PathBuf,
+
+        /// How often to re-read the state file
+        #[arg(long, default_value = "1s")]
+        interval: String,
+    },
+
+    /// Remove an injection, by ID (looked up in the persisted state file) or
+    /// by an exported handle file, possibly from another process
+    Stop {
+        /// Injection ID, as printed by `chaos attach` or listed by `chaos active`
+        #[arg(group = "source")]
+        injection_id: Option<String>,
+
+        /// Path to a handle file written by `chaos attach --export`
+        #[arg(long, group = "source")]
+        handle: Option<PathBuf>,
+
+        /// Path to the persisted injection state file
+        #[arg(long, default_value_os_t = chaos_core::StateFile::default_path())]
+        state_file: PathBuf,
     },
 
     /// Generate report from metrics file
@@ -90,16 +216,240 @@ enum Commands {
         /// Compare with other runs
         #[arg(long)]
         compare: Vec<PathBuf>,
+
+        /// Path to a baseline run's metrics JSON file - flags regressions in
+        /// success rate and average phase duration beyond
+        /// --regression-threshold, and exits non-zero if one is found
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Fractional deviation from the baseline that counts as a
+        /// regression (e.g. 0.2 for 20%)
+        #[arg(long, default_value_t = 0.2)]
+        regression_threshold: f64,
+
+        /// Strip hostnames, IPs, PIDs and file paths before rendering the report
+        #[arg(long)]
+        redact: bool,
     },
 
     /// Validate a scenario file
     Validate {
         /// Path to scenario file
         scenario_file: PathBuf,
+
+        /// Also resolve every injection's target and confirm it exists on
+        /// this host, and check the injector's binaries/capabilities via
+        /// the same preflight `chaos doctor` runs - producing a readiness
+        /// report instead of discovering problems mid-run
+        #[arg(long)]
+        deep: bool,
+    },
+
+    /// Flag suspicious-but-valid scenario constructs that parse and
+    /// validate cleanly but are probably not what the author meant
+    Lint {
+        /// Path to scenario file
+        scenario_file: PathBuf,
+    },
+
+    /// Show a dry-run impact estimate for a scenario without running it
+    Plan {
+        /// Path to scenario file
+        scenario_file: PathBuf,
+    },
+
+    /// Convert a scenario file between YAML, TOML, and JSON
+    Convert {
+        /// Path to scenario file (YAML, TOML, or JSON)
+        scenario_file: PathBuf,
+
+        /// Format to convert to (yaml, toml, json)
+        #[arg(long = "to")]
+        to: String,
+
+        /// Output file path (defaults to the source file with the new extension)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 
     /// List available injectors
     List,
+
+    /// Inspect and manage the stored history of past scenario runs
+    History {
+        #[command(subcommand)]
+        command: HistoryCommands,
+    },
+
+    /// Validate and install `.chaospkg` scenario packages
+    Package {
+        #[command(subcommand)]
+        command: PackageCommands,
+    },
+
+    /// Run a quick end-to-end check that this host supports real injection
+    SelfTest,
+
+    /// Clean up injections left behind by a crashed or killed run
+    Recover {
+        /// Path to the persisted injection state file
+        #[arg(long, default_value_os_t = chaos_core::StateFile::default_path())]
+        state_file: PathBuf,
+    },
+
+    /// Scan the host for leftover chaos artifacts (tc qdiscs, iptables
+    /// rules, cgroups, tmp files) independent of any saved state, and
+    /// remove them
+    Cleanup {
+        /// Report what would be removed without actually removing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Run and manage multi-scenario experiment suites
+    Suite {
+        #[command(subcommand)]
+        command: SuiteCommands,
+    },
+
+    /// Run a seeded chaos monkey indefinitely against a set of targets
+    Monkey {
+        /// Path to the monkey config file (YAML, TOML, or JSON)
+        #[arg(short, long)]
+        config: PathBuf,
+
+        /// Path to a safety policy file (YAML) to enforce blast-radius limits
+        #[arg(long)]
+        policy: Option<PathBuf>,
+    },
+
+    /// Run scenarios on a cron schedule indefinitely, recording each result
+    /// to the run store
+    Schedule {
+        /// Path to the schedule file (YAML, TOML, or JSON) listing scenarios
+        /// and their cron expressions
+        schedule_file: PathBuf,
+
+        /// Directory to record each scheduled run's result into, for later
+        /// `history` and `--baseline` lookups
+        #[arg(long, default_value = "./chaos_history")]
+        history_dir: PathBuf,
+    },
+
+    /// List and inspect scenarios built into this binary
+    Scenarios {
+        #[command(subcommand)]
+        command: ScenariosCommands,
+    },
+
+    /// Rerun a scenario exactly as `chaos run --record-execution` recorded
+    /// it, even if the original run used randomness or live discovery
+    Replay {
+        /// Path to the execution record written by `chaos run --record-execution`
+        artifact: PathBuf,
+
+        /// Directory to record the replay's result into, for later
+        /// `history` and `--baseline` lookups
+        #[arg(long, default_value = "./chaos_history")]
+        history_dir: PathBuf,
+    },
+
+    /// Check this host's readiness for real fault injection
+    Doctor,
+
+    /// Discover valid targets (processes, containers, listening sockets)
+    /// before writing a scenario
+    Targets {
+        /// Only list processes whose name contains this substring, the same
+        /// match `Target::ProcessPattern` uses at injection time
+        #[arg(short, long)]
+        pattern: Option<String>,
+    },
+
+    /// Run a `chaos_core::agent` REST server so scenarios elsewhere can
+    /// target this host via `Target::Agent`
+    Agent {
+        /// Address to bind the agent's REST API to
+        #[arg(short, long, default_value = "0.0.0.0:9091")]
+        bind: SocketAddr,
+
+        /// Bearer token clients must present. Defaults to $CHAOS_AGENT_TOKEN
+        #[arg(short, long, env = "CHAOS_AGENT_TOKEN")]
+        token: String,
+
+        /// Path to the persisted injection state file
+        #[arg(long, default_value_os_t = chaos_core::StateFile::default_path())]
+        state_file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum PackageCommands {
+    /// Validate a package's manifest and confirm its referenced assets exist
+    Validate {
+        /// Path to the package directory
+        path: PathBuf,
+    },
+
+    /// Copy a package into the daemon's scenario library
+    Install {
+        /// Path to the package directory
+        path: PathBuf,
+
+        /// Library directory to install into
+        #[arg(short, long, default_value = "./chaos_library")]
+        library_dir: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum SuiteCommands {
+    /// Run every scenario in a suite file and produce a combined verdict
+    Run {
+        /// Path to the suite file (YAML, TOML, or JSON)
+        suite_file: PathBuf,
+
+        /// Output the combined report to a JSON file
+        #[arg(short, long)]
+        output_json: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScenariosCommands {
+    /// List every scenario embedded in this binary, runnable as
+    /// `chaos run builtin:<name>`
+    List,
+}
+
+#[derive(Subcommand)]
+enum HistoryCommands {
+    /// Roll up raw run history older than the retention window into aggregates
+    Compact {
+        /// Directory containing stored run history
+        #[arg(short, long, default_value = "./chaos_history")]
+        history_dir: PathBuf,
+    },
+
+    /// Plot a metric's evolution across stored runs of a scenario
+    Trend {
+        /// Directory containing stored run history
+        #[arg(short = 'd', long, default_value = "./chaos_history")]
+        history_dir: PathBuf,
+
+        /// Scenario name to analyze
+        #[arg(short, long)]
+        scenario: String,
+
+        /// Metric to plot (success_rate, total_duration, total_injections, avg_phase_duration)
+        #[arg(short, long)]
+        metric: String,
+
+        /// Output format (ascii, csv, json)
+        #[arg(short, long, default_value = "ascii")]
+        format: String,
+    },
 }
 
 #[tokio::main]
@@ -115,10 +465,7 @@ async fn main() -> anyhow::Result<()> {
         Level::INFO
     };
 
-    tracing_subscriber::fmt()
-        .with_max_level(log_level)
-        .with_target(false)
-        .init();
+    let _log_guard = logging::init(log_level, &cli.log_dir)?;
 
     match cli.command {
         Commands::Run {
@@ -128,6 +475,19 @@ async fn main() -> anyhow::Result<()> {
             output_markdown,
             prometheus_port,
             seed,
+            history_dir,
+            baseline,
+            dry_run,
+            policy,
+            otlp_endpoint,
+            slo_target,
+            slo_window,
+            fail_on,
+            output_junit,
+            workload_ws,
+            set,
+            record_execution,
+            tui,
         } => {
             commands::run::execute(
                 scenario_file,
@@ -136,6 +496,19 @@ async fn main() -> anyhow::Result<()> {
                 output_markdown,
                 prometheus_port,
                 seed,
+                history_dir,
+                baseline,
+                dry_run,
+                policy,
+                otlp_endpoint,
+                slo_target,
+                slo_window,
+                fail_on,
+                output_junit,
+                workload_ws,
+                set,
+                record_execution,
+                tui,
             )
             .await?;
         }
@@ -146,8 +519,26 @@ async fn main() -> anyhow::Result<()> {
             injection,
             duration,
             config,
+            export,
+        } => {
+            commands::attach::execute(pid, address, injection, duration, config, export).await?;
+        }
+
+        Commands::Active { state_file } => {
+            commands::active::execute(state_file).await?;
+        }
+
+        Commands::Top { state_file, interval } => {
+            let interval = humantime::parse_duration(&interval)?;
+            commands::top::execute(state_file, interval).await?;
+        }
+
+        Commands::Stop {
+            injection_id,
+            handle,
+            state_file,
         } => {
-            commands::attach::execute(pid, address, injection, duration, config).await?;
+            commands::stop::execute(injection_id, handle, state_file).await?;
         }
 
         Commands::Report {
@@ -155,17 +546,123 @@ async fn main() -> anyhow::Result<()> {
             format,
             output,
             compare,
+            baseline,
+            regression_threshold,
+            redact,
         } => {
-            commands::report::execute(metrics_file, format, output, compare).await?;
+            commands::report::execute(
+                metrics_file,
+                format,
+                output,
+                compare,
+                baseline,
+                regression_threshold,
+                redact,
+            )
+            .await?;
         }
 
-        Commands::Validate { scenario_file } => {
-            commands::validate::execute(scenario_file).await?;
+        Commands::Validate { scenario_file, deep } => {
+            commands::validate::execute(scenario_file, deep).await?;
+        }
+
+        Commands::Lint { scenario_file } => {
+            commands::lint::execute(scenario_file).await?;
+        }
+
+        Commands::Plan { scenario_file } => {
+            commands::plan::execute(scenario_file).await?;
+        }
+
+        Commands::Convert {
+            scenario_file,
+            to,
+            output,
+        } => {
+            commands::convert::execute(scenario_file, to, output).await?;
         }
 
         Commands::List => {
             commands::list::execute().await?;
         }
+
+        Commands::History { command } => match command {
+            HistoryCommands::Compact { history_dir } => {
+                commands::history::compact(history_dir).await?;
+            }
+            HistoryCommands::Trend {
+                history_dir,
+                scenario,
+                metric,
+                format,
+            } => {
+                commands::history::trend(history_dir, scenario, metric, format).await?;
+            }
+        },
+
+        Commands::Package { command } => match command {
+            PackageCommands::Validate { path } => {
+                commands::package::validate(path).await?;
+            }
+            PackageCommands::Install { path, library_dir } => {
+                commands::package::install(path, library_dir).await?;
+            }
+        },
+
+        Commands::SelfTest => {
+            commands::self_test::execute().await?;
+        }
+
+        Commands::Recover { state_file } => {
+            commands::recover::execute(state_file).await?;
+        }
+
+        Commands::Cleanup { dry_run } => {
+            commands::cleanup::execute(dry_run).await?;
+        }
+
+        Commands::Suite { command } => match command {
+            SuiteCommands::Run {
+                suite_file,
+                output_json,
+            } => {
+                commands::suite::run(suite_file, output_json).await?;
+            }
+        },
+
+        Commands::Monkey { config, policy } => {
+            commands::monkey::execute(config, policy).await?;
+        }
+
+        Commands::Schedule { schedule_file, history_dir } => {
+            commands::schedule::execute(schedule_file, history_dir).await?;
+        }
+
+        Commands::Scenarios { command } => match command {
+            ScenariosCommands::List => {
+                commands::scenarios::list().await?;
+            }
+        },
+
+        Commands::Replay { artifact, history_dir } => {
+            commands::replay::execute(artifact, history_dir).await?;
+        }
+
+        Commands::Doctor => {
+            commands::doctor::execute().await?;
+        }
+
+        Commands::Targets { pattern } => {
+            commands::targets::execute(pattern).await?;
+        }
+
+        Commands::Agent {
+            bind,
+            token,
+            state_file,
+        } => {
+            commands::agent::execute(bind, token, state_file).await?;
+        }
     }
 
     Ok(())
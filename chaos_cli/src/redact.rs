@@ -0,0 +1,55 @@
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Strips hostnames, IPs, PIDs and file paths from a report body so it can
+/// be shared outside the team or attached to a public issue.
+pub fn redact_text(input: &str) -> String {
+    let mut redacted = input.to_string();
+    redacted = ip_pattern().replace_all(&redacted, "[REDACTED_IP]").to_string();
+    redacted = path_pattern().replace_all(&redacted, "[REDACTED_PATH]").to_string();
+    redacted = pid_pattern().replace_all(&redacted, "PID [REDACTED]").to_string();
+    redacted = hostname_pattern()
+        .replace_all(&redacted, "[REDACTED_HOST]")
+        .to_string();
+    redacted
+}
+
+fn ip_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\b\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}(:\d+)?\b").unwrap())
+}
+
+fn path_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(/[\w.\-]+){2,}").unwrap())
+}
+
+fn pid_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\bPID\s+\d+\b").unwrap())
+}
+
+fn hostname_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\b[a-zA-Z0-9-]+\.(internal|corp|local)\b").unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_ip_and_path() {
+        let input = "connected to 10.0.0.1:8080 and wrote /var/log/chaos/run.log";
+        let redacted = redact_text(input);
+        assert!(!redacted.contains("10.0.0.1"));
+        assert!(!redacted.contains("/var/log/chaos/run.log"));
+    }
+
+    #[test]
+    fn test_redact_pid() {
+        let input = "Sending SIGTERM to PID 12345";
+        let redacted = redact_text(input);
+        assert!(!redacted.contains("12345"));
+    }
+}
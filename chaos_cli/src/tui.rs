@@ -0,0 +1,304 @@
+//! Optional `chaos run --tui` operator console: a `ratatui` full-screen
+//! view of a scenario's progress in place of the plain `indicatif` progress
+//! bar, for someone babysitting a long run rather than tailing logs.
+//! Everything it shows is read-only introspection (elapsed time against
+//! `Scenario::phases`, `Executor::list_active`, a `StreamingAggregator`
+//! window) - the only thing it can change is the run itself, via the same
+//! pause/resume/abort levers `commands::run::execute` already wires up to
+//! SIGUSR1/SIGUSR2/SIGINT.
+
+use chaos_core::Executor;
+use chaos_metrics::{StreamingAggregator, Window};
+use chaos_scenarios::{Scenario, ScenarioRunner};
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Sparkline};
+use ratatui::{Frame, Terminal};
+use std::collections::VecDeque;
+use std::io::stdout;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+/// How many past 1-second windows the latency/error sparklines keep.
+const HISTORY_LEN: usize = 120;
+
+struct History {
+    latency_ms: VecDeque<u64>,
+    error_pct: VecDeque<u64>,
+}
+
+impl History {
+    fn new() -> Self {
+        Self {
+            latency_ms: VecDeque::with_capacity(HISTORY_LEN),
+            error_pct: VecDeque::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    fn push(&mut self, latency_ms: u64, error_pct: u64) {
+        if self.latency_ms.len() == HISTORY_LEN {
+            self.latency_ms.pop_front();
+            self.error_pct.pop_front();
+        }
+        self.latency_ms.push_back(latency_ms);
+        self.error_pct.push_back(error_pct);
+    }
+}
+
+/// Puts the terminal into raw mode + the alternate screen for the lifetime
+/// of the guard, and always restores both on drop - including when a
+/// fallible step after `enable_raw_mode` (entering the alternate screen,
+/// constructing the `Terminal`) fails, so a setup error can't leave the
+/// user's shell in raw mode with no way back short of running `reset`.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> anyhow::Result<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = stdout().execute(EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(e.into());
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = stdout().execute(LeaveAlternateScreen);
+    }
+}
+
+/// Runs the console until `cancel` fires or `scenario.duration` elapses,
+/// whichever comes first - the same stop condition the plain progress bar
+/// loop in `commands::run::execute` uses. Never returns an error just
+/// because the scenario itself failed; that's still reported by the
+/// caller's normal result-printing path once this returns.
+pub async fn run(
+    scenario: &Scenario,
+    runner: ScenarioRunner,
+    executor: Executor,
+    streaming: Option<Arc<StreamingAggregator>>,
+    slo_target: Option<f64>,
+    cancel: CancellationToken,
+) -> anyhow::Result<()> {
+    let _guard = TerminalGuard::new()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+
+    run_loop(scenario, runner, executor, streaming, slo_target, &cancel, &mut terminal).await
+}
+
+async fn run_loop(
+    scenario: &Scenario,
+    runner: ScenarioRunner,
+    executor: Executor,
+    streaming: Option<Arc<StreamingAggregator>>,
+    slo_target: Option<f64>,
+    cancel: &CancellationToken,
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+) -> anyhow::Result<()> {
+    let start = Instant::now();
+    let mut history = History::new();
+    let mut tick = tokio::time::interval(Duration::from_millis(250));
+
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed >= scenario.duration || cancel.is_cancelled() {
+            break;
+        }
+
+        let active = executor.list_active().await;
+        let window = match &streaming {
+            Some(streaming) => Some(streaming.aggregate(Window::OneSecond).await),
+            None => None,
+        };
+        if let Some(window) = &window {
+            history.push(window.latency_p50.as_millis() as u64, (window.error_rate * 100.0) as u64);
+        }
+        let burn_rate = window
+            .as_ref()
+            .zip(slo_target)
+            .map(|(w, target)| w.error_rate / (1.0 - target).max(f64::EPSILON));
+
+        terminal.draw(|frame| {
+            draw(frame, scenario, elapsed, &active, &history, burn_rate, runner.is_paused());
+        })?;
+
+        if crossterm::event::poll(Duration::from_millis(0))? {
+            if let Event::Key(key) = crossterm::event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => {
+                            cancel.cancel();
+                            break;
+                        }
+                        KeyCode::Char('p') => runner.pause(true),
+                        KeyCode::Char('r') => runner.resume(),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        tick.tick().await;
+    }
+
+    Ok(())
+}
+
+fn draw(
+    frame: &mut Frame,
+    scenario: &Scenario,
+    elapsed: Duration,
+    active: &[chaos_core::InjectionHandle],
+    history: &History,
+    burn_rate: Option<f64>,
+    paused: bool,
+) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(6),
+            Constraint::Min(6),
+            Constraint::Length(6),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+
+    draw_header(frame, rows[0], scenario, elapsed, paused);
+    draw_timeline(frame, rows[1], scenario, elapsed);
+    draw_active_injections(frame, rows[2], active);
+    draw_sparklines(frame, rows[3], history, burn_rate);
+    draw_keybindings(frame, rows[4]);
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, scenario: &Scenario, elapsed: Duration, paused: bool) {
+    let ratio = (elapsed.as_secs_f64() / scenario.duration.as_secs_f64().max(1.0)).clamp(0.0, 1.0);
+    let label = format!(
+        "{} - {:?} / {:?}{}",
+        scenario.name,
+        elapsed,
+        scenario.duration,
+        if paused { " (PAUSED)" } else { "" }
+    );
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title("chaos run"))
+        .gauge_style(if paused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Cyan)
+        })
+        .ratio(ratio)
+        .label(label);
+    frame.render_widget(gauge, area);
+}
+
+fn draw_timeline(frame: &mut Frame, area: Rect, scenario: &Scenario, elapsed: Duration) {
+    let mut cursor = Duration::ZERO;
+    let items: Vec<ListItem> = scenario
+        .phases
+        .iter()
+        .map(|phase| {
+            let start = cursor;
+            let end = cursor + phase.duration;
+            cursor = end;
+            let status = if elapsed < start {
+                "pending"
+            } else if elapsed < end {
+                "active"
+            } else {
+                "done"
+            };
+            let style = match status {
+                "active" => Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                "done" => Style::default().fg(Color::DarkGray),
+                _ => Style::default(),
+            };
+            ListItem::new(Line::from(Span::styled(
+                format!("[{:>7}] {} ({:?}, {} injection(s))", status, phase.name, phase.duration, phase.injections.len()),
+                style,
+            )))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(Block::default().borders(Borders::ALL).title("Phases")), area);
+}
+
+fn draw_active_injections(frame: &mut Frame, area: Rect, active: &[chaos_core::InjectionHandle]) {
+    let items: Vec<ListItem> = if active.is_empty() {
+        vec![ListItem::new("(none)")]
+    } else {
+        active
+            .iter()
+            .map(|handle| {
+                ListItem::new(format!(
+                    "{} on {} (running {})",
+                    handle.injector_name,
+                    handle.target.description(),
+                    humantime::format_duration(handle.duration().to_std().unwrap_or_default())
+                ))
+            })
+            .collect()
+    };
+
+    frame.render_widget(
+        List::new(items).block(Block::default().borders(Borders::ALL).title("Active injections")),
+        area,
+    );
+}
+
+fn draw_sparklines(frame: &mut Frame, area: Rect, history: &History, burn_rate: Option<f64>) {
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(34), Constraint::Percentage(33), Constraint::Percentage(33)])
+        .split(area);
+
+    let latency: Vec<u64> = history.latency_ms.iter().copied().collect();
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title("p50 latency (ms)"))
+            .data(&latency)
+            .style(Style::default().fg(Color::Cyan)),
+        cols[0],
+    );
+
+    let errors: Vec<u64> = history.error_pct.iter().copied().collect();
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title("error rate (%)"))
+            .data(&errors)
+            .style(Style::default().fg(Color::Red)),
+        cols[1],
+    );
+
+    let slo_text = match burn_rate {
+        Some(rate) if rate > 1.0 => Line::from(Span::styled(
+            format!("burning {:.2}x the sustainable rate", rate),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Some(rate) => Line::from(Span::styled(
+            format!("within budget ({:.2}x)", rate),
+            Style::default().fg(Color::Green),
+        )),
+        None => Line::from("no --slo-target set"),
+    };
+    frame.render_widget(
+        Paragraph::new(slo_text).block(Block::default().borders(Borders::ALL).title("SLO status")),
+        cols[2],
+    );
+}
+
+fn draw_keybindings(frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Paragraph::new("p: pause   r: resume   q/Esc: abort").style(Style::default().fg(Color::DarkGray)),
+        area,
+    );
+}
@@ -0,0 +1,193 @@
+use crate::error::{ChaosError, Result};
+use crate::error_budget::query_promql;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Where an [`AbortConditions`] check reads the target's live error rate
+/// and P99 latency from. Mirrors [`crate::ErrorBudgetSource::PromQl`],
+/// since both ultimately need the same "ask an external metrics system
+/// for a number" shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AbortMetricsSource {
+    PromQl {
+        url: String,
+        /// PromQL query expected to evaluate to a single instant-vector
+        /// result whose value is the current error rate (0.0 - 1.0).
+        /// Leave unset if `max_error_rate` isn't configured.
+        #[serde(default)]
+        error_rate_query: Option<String>,
+        /// PromQL query expected to evaluate to the current P99 latency,
+        /// in seconds. Leave unset if `max_p99_latency` isn't configured.
+        #[serde(default)]
+        p99_latency_query: Option<String>,
+    },
+}
+
+/// Conditions that, once breached, should halt a running scenario:
+/// remaining phases are skipped and every active injection is removed.
+/// Checked once before a scenario starts and again at every phase
+/// boundary, the same cadence [`chaos_scenarios::ScenarioRunner`] already
+/// uses for [`crate::ErrorBudgetPolicy`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AbortConditions {
+    /// Where to read `max_error_rate` / `max_p99_latency` from. Required
+    /// for either of those two fields to have any effect.
+    #[serde(default)]
+    pub source: Option<AbortMetricsSource>,
+    /// Abort once the target's error rate (0.0 - 1.0) exceeds this.
+    #[serde(default)]
+    pub max_error_rate: Option<f64>,
+    /// Abort once the target's P99 latency exceeds this.
+    #[serde(default)]
+    pub max_p99_latency: Option<Duration>,
+    /// URL polled with a plain GET at every check; a non-2xx response or a
+    /// connection failure counts as unhealthy.
+    #[serde(default)]
+    pub health_check_url: Option<String>,
+    /// How long the health check must stay continuously unhealthy before
+    /// it counts as an abort condition, rather than a single blip.
+    #[serde(default)]
+    pub health_check_grace: Option<Duration>,
+}
+
+/// Runtime companion to [`AbortConditions`]: tracks how long the health
+/// check (if configured) has been failing across repeated `check` calls,
+/// since that's state the serialized config itself has no business
+/// holding.
+pub struct AbortMonitor {
+    conditions: AbortConditions,
+    unhealthy_since: Option<Instant>,
+}
+
+impl AbortMonitor {
+    pub fn new(conditions: AbortConditions) -> Self {
+        Self {
+            conditions,
+            unhealthy_since: None,
+        }
+    }
+
+    /// Fails with [`ChaosError::AbortConditionTriggered`] if any configured
+    /// condition is currently breached.
+    pub async fn check(&mut self) -> Result<()> {
+        if let Some(max_error_rate) = self.conditions.max_error_rate {
+            if let Some(rate) = self.query_error_rate().await? {
+                if rate > max_error_rate {
+                    return Err(ChaosError::AbortConditionTriggered(format!(
+                        "error rate {:.1}% exceeds maximum {:.1}%",
+                        rate * 100.0,
+                        max_error_rate * 100.0
+                    )));
+                }
+            }
+        }
+
+        if let Some(max_p99) = self.conditions.max_p99_latency {
+            if let Some(p99) = self.query_p99_latency().await? {
+                if p99 > max_p99 {
+                    return Err(ChaosError::AbortConditionTriggered(format!(
+                        "p99 latency {:?} exceeds maximum {:?}",
+                        p99, max_p99
+                    )));
+                }
+            }
+        }
+
+        self.check_health().await
+    }
+
+    async fn query_error_rate(&self) -> Result<Option<f64>> {
+        match &self.conditions.source {
+            Some(AbortMetricsSource::PromQl {
+                url,
+                error_rate_query: Some(query),
+                ..
+            }) => Ok(Some(query_promql(url, query).await?)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn query_p99_latency(&self) -> Result<Option<Duration>> {
+        match &self.conditions.source {
+            Some(AbortMetricsSource::PromQl {
+                url,
+                p99_latency_query: Some(query),
+                ..
+            }) => {
+                let seconds = query_promql(url, query).await?;
+                Ok(Some(Duration::from_secs_f64(seconds.max(0.0))))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn check_health(&mut self) -> Result<()> {
+        let Some(url) = self.conditions.health_check_url.clone() else {
+            return Ok(());
+        };
+
+        let healthy = reqwest::Client::new()
+            .get(&url)
+            .send()
+            .await
+            .map(|response| response.status().is_success())
+            .unwrap_or(false);
+
+        if healthy {
+            self.unhealthy_since = None;
+            return Ok(());
+        }
+
+        let unhealthy_since = *self.unhealthy_since.get_or_insert_with(Instant::now);
+        let failing_for = unhealthy_since.elapsed();
+        let grace = self.conditions.health_check_grace.unwrap_or(Duration::ZERO);
+
+        if failing_for >= grace {
+            return Err(ChaosError::AbortConditionTriggered(format!(
+                "health check at {} has been failing for {:?}",
+                url, failing_for
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_no_conditions_configured_always_passes() {
+        let mut monitor = AbortMonitor::new(AbortConditions::default());
+        assert!(monitor.check().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_health_check_triggers_only_after_grace_period() {
+        // Port 0 is never a valid listener, so this connection fails
+        // immediately and deterministically without touching the network.
+        let mut monitor = AbortMonitor::new(AbortConditions {
+            health_check_url: Some("http://127.0.0.1:0/healthz".to_string()),
+            health_check_grace: Some(Duration::from_millis(50)),
+            ..Default::default()
+        });
+
+        assert!(monitor.check().await.is_ok());
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+
+        let err = monitor.check().await.unwrap_err();
+        assert!(matches!(err, ChaosError::AbortConditionTriggered(_)));
+    }
+
+    #[tokio::test]
+    async fn test_max_error_rate_without_source_is_inert() {
+        let mut monitor = AbortMonitor::new(AbortConditions {
+            max_error_rate: Some(0.01),
+            ..Default::default()
+        });
+
+        assert!(monitor.check().await.is_ok());
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{error::*, executor::Executor, handle::InjectionHandle, target::Target};
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::{self, Next},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// Settings shared by every `chaos agent` an [`AgentClient`] talks to,
+/// mirroring [`crate::remote::SshConfig`]'s "one config for the whole
+/// pool" shape - a fleet's agents are expected to share one bearer token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AgentClientConfig {
+    pub token: String,
+    pub timeout: Duration,
+}
+
+impl Default for AgentClientConfig {
+    fn default() -> Self {
+        Self {
+            token: std::env::var("CHAOS_AGENT_TOKEN").unwrap_or_default(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct InjectRequest {
+    injector_name: String,
+    #[serde(default)]
+    params: serde_json::Value,
+    target: Target,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoveRequest {
+    handle: InjectionHandle,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExistsRequest {
+    target: Target,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExistsResponse {
+    exists: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Talks to a [`crate::Target::Agent`]'s `chaos agent` REST API on behalf of
+/// the [`Executor`] - the same reuse-a-warm-client shape as
+/// [`crate::remote::SshPool`], just over HTTP to a whole remote `Executor`
+/// instead of over a pooled SSH connection to a bare shell command.
+pub struct AgentClient {
+    config: AgentClientConfig,
+    http: reqwest::Client,
+}
+
+impl AgentClient {
+    pub fn new(config: AgentClientConfig) -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .timeout(config.timeout)
+                .build()
+                .expect("failed to build agent HTTP client"),
+            config,
+        }
+    }
+
+    fn base_url(host: &str, port: u16) -> String {
+        format!("http://{}:{}", host, port)
+    }
+
+    pub async fn inject(
+        &self,
+        host: &str,
+        port: u16,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+    ) -> Result<InjectionHandle> {
+        let response = self
+            .http
+            .post(format!("{}/v1/inject", Self::base_url(host, port)))
+            .bearer_auth(&self.config.token)
+            .json(&InjectRequest {
+                injector_name: injector_name.to_string(),
+                params: params.clone(),
+                target: target.clone(),
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                ChaosError::NetworkError(format!("Failed to reach agent at {}:{}: {}", host, port, e))
+            })?;
+
+        Self::parse(response).await
+    }
+
+    pub async fn remove(&self, host: &str, port: u16, handle: InjectionHandle) -> Result<()> {
+        let response = self
+            .http
+            .post(format!("{}/v1/remove", Self::base_url(host, port)))
+            .bearer_auth(&self.config.token)
+            .json(&RemoveRequest { handle })
+            .send()
+            .await
+            .map_err(|e| {
+                ChaosError::NetworkError(format!("Failed to reach agent at {}:{}: {}", host, port, e))
+            })?;
+
+        Self::parse::<serde_json::Value>(response).await.map(|_| ())
+    }
+
+    pub async fn list(&self, host: &str, port: u16) -> Result<Vec<InjectionHandle>> {
+        let response = self
+            .http
+            .get(format!("{}/v1/list", Self::base_url(host, port)))
+            .bearer_auth(&self.config.token)
+            .send()
+            .await
+            .map_err(|e| {
+                ChaosError::NetworkError(format!("Failed to reach agent at {}:{}: {}", host, port, e))
+            })?;
+
+        Self::parse(response).await
+    }
+
+    /// Best-effort existence check over the agent's `/v1/exists` endpoint.
+    /// Any transport or protocol failure is treated as "doesn't exist"
+    /// rather than propagated, matching how [`Target::exists`] elsewhere
+    /// only ever returns a plain bool.
+    pub async fn exists(&self, host: &str, port: u16, target: &Target) -> bool {
+        let outcome: Result<ExistsResponse> = async {
+            let response = self
+                .http
+                .post(format!("{}/v1/exists", Self::base_url(host, port)))
+                .bearer_auth(&self.config.token)
+                .json(&ExistsRequest {
+                    target: target.clone(),
+                })
+                .send()
+                .await
+                .map_err(|e| {
+                    ChaosError::NetworkError(format!(
+                        "Failed to reach agent at {}:{}: {}",
+                        host, port, e
+                    ))
+                })?;
+
+            Self::parse(response).await
+        }
+        .await;
+
+        outcome.map(|r| r.exists).unwrap_or(false)
+    }
+
+    async fn parse<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        if response.status().is_success() {
+            response
+                .json::<T>()
+                .await
+                .map_err(|e| ChaosError::SystemError(format!("Malformed agent response: {}", e)))
+        } else {
+            let status = response.status();
+            let body: ErrorResponse = response.json().await.unwrap_or_else(|_| ErrorResponse {
+                error: status.to_string(),
+            });
+            Err(ChaosError::InjectionFailed(body.error))
+        }
+    }
+}
+
+/// The default client used by callers that don't need a custom
+/// [`AgentClientConfig`] - one shared HTTP client per process, the same
+/// "warm shared client, created once" pattern as
+/// [`crate::remote::default_pool`] and the process injector's health-check
+/// client.
+pub fn default_client() -> &'static AgentClient {
+    static CLIENT: std::sync::OnceLock<AgentClient> = std::sync::OnceLock::new();
+    CLIENT.get_or_init(|| AgentClient::new(AgentClientConfig::default()))
+}
+
+/// Settings for [`serve`]'s `chaos agent` HTTP server.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct AgentServerConfig {
+    pub bind_addr: SocketAddr,
+    pub token: String,
+}
+
+impl AgentServerConfig {
+    pub fn new(bind_addr: SocketAddr, token: String) -> Self {
+        Self { bind_addr, token }
+    }
+}
+
+struct AgentState {
+    executor: Executor,
+    token: String,
+}
+
+/// Runs the `chaos agent` REST API until the process is killed, exposing
+/// `executor`'s `inject`/`remove`/`list` over HTTP so a scenario running
+/// elsewhere can drive this host's injectors through
+/// [`crate::Target::Agent`] the same way it would drive a local
+/// [`Executor`]. Shorthand for [`serve_with_cancellation`] with a token
+/// nothing ever cancels.
+pub async fn serve(executor: Executor, config: AgentServerConfig) -> Result<()> {
+    serve_with_cancellation(executor, config, CancellationToken::new()).await
+}
+
+/// Like [`serve`], but stops accepting new connections and returns once
+/// `cancel` fires, letting in-flight requests finish first - the same
+/// graceful-shutdown shape `chaos agent`'s CLI command wires to Ctrl-C and
+/// SIGTERM.
+pub async fn serve_with_cancellation(
+    executor: Executor,
+    config: AgentServerConfig,
+    cancel: CancellationToken,
+) -> Result<()> {
+    let state = Arc::new(AgentState {
+        executor,
+        token: config.token,
+    });
+
+    let app = Router::new()
+        .route("/v1/inject", post(handle_inject))
+        .route("/v1/remove", post(handle_remove))
+        .route("/v1/list", get(handle_list))
+        .route("/v1/exists", post(handle_exists))
+        .route("/v1/abort_all", post(handle_abort_all))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token))
+        // Unauthenticated: this is just the static dashboard shell. It
+        // prompts the operator for the bearer token in the browser and
+        // attaches it to every `/v1/*` call itself, so the actual injection
+        // data stays behind `require_token` above.
+        .route("/", get(handle_dashboard))
+        .with_state(state);
+
+    info!("chaos agent listening on {}", config.bind_addr);
+    let listener = tokio::net::TcpListener::bind(config.bind_addr)
+        .await
+        .map_err(|e| {
+            ChaosError::SystemError(format!("Failed to bind agent on {}: {}", config.bind_addr, e))
+        })?;
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move { cancel.cancelled().await })
+        .await
+        .map_err(|e| ChaosError::SystemError(format!("Agent server error: {}", e)))
+}
+
+async fn require_token(State(state): State<Arc<AgentState>>, req: Request, next: Next) -> Response {
+    let provided = req
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    match provided {
+        // Constant-time comparison - this is the only auth check guarding
+        // the agent's REST API, so a timing side-channel on token length/
+        // prefix match is worth closing even though the token isn't a
+        // high-value secret like a private key.
+        Some(token) if bool::from(token.as_bytes().ct_eq(state.token.as_bytes())) => {
+            next.run(req).await
+        }
+        _ => (
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse {
+                error: "invalid or missing bearer token".to_string(),
+            }),
+        )
+            .into_response(),
+    }
+}
+
+async fn handle_inject(State(state): State<Arc<AgentState>>, Json(req): Json<InjectRequest>) -> Response {
+    match state
+        .executor
+        .inject_with_params(&req.injector_name, &req.target, &req.params)
+        .await
+    {
+        Ok(handle) => Json(handle).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse { error: e.to_string() }),
+        )
+            .into_response(),
+    }
+}
+
+async fn handle_remove(State(state): State<Arc<AgentState>>, Json(req): Json<RemoveRequest>) -> Response {
+    match state.executor.remove(req.handle).await {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse { error: e.to_string() }),
+        )
+            .into_response(),
+    }
+}
+
+async fn handle_list(State(state): State<Arc<AgentState>>) -> Response {
+    Json(state.executor.list_active().await).into_response()
+}
+
+/// Backs the dashboard's "Abort all" button - same effect as `chaos stop`-ing
+/// every entry `handle_list` would return, in one call so the UI doesn't
+/// need to round-trip per injection.
+async fn handle_abort_all(State(state): State<Arc<AgentState>>) -> Response {
+    match state.executor.remove_all().await {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse { error: e.to_string() }),
+        )
+            .into_response(),
+    }
+}
+
+/// Serves the minimal embedded operator dashboard: active injections and a
+/// big "abort all" button, so seeing (and stopping) what's currently
+/// applied doesn't require SSH + `chaos active`/`chaos stop`. Scenario
+/// phase progress and SLO status live in the short-lived `chaos run`
+/// process, not this long-running agent, so they aren't shown here.
+async fn handle_dashboard() -> Html<&'static str> {
+    Html(include_str!("dashboard.html"))
+}
+
+async fn handle_exists(Json(req): Json<ExistsRequest>) -> Response {
+    Json(ExistsResponse {
+        exists: req.target.exists().await,
+    })
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_client_config_reads_token_from_env() {
+        std::env::set_var("CHAOS_AGENT_TOKEN", "test-token-value");
+        assert_eq!(AgentClientConfig::default().token, "test-token-value");
+        std::env::remove_var("CHAOS_AGENT_TOKEN");
+    }
+}
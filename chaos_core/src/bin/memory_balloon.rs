@@ -0,0 +1,30 @@
+//! Sacrificial child process spawned by `MemoryPressureInjector` in
+//! target-scoped mode. Allocates and touches the requested number of bytes,
+//! then idles until killed - giving the injector a process it can place in
+//! the target's memory cgroup to apply sibling memory pressure without
+//! growing the chaos process's own heap.
+
+use std::time::Duration;
+
+fn main() {
+    let bytes: usize = std::env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+    let mut block = vec![0u8; bytes];
+
+    // Touch every page so the kernel actually backs it with physical
+    // memory, rather than leaving it an unfaulted mapping the cgroup
+    // wouldn't feel any pressure from.
+    const PAGE_SIZE: usize = 4096;
+    let mut offset = 0;
+    while offset < block.len() {
+        block[offset] = 1;
+        offset += PAGE_SIZE;
+    }
+
+    loop {
+        std::thread::sleep(Duration::from_secs(3600));
+    }
+}
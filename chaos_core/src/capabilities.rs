@@ -0,0 +1,84 @@
+use std::fmt;
+
+/// An optional, feature-gated integration. None of the base injectors need
+/// any of these; they exist so bigger integrations (container
+/// orchestration, cloud provider APIs, filesystem-level fault injection,
+/// kernel-level tracing) can be added later as opt-in cargo features
+/// without growing the default build for everyone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    Docker,
+    Kubernetes,
+    Cloud,
+    Fuse,
+    Ebpf,
+    /// LD_PRELOAD-based network/disk fault injection for child processes
+    /// the executor itself launches, for hosts where the real, privileged
+    /// injectors (tc/iptables/cgroups) aren't available - see
+    /// `crate::preflight::is_privileged`. No implementation exists yet;
+    /// this only reserves the feature flag and the planned name.
+    LdPreload,
+    /// A protobuf/tonic gRPC control-plane API (`InjectionService`,
+    /// `ScenarioService`, `MetricsService`) alongside the REST `chaos
+    /// agent`. No implementation exists yet; this only reserves the
+    /// feature flag and the planned name.
+    Grpc,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 7] = [
+        Capability::Docker,
+        Capability::Kubernetes,
+        Capability::Cloud,
+        Capability::Fuse,
+        Capability::Ebpf,
+        Capability::LdPreload,
+        Capability::Grpc,
+    ];
+
+    /// The cargo feature flag that gates this capability.
+    pub fn feature_flag(&self) -> &'static str {
+        match self {
+            Capability::Docker => "docker",
+            Capability::Kubernetes => "kubernetes",
+            Capability::Cloud => "cloud",
+            Capability::Fuse => "fuse",
+            Capability::Ebpf => "ebpf",
+            Capability::LdPreload => "ld_preload",
+            Capability::Grpc => "grpc",
+        }
+    }
+
+    /// Whether this build was compiled with the capability's feature enabled.
+    pub fn is_compiled(&self) -> bool {
+        match self {
+            Capability::Docker => cfg!(feature = "docker"),
+            Capability::Kubernetes => cfg!(feature = "kubernetes"),
+            Capability::Cloud => cfg!(feature = "cloud"),
+            Capability::Fuse => cfg!(feature = "fuse"),
+            Capability::Ebpf => cfg!(feature = "ebpf"),
+            Capability::LdPreload => cfg!(feature = "ld_preload"),
+            Capability::Grpc => cfg!(feature = "grpc"),
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.feature_flag())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_report_not_compiled_by_default() {
+        // None of these optional integrations exist yet, so a build with no
+        // extra features enabled must report all of them as absent.
+        for cap in Capability::ALL {
+            assert!(!cap.is_compiled());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::{ChaosError, Result};
+use crate::injectors::network::CHAOS_IPTABLES_COMMENT;
+use crate::system_backend::SystemBackend;
+use serde::Serialize;
+
+/// A leftover artifact `chaos cleanup` found on the host that this crate's
+/// injectors could have created, discovered by scanning the host directly
+/// rather than from any saved [`crate::state_file::StateFile`] - the only
+/// way to find anything left behind by a run whose executor was killed
+/// before it ever wrote (or finished writing) that state.
+#[derive(Debug, Clone, Serialize)]
+pub enum OrphanArtifact {
+    /// A `netem`/`loss` tc qdisc still attached to an interface, left by
+    /// `network_latency`/`packet_loss` whose process died before `remove` ran.
+    NetemQdisc { interface: String },
+    /// An iptables `OUTPUT` rule carrying [`CHAOS_IPTABLES_COMMENT`], left by
+    /// `tcp_reset`.
+    IptablesRule { delete_args: Vec<String> },
+    /// A `chaos_*` cgroup directory under the cpu or memory hierarchy, such
+    /// as `chaos_cpu_<pid>_<id>` left by `cpu_quota` or
+    /// `chaos_mem_failure_<pid>_<id>`/`chaos_mem_pressure_<pid>_<id>` left by
+    /// `memory_pressure`.
+    ChaosCgroup { path: String },
+    /// A `chaos_*` file in the system temp directory, such as the marker
+    /// file left by `disk_slow`, or a scratch file left by `disk_space` or
+    /// `log_flood` when pointed at that directory.
+    TmpFile { path: String },
+}
+
+impl OrphanArtifact {
+    /// One-line, human-readable description for `chaos cleanup`'s output.
+    pub fn description(&self) -> String {
+        match self {
+            Self::NetemQdisc { interface } => format!("netem qdisc on interface {}", interface),
+            Self::IptablesRule { delete_args } => {
+                format!("iptables rule (iptables {})", delete_args.join(" "))
+            }
+            Self::ChaosCgroup { path } => format!("cgroup {}", path),
+            Self::TmpFile { path } => format!("file {}", path),
+        }
+    }
+
+    /// Tears the artifact down. Best-effort: a qdisc or iptables rule that
+    /// another process already removed between scan and remove is not an
+    /// error, since the end state (gone) is what was wanted either way.
+    pub async fn remove(&self, backend: &dyn SystemBackend) -> Result<()> {
+        match self {
+            Self::NetemQdisc { interface } => {
+                backend
+                    .run_command("tc", &["qdisc", "del", "dev", interface, "root"])
+                    .await?;
+                Ok(())
+            }
+            Self::IptablesRule { delete_args } => {
+                let args: Vec<&str> = delete_args.iter().map(String::as_str).collect();
+                backend.run_command("iptables", &args).await?;
+                Ok(())
+            }
+            Self::ChaosCgroup { path } => tokio::fs::remove_dir(path)
+                .await
+                .map_err(|e| ChaosError::CleanupFailed(format!("failed to remove {}: {}", path, e))),
+            Self::TmpFile { path } => tokio::fs::remove_file(path)
+                .await
+                .map_err(|e| ChaosError::CleanupFailed(format!("failed to remove {}: {}", path, e))),
+        }
+    }
+}
+
+/// Scans the host for every kind of artifact `chaos` injectors can leave
+/// behind, independent of any saved executor state. Individual scans are
+/// best-effort: a missing `/sys/fs/cgroup/cpu` (cgroup v2, or no Linux at
+/// all) or a failing `tc`/`iptables` invocation just yields no results for
+/// that category rather than failing the whole scan.
+pub async fn scan(backend: &dyn SystemBackend) -> Vec<OrphanArtifact> {
+    let mut orphans = Vec::new();
+    orphans.extend(scan_netem_qdiscs(backend).await);
+    orphans.extend(scan_iptables_rules(backend).await);
+    orphans.extend(scan_chaos_cgroups().await);
+    orphans.extend(scan_tmp_files().await);
+    orphans
+}
+
+async fn scan_netem_qdiscs(backend: &dyn SystemBackend) -> Vec<OrphanArtifact> {
+    let mut orphans = Vec::new();
+
+    let Ok(mut entries) = tokio::fs::read_dir("/sys/class/net").await else {
+        return orphans;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let Some(interface) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+
+        let Ok(output) = backend
+            .run_command("tc", &["qdisc", "show", "dev", &interface])
+            .await
+        else {
+            continue;
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.contains("netem") || stdout.contains("loss") {
+            orphans.push(OrphanArtifact::NetemQdisc { interface });
+        }
+    }
+
+    orphans
+}
+
+async fn scan_iptables_rules(backend: &dyn SystemBackend) -> Vec<OrphanArtifact> {
+    let Ok(output) = backend.run_command("iptables", &["-S", "OUTPUT"]).await else {
+        return Vec::new();
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.contains(CHAOS_IPTABLES_COMMENT))
+        .map(|line| {
+            let mut tokens: Vec<String> = line.split_whitespace().map(String::from).collect();
+            if tokens.first().map(String::as_str) == Some("-A") {
+                tokens[0] = "-D".to_string();
+            }
+            OrphanArtifact::IptablesRule { delete_args: tokens }
+        })
+        .collect()
+}
+
+async fn scan_chaos_cgroups() -> Vec<OrphanArtifact> {
+    let mut orphans = Vec::new();
+
+    for root in ["/sys/fs/cgroup/cpu", "/sys/fs/cgroup/memory"] {
+        let Ok(mut entries) = tokio::fs::read_dir(root).await else {
+            continue;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if name.starts_with("chaos_") {
+                orphans.push(OrphanArtifact::ChaosCgroup {
+                    path: entry.path().to_string_lossy().to_string(),
+                });
+            }
+        }
+    }
+
+    orphans
+}
+
+async fn scan_tmp_files() -> Vec<OrphanArtifact> {
+    let mut orphans = Vec::new();
+
+    let Ok(mut entries) = tokio::fs::read_dir(std::env::temp_dir()).await else {
+        return orphans;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        if name.starts_with("chaos_") {
+            orphans.push(OrphanArtifact::TmpFile {
+                path: entry.path().to_string_lossy().to_string(),
+            });
+        }
+    }
+
+    orphans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system_backend::RecordingSystemBackend;
+
+    #[tokio::test]
+    async fn test_scan_iptables_rules_only_matches_chaos_marker() {
+        let backend = RecordingSystemBackend::new();
+        // Exercise the parser directly against representative `iptables -S`
+        // output rather than the real scan_iptables_rules, since that
+        // requires RecordingSystemBackend to return canned stdout, which it
+        // doesn't support - so build the same line-parsing logic's input by
+        // hand and check the derived delete args.
+        let line = format!(
+            "-A OUTPUT -p tcp --dport 8080 -j REJECT --reject-with tcp-reset -m comment --comment {}",
+            CHAOS_IPTABLES_COMMENT
+        );
+        let mut tokens: Vec<String> = line.split_whitespace().map(String::from).collect();
+        if tokens.first().map(String::as_str) == Some("-A") {
+            tokens[0] = "-D".to_string();
+        }
+
+        assert_eq!(tokens[0], "-D");
+        assert!(tokens.contains(&CHAOS_IPTABLES_COMMENT.to_string()));
+
+        // scan_iptables_rules itself still runs against a backend that never
+        // returns matching output, so it should report nothing found.
+        assert!(scan_iptables_rules(&backend).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_orphan_artifact_remove_dispatches_through_backend() {
+        let backend = RecordingSystemBackend::new();
+
+        OrphanArtifact::NetemQdisc {
+            interface: "eth0".to_string(),
+        }
+        .remove(&backend)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec![crate::system_backend::RecordedCall::RunCommand {
+                program: "tc".to_string(),
+                args: vec![
+                    "qdisc".to_string(),
+                    "del".to_string(),
+                    "dev".to_string(),
+                    "eth0".to_string(),
+                    "root".to_string(),
+                ],
+            }]
+        );
+    }
+}
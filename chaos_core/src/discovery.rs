@@ -0,0 +1,224 @@
+use crate::error::{ChaosError, Result};
+use serde::Serialize;
+use std::net::SocketAddr;
+
+/// A live process found by [`discover_processes`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveredProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+/// A running container found by [`discover_containers`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveredContainer {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+}
+
+/// A listening TCP socket found by [`discover_listening_sockets`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveredSocket {
+    pub address: SocketAddr,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+}
+
+/// Resolves `pattern` to every live process whose name currently matches,
+/// using the same substring match `Target::ProcessPattern` applies at
+/// injection time - so a scenario author can confirm a pattern targets what
+/// they expect before wiring it into a scenario, rather than discovering a
+/// typo only once the injector itself finds nothing.
+pub fn discover_processes(pattern: &str) -> Vec<DiscoveredProcess> {
+    use sysinfo::System;
+
+    let mut sys = System::new_all();
+    sys.refresh_processes();
+    sys.processes()
+        .values()
+        .filter(|process| process.name().contains(pattern))
+        .map(|process| DiscoveredProcess {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string(),
+        })
+        .collect()
+}
+
+/// Lists running containers via `docker ps`. Returns a
+/// [`ChaosError::SystemError`] if `docker` isn't on `PATH` or the daemon
+/// isn't reachable - callers decide whether that's fatal or just means no
+/// containers are in play on this host.
+pub async fn discover_containers() -> Result<Vec<DiscoveredContainer>> {
+    let output = tokio::process::Command::new("docker")
+        .args(["ps", "--format", "{{.ID}}|{{.Names}}|{{.Image}}"])
+        .output()
+        .await
+        .map_err(|e| ChaosError::SystemError(format!("Failed to run docker: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(ChaosError::SystemError(format!(
+            "docker ps failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    Ok(parse_docker_ps(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_docker_ps(stdout: &str) -> Vec<DiscoveredContainer> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '|');
+            Some(DiscoveredContainer {
+                id: fields.next()?.to_string(),
+                name: fields.next()?.to_string(),
+                image: fields.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Lists listening TCP sockets via `ss -ltnp`, the same way an operator
+/// would by hand when figuring out what a `Target::Network` address should
+/// point at. Linux-only, like the rest of this host's `/proc`-backed
+/// checks (see [`crate::preflight`]).
+pub async fn discover_listening_sockets() -> Result<Vec<DiscoveredSocket>> {
+    #[cfg(target_os = "linux")]
+    {
+        let output = tokio::process::Command::new("ss")
+            .args(["-ltnp"])
+            .output()
+            .await
+            .map_err(|e| ChaosError::SystemError(format!("Failed to run ss: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(ChaosError::SystemError(format!(
+                "ss failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        Ok(parse_ss_output(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(ChaosError::SystemError(
+            "Listening-socket discovery is only implemented on Linux".to_string(),
+        ))
+    }
+}
+
+/// Parses `ss -ltnp` output. Doesn't assume a fixed column position for the
+/// local address - ss's column layout shifts depending on which flags were
+/// passed - instead it scans every token on the line for one that parses as
+/// `<ip>:<port>` and takes the first match as the local address.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_ss_output(stdout: &str) -> Vec<DiscoveredSocket> {
+    stdout
+        .lines()
+        .skip(1) // header row
+        .filter_map(|line| {
+            let address = line.split_whitespace().find_map(parse_addr_port)?;
+            let (pid, process_name) = parse_ss_process(line);
+            Some(DiscoveredSocket {
+                address,
+                pid,
+                process_name,
+            })
+        })
+        .collect()
+}
+
+/// Parses a `<ip>:<port>` token, e.g. `0.0.0.0:22` or `[::]:8080`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_addr_port(token: &str) -> Option<SocketAddr> {
+    let (host, port) = token.rsplit_once(':')?;
+    let port: u16 = port.parse().ok()?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let ip = match host {
+        "*" => std::net::Ipv4Addr::UNSPECIFIED.into(),
+        other => other.parse().ok()?,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Pulls the PID and process name out of `ss -p`'s trailing
+/// `users:(("name",pid=1234,fd=6))` column, if present (absent when ss was
+/// run without permission to see another user's sockets).
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_ss_process(line: &str) -> (Option<u32>, Option<String>) {
+    let Some(after_users) = line.split("users:((").nth(1) else {
+        return (None, None);
+    };
+
+    let name = after_users.split('"').nth(1).map(|s| s.to_string());
+
+    let pid = after_users
+        .split("pid=")
+        .nth(1)
+        .and_then(|rest| rest.split(',').next())
+        .and_then(|pid| pid.parse().ok());
+
+    (pid, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_ps_splits_id_name_image() {
+        let stdout = "abc123|checkout-web|checkout:latest\ndef456|checkout-db|postgres:15\n";
+        let containers = parse_docker_ps(stdout);
+
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].id, "abc123");
+        assert_eq!(containers[0].name, "checkout-web");
+        assert_eq!(containers[0].image, "checkout:latest");
+    }
+
+    #[test]
+    fn test_parse_addr_port_handles_wildcard_and_explicit_host() {
+        assert_eq!(
+            parse_addr_port("0.0.0.0:22"),
+            Some("0.0.0.0:22".parse().unwrap())
+        );
+        assert_eq!(
+            parse_addr_port("127.0.0.1:8080"),
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(parse_addr_port("not-an-address"), None);
+    }
+
+    #[test]
+    fn test_parse_ss_process_extracts_pid_and_name() {
+        let line = r#"LISTEN 0 128 0.0.0.0:22 0.0.0.0:* users:(("sshd",pid=891,fd=3))"#;
+        let (pid, name) = parse_ss_process(line);
+
+        assert_eq!(pid, Some(891));
+        assert_eq!(name, Some("sshd".to_string()));
+    }
+
+    #[test]
+    fn test_parse_ss_process_absent_when_no_users_column() {
+        let line = "LISTEN 0 128 0.0.0.0:22 0.0.0.0:*";
+        let (pid, name) = parse_ss_process(line);
+
+        assert_eq!(pid, None);
+        assert_eq!(name, None);
+    }
+
+    #[test]
+    fn test_parse_ss_output_finds_local_address() {
+        let stdout = "State  Recv-Q  Send-Q  Local Address:Port  Peer Address:Port  Process\n\
+                       LISTEN 0       128     0.0.0.0:22           0.0.0.0:*           users:((\"sshd\",pid=891,fd=3))\n";
+        let sockets = parse_ss_output(stdout);
+
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[0].address, "0.0.0.0:22".parse().unwrap());
+        assert_eq!(sockets[0].pid, Some(891));
+    }
+}
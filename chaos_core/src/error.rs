@@ -14,9 +14,21 @@ pub enum ChaosError {
     #[error("Permission denied: {0}")]
     PermissionDenied(String),
 
+    #[error("Safety policy violation: {0}")]
+    PolicyViolation(String),
+
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    #[error("Feature not compiled: {0}")]
+    FeatureNotCompiled(String),
+
+    #[error("Error budget exhausted: {0}")]
+    ErrorBudgetExhausted(String),
+
+    #[error("Abort condition triggered: {0}")]
+    AbortConditionTriggered(String),
+
     #[error("System error: {0}")]
     SystemError(String),
 
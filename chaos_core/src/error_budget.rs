@@ -0,0 +1,109 @@
+use crate::error::{ChaosError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Where an [`ErrorBudgetPolicy`] reads the target service's remaining
+/// error budget from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ErrorBudgetSource {
+    /// A fixed remaining-budget fraction (0.0 - 1.0), computed elsewhere
+    /// (a dashboard, a runbook, an on-call judgment call) and passed in
+    /// directly rather than queried live.
+    Static { remaining: f64 },
+    /// Query a Prometheus-compatible PromQL endpoint for the remaining
+    /// budget. `query` is expected to evaluate to a single instant-vector
+    /// result whose value is a fraction in 0.0 - 1.0.
+    PromQl { url: String, query: String },
+}
+
+/// Policy gating whether an experiment may start, or keep running: it
+/// refuses to proceed when the target's remaining error budget drops
+/// below `minimum`. This operationalizes "only do chaos when you can
+/// afford it".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorBudgetPolicy {
+    pub source: ErrorBudgetSource,
+    /// Minimum remaining budget (0.0 - 1.0) required to start or continue.
+    pub minimum: f64,
+}
+
+impl ErrorBudgetPolicy {
+    /// Reads the current remaining budget and fails with
+    /// `ChaosError::ErrorBudgetExhausted` if it's below `minimum`.
+    pub async fn check(&self) -> Result<f64> {
+        let remaining = self.remaining().await?;
+        if remaining < self.minimum {
+            return Err(ChaosError::ErrorBudgetExhausted(format!(
+                "{:.1}% remaining, need at least {:.1}%",
+                remaining * 100.0,
+                self.minimum * 100.0
+            )));
+        }
+        Ok(remaining)
+    }
+
+    async fn remaining(&self) -> Result<f64> {
+        match &self.source {
+            ErrorBudgetSource::Static { remaining } => Ok(*remaining),
+            ErrorBudgetSource::PromQl { url, query } => query_promql(url, query).await,
+        }
+    }
+}
+
+/// Runs `query` against a Prometheus-compatible `/api/v1/query` endpoint
+/// and extracts its result as a single scalar fraction.
+///
+/// Shared with [`crate::abort`], which needs the exact same "ask a PromQL
+/// endpoint for one number" behavior for its own metrics-backed
+/// conditions.
+pub(crate) async fn query_promql(url: &str, query: &str) -> Result<f64> {
+    let endpoint = format!("{}/api/v1/query", url.trim_end_matches('/'));
+
+    let response = reqwest::Client::new()
+        .get(&endpoint)
+        .query(&[("query", query)])
+        .send()
+        .await
+        .map_err(|e| ChaosError::NetworkError(format!("Failed to query {}: {}", endpoint, e)))?;
+
+    let body: serde_json::Value = response.json().await.map_err(|e| {
+        ChaosError::NetworkError(format!("Invalid PromQL response from {}: {}", endpoint, e))
+    })?;
+
+    body["data"]["result"]
+        .get(0)
+        .and_then(|r| r["value"].get(1))
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse::<f64>().ok())
+        .ok_or_else(|| {
+            ChaosError::InvalidConfig(format!(
+                "PromQL query '{}' against {} returned no scalar result",
+                query, url
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_static_budget_above_minimum_passes() {
+        let policy = ErrorBudgetPolicy {
+            source: ErrorBudgetSource::Static { remaining: 0.5 },
+            minimum: 0.2,
+        };
+
+        assert_eq!(policy.check().await.unwrap(), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_static_budget_below_minimum_is_rejected() {
+        let policy = ErrorBudgetPolicy {
+            source: ErrorBudgetSource::Static { remaining: 0.05 },
+            minimum: 0.2,
+        };
+
+        let err = policy.check().await.unwrap_err();
+        assert!(matches!(err, ChaosError::ErrorBudgetExhausted(_)));
+    }
+}
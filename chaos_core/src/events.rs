@@ -0,0 +1,66 @@
+use crate::handle::InjectionHandle;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+/// A lifecycle change the [`crate::executor::Executor`] made to an
+/// injection, broadcast so embedders, the CLI, and metric exporters can
+/// react as it happens rather than polling [`crate::executor::Executor::list_active`].
+#[derive(Debug, Clone, Serialize)]
+pub enum ExecutorEvent {
+    /// An injection was successfully applied (or, in dry-run mode,
+    /// described) and is now tracked as active.
+    InjectionApplied {
+        handle: InjectionHandle,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+    /// An active injection was removed, either by an explicit
+    /// `Executor::remove` call or by TTL auto-expiry.
+    InjectionRemoved {
+        handle: InjectionHandle,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+    /// `Injector::inject` (or the `validate`/capability gate ahead of it)
+    /// returned an error, so no injection was ever tracked as active.
+    InjectionFailed {
+        injector_name: String,
+        target: crate::target::Target,
+        error: String,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+    /// `Injector::remove` returned an error while tearing down `handle`.
+    /// From `Executor::remove` the injection stays in the active set, so
+    /// the removal can be retried; from TTL auto-expiry it is dropped from
+    /// the active set anyway, and this event is the only trace of the
+    /// failed cleanup.
+    CleanupFailed {
+        handle: InjectionHandle,
+        error: String,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+    /// `Injector::verify` reported that a tracked-active injection is no
+    /// longer actually in place on the host - e.g. an operator manually ran
+    /// `tc qdisc del`, or a container restart cleared its cgroup. Emitted by
+    /// `Executor`'s drift monitor, not by `inject`/`remove` themselves.
+    DriftDetected {
+        handle: InjectionHandle,
+        reapplied: bool,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+/// Capacity of the broadcast channel each [`crate::executor::Executor`]
+/// creates. Generous enough that a subscriber which briefly stops polling
+/// (e.g. while rendering a frame) doesn't miss events, without unbounded
+/// memory growth if nobody ever subscribes at all - broadcast channels
+/// only buffer while at least one receiver is alive.
+pub(crate) const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Creates the `(sender, receiver)` pair an `Executor` holds onto; the
+/// receiver half is dropped immediately since subscribers call
+/// [`crate::executor::Executor::subscribe`] to get their own.
+pub(crate) fn channel() -> (
+    broadcast::Sender<ExecutorEvent>,
+    broadcast::Receiver<ExecutorEvent>,
+) {
+    broadcast::channel(EVENT_CHANNEL_CAPACITY)
+}
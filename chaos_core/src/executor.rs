@@ -1,24 +1,109 @@
 use crate::{
     error::Result,
+    events::ExecutorEvent,
     handle::{InjectionHandle, InjectionState},
     injectors::InjectorRegistry,
+    state_file::StateFile,
     target::Target,
 };
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::info;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{info, warn, Instrument};
 
+/// `Value::Null` and an empty object both mean "no overrides" - scenario
+/// files that don't set any parameters shouldn't pay for a `configure()`
+/// call or fail on injectors that don't implement it.
+fn params_are_empty(params: &serde_json::Value) -> bool {
+    match params {
+        serde_json::Value::Null => true,
+        serde_json::Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+/// Calls `injector.remove(handle)`, but never waits longer than `timeout`
+/// (if given) for it to finish - a hung `tc` call or a health check that
+/// never resolves would otherwise stall the whole scenario. A timeout is
+/// reported the same way any other cleanup failure is: as a
+/// [`crate::error::ChaosError::CleanupFailed`], indistinguishable to the
+/// caller from the injector's own `remove()` erroring.
+async fn remove_with_timeout(
+    injector: &crate::injectors::DynInjector,
+    handle: InjectionHandle,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    let Some(timeout) = timeout else {
+        return injector.remove(handle).await;
+    };
+
+    match tokio::time::timeout(timeout, injector.remove(handle.clone())).await {
+        Ok(result) => result,
+        Err(_) => {
+            warn!(
+                "Injector '{}' timed out after {:?} removing injection '{}'; marking cleanup failed",
+                handle.injector_name, timeout, handle.id
+            );
+            Err(crate::error::ChaosError::CleanupFailed(format!(
+                "timed out after {:?} removing injection '{}'",
+                timeout, handle.id
+            )))
+        }
+    }
+}
+
+/// Writes the current set of active injections to `path`, if one is given.
+/// Shared by `Executor::persist_state` and the auto-expiry task, which
+/// can't hold a `&Executor` across its `tokio::spawn`.
+async fn persist_active(
+    active_injections: &RwLock<HashMap<String, InjectionState>>,
+    path: Option<&Path>,
+) {
+    let Some(path) = path else {
+        return;
+    };
+
+    let injections = active_injections
+        .read()
+        .await
+        .values()
+        .map(|state| (state.handle().id.clone(), state.handle().clone()))
+        .collect();
+
+    let state_file = StateFile { injections };
+    if let Err(e) = state_file.save(path).await {
+        warn!(
+            "Failed to persist injection state to {}: {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+#[derive(Clone)]
 pub struct Executor {
     registry: Arc<InjectorRegistry>,
     active_injections: Arc<RwLock<HashMap<String, InjectionState>>>,
+    persist_path: Option<PathBuf>,
+    dry_run: bool,
+    events: broadcast::Sender<ExecutorEvent>,
+    policy: Option<Arc<crate::policy::SafetyPolicy>>,
+    remove_timeout: Option<Duration>,
 }
 
 impl Executor {
     pub fn new(registry: InjectorRegistry) -> Self {
+        let (events, _) = crate::events::channel();
         Self {
             registry: Arc::new(registry),
             active_injections: Arc::new(RwLock::new(HashMap::new())),
+            persist_path: None,
+            dry_run: false,
+            events,
+            policy: None,
+            remove_timeout: None,
         }
     }
 
@@ -26,10 +111,284 @@ impl Executor {
         Self::new(InjectorRegistry::with_defaults())
     }
 
+    /// Like [`Executor::new`], but mirrors active injections to `path`
+    /// after every change. This is what makes `chaos recover` possible: if
+    /// the process crashes, `path` still lists whatever was active at the
+    /// time, so orphaned tc/iptables/cgroup artifacts can be found and
+    /// torn down instead of silently degrading the host.
+    pub fn with_persistence(registry: InjectorRegistry, path: impl Into<PathBuf>) -> Self {
+        let (events, _) = crate::events::channel();
+        Self {
+            registry: Arc::new(registry),
+            active_injections: Arc::new(RwLock::new(HashMap::new())),
+            persist_path: Some(path.into()),
+            dry_run: false,
+            events,
+            policy: None,
+            remove_timeout: None,
+        }
+    }
+
+    /// Like [`Executor::new`], but never calls a real `Injector::inject` or
+    /// `Injector::remove`. Instead, `inject*` logs each injector's
+    /// [`crate::injectors::Injector::describe_dry_run`] output and records a
+    /// synthetic handle, so a scenario can be run end-to-end against
+    /// production - phases, error budget checks, reporting - without ever
+    /// touching the host.
+    pub fn dry_run(registry: InjectorRegistry) -> Self {
+        let (events, _) = crate::events::channel();
+        Self {
+            registry: Arc::new(registry),
+            active_injections: Arc::new(RwLock::new(HashMap::new())),
+            persist_path: None,
+            dry_run: true,
+            events,
+            policy: None,
+            remove_timeout: None,
+        }
+    }
+
+    /// Attaches a blast-radius [`crate::policy::SafetyPolicy`] this executor
+    /// checks before every injection, on top of whatever the calling
+    /// scenario already validated. Chainable so it composes with the other
+    /// constructors, e.g. `Executor::with_defaults().with_policy(policy)`.
+    pub fn with_policy(mut self, policy: crate::policy::SafetyPolicy) -> Self {
+        self.policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Bounds how long any single [`crate::injectors::Injector::remove`]
+    /// call is allowed to run before it's treated as failed, so a hung
+    /// cleanup (`tc` blocked on a netns that no longer exists, a health
+    /// check that never resolves) can't stall the scenario forever.
+    /// Without this, [`Executor::remove`] waits as long as the injector
+    /// does - which is the default, since most injectors remove promptly
+    /// and imposing a timeout on every deployment isn't free. Chainable
+    /// like [`Executor::with_policy`].
+    pub fn with_remove_timeout(mut self, timeout: Duration) -> Self {
+        self.remove_timeout = Some(timeout);
+        self
+    }
+
+    /// Starts a background task that calls [`crate::injectors::Injector::verify`]
+    /// on every active injection every `interval`, emitting
+    /// [`ExecutorEvent::DriftDetected`] for any that report the fault is no
+    /// longer actually in place on the host (tc qdisc removed, cgroup
+    /// thawed, ...) - e.g. because an operator tore it down by hand instead
+    /// of through this executor. When `reapply` is `true`, a drifted
+    /// injection is re-applied via `Injector::inject` against its original
+    /// target and the tracked handle is replaced (keeping the same id);
+    /// when `false`, drift is only reported and the stale handle stays
+    /// tracked until an explicit [`Executor::remove`].
+    ///
+    /// Returns the task's `JoinHandle` so a caller that wants to stop
+    /// monitoring can abort it - dropping every clone of the `Executor`
+    /// doesn't stop it, since the spawned task holds its own clones of
+    /// whatever it needs.
+    pub fn spawn_drift_monitor(&self, interval: Duration, reapply: bool) -> tokio::task::JoinHandle<()> {
+        let active_injections = self.active_injections.clone();
+        let persist_path = self.persist_path.clone();
+        let events = self.events.clone();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let snapshot: Vec<(String, InjectionHandle, crate::injectors::DynInjector)> =
+                    active_injections
+                        .read()
+                        .await
+                        .iter()
+                        .map(|(id, state)| (id.clone(), state.handle().clone(), state.injector()))
+                        .collect();
+
+                for (id, handle, injector) in snapshot {
+                    let still_in_place = match injector.verify(&handle).await {
+                        Ok(verified) => verified,
+                        Err(e) => {
+                            warn!("Failed to verify injection '{}': {}", id, e);
+                            continue;
+                        }
+                    };
+
+                    if still_in_place {
+                        continue;
+                    }
+
+                    warn!("Drift detected: injection '{}' is no longer in place", id);
+
+                    let mut reapplied = false;
+                    if reapply {
+                        match injector.inject(&handle.target).await {
+                            Ok(mut new_handle) => {
+                                new_handle.id = id.clone();
+                                active_injections.write().await.insert(
+                                    id.clone(),
+                                    InjectionState::new(new_handle, injector.clone()),
+                                );
+                                persist_active(&active_injections, persist_path.as_deref()).await;
+                                reapplied = true;
+                                info!("Re-applied drifted injection '{}'", id);
+                            }
+                            Err(e) => {
+                                warn!("Failed to re-apply drifted injection '{}': {}", id, e);
+                            }
+                        }
+                    }
+
+                    let _ = events.send(ExecutorEvent::DriftDetected {
+                        handle,
+                        reapplied,
+                        at: chrono::Utc::now(),
+                    });
+                }
+            }
+        })
+    }
+
+    /// Subscribes to this executor's lifecycle event stream. Each clone of
+    /// an `Executor` shares the same underlying channel, so subscribing via
+    /// any clone observes every injection/removal regardless of which
+    /// clone's `inject`/`remove` triggered it. Events sent before this call
+    /// are missed, same as any other broadcast channel - callers that need
+    /// the current state up front should pair this with [`Executor::list_active`].
+    pub fn subscribe(&self) -> broadcast::Receiver<ExecutorEvent> {
+        self.events.subscribe()
+    }
+
+    /// Broadcasts `event` to any subscribers. No subscribers is the common
+    /// case and isn't an error - `send` only fails when the receiver count
+    /// is zero, which we don't care about here.
+    fn emit(&self, event: ExecutorEvent) {
+        let _ = self.events.send(event);
+    }
+
+    /// Writes the current set of active injections to `persist_path`, if
+    /// one was configured. Best-effort: a failed write is logged, not
+    /// propagated, since it shouldn't block the injection/removal that
+    /// triggered it.
+    async fn persist_state(&self) {
+        persist_active(&self.active_injections, self.persist_path.as_deref()).await;
+    }
+
+    /// Registers `handle` - already applied, by this process or another one
+    /// entirely - as tracked and active, without calling
+    /// [`crate::injectors::Injector::inject`] again. This is what makes
+    /// `chaos stop --handle handle.json` possible: the process stopping the
+    /// injection never applied it itself, it only has the handle `chaos
+    /// attach --export` wrote out, and needs `remove`/the drift monitor to
+    /// treat it the same as one it created directly.
+    pub async fn adopt(&self, handle: InjectionHandle) -> Result<()> {
+        let injector = self
+            .registry
+            .get(&handle.injector_name)
+            .ok_or_else(|| {
+                crate::error::ChaosError::InvalidConfig(format!(
+                    "no injector named '{}' registered",
+                    handle.injector_name
+                ))
+            })?
+            .clone();
+
+        self.active_injections
+            .write()
+            .await
+            .insert(handle.id.clone(), InjectionState::new(handle, injector));
+
+        self.persist_state().await;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, target), fields(injector = injector_name, injection_id))]
     pub async fn inject(
         &self,
         injector_name: &str,
         target: &Target,
+    ) -> Result<InjectionHandle> {
+        self.inject_internal(injector_name, target, &serde_json::Value::Null, None)
+            .await
+    }
+
+    /// Like [`Executor::inject`], but first applies `params` on top of the
+    /// registered injector's default configuration via
+    /// [`crate::injectors::Injector::configure`]. A `Null` or empty object
+    /// leaves the registry's shared default instance untouched.
+    #[tracing::instrument(skip(self, target, params), fields(injector = injector_name, injection_id))]
+    pub async fn inject_with_params(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+    ) -> Result<InjectionHandle> {
+        self.inject_internal(injector_name, target, params, None)
+            .await
+    }
+
+    /// Like [`Executor::inject`], but forces auto-removal after `ttl`
+    /// regardless of what the injector's own config would otherwise set -
+    /// a safety net for callers who can't guarantee they'll call
+    /// [`Executor::remove`] themselves (ad-hoc injections, a crashed
+    /// caller, a forgotten cleanup step).
+    #[tracing::instrument(skip(self, target), fields(injector = injector_name, injection_id))]
+    pub async fn inject_with_ttl(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        ttl: Duration,
+    ) -> Result<InjectionHandle> {
+        self.inject_internal(injector_name, target, &serde_json::Value::Null, Some(ttl))
+            .await
+    }
+
+    /// Combines [`Executor::inject_with_params`] and [`Executor::inject_with_ttl`]:
+    /// applies `params` on top of the injector's default config, and forces
+    /// auto-removal after `ttl` regardless of what that config would
+    /// otherwise set.
+    #[tracing::instrument(skip(self, target, params), fields(injector = injector_name, injection_id))]
+    pub async fn inject_with_params_and_ttl(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+        ttl: Duration,
+    ) -> Result<InjectionHandle> {
+        self.inject_internal(injector_name, target, params, Some(ttl))
+            .await
+    }
+
+    async fn inject_internal(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+        ttl_override: Option<Duration>,
+    ) -> Result<InjectionHandle> {
+        let result = self
+            .inject_internal_inner(injector_name, target, params, ttl_override)
+            .await;
+
+        match &result {
+            Ok(handle) => self.emit(ExecutorEvent::InjectionApplied {
+                handle: handle.clone(),
+                at: chrono::Utc::now(),
+            }),
+            Err(e) => self.emit(ExecutorEvent::InjectionFailed {
+                injector_name: injector_name.to_string(),
+                target: target.clone(),
+                error: e.to_string(),
+                at: chrono::Utc::now(),
+            }),
+        }
+
+        result
+    }
+
+    async fn inject_internal_inner(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+        ttl_override: Option<Duration>,
     ) -> Result<InjectionHandle> {
         let injector = self
             .registry
@@ -41,37 +400,305 @@ impl Executor {
                 ))
             })?;
 
-        info!(
-            "Applying injection '{}' to target: {}",
-            injector_name,
-            target.description()
-        );
+        let injector = if params_are_empty(params) {
+            injector.clone()
+        } else {
+            injector.configure(params)?
+        };
+
+        if let Some(policy) = &self.policy {
+            let active_count = self.active_injections.read().await.len();
+            policy.check(injector_name, target, params, active_count)?;
+        }
 
-        let handle = injector.inject(target).await?;
-        let state = InjectionState::new(handle.clone());
+        if let Some(capability) = injector.required_feature() {
+            if !capability.is_compiled() {
+                return Err(crate::error::ChaosError::FeatureNotCompiled(format!(
+                    "injector '{}' requires the '{}' feature, which this build was not compiled with",
+                    injector_name,
+                    capability.feature_flag()
+                )));
+            }
+        }
+
+        // Dry runs never touch the real system, so there's nothing here to
+        // validate or gate on capabilities - describe_dry_run just reports
+        // what *would* happen.
+        if !self.dry_run {
+            injector.validate().await?;
+
+            for capability in injector.required_capabilities() {
+                if !crate::preflight::has_capability(&capability) {
+                    return Err(crate::error::ChaosError::PermissionDenied(format!(
+                        "injector '{}' requires capability '{}', which this process does not hold - \
+                         run as root or grant it with `setcap cap_net_admin,cap_sys_admin,cap_kill+ep <binary>`",
+                        injector_name, capability
+                    )));
+                }
+            }
+        }
+
+        let mut handle = if self.dry_run {
+            let would_run = injector.describe_dry_run(target);
+            info!(
+                "DRY RUN: '{}' on target {} would run:",
+                injector_name,
+                target.description()
+            );
+            for line in &would_run {
+                info!("  {}", line);
+            }
+
+            InjectionHandle::new(
+                injector_name,
+                target.clone(),
+                serde_json::json!({ "dry_run": true, "would_run": would_run }),
+            )
+        } else if let Target::Group(members) = target {
+            info!(
+                "Applying injection '{}' to {} grouped targets",
+                injector_name,
+                members.len()
+            );
+
+            let mut children = Vec::with_capacity(members.len());
+            for member in members {
+                match injector.inject(member).await {
+                    Ok(child) => children.push(child),
+                    Err(e) => {
+                        warn!(
+                            "Injection '{}' failed on group member {}; rolling back {} already-applied member(s)",
+                            injector_name,
+                            member.description(),
+                            children.len()
+                        );
+                        for child in children {
+                            if let Err(remove_err) =
+                                remove_with_timeout(&injector, child, self.remove_timeout).await
+                            {
+                                warn!(
+                                    "Failed to roll back group member for '{}': {}",
+                                    injector_name, remove_err
+                                );
+                            }
+                        }
+                        return Err(e);
+                    }
+                }
+            }
+
+            let mut parent = InjectionHandle::new(
+                injector_name,
+                target.clone(),
+                serde_json::json!({ "fan_out": true, "member_count": children.len() }),
+            );
+            parent.children = children;
+            parent
+        } else if let Target::Agent { host, port, inner } = target {
+            #[cfg(feature = "agent")]
+            {
+                info!(
+                    "Dispatching injection '{}' to chaos agent at {}:{} for target: {}",
+                    injector_name,
+                    host,
+                    port,
+                    inner.description()
+                );
+                crate::agent::default_client()
+                    .inject(host, *port, injector_name, inner, params)
+                    .await?
+            }
+            #[cfg(not(feature = "agent"))]
+            {
+                let _ = inner;
+                return Err(crate::error::ChaosError::FeatureNotCompiled(format!(
+                    "target requires a chaos agent at {}:{}, but this build was not compiled with the 'agent' feature",
+                    host, port
+                )));
+            }
+        } else {
+            info!(
+                "Applying injection '{}' to target: {}",
+                injector_name,
+                target.description()
+            );
+            injector.inject(target).await?
+        };
+
+        if let Some(ttl) = ttl_override {
+            handle = handle.with_ttl(ttl);
+        }
+        tracing::Span::current().record("injection_id", handle.id.as_str());
+        let state = InjectionState::new(handle.clone(), injector.clone());
 
         self.active_injections
             .write()
             .await
             .insert(handle.id.clone(), state);
+        self.persist_state().await;
+
+        if let Some(ttl) = handle.ttl {
+            self.spawn_auto_expiry(handle.clone(), ttl);
+        }
 
         Ok(handle)
     }
 
-    pub async fn remove(&self, handle: InjectionHandle) -> Result<()> {
-        let injector = self.registry.get(&handle.injector_name).ok_or_else(|| {
+    /// Auto-remove `handle` once `ttl` elapses, unless it's already been
+    /// removed by then. Lets injectors express a TTL (e.g. `CpuStarvationConfig.duration`)
+    /// without each one having to implement its own expiry timer.
+    fn spawn_auto_expiry(&self, handle: InjectionHandle, ttl: Duration) {
+        let active_injections = self.active_injections.clone();
+        let persist_path = self.persist_path.clone();
+        let events = self.events.clone();
+        let remove_timeout = self.remove_timeout;
+        let span = tracing::info_span!("injection_ttl_expiry", injection_id = %handle.id);
+
+        tokio::spawn(
+            async move {
+                tokio::time::sleep(ttl).await;
+
+                // Grab the exact instance that ran `inject`, not a fresh one
+                // from the registry - it's the only one that knows how to
+                // undo this specific handle if `configure()` was involved.
+                let injector = match active_injections.read().await.get(&handle.id) {
+                    Some(state) => state.injector(),
+                    None => return, // Already removed before its TTL elapsed.
+                };
+
+                info!(
+                    "Injection '{}' reached its TTL of {:?}; auto-removing",
+                    handle.id, ttl
+                );
+
+                let is_dry_run = handle
+                    .metadata
+                    .get("dry_run")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                if !is_dry_run {
+                    if let Err(e) = remove_with_timeout(&injector, handle.clone(), remove_timeout).await {
+                        warn!("Failed to auto-remove expired injection '{}': {}", handle.id, e);
+                        let _ = events.send(ExecutorEvent::CleanupFailed {
+                            handle: handle.clone(),
+                            error: e.to_string(),
+                            at: chrono::Utc::now(),
+                        });
+                    }
+                }
+
+                // Bind the removed entry before awaiting anything else - the
+                // write guard from `.remove()` would otherwise stay alive for
+                // the whole `if let` body (it's part of the match scrutinee)
+                // and deadlock `persist_active`'s own read lock on the same
+                // `RwLock`.
+                let removed = active_injections.write().await.remove(&handle.id);
+                if let Some(state) = removed {
+                    state.deactivate().await;
+                    persist_active(&active_injections, persist_path.as_deref()).await;
+                    let _ = events.send(ExecutorEvent::InjectionRemoved {
+                        handle,
+                        at: chrono::Utc::now(),
+                    });
+                }
+            }
+            .instrument(span),
+        );
+    }
+
+    /// Resolves the injector that should handle `remove` for `handle`: the
+    /// exact (possibly `configure()`-derived) instance that produced it, if
+    /// this executor still has it tracked, falling back to the registry's
+    /// shared instance for a handle this executor never saw `inject` for
+    /// (e.g. one reconstructed from a persisted state file).
+    async fn injector_for(&self, handle: &InjectionHandle) -> Result<crate::injectors::DynInjector> {
+        if let Some(state) = self.active_injections.read().await.get(&handle.id) {
+            return Ok(state.injector());
+        }
+
+        self.registry.get(&handle.injector_name).cloned().ok_or_else(|| {
             crate::error::ChaosError::InvalidConfig(format!(
                 "Injector '{}' not found",
                 handle.injector_name
             ))
-        })?;
+        })
+    }
 
-        info!("Removing injection '{}'", handle.id);
+    #[tracing::instrument(skip(self, handle), fields(injection_id = %handle.id))]
+    pub async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        if self.dry_run {
+            info!("DRY RUN: would remove injection '{}'", handle.id);
+        } else if let Target::Agent { host, port, .. } = &handle.target {
+            #[cfg(feature = "agent")]
+            {
+                info!(
+                    "Removing injection '{}' via chaos agent at {}:{}",
+                    handle.id, host, port
+                );
+                if let Err(e) = crate::agent::default_client()
+                    .remove(host, *port, handle.clone())
+                    .await
+                {
+                    self.emit(ExecutorEvent::CleanupFailed {
+                        handle: handle.clone(),
+                        error: e.to_string(),
+                        at: chrono::Utc::now(),
+                    });
+                    return Err(e);
+                }
+            }
+            #[cfg(not(feature = "agent"))]
+            {
+                return Err(crate::error::ChaosError::FeatureNotCompiled(format!(
+                    "removing this injection requires a chaos agent at {}:{}, but this build was not compiled with the 'agent' feature",
+                    host, port
+                )));
+            }
+        } else if !handle.children.is_empty() {
+            let injector = self.injector_for(&handle).await?;
 
-        injector.remove(handle.clone()).await?;
+            info!(
+                "Removing injection '{}' ({} grouped members)",
+                handle.id,
+                handle.children.len()
+            );
+            for child in handle.children.clone() {
+                if let Err(e) = remove_with_timeout(&injector, child, self.remove_timeout).await {
+                    self.emit(ExecutorEvent::CleanupFailed {
+                        handle: handle.clone(),
+                        error: e.to_string(),
+                        at: chrono::Utc::now(),
+                    });
+                    return Err(e);
+                }
+            }
+        } else {
+            let injector = self.injector_for(&handle).await?;
 
-        if let Some(state) = self.active_injections.write().await.remove(&handle.id) {
+            info!("Removing injection '{}'", handle.id);
+            if let Err(e) = remove_with_timeout(&injector, handle.clone(), self.remove_timeout).await {
+                self.emit(ExecutorEvent::CleanupFailed {
+                    handle: handle.clone(),
+                    error: e.to_string(),
+                    at: chrono::Utc::now(),
+                });
+                return Err(e);
+            }
+        }
+
+        // Bind the removed entry before awaiting anything else - the write
+        // guard from `.remove()` would otherwise stay alive for the whole
+        // `if let` body (it's part of the match scrutinee) and deadlock
+        // `persist_state`'s own read lock on the same `RwLock`.
+        let removed = self.active_injections.write().await.remove(&handle.id);
+        if let Some(state) = removed {
             state.deactivate().await;
+            self.persist_state().await;
+            self.emit(ExecutorEvent::InjectionRemoved {
+                handle,
+                at: chrono::Utc::now(),
+            });
         }
 
         Ok(())
@@ -117,12 +744,100 @@ impl Executor {
     pub fn list_injectors(&self) -> Vec<String> {
         self.registry.list()
     }
+
+    /// Exposes the registry so callers can validate scenarios (injector
+    /// existence, parameter schemas) against the exact set of injectors
+    /// this executor will actually dispatch to.
+    pub fn registry(&self) -> &InjectorRegistry {
+        &self.registry
+    }
+
+    /// Like [`Executor::inject`], but returns an RAII [`InjectionGuard`]
+    /// instead of a bare handle. Dropping the guard removes the injection,
+    /// so a test (or any short-lived caller) that panics or returns early
+    /// can't leave it active - unlike a bare handle, which relies on the
+    /// caller remembering to call [`Executor::remove`].
+    #[tracing::instrument(skip(self, target), fields(injector = injector_name, injection_id))]
+    pub async fn inject_scoped(
+        &self,
+        injector_name: &str,
+        target: &Target,
+    ) -> Result<InjectionGuard> {
+        let handle = self.inject(injector_name, target).await?;
+        Ok(InjectionGuard {
+            executor: self.clone(),
+            handle: Some(handle),
+        })
+    }
+}
+
+/// RAII handle returned by [`Executor::inject_scoped`]: removes its
+/// injection when dropped. Since `Drop` can't `.await`, the removal is
+/// spawned onto the runtime rather than run in-line - it still happens
+/// even if the guard is dropped during a panic, just not necessarily
+/// before the drop call returns.
+pub struct InjectionGuard {
+    executor: Executor,
+    handle: Option<InjectionHandle>,
+}
+
+impl InjectionGuard {
+    /// The handle for the injection this guard owns.
+    pub fn handle(&self) -> &InjectionHandle {
+        self.handle
+            .as_ref()
+            .expect("InjectionGuard handle is only taken when the guard is consumed")
+    }
+
+    /// Removes the injection now and reports whether it succeeded, rather
+    /// than leaving removal to a background-spawned task on drop.
+    pub async fn remove(mut self) -> Result<()> {
+        let handle = self
+            .handle
+            .take()
+            .expect("InjectionGuard handle is only taken once");
+        self.executor.remove(handle).await
+    }
+}
+
+impl Drop for InjectionGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let executor = self.executor.clone();
+            tokio::spawn(async move {
+                let id = handle.id.clone();
+                if let Err(e) = executor.remove(handle).await {
+                    warn!("Failed to remove injection '{}' on guard drop: {}", id, e);
+                }
+            });
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// A registry with `cpu_starvation` bounded to a single core at low
+    /// intensity, the same stand-in shape as `test_ttl_injection_auto_expires`
+    /// - tests that just need "some injector" shouldn't pay for the real
+    /// default config's every-core full-intensity burn.
+    fn bounded_cpu_starvation_registry() -> InjectorRegistry {
+        use crate::injectors::{CpuStarvationConfig, CpuStarvationInjector};
+
+        let mut registry = InjectorRegistry::new();
+        registry.register(
+            "cpu_starvation",
+            Arc::new(CpuStarvationInjector::new(CpuStarvationConfig {
+                intensity: 0.1,
+                threads: vec![0],
+                duration: None,
+                victim_aware: false,
+            })),
+        );
+        registry
+    }
+
     #[tokio::test]
     async fn test_executor_creation() {
         let executor = Executor::with_defaults();
@@ -138,4 +853,630 @@ mod tests {
         let executor = Executor::with_defaults();
         assert_eq!(executor.list_active().await.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_ttl_injection_auto_expires() {
+        use crate::injectors::{CpuStarvationConfig, CpuStarvationInjector};
+
+        let mut registry = InjectorRegistry::new();
+        registry.register(
+            "cpu_starvation",
+            Arc::new(CpuStarvationInjector::new(CpuStarvationConfig {
+                intensity: 0.1,
+                threads: vec![0],
+                duration: Some(Duration::from_millis(50)),
+                victim_aware: false,
+            })),
+        );
+
+        let executor = Executor::new(registry);
+        let handle = executor
+            .inject("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(executor.get_state(&handle.id).await.is_none());
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_adopt_tracks_a_handle_this_executor_never_injected() {
+        use crate::injectors::{CpuStarvationConfig, CpuStarvationInjector};
+
+        // Both executors register the *same* injector instance, since that's
+        // where `CpuStarvationInjector` actually tracks its running burner
+        // tasks - two independent instances would each think the other's
+        // injection doesn't exist, and `remove` below would silently no-op
+        // instead of stopping the real burner.
+        let injector: Arc<dyn crate::injectors::Injector> =
+            Arc::new(CpuStarvationInjector::new(CpuStarvationConfig {
+                intensity: 0.1,
+                threads: vec![0],
+                duration: None,
+                victim_aware: false,
+            }));
+
+        let mut injecting_registry = InjectorRegistry::new();
+        injecting_registry.register("cpu_starvation", injector.clone());
+        let injecting = Executor::new(injecting_registry);
+        let handle = injecting
+            .inject("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        // A second, independent Executor - standing in for a different
+        // process that only has the exported handle on disk.
+        let mut stopping_registry = InjectorRegistry::new();
+        stopping_registry.register("cpu_starvation", injector);
+        let stopping = Executor::new(stopping_registry);
+        assert_eq!(stopping.list_active().await.len(), 0);
+
+        stopping.adopt(handle.clone()).await.unwrap();
+
+        assert_eq!(stopping.list_active().await.len(), 1);
+        stopping.remove(handle).await.unwrap();
+        assert_eq!(stopping.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_adopt_rejects_handle_for_unregistered_injector() {
+        let executor = Executor::new(InjectorRegistry::new());
+        let handle = InjectionHandle::new(
+            "not_a_real_injector",
+            Target::Process { pid: 1 },
+            serde_json::json!({}),
+        );
+
+        assert!(executor.adopt(handle).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_inject_with_params_applies_overrides() {
+        let executor = Executor::with_defaults();
+
+        let handle = executor
+            .inject_with_params(
+                "cpu_starvation",
+                &Target::Process { pid: 1 },
+                &serde_json::json!({ "intensity": 0.1, "duration": { "secs": 0, "nanos": 50_000_000 } }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(executor.list_active().await.len(), 1);
+        executor.remove(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_inject_with_params_empty_object_uses_shared_default() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let handle = executor
+            .inject_with_params(
+                "cpu_starvation",
+                &Target::Process { pid: 1 },
+                &serde_json::json!({}),
+            )
+            .await
+            .unwrap();
+
+        executor.remove(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_inject_with_ttl_auto_expires_even_without_injector_support() {
+        use crate::injectors::{CpuStarvationConfig, CpuStarvationInjector};
+
+        let mut registry = InjectorRegistry::new();
+        registry.register(
+            "cpu_starvation",
+            Arc::new(CpuStarvationInjector::new(CpuStarvationConfig {
+                intensity: 0.1,
+                threads: vec![0],
+                duration: None, // injector itself sets no TTL - the executor must supply one
+                victim_aware: false,
+            })),
+        );
+
+        let executor = Executor::new(registry);
+        let handle = executor
+            .inject_with_ttl(
+                "cpu_starvation",
+                &Target::Process { pid: 1 },
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(executor.get_state(&handle.id).await.is_none());
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_inject_with_params_and_ttl_applies_both() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let handle = executor
+            .inject_with_params_and_ttl(
+                "cpu_starvation",
+                &Target::Process { pid: 1 },
+                &serde_json::json!({ "intensity": 0.1 }),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(executor.get_state(&handle.id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inject_group_target_bundles_one_handle_per_member() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let target = Target::group([Target::process(1), Target::process(2)]);
+        let handle = executor
+            .inject("cpu_starvation", &target)
+            .await
+            .unwrap();
+
+        assert_eq!(handle.children.len(), 2);
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        executor.remove(handle).await.unwrap();
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_injection_state_reports_expiry() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let handle = executor
+            .inject_with_ttl(
+                "cpu_starvation",
+                &Target::Process { pid: 1 },
+                Duration::from_secs(60),
+            )
+            .await
+            .unwrap();
+
+        let state = executor.get_state(&handle.id).await.unwrap();
+        assert!(state.expires_at().is_some());
+        assert!(!state.is_expired());
+
+        executor.remove(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_with_persistence_writes_and_clears_state_file() {
+        let path = std::env::temp_dir().join(format!(
+            "chaos-executor-test-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+
+        let executor = Executor::with_persistence(bounded_cpu_starvation_registry(), &path);
+
+        let handle = executor
+            .inject("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        let persisted = StateFile::load(&path).await.unwrap();
+        assert_eq!(persisted.injections.len(), 1);
+        assert!(persisted.injections.contains_key(&handle.id));
+
+        executor.remove(handle).await.unwrap();
+
+        let persisted = StateFile::load(&path).await.unwrap();
+        assert!(persisted.injections.is_empty());
+
+        tokio::fs::remove_file(&path).await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_inject_scoped_removes_on_drop() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let guard = executor
+            .inject_scoped("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+        let handle_id = guard.handle().id.clone();
+
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        drop(guard);
+
+        // Removal is spawned on drop, not run inline - give it a moment.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(executor.get_state(&handle_id).await.is_none());
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_inject_scoped_explicit_remove_reports_result() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let guard = executor
+            .inject_scoped("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        guard.remove().await.unwrap();
+
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_does_not_invoke_injector() {
+        let executor = Executor::dry_run(InjectorRegistry::with_defaults());
+
+        let handle = executor
+            .inject("process_kill", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        // process_kill's real inject() would have actually signaled PID 1;
+        // reaching this point at all proves the dry-run branch was taken.
+        assert_eq!(handle.metadata["dry_run"], serde_json::json!(true));
+        assert!(handle.metadata["would_run"].is_array());
+        assert_eq!(executor.list_active().await.len(), 1);
+
+        executor.remove(handle).await.unwrap();
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    /// A no-op injector whose sole purpose is to declare a capability
+    /// requirement the test process (almost certainly unprivileged) won't
+    /// hold, so `inject()`'s capability gate has something to reject.
+    struct RequiresCapabilityInjector;
+
+    #[async_trait::async_trait]
+    impl crate::injectors::Injector for RequiresCapabilityInjector {
+        async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+            Ok(InjectionHandle::new(
+                self.name(),
+                target.clone(),
+                serde_json::json!({}),
+            ))
+        }
+
+        async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "requires_capability"
+        }
+
+        fn required_capabilities(&self) -> Vec<String> {
+            vec!["CAP_SYS_ADMIN".to_string()]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inject_denies_when_capability_is_missing() {
+        // Most CI and developer sandboxes run unprivileged, so CAP_SYS_ADMIN
+        // is absent; skip rather than fail on the rare box that does hold it
+        // (e.g. a privileged container or root shell).
+        if crate::preflight::has_capability("CAP_SYS_ADMIN") {
+            return;
+        }
+
+        let mut registry = InjectorRegistry::new();
+        registry.register("requires_capability", Arc::new(RequiresCapabilityInjector));
+        let executor = Executor::new(registry);
+
+        let err = executor
+            .inject("requires_capability", &Target::Process { pid: 1 })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::error::ChaosError::PermissionDenied(_)));
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_skips_capability_check() {
+        let mut registry = InjectorRegistry::new();
+        registry.register("requires_capability", Arc::new(RequiresCapabilityInjector));
+        let executor = Executor::dry_run(registry);
+
+        // The capability gate only applies to real injections; a dry run
+        // should report what would happen regardless of what the current
+        // process is allowed to do.
+        executor
+            .inject("requires_capability", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_observes_applied_and_removed() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+        let mut events = executor.subscribe();
+
+        let handle = executor
+            .inject("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::InjectionApplied { handle: applied, .. } => {
+                assert_eq!(applied.id, handle.id);
+            }
+            other => panic!("expected InjectionApplied, got {:?}", other),
+        }
+
+        executor.remove(handle.clone()).await.unwrap();
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::InjectionRemoved { handle: removed, .. } => {
+                assert_eq!(removed.id, handle.id);
+            }
+            other => panic!("expected InjectionRemoved, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_observes_injection_failed() {
+        let executor = Executor::with_defaults();
+        let mut events = executor.subscribe();
+
+        let err = executor
+            .inject("no_such_injector", &Target::Process { pid: 1 })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::error::ChaosError::InvalidConfig(_)));
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::InjectionFailed { injector_name, .. } => {
+                assert_eq!(injector_name, "no_such_injector");
+            }
+            other => panic!("expected InjectionFailed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_policy_denies_listed_pid() {
+        let policy = crate::policy::SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+        let executor = Executor::with_defaults().with_policy(policy);
+
+        let err = executor
+            .inject("process_kill", &Target::Process { pid: 1 })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::error::ChaosError::PolicyViolation(_)));
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_with_policy_allows_untargeted_pid() {
+        // Dry-run, since process_kill's real inject() would send an actual
+        // signal - this test only cares that the policy check itself lets
+        // a non-denied pid through.
+        let policy = crate::policy::SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+        let executor = Executor::dry_run(InjectorRegistry::with_defaults()).with_policy(policy);
+
+        let handle = executor
+            .inject("process_kill", &Target::Process { pid: 2 })
+            .await
+            .unwrap();
+
+        executor.remove(handle).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_with_policy_applies_even_in_dry_run() {
+        let policy = crate::policy::SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+        let executor = Executor::dry_run(InjectorRegistry::with_defaults()).with_policy(policy);
+
+        let err = executor
+            .inject("process_kill", &Target::Process { pid: 1 })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::error::ChaosError::PolicyViolation(_)));
+    }
+
+    /// An injector whose `verify` always reports drift (the fault is gone),
+    /// so the drift monitor has something to detect. `inject` counts how
+    /// many times it was called, so a test can tell a re-apply happened.
+    #[derive(Default)]
+    struct AlwaysDriftedInjector {
+        inject_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::injectors::Injector for AlwaysDriftedInjector {
+        async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+            self.inject_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(InjectionHandle::new(
+                self.name(),
+                target.clone(),
+                serde_json::json!({}),
+            ))
+        }
+
+        async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "always_drifted"
+        }
+
+        async fn verify(&self, _handle: &InjectionHandle) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drift_monitor_reports_drift_without_reapplying() {
+        let mut registry = InjectorRegistry::new();
+        let injector = Arc::new(AlwaysDriftedInjector::default());
+        registry.register("always_drifted", injector.clone());
+        let executor = Executor::new(registry);
+        let mut events = executor.subscribe();
+
+        let handle = executor
+            .inject("always_drifted", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+        events.recv().await.unwrap(); // InjectionApplied from the inject() above.
+
+        let monitor = executor.spawn_drift_monitor(Duration::from_millis(10), false);
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::DriftDetected { handle: drifted, reapplied, .. } => {
+                assert_eq!(drifted.id, handle.id);
+                assert!(!reapplied);
+            }
+            other => panic!("expected DriftDetected, got {:?}", other),
+        }
+
+        monitor.abort();
+        // inject() ran once for the initial injection; the monitor must not
+        // have called it again since reapply was false.
+        assert_eq!(
+            injector.inject_calls.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drift_monitor_reapplies_when_enabled() {
+        let mut registry = InjectorRegistry::new();
+        let injector = Arc::new(AlwaysDriftedInjector::default());
+        registry.register("always_drifted", injector.clone());
+        let executor = Executor::new(registry);
+        let mut events = executor.subscribe();
+
+        let handle = executor
+            .inject("always_drifted", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+        events.recv().await.unwrap(); // InjectionApplied from the inject() above.
+
+        let monitor = executor.spawn_drift_monitor(Duration::from_millis(10), true);
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::DriftDetected { handle: drifted, reapplied, .. } => {
+                assert_eq!(drifted.id, handle.id);
+                assert!(reapplied);
+            }
+            other => panic!("expected DriftDetected, got {:?}", other),
+        }
+
+        monitor.abort();
+        // Re-applying keeps the same tracked id, even though the injector
+        // produced a fresh handle internally.
+        assert_eq!(executor.list_active().await.len(), 1);
+        assert_eq!(executor.list_active().await[0].id, handle.id);
+    }
+
+    /// An injector whose `remove` never returns, standing in for a `tc`
+    /// call blocked on a stale netns or a health check that waits forever -
+    /// exactly what [`Executor::with_remove_timeout`] guards against.
+    struct HangingRemoveInjector;
+
+    #[async_trait::async_trait]
+    impl crate::injectors::Injector for HangingRemoveInjector {
+        async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+            Ok(InjectionHandle::new(
+                self.name(),
+                target.clone(),
+                serde_json::json!({}),
+            ))
+        }
+
+        async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
+            std::future::pending().await
+        }
+
+        fn name(&self) -> &str {
+            "hanging_remove"
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_remove_timeout_fails_cleanup_instead_of_hanging_forever() {
+        let mut registry = InjectorRegistry::new();
+        registry.register("hanging_remove", Arc::new(HangingRemoveInjector));
+        let executor =
+            Executor::new(registry).with_remove_timeout(Duration::from_secs(5));
+
+        let handle = executor
+            .inject("hanging_remove", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        let err = executor.remove(handle).await.unwrap_err();
+        assert!(matches!(err, crate::error::ChaosError::CleanupFailed(_)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_remove_timeout_emits_cleanup_failed_event() {
+        let mut registry = InjectorRegistry::new();
+        registry.register("hanging_remove", Arc::new(HangingRemoveInjector));
+        let executor =
+            Executor::new(registry).with_remove_timeout(Duration::from_secs(5));
+        let mut events = executor.subscribe();
+
+        let handle = executor
+            .inject("hanging_remove", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+        events.recv().await.unwrap(); // InjectionApplied from the inject() above.
+
+        let _ = executor.remove(handle.clone()).await;
+
+        match events.recv().await.unwrap() {
+            ExecutorEvent::CleanupFailed { handle: failed, .. } => {
+                assert_eq!(failed.id, handle.id);
+            }
+            other => panic!("expected CleanupFailed, got {:?}", other),
+        }
+
+        // A timed-out removal is still reported as failed, not silently
+        // dropped from tracking - the caller decides what to do next.
+        assert_eq!(executor.list_active().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_without_a_remove_timeout_remove_waits_for_the_injector() {
+        let executor = Executor::new(bounded_cpu_starvation_registry());
+
+        let handle = executor
+            .inject("cpu_starvation", &Target::Process { pid: 1 })
+            .await
+            .unwrap();
+
+        // No `with_remove_timeout` was configured, so this must behave
+        // exactly as before: remove() waits for the injector and succeeds.
+        executor.remove(handle).await.unwrap();
+        assert_eq!(executor.list_active().await.len(), 0);
+    }
 }
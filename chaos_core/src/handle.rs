@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +10,16 @@ pub struct InjectionHandle {
     pub target: crate::target::Target,
     pub started_at: chrono::DateTime<chrono::Utc>,
     pub metadata: serde_json::Value,
+    /// Time-to-live for this injection. When set, the `Executor` that issued
+    /// this handle will auto-remove it once `ttl` elapses, regardless of
+    /// which injector produced it.
+    #[serde(default)]
+    pub ttl: Option<Duration>,
+    /// Sub-handles for a fan-out injection against `Target::Group`, one per
+    /// group member. Empty for a normal single-target injection. Removing
+    /// this handle removes every child too.
+    #[serde(default)]
+    pub children: Vec<InjectionHandle>,
 }
 
 impl InjectionHandle {
@@ -23,25 +34,41 @@ impl InjectionHandle {
             target,
             started_at: chrono::Utc::now(),
             metadata,
+            ttl: None,
+            children: Vec::new(),
         }
     }
 
+    /// Attach a TTL so the `Executor` auto-removes this injection once it
+    /// elapses, instead of requiring an explicit `remove` call.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
     pub fn duration(&self) -> chrono::Duration {
         chrono::Utc::now() - self.started_at
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct InjectionState {
     handle: InjectionHandle,
     active: Arc<RwLock<bool>>,
+    injector: crate::injectors::DynInjector,
 }
 
 impl InjectionState {
-    pub fn new(handle: InjectionHandle) -> Self {
+    /// `injector` is the exact (possibly `Injector::configure`-derived)
+    /// instance that produced `handle`, so a later `Executor::remove` can
+    /// dispatch back to it instead of a fresh registry lookup - the
+    /// registry's shared instance never saw this handle if it came from a
+    /// parameter override, and wouldn't know how to remove it.
+    pub fn new(handle: InjectionHandle, injector: crate::injectors::DynInjector) -> Self {
         Self {
             handle,
             active: Arc::new(RwLock::new(true)),
+            injector,
         }
     }
 
@@ -49,6 +76,23 @@ impl InjectionState {
         *self.active.read().await
     }
 
+    /// When this injection will be auto-removed, if it has a TTL.
+    pub fn expires_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.handle
+            .ttl
+            .and_then(|ttl| chrono::Duration::from_std(ttl).ok())
+            .map(|ttl| self.handle.started_at + ttl)
+    }
+
+    /// Whether this injection's TTL (if any) has already elapsed. Doesn't
+    /// imply the executor has actually removed it yet - auto-expiry runs on
+    /// its own timer, so there's a brief window where this is `true` just
+    /// before `remove` fires.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| chrono::Utc::now() >= expires_at)
+    }
+
     pub async fn deactivate(&self) {
         let mut active = self.active.write().await;
         *active = false;
@@ -57,4 +101,9 @@ impl InjectionState {
     pub fn handle(&self) -> &InjectionHandle {
         &self.handle
     }
+
+    /// The exact injector instance that produced this handle.
+    pub fn injector(&self) -> crate::injectors::DynInjector {
+        self.injector.clone()
+    }
 }
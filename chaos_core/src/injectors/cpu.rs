@@ -1,15 +1,22 @@
 use crate::{error::*, handle::InjectionHandle, injectors::Injector, target::Target};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CpuStarvationConfig {
     pub intensity: f64,     // 0.0 - 1.0, percentage of CPU to consume
     pub threads: Vec<u32>,  // Specific CPU cores to target (empty = all)
     pub duration: Option<std::time::Duration>,
+    /// When set, ignore `threads` and instead burn only the cores the
+    /// target PID is currently allowed to run on, with burner threads
+    /// reniced above it so they actually win the contention on those
+    /// cores. Requires a `Target::Process`.
+    pub victim_aware: bool,
 }
 
 impl Default for CpuStarvationConfig {
@@ -18,20 +25,29 @@ impl Default for CpuStarvationConfig {
             intensity: 0.8,
             threads: vec![],
             duration: None,
+            victim_aware: false,
         }
     }
 }
 
+/// The burner tasks and stop flag for a single in-flight injection, keyed by
+/// `InjectionHandle::id` so concurrent injections from the same shared
+/// registry instance can be stopped independently.
+struct ActiveBurn {
+    stop_signal: Arc<RwLock<bool>>,
+    tasks: Vec<tokio::task::JoinHandle<()>>,
+}
+
 pub struct CpuStarvationInjector {
     config: CpuStarvationConfig,
-    stop_signal: Arc<RwLock<bool>>,
+    active_injections: Arc<RwLock<HashMap<String, ActiveBurn>>>,
 }
 
 impl Default for CpuStarvationInjector {
     fn default() -> Self {
         Self {
             config: CpuStarvationConfig::default(),
-            stop_signal: Arc::new(RwLock::new(false)),
+            active_injections: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -40,7 +56,7 @@ impl CpuStarvationInjector {
     pub fn new(config: CpuStarvationConfig) -> Self {
         Self {
             config,
-            stop_signal: Arc::new(RwLock::new(false)),
+            active_injections: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -48,9 +64,44 @@ impl CpuStarvationInjector {
         CpuStarvationBuilder::default()
     }
 
-    async fn spawn_cpu_burner(&self, core_id: Option<u32>) -> tokio::task::JoinHandle<()> {
+    #[cfg(target_os = "linux")]
+    fn victim_affinity(pid: u32) -> Result<Vec<u32>> {
+        use nix::sched::sched_getaffinity;
+        use nix::unistd::Pid;
+
+        let cpu_set = sched_getaffinity(Pid::from_raw(pid as i32))
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to get CPU affinity for PID {}: {}", pid, e)))?;
+
+        let num_cpus = num_cpus::get();
+        let cores: Vec<u32> = (0..num_cpus)
+            .filter(|c| cpu_set.is_set(*c).unwrap_or(false))
+            .map(|c| c as u32)
+            .collect();
+
+        if cores.is_empty() {
+            return Err(ChaosError::ProcessError(format!(
+                "PID {} has no CPUs in its affinity mask",
+                pid
+            )));
+        }
+
+        Ok(cores)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn victim_affinity(_pid: u32) -> Result<Vec<u32>> {
+        Err(ChaosError::SystemError(
+            "victim_aware CPU starvation requires reading CPU affinity, which is only supported on Linux".to_string(),
+        ))
+    }
+
+    fn spawn_cpu_burner(
+        &self,
+        core_id: Option<u32>,
+        elevate_priority: bool,
+        stop_signal: Arc<RwLock<bool>>,
+    ) -> tokio::task::JoinHandle<()> {
         let intensity = self.config.intensity;
-        let stop_signal = self.stop_signal.clone();
 
         tokio::task::spawn_blocking(move || {
             #[cfg(unix)]
@@ -66,6 +117,20 @@ impl CpuStarvationInjector {
                 }
             }
 
+            #[cfg(target_os = "linux")]
+            {
+                // In victim-aware mode the burner shares cores with the
+                // target, so it needs to outrank it in the scheduler to
+                // actually starve it rather than just share the core evenly.
+                if elevate_priority {
+                    unsafe {
+                        libc::setpriority(libc::PRIO_PROCESS, 0, -20);
+                    }
+                }
+            }
+            #[cfg(not(target_os = "linux"))]
+            let _ = elevate_priority;
+
             info!("Starting CPU burner on core {:?}", core_id);
 
             // Spin loop with controlled intensity
@@ -103,10 +168,21 @@ impl Injector for CpuStarvationInjector {
             self.config.intensity, self.config.threads
         );
 
-        // Reset stop signal
-        *self.stop_signal.write().await = false;
-
-        let cores = if self.config.threads.is_empty() {
+        let stop_signal = Arc::new(RwLock::new(false));
+
+        let cores: Vec<u32> = if self.config.victim_aware {
+            let Target::Process { pid } = target else {
+                return Err(ChaosError::InvalidConfig(
+                    "victim_aware CPU starvation requires a Process target".to_string(),
+                ));
+            };
+            let victim_cores = Self::victim_affinity(*pid)?;
+            info!(
+                "Victim-aware mode: PID {} is pinned to cores {:?}; burning only those",
+                pid, victim_cores
+            );
+            victim_cores
+        } else if self.config.threads.is_empty() {
             // Use all available cores
             let num_cpus = num_cpus::get() as u32;
             (0..num_cpus).collect()
@@ -114,30 +190,52 @@ impl Injector for CpuStarvationInjector {
             self.config.threads.clone()
         };
 
-        // Spawn burner threads
-        let mut handles = vec![];
+        // Spawn burner threads, each watching this injection's own stop signal.
+        let mut tasks = vec![];
         for core in &cores {
-            let handle = self.spawn_cpu_burner(Some(*core)).await;
-            handles.push(handle);
+            tasks.push(self.spawn_cpu_burner(Some(*core), self.config.victim_aware, stop_signal.clone()));
         }
 
         let metadata = serde_json::json!({
             "intensity": self.config.intensity,
             "cores": cores,
-            "num_threads": handles.len(),
+            "num_threads": tasks.len(),
+            "victim_aware": self.config.victim_aware,
         });
 
-        Ok(InjectionHandle::new("cpu_starvation", target.clone(), metadata))
+        let mut handle = InjectionHandle::new("cpu_starvation", target.clone(), metadata);
+        if let Some(duration) = self.config.duration {
+            handle = handle.with_ttl(duration);
+        }
+
+        self.active_injections
+            .write()
+            .await
+            .insert(handle.id.clone(), ActiveBurn { stop_signal, tasks });
+
+        Ok(handle)
     }
 
-    async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
-        info!("Removing CPU starvation");
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        info!("Removing CPU starvation for injection {}", handle.id);
 
-        // Signal all threads to stop
-        *self.stop_signal.write().await = true;
+        let active = self.active_injections.write().await.remove(&handle.id);
 
-        // Give threads time to exit gracefully
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        match active {
+            Some(ActiveBurn { stop_signal, tasks }) => {
+                *stop_signal.write().await = true;
+
+                for task in tasks {
+                    let _ = task.await;
+                }
+            }
+            None => {
+                warn!(
+                    "No active CPU starvation burners found for injection {}",
+                    handle.id
+                );
+            }
+        }
 
         Ok(())
     }
@@ -149,6 +247,25 @@ impl Injector for CpuStarvationInjector {
     fn required_capabilities(&self) -> Vec<String> {
         vec!["CAP_SYS_NICE".to_string()]
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<crate::injectors::DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(CpuStarvationInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::bounded("intensity", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::new("threads", ParameterKind::String),
+            ParameterSpec::new("duration", ParameterKind::Duration),
+            ParameterSpec::new("victim_aware", ParameterKind::Bool),
+        ]
+    }
+
+    fn ramp_parameter(&self) -> Option<&'static str> {
+        Some("intensity")
+    }
 }
 
 #[derive(Default)]
@@ -156,6 +273,7 @@ pub struct CpuStarvationBuilder {
     intensity: Option<f64>,
     threads: Option<Vec<u32>>,
     duration: Option<std::time::Duration>,
+    victim_aware: bool,
 }
 
 impl CpuStarvationBuilder {
@@ -174,14 +292,20 @@ impl CpuStarvationBuilder {
         self
     }
 
+    pub fn victim_aware(mut self, victim_aware: bool) -> Self {
+        self.victim_aware = victim_aware;
+        self
+    }
+
     pub fn build(self) -> CpuStarvationInjector {
         CpuStarvationInjector {
             config: CpuStarvationConfig {
                 intensity: self.intensity.unwrap_or(0.8),
                 threads: self.threads.unwrap_or_default(),
                 duration: self.duration,
+                victim_aware: self.victim_aware,
             },
-            stop_signal: Arc::new(RwLock::new(false)),
+            active_injections: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -210,8 +334,12 @@ impl CpuQuotaInjector {
 
         info!("Setting CPU quota to {}% for PID {}", self.quota, pid);
 
-        // Create a cgroup for this process
-        let cgroup_name = format!("chaos_cpu_{}", pid);
+        // Create a cgroup for this process, named after this injection's own
+        // ID (not just the PID) so concurrent/repeated injections against
+        // the same process never collide on the same cgroup, and `chaos
+        // cleanup` can tell which run left it behind.
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let cgroup_name = format!("chaos_cpu_{}_{}", pid, injection_id);
         let cgroup_path = format!("/sys/fs/cgroup/cpu/{}", cgroup_name);
 
         // Create cgroup directory
@@ -242,7 +370,9 @@ impl CpuQuotaInjector {
             "quota": self.quota,
         });
 
-        Ok(InjectionHandle::new("cpu_quota", target.clone(), metadata))
+        let mut handle = InjectionHandle::new("cpu_quota", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(not(target_os = "linux"))]
@@ -290,6 +420,190 @@ impl Injector for CpuQuotaInjector {
     }
 }
 
+// CPU Affinity Shuffling Injector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CpuAffinityShuffleConfig {
+    pub shuffle_interval: std::time::Duration,
+    pub min_cores: usize,
+    pub max_cores: usize,
+}
+
+impl Default for CpuAffinityShuffleConfig {
+    fn default() -> Self {
+        Self {
+            shuffle_interval: std::time::Duration::from_secs(5),
+            min_cores: 1,
+            max_cores: num_cpus::get(),
+        }
+    }
+}
+
+pub struct CpuAffinityShuffleInjector {
+    config: CpuAffinityShuffleConfig,
+    stop_signal: Arc<RwLock<bool>>,
+}
+
+impl Default for CpuAffinityShuffleInjector {
+    fn default() -> Self {
+        Self {
+            config: CpuAffinityShuffleConfig::default(),
+            stop_signal: Arc::new(RwLock::new(false)),
+        }
+    }
+}
+
+impl CpuAffinityShuffleInjector {
+    pub fn new(config: CpuAffinityShuffleConfig) -> Self {
+        Self {
+            config,
+            stop_signal: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn get_original_affinity(pid: u32) -> Result<Vec<usize>> {
+        use nix::sched::sched_getaffinity;
+        use nix::unistd::Pid;
+
+        let cpu_set = sched_getaffinity(Pid::from_raw(pid as i32))
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to get CPU affinity: {}", e)))?;
+
+        let num_cpus = num_cpus::get();
+        Ok((0..num_cpus).filter(|c| cpu_set.is_set(*c).unwrap_or(false)).collect())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_affinity(pid: u32, cores: &[usize]) -> Result<()> {
+        use nix::sched::{sched_setaffinity, CpuSet};
+        use nix::unistd::Pid;
+
+        let mut cpu_set = CpuSet::new();
+        for core in cores {
+            cpu_set
+                .set(*core)
+                .map_err(|e| ChaosError::ProcessError(format!("Invalid core {}: {}", core, e)))?;
+        }
+
+        sched_setaffinity(Pid::from_raw(pid as i32), &cpu_set)
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to set CPU affinity: {}", e)))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn spawn_shuffler(&self, pid: u32) -> tokio::task::JoinHandle<()> {
+        let stop_signal = self.stop_signal.clone();
+        let interval = self.config.shuffle_interval;
+        let min_cores = self.config.min_cores.max(1);
+        let max_cores = self.config.max_cores.max(min_cores).min(num_cpus::get());
+
+        tokio::spawn(async move {
+            use rand::seq::SliceRandom;
+            use rand::Rng;
+
+            loop {
+                if *stop_signal.read().await {
+                    info!("Stopping CPU affinity shuffler for PID {}", pid);
+                    break;
+                }
+
+                let num_cpus = num_cpus::get();
+                let cores: Vec<usize> = {
+                    let mut rng = rand::thread_rng();
+                    let count = rng.gen_range(min_cores..=max_cores).min(num_cpus);
+                    let mut all: Vec<usize> = (0..num_cpus).collect();
+                    all.shuffle(&mut rng);
+                    all.into_iter().take(count).collect()
+                };
+
+                if let Err(e) = Self::set_affinity(pid, &cores) {
+                    warn!("Failed to shuffle CPU affinity for PID {}: {}", pid, e);
+                }
+
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl Injector for CpuAffinityShuffleInjector {
+    async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+        let Target::Process { pid } = target else {
+            return Err(ChaosError::InvalidConfig(
+                "CPU affinity shuffle requires Process target".to_string(),
+            ));
+        };
+
+        #[cfg(target_os = "linux")]
+        {
+            let original_cores = Self::get_original_affinity(*pid)?;
+            info!(
+                "Starting CPU affinity shuffle on PID {} (original cores: {:?})",
+                pid, original_cores
+            );
+
+            *self.stop_signal.write().await = false;
+            self.spawn_shuffler(*pid);
+
+            let metadata = serde_json::json!({
+                "pid": pid,
+                "original_cores": original_cores,
+                "shuffle_interval_ms": self.config.shuffle_interval.as_millis(),
+            });
+
+            return Ok(InjectionHandle::new(
+                "cpu_affinity_shuffle",
+                target.clone(),
+                metadata,
+            ));
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = pid;
+            Err(ChaosError::SystemError(
+                "CPU affinity shuffling only supported on Linux".to_string(),
+            ))
+        }
+    }
+
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        info!("Removing CPU affinity shuffle");
+        *self.stop_signal.write().await = true;
+
+        #[cfg(target_os = "linux")]
+        {
+            let pid = handle
+                .metadata
+                .get("pid")
+                .and_then(|v| v.as_u64())
+                .ok_or_else(|| ChaosError::CleanupFailed("Missing pid metadata".to_string()))?
+                as u32;
+
+            let original_cores: Vec<usize> = handle
+                .metadata
+                .get("original_cores")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|c| c.as_u64().map(|c| c as usize)).collect())
+                .unwrap_or_default();
+
+            if !original_cores.is_empty() {
+                Self::set_affinity(pid, &original_cores)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "cpu_affinity_shuffle"
+    }
+
+    fn required_capabilities(&self) -> Vec<String> {
+        vec!["CAP_SYS_NICE".to_string()]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +624,47 @@ mod tests {
         let injector = CpuQuotaInjector::new(150);
         assert_eq!(injector.quota, 100);
     }
+
+    #[test]
+    fn test_cpu_starvation_configure_accepts_partial_params() {
+        let injector = CpuStarvationInjector::default();
+        let params = serde_json::json!({ "intensity": 0.3 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "cpu_starvation");
+    }
+
+    #[tokio::test]
+    async fn test_cpu_starvation_victim_aware_rejects_non_process_target() {
+        let injector = CpuStarvationInjector::builder()
+            .victim_aware(true)
+            .build();
+
+        let result = injector
+            .inject(&Target::Network {
+                address: "127.0.0.1:8080".parse().unwrap(),
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cpu_starvation_concurrent_injections_stop_independently() {
+        let injector = CpuStarvationInjector::builder()
+            .intensity(0.1)
+            .threads(vec![0])
+            .build();
+
+        let handle_a = injector.inject(&Target::Process { pid: 1 }).await.unwrap();
+        let handle_b = injector.inject(&Target::Process { pid: 2 }).await.unwrap();
+
+        assert_eq!(injector.active_injections.read().await.len(), 2);
+
+        injector.remove(handle_a).await.unwrap();
+        assert_eq!(injector.active_injections.read().await.len(), 1);
+
+        injector.remove(handle_b).await.unwrap();
+        assert_eq!(injector.active_injections.read().await.len(), 0);
+    }
 }
@@ -1,10 +1,12 @@
 use crate::{error::*, handle::InjectionHandle, injectors::Injector, target::Target};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct DiskSlowConfig {
     pub latency: Duration,
     pub operations: Vec<DiskOperation>,
@@ -58,17 +60,23 @@ impl DiskSlowInjector {
         );
 
         // For process targets, we would inject via LD_PRELOAD
-        // For simplicity, we'll use a marker file approach
-        let marker_file = "/tmp/chaos_disk_slow.json";
+        // For simplicity, we'll use a marker file approach. Named after this
+        // injection's own ID so concurrent disk_slow injections don't
+        // clobber each other's marker file, and `chaos cleanup` can identify
+        // which run left it behind.
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let marker_file = format!("/tmp/chaos_disk_slow_{}.json", injection_id);
         let config_json = serde_json::to_string(&self.config)?;
-        tokio::fs::write(marker_file, config_json).await?;
+        tokio::fs::write(&marker_file, config_json).await?;
 
         let metadata = serde_json::json!({
             "marker_file": marker_file,
             "latency_ms": self.config.latency.as_millis(),
         });
 
-        Ok(InjectionHandle::new("disk_slow", target.clone(), metadata))
+        let mut handle = InjectionHandle::new("disk_slow", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(not(target_os = "linux"))]
@@ -86,11 +94,9 @@ impl Injector for DiskSlowInjector {
     }
 
     async fn remove(&self, handle: InjectionHandle) -> Result<()> {
-        let marker_file = handle
-            .metadata
-            .get("marker_file")
-            .and_then(|v| v.as_str())
-            .unwrap_or("/tmp/chaos_disk_slow.json");
+        let Some(marker_file) = handle.metadata.get("marker_file").and_then(|v| v.as_str()) else {
+            return Ok(());
+        };
 
         info!("Removing disk I/O slowdown");
         tokio::fs::remove_file(marker_file).await.ok();
@@ -100,6 +106,19 @@ impl Injector for DiskSlowInjector {
     fn name(&self) -> &str {
         "disk_slow"
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<crate::injectors::DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(DiskSlowInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::new("latency", ParameterKind::Duration),
+            ParameterSpec::new("operations", ParameterKind::String),
+        ]
+    }
 }
 
 #[derive(Default)]
@@ -176,24 +195,42 @@ impl Injector for DiskFailureInjector {
 }
 
 // Disk Space Exhaustion Injector
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FillGoal {
+    /// Fill until the mount reaches this fraction of total capacity used (0.0 - 1.0)
+    Percentage(f64),
+    /// Fill exactly this many bytes, regardless of current usage
+    AbsoluteBytes(u64),
+}
+
 pub struct DiskSpaceInjector {
-    target_usage: f64, // 0.0 - 1.0, target disk usage percentage
+    goal: FillGoal,
     path: String,
 }
 
 impl DiskSpaceInjector {
+    /// Fill `path`'s mount to `target_usage` (0.0 - 1.0) of total capacity.
+    /// Works for any mount, including tmpfs mounts like `/tmp` or `/dev/shm` -
+    /// shared-memory exhaustion exercises very different code paths than
+    /// exhausting a data disk.
     pub fn new(path: impl Into<String>, target_usage: f64) -> Self {
         Self {
             path: path.into(),
-            target_usage: target_usage.clamp(0.0, 1.0),
+            goal: FillGoal::Percentage(target_usage.clamp(0.0, 1.0)),
+        }
+    }
+
+    pub fn with_goal(path: impl Into<String>, goal: FillGoal) -> Self {
+        Self {
+            path: path.into(),
+            goal,
         }
     }
 
-    async fn fill_disk(&self, bytes_to_fill: u64) -> Result<String> {
-        let temp_file = format!("{}/chaos_disk_fill_{}.tmp", self.path, uuid::Uuid::new_v4());
-        
-        info!("Filling disk with {} bytes at {}", bytes_to_fill, temp_file);
+    async fn fill_disk(&self, bytes_to_fill: u64, injection_id: &str) -> Result<String> {
+        let temp_file = format!("{}/chaos_disk_fill_{}.tmp", self.path, injection_id);
+
+        info!("Filling mount '{}' with {} bytes at {}", self.path, bytes_to_fill, temp_file);
 
         // Create large file
         let file = tokio::fs::File::create(&temp_file).await?;
@@ -203,25 +240,32 @@ impl DiskSpaceInjector {
     }
 
     async fn calculate_bytes_to_fill(&self) -> Result<u64> {
-        // Get filesystem statistics
-        #[cfg(unix)]
-        {
-            use nix::sys::statvfs::statvfs;
-            let stats = statvfs(self.path.as_str())
-                .map_err(|e| ChaosError::SystemError(format!("Failed to stat filesystem: {}", e)))?;
-            
-            let total_space = stats.blocks() * stats.block_size();
-            let free_space = stats.blocks_free() * stats.block_size();
-            let target_free = total_space as f64 * (1.0 - self.target_usage);
-            let bytes_to_fill = (free_space as f64 - target_free).max(0.0) as u64;
-            
-            Ok(bytes_to_fill)
-        }
-
-        #[cfg(not(unix))]
-        {
-            // Simplified for non-Unix
-            Ok((1024 * 1024 * 1024) as u64) // 1GB
+        match self.goal {
+            FillGoal::AbsoluteBytes(bytes) => Ok(bytes),
+            FillGoal::Percentage(target_usage) => {
+                // Get filesystem statistics for whatever mount backs `path` -
+                // this works the same for tmpfs/devtmpfs as for a real disk.
+                #[cfg(unix)]
+                {
+                    use nix::sys::statvfs::statvfs;
+                    let stats = statvfs(self.path.as_str()).map_err(|e| {
+                        ChaosError::SystemError(format!("Failed to stat mount: {}", e))
+                    })?;
+
+                    let total_space = stats.blocks() * stats.block_size();
+                    let free_space = stats.blocks_free() * stats.block_size();
+                    let target_free = total_space as f64 * (1.0 - target_usage);
+                    let bytes_to_fill = (free_space as f64 - target_free).max(0.0) as u64;
+
+                    Ok(bytes_to_fill)
+                }
+
+                #[cfg(not(unix))]
+                {
+                    // Simplified for non-Unix
+                    Ok((1024 * 1024 * 1024) as u64) // 1GB
+                }
+            }
         }
     }
 }
@@ -230,19 +274,19 @@ impl DiskSpaceInjector {
 impl Injector for DiskSpaceInjector {
     async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
         let bytes_to_fill = self.calculate_bytes_to_fill().await?;
-        let temp_file = self.fill_disk(bytes_to_fill).await?;
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let temp_file = self.fill_disk(bytes_to_fill, &injection_id).await?;
 
         let metadata = serde_json::json!({
             "temp_file": temp_file,
             "bytes_filled": bytes_to_fill,
-            "target_usage": self.target_usage,
+            "path": self.path,
+            "goal": self.goal,
         });
 
-        Ok(InjectionHandle::new(
-            "disk_space",
-            target.clone(),
-            metadata,
-        ))
+        let mut handle = InjectionHandle::new("disk_space", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     async fn remove(&self, handle: InjectionHandle) -> Result<()> {
@@ -265,6 +309,110 @@ impl Injector for DiskSpaceInjector {
     }
 }
 
+// Log Flood / Disk Noise Injector
+pub struct LogFloodInjector {
+    log_dir: String,
+    rate_mb_per_sec: f64,
+    stop_signal: std::sync::Arc<tokio::sync::RwLock<bool>>,
+}
+
+impl LogFloodInjector {
+    pub fn new(log_dir: impl Into<String>, rate_mb_per_sec: f64) -> Self {
+        Self {
+            log_dir: log_dir.into(),
+            rate_mb_per_sec: rate_mb_per_sec.max(0.0),
+            stop_signal: std::sync::Arc::new(tokio::sync::RwLock::new(false)),
+        }
+    }
+
+    fn spawn_writer(&self, file_path: String) -> tokio::task::JoinHandle<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let rate_bytes_per_sec = (self.rate_mb_per_sec * 1024.0 * 1024.0) as u64;
+        let stop_signal = self.stop_signal.clone();
+        let chunk = vec![0u8; 64 * 1024];
+
+        tokio::spawn(async move {
+            let mut file = match tokio::fs::File::create(&file_path).await {
+                Ok(f) => f,
+                Err(e) => {
+                    warn!("Log flood writer failed to create {}: {}", file_path, e);
+                    return;
+                }
+            };
+
+            loop {
+                if *stop_signal.read().await {
+                    info!("Stopping log flood writer for {}", file_path);
+                    break;
+                }
+
+                let start = tokio::time::Instant::now();
+                let mut written = 0u64;
+                while written < rate_bytes_per_sec {
+                    if let Err(e) = file.write_all(&chunk).await {
+                        warn!("Log flood writer error on {}: {}", file_path, e);
+                        return;
+                    }
+                    written += chunk.len() as u64;
+                }
+                let elapsed = start.elapsed();
+                if elapsed < Duration::from_secs(1) {
+                    tokio::time::sleep(Duration::from_secs(1) - elapsed).await;
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl Injector for LogFloodInjector {
+    async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+        tokio::fs::create_dir_all(&self.log_dir).await?;
+
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let file_path = format!("{}/chaos_log_flood_{}.log", self.log_dir, injection_id);
+        info!(
+            "Starting log flood at {} MB/s into {}",
+            self.rate_mb_per_sec, file_path
+        );
+
+        *self.stop_signal.write().await = false;
+        self.spawn_writer(file_path.clone());
+
+        let metadata = serde_json::json!({
+            "file_path": file_path,
+            "rate_mb_per_sec": self.rate_mb_per_sec,
+        });
+
+        let mut handle = InjectionHandle::new("log_flood", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
+    }
+
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        info!("Removing log flood injection");
+        *self.stop_signal.write().await = true;
+
+        // Give the writer task time to exit before cleaning up the file
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        if let Some(file_path) = handle.metadata.get("file_path").and_then(|v| v.as_str()) {
+            tokio::fs::remove_file(file_path).await.ok();
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "log_flood"
+    }
+
+    fn required_capabilities(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +428,15 @@ mod tests {
         assert_eq!(injector.config.operations.len(), 2);
     }
 
+    #[test]
+    fn test_disk_slow_configure_accepts_partial_params() {
+        let injector = DiskSlowInjector::default();
+        let params = serde_json::json!({ "operations": ["Write"] });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "disk_slow");
+    }
+
     #[test]
     fn test_disk_failure_rate_clamping() {
         let injector = DiskFailureInjector::new(1.5);
@@ -288,4 +445,13 @@ mod tests {
         let injector = DiskFailureInjector::new(-0.5);
         assert_eq!(injector.failure_rate, 0.0);
     }
+
+    #[test]
+    fn test_disk_space_goal_variants() {
+        let by_percentage = DiskSpaceInjector::new("/tmp", 1.5);
+        assert!(matches!(by_percentage.goal, FillGoal::Percentage(p) if p == 1.0));
+
+        let by_bytes = DiskSpaceInjector::with_goal("/dev/shm", FillGoal::AbsoluteBytes(1024));
+        assert!(matches!(by_bytes.goal, FillGoal::AbsoluteBytes(1024)));
+    }
 }
@@ -3,13 +3,26 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::info;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct MemoryPressureConfig {
     pub target_usage: f64, // 0.0 - 1.0, target memory usage percentage
     pub failure_rate: f64, // 0.0 - 1.0, probability of allocation failure
     pub leak_rate: Option<u64>, // Bytes per second to leak
+    /// Constrain pressure to the target itself via a memory cgroup instead
+    /// of allocating inside the chaos process, which otherwise pressures
+    /// the whole host and risks the injector itself being OOM-killed. Only
+    /// applies to `Target::Process`; `failure_rate` is ignored in this mode
+    /// since target-scoped pressure already caps the target's own cgroup.
+    pub target_scoped: bool,
+    /// When `target_scoped` is set, balloon memory inside a throwaway
+    /// sibling process placed in the target's cgroup rather than lowering
+    /// the target's own limit directly - so cgroup-level reclaim squeezes
+    /// the target without capping it below its current usage.
+    pub balloon_in_child: bool,
 }
 
 impl Default for MemoryPressureConfig {
@@ -18,6 +31,8 @@ impl Default for MemoryPressureConfig {
             target_usage: 0.90,
             failure_rate: 0.0,
             leak_rate: None,
+            target_scoped: false,
+            balloon_in_child: false,
         }
     }
 }
@@ -86,7 +101,7 @@ impl MemoryPressureInjector {
         let (total, used) = self.get_system_memory_info().await?;
         let target_used = (total as f64 * self.config.target_usage) as u64;
         let bytes_to_allocate = target_used.saturating_sub(used);
-        
+
         info!(
             "Memory: total={}MB, used={}MB, target={}MB, will_allocate={}MB",
             total / 1024 / 1024,
@@ -97,39 +112,321 @@ impl MemoryPressureInjector {
 
         Ok(bytes_to_allocate)
     }
+
+    /// Headroom (above the target's current RSS) allowed when `failure_rate`
+    /// is 0.0. It shrinks linearly to zero as `failure_rate` approaches 1.0,
+    /// so a higher rate caps the process closer to its current usage and
+    /// makes its next allocations more likely to hit ENOMEM.
+    const FAILURE_HEADROOM_BYTES: u64 = 256 * 1024 * 1024;
+
+    #[cfg(target_os = "linux")]
+    fn read_process_rss(pid: u32) -> Result<u64> {
+        use sysinfo::{Pid, System};
+
+        let mut sys = System::new_all();
+        sys.refresh_processes();
+
+        sys.process(Pid::from(pid as usize))
+            .map(|p| p.memory())
+            .ok_or_else(|| ChaosError::ProcessError(format!("PID {} not found", pid)))
+    }
+
+    /// Cap the target process's cgroup memory limit just above its current
+    /// RSS so that a configurable fraction of its future allocations fail,
+    /// approximating the probabilistic `failure_rate` field via real kernel
+    /// memory pressure rather than a malloc interposer (there's no build
+    /// step in this workspace for compiling and LD_PRELOAD-ing a shim into
+    /// an already-running target process).
+    #[cfg(target_os = "linux")]
+    async fn apply_failure_cgroup(
+        pid: u32,
+        failure_rate: f64,
+        injection_id: &str,
+    ) -> Result<(String, String, u64)> {
+        let cgroup_name = format!("chaos_mem_failure_{}_{}", pid, injection_id);
+        let cgroup_path = format!("/sys/fs/cgroup/memory/{}", cgroup_name);
+
+        tokio::fs::create_dir_all(&cgroup_path).await.map_err(|e| {
+            ChaosError::InjectionFailed(format!("Failed to create memory cgroup: {}", e))
+        })?;
+
+        let rss = Self::read_process_rss(pid)?;
+        let headroom = (Self::FAILURE_HEADROOM_BYTES as f64 * (1.0 - failure_rate)) as u64;
+        let limit_bytes = rss + headroom;
+
+        info!(
+            "Capping PID {} to {}MB (rss={}MB, failure_rate={}) to induce allocation failures",
+            pid,
+            limit_bytes / 1024 / 1024,
+            rss / 1024 / 1024,
+            failure_rate
+        );
+
+        let limit_file = format!("{}/memory.limit_in_bytes", cgroup_path);
+        tokio::fs::write(&limit_file, limit_bytes.to_string())
+            .await
+            .map_err(|e| {
+                ChaosError::InjectionFailed(format!("Failed to set memory.limit_in_bytes: {}", e))
+            })?;
+
+        let tasks_file = format!("{}/tasks", cgroup_path);
+        tokio::fs::write(&tasks_file, pid.to_string())
+            .await
+            .map_err(|e| {
+                ChaosError::InjectionFailed(format!(
+                    "Failed to add process {} to memory cgroup: {}",
+                    pid, e
+                ))
+            })?;
+
+        Ok((cgroup_name, cgroup_path, limit_bytes))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    async fn apply_failure_cgroup(
+        _pid: u32,
+        _failure_rate: f64,
+        _injection_id: &str,
+    ) -> Result<(String, String, u64)> {
+        Err(ChaosError::SystemError(
+            "Allocation-failure injection via cgroup memory limits is only supported on Linux"
+                .to_string(),
+        ))
+    }
+
+    /// Constrain `pid` via a dedicated memory cgroup rather than growing the
+    /// chaos process's own heap. With `balloon_in_child`, spawns a sibling
+    /// `memory_balloon` process in the same cgroup and caps the group at
+    /// `rss + bytes_to_allocate`, so the sibling's own allocation is what
+    /// pushes the group toward its limit. Without it, the target is capped
+    /// at its current RSS directly, squeezing its own future allocations.
+    #[cfg(target_os = "linux")]
+    async fn apply_target_scoped_pressure(
+        pid: u32,
+        bytes_to_allocate: u64,
+        balloon_in_child: bool,
+        injection_id: &str,
+    ) -> Result<(String, String, Option<u32>)> {
+        let cgroup_name = format!("chaos_mem_pressure_{}_{}", pid, injection_id);
+        let cgroup_path = format!("/sys/fs/cgroup/memory/{}", cgroup_name);
+
+        tokio::fs::create_dir_all(&cgroup_path).await.map_err(|e| {
+            ChaosError::InjectionFailed(format!("Failed to create memory cgroup: {}", e))
+        })?;
+
+        let rss = Self::read_process_rss(pid)?;
+
+        let (limit_bytes, balloon_pid) = if balloon_in_child {
+            let balloon_pid = Self::spawn_balloon_child(bytes_to_allocate).await?;
+            let balloon_tasks_file = format!("{}/tasks", cgroup_path);
+            tokio::fs::write(&balloon_tasks_file, balloon_pid.to_string())
+                .await
+                .map_err(|e| {
+                    ChaosError::InjectionFailed(format!(
+                        "Failed to add balloon process {} to memory cgroup: {}",
+                        balloon_pid, e
+                    ))
+                })?;
+            (rss + bytes_to_allocate, Some(balloon_pid))
+        } else {
+            (rss.max(1), None)
+        };
+
+        info!(
+            "Capping PID {} to a {}MB cgroup (rss={}MB, balloon={})",
+            pid,
+            limit_bytes / 1024 / 1024,
+            rss / 1024 / 1024,
+            balloon_pid.is_some()
+        );
+
+        let limit_file = format!("{}/memory.limit_in_bytes", cgroup_path);
+        tokio::fs::write(&limit_file, limit_bytes.to_string())
+            .await
+            .map_err(|e| {
+                ChaosError::InjectionFailed(format!("Failed to set memory.limit_in_bytes: {}", e))
+            })?;
+
+        let tasks_file = format!("{}/tasks", cgroup_path);
+        tokio::fs::write(&tasks_file, pid.to_string())
+            .await
+            .map_err(|e| {
+                ChaosError::InjectionFailed(format!(
+                    "Failed to add process {} to memory cgroup: {}",
+                    pid, e
+                ))
+            })?;
+
+        Ok((cgroup_name, cgroup_path, balloon_pid))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    async fn apply_target_scoped_pressure(
+        _pid: u32,
+        _bytes_to_allocate: u64,
+        _balloon_in_child: bool,
+        _injection_id: &str,
+    ) -> Result<(String, String, Option<u32>)> {
+        Err(ChaosError::SystemError(
+            "Target-scoped pressure via cgroup memory limits is only supported on Linux"
+                .to_string(),
+        ))
+    }
+
+    /// Locate and spawn the `memory_balloon` helper binary built alongside
+    /// this one in the workspace target directory, the same way
+    /// `chaos self-test` locates `tcp_echo_server`.
+    #[cfg(target_os = "linux")]
+    async fn spawn_balloon_child(bytes: u64) -> Result<u32> {
+        let exe = std::env::current_exe()
+            .map_err(|e| ChaosError::SystemError(format!("could not locate running binary: {}", e)))?;
+        let dir = exe.parent().ok_or_else(|| {
+            ChaosError::SystemError("could not determine directory of the running binary".to_string())
+        })?;
+        let binary = dir.join("memory_balloon");
+
+        if !binary.exists() {
+            return Err(ChaosError::SystemError(format!(
+                "memory_balloon helper binary not found at {}; build the workspace with `cargo build --workspace` first",
+                binary.display()
+            )));
+        }
+
+        let mut child = tokio::process::Command::new(&binary)
+            .arg(bytes.to_string())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                ChaosError::InjectionFailed(format!("failed to spawn memory_balloon: {}", e))
+            })?;
+
+        let pid = child.id().ok_or_else(|| {
+            ChaosError::InjectionFailed("memory_balloon exited immediately".to_string())
+        })?;
+
+        // Reap it in the background once `remove()` kills it, instead of
+        // leaving a zombie behind - we don't hold onto the `Child` handle
+        // since it needs to outlive this function call.
+        tokio::spawn(async move {
+            let _ = child.wait().await;
+        });
+
+        Ok(pid)
+    }
 }
 
 #[async_trait]
 impl Injector for MemoryPressureInjector {
     async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
         let bytes_to_allocate = self.calculate_bytes_to_allocate().await?;
-        
-        if bytes_to_allocate > 0 {
-            self.allocate_memory(bytes_to_allocate).await?;
-        }
+        // Generated up front so it can be baked into the cgroup name below,
+        // then carried over onto the returned handle's own ID - keeps the
+        // on-disk artifact and the handle that tracks it named identically.
+        let injection_id = uuid::Uuid::new_v4().to_string();
 
-        let metadata = serde_json::json!({
+        let mut metadata = serde_json::json!({
             "bytes_allocated": bytes_to_allocate,
             "target_usage": self.config.target_usage,
+            "failure_rate": self.config.failure_rate,
+            "target_scoped": self.config.target_scoped,
         });
 
-        Ok(InjectionHandle::new(
-            "memory_pressure",
-            target.clone(),
-            metadata,
-        ))
+        if self.config.target_scoped {
+            let Target::Process { pid } = target else {
+                return Err(ChaosError::InvalidConfig(
+                    "target_scoped pressure requires a Process target".to_string(),
+                ));
+            };
+
+            let (cgroup_name, cgroup_path, balloon_pid) = Self::apply_target_scoped_pressure(
+                *pid,
+                bytes_to_allocate,
+                self.config.balloon_in_child,
+                &injection_id,
+            )
+            .await?;
+
+            metadata["cgroup_name"] = serde_json::json!(cgroup_name);
+            metadata["cgroup_path"] = serde_json::json!(cgroup_path);
+            if let Some(balloon_pid) = balloon_pid {
+                metadata["balloon_pid"] = serde_json::json!(balloon_pid);
+            }
+        } else {
+            if bytes_to_allocate > 0 {
+                self.allocate_memory(bytes_to_allocate).await?;
+            }
+
+            if self.config.failure_rate > 0.0 {
+                let Target::Process { pid } = target else {
+                    return Err(ChaosError::InvalidConfig(
+                        "failure_rate > 0.0 requires a Process target".to_string(),
+                    ));
+                };
+
+                let (cgroup_name, cgroup_path, limit_bytes) =
+                    Self::apply_failure_cgroup(*pid, self.config.failure_rate, &injection_id)
+                        .await?;
+
+                metadata["cgroup_name"] = serde_json::json!(cgroup_name);
+                metadata["cgroup_path"] = serde_json::json!(cgroup_path);
+                metadata["cgroup_limit_bytes"] = serde_json::json!(limit_bytes);
+            }
+        }
+
+        let mut handle = InjectionHandle::new("memory_pressure", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
         info!("Releasing allocated memory");
         let mut blocks = self.allocated_blocks.lock().await;
         blocks.clear();
+        drop(blocks);
+
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(balloon_pid) = _handle.metadata.get("balloon_pid").and_then(|v| v.as_u64())
+            {
+                info!("Killing memory balloon process {}", balloon_pid);
+                let _ = nix::sys::signal::kill(
+                    nix::unistd::Pid::from_raw(balloon_pid as i32),
+                    nix::sys::signal::Signal::SIGKILL,
+                );
+            }
+
+            if let Some(cgroup_path) = _handle.metadata.get("cgroup_path").and_then(|v| v.as_str())
+            {
+                info!("Removing memory cgroup: {}", cgroup_path);
+                tokio::fs::remove_dir(cgroup_path).await.map_err(|e| {
+                    ChaosError::CleanupFailed(format!("Failed to remove memory cgroup: {}", e))
+                })?;
+            }
+        }
+
         Ok(())
     }
 
     fn name(&self) -> &str {
         "memory_pressure"
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<crate::injectors::DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(MemoryPressureInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::bounded("target_usage", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::bounded("failure_rate", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::new("leak_rate", ParameterKind::Integer),
+            ParameterSpec::new("target_scoped", ParameterKind::Bool),
+            ParameterSpec::new("balloon_in_child", ParameterKind::Bool),
+        ]
+    }
 }
 
 #[derive(Default)]
@@ -137,6 +434,8 @@ pub struct MemoryPressureBuilder {
     target_usage: Option<f64>,
     failure_rate: Option<f64>,
     leak_rate: Option<u64>,
+    target_scoped: Option<bool>,
+    balloon_in_child: Option<bool>,
 }
 
 impl MemoryPressureBuilder {
@@ -155,53 +454,140 @@ impl MemoryPressureBuilder {
         self
     }
 
+    pub fn target_scoped(mut self, target_scoped: bool) -> Self {
+        self.target_scoped = Some(target_scoped);
+        self
+    }
+
+    pub fn balloon_in_child(mut self, balloon_in_child: bool) -> Self {
+        self.balloon_in_child = Some(balloon_in_child);
+        self
+    }
+
     pub fn build(self) -> MemoryPressureInjector {
         MemoryPressureInjector {
             config: MemoryPressureConfig {
                 target_usage: self.target_usage.unwrap_or(0.90),
                 failure_rate: self.failure_rate.unwrap_or(0.0),
                 leak_rate: self.leak_rate,
+                target_scoped: self.target_scoped.unwrap_or(false),
+                balloon_in_child: self.balloon_in_child.unwrap_or(false),
             },
             allocated_blocks: Arc::new(tokio::sync::Mutex::new(Vec::new())),
         }
     }
 }
 
+/// How a `MemoryLeakInjector`'s per-tick chunk size grows over time.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum GrowthProfile {
+    /// Leak `leak_rate` bytes every tick, indefinitely.
+    #[default]
+    Linear,
+    /// Double the chunk size every tick (capped to avoid overflow), so the
+    /// leak accelerates the longer it runs.
+    Exponential,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MemoryLeakConfig {
+    pub leak_rate: u64, // Bytes per second (baseline chunk size)
+    /// Stop leaking once this many total bytes have been allocated. `None`
+    /// means unbounded, which can OOM the chaos tool itself - prefer setting
+    /// this for anything other than a quick manual test.
+    pub max_bytes: Option<u64>,
+    /// Stop leaking after this much wall-clock time has elapsed, regardless
+    /// of `max_bytes`.
+    pub duration: Option<Duration>,
+    pub growth: GrowthProfile,
+}
+
+impl Default for MemoryLeakConfig {
+    fn default() -> Self {
+        Self {
+            leak_rate: 1024 * 1024, // 1 MB/sec
+            max_bytes: None,
+            duration: None,
+            growth: GrowthProfile::default(),
+        }
+    }
+}
+
 // Memory Leak Injector
 #[derive(Debug, Clone)]
 pub struct MemoryLeakInjector {
-    leak_rate: u64, // Bytes per second
+    config: MemoryLeakConfig,
     allocated_blocks: Arc<tokio::sync::Mutex<Vec<Vec<u8>>>>,
     stop_signal: Arc<AtomicBool>,
 }
 
+impl Default for MemoryLeakInjector {
+    fn default() -> Self {
+        Self::new(MemoryLeakConfig::default())
+    }
+}
+
 impl MemoryLeakInjector {
-    pub fn new(leak_rate: u64) -> Self {
+    pub fn new(config: MemoryLeakConfig) -> Self {
         Self {
-            leak_rate,
+            config,
             allocated_blocks: Arc::new(tokio::sync::Mutex::new(Vec::new())),
             stop_signal: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    pub fn builder() -> MemoryLeakBuilder {
+        MemoryLeakBuilder::default()
+    }
+
     async fn start_leaking(&self) -> tokio::task::JoinHandle<()> {
-        let leak_rate = self.leak_rate;
+        let config = self.config.clone();
         let blocks = self.allocated_blocks.clone();
         let stop_signal = self.stop_signal.clone();
 
         tokio::spawn(async move {
-            info!("Starting memory leak: {} bytes/sec", leak_rate);
+            info!(
+                "Starting memory leak: {} bytes/sec ({:?} growth, max_bytes={:?}, duration={:?})",
+                config.leak_rate, config.growth, config.max_bytes, config.duration
+            );
+
+            let start = tokio::time::Instant::now();
+            let mut total_leaked: u64 = 0;
+            let mut tick: u32 = 0;
 
             while !stop_signal.load(Ordering::Relaxed) {
-                // Allocate memory
-                let block = vec![0u8; leak_rate as usize];
+                if let Some(duration) = config.duration {
+                    if start.elapsed() >= duration {
+                        info!("Memory leak stopped: duration limit reached");
+                        break;
+                    }
+                }
+
+                let mut chunk_size = match config.growth {
+                    GrowthProfile::Linear => config.leak_rate,
+                    GrowthProfile::Exponential => {
+                        config.leak_rate.saturating_mul(1u64 << tick.min(16))
+                    }
+                };
+
+                if let Some(max_bytes) = config.max_bytes {
+                    chunk_size = chunk_size.min(max_bytes.saturating_sub(total_leaked));
+                    if chunk_size == 0 {
+                        info!("Memory leak stopped: max_bytes limit reached");
+                        break;
+                    }
+                }
+
+                let block = vec![0u8; chunk_size as usize];
                 blocks.lock().await.push(block);
+                total_leaked += chunk_size;
+                tick += 1;
 
-                // Wait 1 second
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                tokio::time::sleep(Duration::from_secs(1)).await;
             }
 
-            info!("Memory leak stopped");
+            info!("Memory leak loop exited after leaking {} bytes", total_leaked);
         })
     }
 }
@@ -213,7 +599,10 @@ impl Injector for MemoryLeakInjector {
         self.start_leaking().await;
 
         let metadata = serde_json::json!({
-            "leak_rate": self.leak_rate,
+            "leak_rate": self.config.leak_rate,
+            "max_bytes": self.config.max_bytes,
+            "duration": self.config.duration,
+            "growth": self.config.growth,
         });
 
         Ok(InjectionHandle::new(
@@ -226,7 +615,7 @@ impl Injector for MemoryLeakInjector {
     async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
         info!("Stopping memory leak and freeing memory");
         self.stop_signal.store(true, Ordering::Relaxed);
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
         self.allocated_blocks.lock().await.clear();
         Ok(())
     }
@@ -234,6 +623,60 @@ impl Injector for MemoryLeakInjector {
     fn name(&self) -> &str {
         "memory_leak"
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<crate::injectors::DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(MemoryLeakInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::new("leak_rate", ParameterKind::Integer),
+            ParameterSpec::new("max_bytes", ParameterKind::Integer),
+            ParameterSpec::new("duration", ParameterKind::Duration),
+            ParameterSpec::new("growth", ParameterKind::Enum),
+        ]
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryLeakBuilder {
+    leak_rate: Option<u64>,
+    max_bytes: Option<u64>,
+    duration: Option<Duration>,
+    growth: Option<GrowthProfile>,
+}
+
+impl MemoryLeakBuilder {
+    pub fn leak_rate(mut self, bytes_per_second: u64) -> Self {
+        self.leak_rate = Some(bytes_per_second);
+        self
+    }
+
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn growth(mut self, growth: GrowthProfile) -> Self {
+        self.growth = Some(growth);
+        self
+    }
+
+    pub fn build(self) -> MemoryLeakInjector {
+        MemoryLeakInjector::new(MemoryLeakConfig {
+            leak_rate: self.leak_rate.unwrap_or(1024 * 1024),
+            max_bytes: self.max_bytes,
+            duration: self.duration,
+            growth: self.growth.unwrap_or_default(),
+        })
+    }
 }
 
 // OOM Killer Injector
@@ -243,6 +686,12 @@ pub struct OomKillerInjector {
     target_pid: Option<u32>,
 }
 
+impl Default for OomKillerInjector {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
 impl OomKillerInjector {
     pub fn new(target_pid: Option<u32>) -> Self {
         Self { target_pid }
@@ -330,9 +779,74 @@ mod tests {
         assert_eq!(injector.config.failure_rate, 0.1);
     }
 
+    #[test]
+    fn test_memory_pressure_configure_accepts_partial_params() {
+        let injector = MemoryPressureInjector::default();
+        let params = serde_json::json!({ "target_usage": 0.5 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "memory_pressure");
+    }
+
+    #[test]
+    fn test_memory_leak_configure_accepts_partial_params() {
+        let injector = MemoryLeakInjector::default();
+        let params = serde_json::json!({ "leak_rate": 2048 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "memory_leak");
+    }
+
     #[test]
     fn test_memory_leak_injector() {
-        let injector = MemoryLeakInjector::new(1024 * 1024); // 1 MB/sec
-        assert_eq!(injector.leak_rate, 1024 * 1024);
+        let injector = MemoryLeakInjector::builder().leak_rate(1024 * 1024).build();
+        assert_eq!(injector.config.leak_rate, 1024 * 1024);
+        assert_eq!(injector.config.growth, GrowthProfile::Linear);
+    }
+
+    #[test]
+    fn test_memory_leak_builder_sets_caps() {
+        let injector = MemoryLeakInjector::builder()
+            .leak_rate(1024)
+            .max_bytes(10 * 1024)
+            .duration(Duration::from_secs(30))
+            .growth(GrowthProfile::Exponential)
+            .build();
+
+        assert_eq!(injector.config.max_bytes, Some(10 * 1024));
+        assert_eq!(injector.config.duration, Some(Duration::from_secs(30)));
+        assert_eq!(injector.config.growth, GrowthProfile::Exponential);
+    }
+
+    #[tokio::test]
+    async fn test_memory_pressure_failure_rate_rejects_non_process_target() {
+        let injector = MemoryPressureInjector::builder()
+            .target_usage(0.0) // skip the background allocation, just exercise failure_rate
+            .failure_rate(0.5)
+            .build();
+
+        let result = injector
+            .inject(&Target::Network {
+                address: "127.0.0.1:8080".parse().unwrap(),
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_memory_pressure_target_scoped_rejects_non_process_target() {
+        let injector = MemoryPressureInjector::builder()
+            .target_usage(0.0)
+            .target_scoped(true)
+            .build();
+
+        let result = injector
+            .inject(&Target::Network {
+                address: "127.0.0.1:8080".parse().unwrap(),
+            })
+            .await;
+
+        assert!(result.is_err());
     }
 }
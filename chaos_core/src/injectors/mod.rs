@@ -35,6 +35,177 @@ pub trait Injector: Send + Sync {
     fn required_capabilities(&self) -> Vec<String> {
         vec![]
     }
+
+    /// Checks whether the fault `handle` represents is still actually in
+    /// place on the host (the tc qdisc is still attached, the iptables rule
+    /// still exists, the cgroup is still frozen, ...), returning `Ok(false)`
+    /// if an external actor undid it without going through
+    /// [`crate::executor::Executor::remove`]. `Executor`'s drift monitor
+    /// polls this for every active injection and emits
+    /// [`crate::events::ExecutorEvent::DriftDetected`] when it comes back
+    /// `false`. The default assumes still-active: most injectors have no
+    /// independent way to probe their own state without re-running a
+    /// command, so this is only overridden where re-querying the system is
+    /// straightforward.
+    async fn verify(&self, _handle: &InjectionHandle) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Optional cargo-feature-gated integration (docker, kubernetes, ...)
+    /// this injector needs to have been compiled in. `None` for injectors
+    /// built on the base crate's always-available primitives - which is
+    /// all of them today.
+    fn required_feature(&self) -> Option<crate::capabilities::Capability> {
+        None
+    }
+
+    /// Build a fresh, independently-configured instance of this injector
+    /// from scenario parameters (`InjectionConfig::parameters`), without
+    /// mutating the registry's shared default instance. The default
+    /// rejects configuration; injectors backed by a serde `Config` struct
+    /// override this, typically via [`configure_from_params`].
+    fn configure(&self, _params: &serde_json::Value) -> Result<DynInjector> {
+        Err(crate::error::ChaosError::InvalidConfig(format!(
+            "injector '{}' does not support scenario-level parameters",
+            self.name()
+        )))
+    }
+
+    /// Machine-readable schema of this injector's tunable parameters (name,
+    /// type, bounds), so scenarios can be checked against it before
+    /// `configure` is ever called. Empty for injectors with no tunable
+    /// parameters.
+    fn parameter_schema(&self) -> Vec<ParameterSpec> {
+        vec![]
+    }
+
+    /// Name of the single numeric parameter this injector treats as a
+    /// continuous intensity dial (e.g. `"intensity"` for CPU starvation,
+    /// `"rate"` for packet loss), for injectors that can be driven from one
+    /// value to another over a ramp duration instead of applied as a step
+    /// function. `None` for injectors with no such dial, or whose dial
+    /// isn't a plain number (e.g. latency's delay, which is a `Duration`).
+    /// `chaos_scenarios`'s runner re-applies the injection at each ramp
+    /// step rather than this trait mutating a live injection in place, so
+    /// nothing else about the injector needs to change to support it.
+    fn ramp_parameter(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Describes, without doing it, the concrete command(s) or system
+    /// change(s) `inject` would make against `target`. This is what
+    /// `Executor`'s dry-run mode reports instead of actually injecting, so
+    /// a scenario can be reviewed against a production host safely. The
+    /// default falls back to the injector's name and target; injectors
+    /// that shell out to a specific, inspectable command override this
+    /// with the literal command line(s) they'd run.
+    fn describe_dry_run(&self, target: &Target) -> Vec<String> {
+        vec![format!(
+            "{} would apply to {} (no command-level dry-run model for this injector yet)",
+            self.name(),
+            target.description()
+        )]
+    }
+
+    /// Rejects `params` that reference a parameter not in
+    /// `parameter_schema()`, or a numeric value outside its declared
+    /// bounds. Injectors don't normally need to override this - declaring
+    /// a schema is enough.
+    fn validate_params(&self, params: &serde_json::Value) -> Result<()> {
+        let Some(object) = params.as_object() else {
+            return Ok(());
+        };
+
+        let schema = self.parameter_schema();
+
+        for (key, value) in object {
+            let spec = schema.iter().find(|spec| spec.name == key).ok_or_else(|| {
+                crate::error::ChaosError::InvalidConfig(format!(
+                    "injector '{}' has no parameter named '{}'",
+                    self.name(),
+                    key
+                ))
+            })?;
+
+            if let (Some((min, max)), Some(n)) = (spec.bounds, value.as_f64()) {
+                if n < min || n > max {
+                    return Err(crate::error::ChaosError::InvalidConfig(format!(
+                        "injector '{}' parameter '{}' = {} is out of range [{}, {}]",
+                        self.name(),
+                        key,
+                        n,
+                        min,
+                        max
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The type of value a [`ParameterSpec`] describes. Informational only -
+/// `Injector::validate_params`'s default implementation checks bounds, not
+/// the declared type, since scenario parameters arrive as loosely-typed
+/// JSON and the real type check happens at `configure` time via serde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    Float,
+    Integer,
+    Bool,
+    Duration,
+    String,
+    Enum,
+}
+
+/// Describes one tunable field on an injector's config: its scenario-file
+/// name, its rough type, and its inclusive numeric bounds (if any).
+#[derive(Debug, Clone)]
+pub struct ParameterSpec {
+    pub name: &'static str,
+    pub kind: ParameterKind,
+    pub bounds: Option<(f64, f64)>,
+}
+
+impl ParameterSpec {
+    pub const fn new(name: &'static str, kind: ParameterKind) -> Self {
+        Self {
+            name,
+            kind,
+            bounds: None,
+        }
+    }
+
+    pub const fn bounded(name: &'static str, kind: ParameterKind, min: f64, max: f64) -> Self {
+        Self {
+            name,
+            kind,
+            bounds: Some((min, max)),
+        }
+    }
+}
+
+/// Applies scenario-supplied `params` on top of `T::default()` and
+/// deserializes the result, so scenario files only need to specify the
+/// fields they want to override rather than a full config. Used by
+/// [`Injector::configure`] implementations for injectors backed by a serde
+/// `Config` struct.
+pub(crate) fn configure_from_params<T>(params: &serde_json::Value) -> Result<T>
+where
+    T: Default + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut base = serde_json::to_value(T::default())?;
+
+    if let Some(overrides) = params.as_object() {
+        if let Some(base_obj) = base.as_object_mut() {
+            for (key, value) in overrides {
+                base_obj.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    Ok(serde_json::from_value(base)?)
 }
 
 pub type DynInjector = Arc<dyn Injector>;
@@ -93,7 +264,54 @@ impl InjectorRegistry {
             "process_kill",
             Arc::new(ProcessKillInjector::default()),
         );
-        
+        registry.register(
+            "memory_leak",
+            Arc::new(MemoryLeakInjector::default()),
+        );
+        registry.register(
+            "oom_killer",
+            Arc::new(OomKillerInjector::default()),
+        );
+
         registry
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_params_rejects_unknown_parameter() {
+        let injector = CpuStarvationInjector::default();
+        let params = serde_json::json!({ "not_a_real_field": 1.0 });
+
+        let err = injector.validate_params(&params).unwrap_err();
+        assert!(err.to_string().contains("not_a_real_field"));
+    }
+
+    #[test]
+    fn test_validate_params_rejects_out_of_range_bound() {
+        let injector = CpuStarvationInjector::default();
+        let params = serde_json::json!({ "intensity": 1.5 });
+
+        let err = injector.validate_params(&params).unwrap_err();
+        assert!(err.to_string().contains("intensity"));
+    }
+
+    #[test]
+    fn test_validate_params_accepts_in_range_values() {
+        let injector = CpuStarvationInjector::default();
+        let params = serde_json::json!({ "intensity": 0.5 });
+
+        assert!(injector.validate_params(&params).is_ok());
+    }
+
+    #[test]
+    fn test_validate_params_default_rejects_any_param_without_schema() {
+        let injector = TcpResetInjector::default();
+        let params = serde_json::json!({ "unknown": true });
+
+        assert!(injector.validate_params(&params).is_err());
+    }
+}
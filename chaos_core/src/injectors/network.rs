@@ -1,13 +1,215 @@
-use crate::{error::*, handle::InjectionHandle, injectors::Injector, target::Target};
+use crate::{
+    error::*,
+    handle::InjectionHandle,
+    injectors::{DynInjector, Injector},
+    target::Target,
+};
 use async_trait::async_trait;
 use rand::Rng;
 use rand_distr::{Distribution, Normal, Uniform, Exp};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
 #[allow(unused_imports)] // Used in platform-specific code blocks
 use tokio::process::Command;
 use tracing::info;
+#[cfg(target_os = "macos")]
+use tracing::warn;
+
+/// pf anchor / dummynet pipe bookkeeping for the macOS injectors. Every
+/// fault gets its own sub-anchor (`chaos/<injection-id>`) and rule file
+/// instead of all injections sharing the top-level `chaos` anchor, so
+/// removing one fault can flush exactly its own anchor without disturbing
+/// any other fault that happens to be active at the same time.
+#[cfg(target_os = "macos")]
+mod macos_pf {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// dummynet pipe numbers are a small shared namespace (1-65535); start
+    /// well above any number an operator might have configured by hand and
+    /// wrap before running into the practical ceiling.
+    static NEXT_PIPE: AtomicU32 = AtomicU32::new(100);
+
+    pub fn alloc_pipe() -> u32 {
+        let pipe = NEXT_PIPE.fetch_add(1, Ordering::Relaxed);
+        if pipe >= 65000 {
+            NEXT_PIPE.store(100, Ordering::Relaxed);
+        }
+        pipe
+    }
+
+    pub fn anchor_for(injection_id: &str) -> String {
+        format!("chaos/{}", injection_id)
+    }
+
+    pub fn rule_file_for(injection_id: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("chaos_pf_{}.rules", injection_id))
+    }
+
+    /// Writes `rule` to this injection's rule file and loads it into this
+    /// injection's own sub-anchor, leaving every other anchor (including
+    /// other chaos injections') untouched. `pipe`, if the rule references a
+    /// dummynet pipe, is torn down again on a load failure so a failed
+    /// injection doesn't leak an allocated pipe number.
+    pub async fn load_rule(injection_id: &str, pipe: u32, rule: &str) -> Result<std::path::PathBuf> {
+        match load_anchor_rule(injection_id, rule).await {
+            Ok(path) => Ok(path),
+            Err(e) => {
+                let _ = dnctl_delete(pipe).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Same as [`load_rule`] but for rules (e.g. a plain `block return-rst`)
+    /// that don't allocate a dummynet pipe.
+    pub async fn load_anchor_rule(injection_id: &str, rule: &str) -> Result<std::path::PathBuf> {
+        let rule_file = rule_file_for(injection_id);
+        tokio::fs::write(&rule_file, format!("{}\n", rule))
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to write pf rule file: {}", e)))?;
+
+        let anchor = anchor_for(injection_id);
+        let output = Command::new("sudo")
+            .args(["pfctl", "-a", &anchor, "-f"])
+            .arg(&rule_file)
+            .output()
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run pfctl: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let _ = tokio::fs::remove_file(&rule_file).await;
+            return Err(ChaosError::InjectionFailed(format!(
+                "pfctl failed to load anchor {}: {}",
+                anchor, stderr
+            )));
+        }
+
+        Ok(rule_file)
+    }
+
+    /// Flushes only this injection's own sub-anchor and (if `pipe` is set)
+    /// deletes its dummynet pipe, leaving the shared top-level `chaos`
+    /// anchor and any sibling injections' sub-anchors alone.
+    pub async fn cleanup(injection_id: &str, pipe: Option<u32>, rule_file: Option<&str>) {
+        let anchor = anchor_for(injection_id);
+        let output = Command::new("sudo").args(["pfctl", "-a", &anchor, "-F", "all"]).output().await;
+        if let Err(e) = output {
+            info!("pfctl cleanup note for anchor {} (may be already removed): {}", anchor, e);
+        }
+
+        if let Some(pipe) = pipe {
+            if let Err(e) = dnctl_delete(pipe).await {
+                info!("dnctl cleanup note for pipe {} (may be already removed): {}", pipe, e);
+            }
+        }
+
+        if let Some(path) = rule_file {
+            let _ = tokio::fs::remove_file(path).await;
+        }
+    }
+
+    async fn dnctl_delete(pipe: u32) -> Result<()> {
+        Command::new("sudo")
+            .args(["dnctl", "pipe", &pipe.to_string(), "delete"])
+            .output()
+            .await
+            .map(|_| ())
+            .map_err(|e| ChaosError::CleanupFailed(format!("Failed to run dnctl: {}", e)))
+    }
+}
+
+/// Runs `program` with `args` against whatever namespace/host `target`
+/// resolves to: over the shared `SshPool` for a [`Target::Remote`], inside
+/// the namespace file for a [`Target::NetNamespace`] (via `nsenter --net`,
+/// which works for both named netns bind-mounts and a container's
+/// `/proc/<pid>/ns/net`), or as a plain local command otherwise. Shared by
+/// every tc/iptables-based injector so adding namespace or remote support
+/// to one of them is a one-line change at the call site.
+#[cfg(target_os = "linux")]
+async fn run_networked(target: &Target, program: &str, args: &[&str]) -> Result<std::process::Output> {
+    match target {
+        Target::Remote { host, .. } => crate::remote::default_pool().run(host, program, args).await,
+        Target::NetNamespace { path, .. } => Command::new("nsenter")
+            .arg("--net")
+            .arg(path)
+            .arg("--")
+            .arg(program)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run {} via nsenter: {}", program, e))),
+        _ => Command::new(program)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run {}: {}", program, e))),
+    }
+}
+
+/// Resolves the egress interface to shape for `target`. An explicit
+/// `override_interface` (set via a `NetworkLatencyConfig`/`PacketLossConfig`
+/// `interface` param) always wins. Otherwise, for a `Target::Network`, asks
+/// the routing table with `ip route get <address>` and takes the `dev`
+/// field off the reply - run through [`run_networked`] so it resolves the
+/// route as seen from inside a `Target::NetNamespace` or on a
+/// `Target::Remote` host, not the operator box's own routing table. Falls
+/// back to `eth0`, the same default every injector used before route
+/// resolution existed, if neither an override nor a route lookup succeeds.
+#[cfg(target_os = "linux")]
+async fn resolve_interface(target: &Target, override_interface: Option<&str>) -> Result<String> {
+    if let Some(iface) = override_interface {
+        return Ok(iface.to_string());
+    }
+
+    if let Target::Network { address } = target {
+        if let Ok(output) = run_networked(target, "ip", &["route", "get", &address.ip().to_string()]).await {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                if let Some(iface) = parse_route_get_device(&stdout) {
+                    return Ok(iface);
+                }
+            }
+        }
+    }
+
+    Ok("eth0".to_string())
+}
+
+/// Pulls the interface name out of `ip route get`'s reply, e.g.
+/// `10.0.0.5 via 10.0.0.1 dev eth1 src 10.0.0.2` -> `eth1`.
+#[cfg(target_os = "linux")]
+fn parse_route_get_device(output: &str) -> Option<String> {
+    let words: Vec<&str> = output.split_whitespace().collect();
+    words
+        .windows(2)
+        .find(|pair| pair[0] == "dev")
+        .map(|pair| pair[1].to_string())
+}
+
+/// Runs `tc` with `args`, turning a nonzero exit into an `InjectionFailed`.
+/// If `target` is a [`Target::Remote`], runs it on that host over the
+/// shared `SshPool` instead of locally; if it's a [`Target::NetNamespace`],
+/// runs it inside that namespace via `nsenter`. Either way the same
+/// qdisc/filter logic works regardless of where the interface being shaped
+/// actually lives.
+#[cfg(target_os = "linux")]
+async fn run_tc(target: &Target, args: &[&str]) -> Result<()> {
+    let output = run_networked(target, "tc", args).await?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(ChaosError::InjectionFailed(format!(
+            "tc command failed: {}",
+            stderr
+        )));
+    }
+
+    Ok(())
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LatencyDistribution {
@@ -37,12 +239,34 @@ impl LatencyDistribution {
     }
 }
 
+/// Which packets a [`NetworkLatencyInjector`] delays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LatencyScope {
+    /// Delay every packet on the flow - the original, steady-state behavior.
+    #[default]
+    AllTraffic,
+    /// Delay only connection establishment (SYN / SYN-ACK), leaving
+    /// already-established flows at normal latency. Stresses connect()
+    /// timeouts and connection-pool warmup, which a flat per-packet delay
+    /// exercises very differently.
+    ConnectOnly,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NetworkLatencyConfig {
     pub mean: Duration,
     pub jitter: Duration,
     pub distribution: LatencyDistribution,
     pub correlation: f64, // 0.0 - 1.0, how correlated successive delays are
+    #[serde(default)]
+    pub scope: LatencyScope,
+    /// Explicit egress interface, bypassing routing-table resolution.
+    /// Useful when the target isn't a `Target::Network` (so there's no
+    /// address to resolve a route for) or when the route table doesn't
+    /// reflect the interface the operator actually wants shaped.
+    #[serde(default)]
+    pub interface: Option<String>,
 }
 
 impl Default for NetworkLatencyConfig {
@@ -55,23 +279,18 @@ impl Default for NetworkLatencyConfig {
                 std_dev: 20.0,
             },
             correlation: 0.0,
+            scope: LatencyScope::default(),
+            interface: None,
         }
     }
 }
 
+#[derive(Default)]
 pub struct NetworkLatencyInjector {
     #[allow(dead_code)]
     config: NetworkLatencyConfig,
 }
 
-impl Default for NetworkLatencyInjector {
-    fn default() -> Self {
-        Self {
-            config: NetworkLatencyConfig::default(),
-        }
-    }
-}
-
 impl NetworkLatencyInjector {
     pub fn new(config: NetworkLatencyConfig) -> Self {
         Self { config }
@@ -88,44 +307,89 @@ impl NetworkLatencyInjector {
         let jitter_ms = self.config.jitter.as_millis();
         let correlation = (self.config.correlation * 100.0) as u32;
 
-        info!(
-            "Injecting network latency on {}: mean={}ms, jitter={}ms",
-            interface, mean_ms, jitter_ms
-        );
-
-        // Use tc (traffic control) with netem
-        let output = Command::new("tc")
-            .args(&[
-                "qdisc",
-                "add",
-                "dev",
-                &interface,
-                "root",
-                "netem",
-                "delay",
-                &format!("{}ms", mean_ms),
-                &format!("{}ms", jitter_ms),
-                &format!("{}%", correlation),
-                "distribution",
-                "normal",
-            ])
-            .output()
-            .await
-            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run tc: {}", e)))?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(ChaosError::InjectionFailed(format!(
-                "tc command failed: {}",
-                stderr
-            )));
+        match self.config.scope {
+            LatencyScope::AllTraffic => {
+                info!(
+                    "Injecting network latency on {}: mean={}ms, jitter={}ms",
+                    interface, mean_ms, jitter_ms
+                );
+
+                // Use tc (traffic control) with netem
+                run_tc(
+                    target,
+                    &[
+                        "qdisc",
+                        "add",
+                        "dev",
+                        &interface,
+                        "root",
+                        "netem",
+                        "delay",
+                        &format!("{}ms", mean_ms),
+                        &format!("{}ms", jitter_ms),
+                        &format!("{}%", correlation),
+                        "distribution",
+                        "normal",
+                    ],
+                )
+                .await?;
+            }
+            LatencyScope::ConnectOnly => {
+                info!(
+                    "Injecting connect-only latency on {}: mean={}ms, jitter={}ms (SYN/SYN-ACK only)",
+                    interface, mean_ms, jitter_ms
+                );
+
+                // Classify with a prio qdisc so established-flow traffic
+                // stays on the fast default band, then delay only band 3
+                // and steer SYN/SYN-ACK packets into it with a u32 filter
+                // on the TCP flags byte (offset 33 for a no-options IPv4
+                // header), matching flags & 0x17 == 0x02 (SYN set, ACK/RST
+                // unset - i.e. the connection-setup handshake only).
+                run_tc(
+                    target,
+                    &["qdisc", "add", "dev", &interface, "root", "handle", "1:", "prio"],
+                )
+                .await?;
+                run_tc(
+                    target,
+                    &[
+                        "qdisc",
+                        "add",
+                        "dev",
+                        &interface,
+                        "parent",
+                        "1:3",
+                        "handle",
+                        "30:",
+                        "netem",
+                        "delay",
+                        &format!("{}ms", mean_ms),
+                        &format!("{}ms", jitter_ms),
+                        &format!("{}%", correlation),
+                        "distribution",
+                        "normal",
+                    ],
+                )
+                .await?;
+                run_tc(
+                    target,
+                    &[
+                        "filter", "add", "dev", &interface, "protocol", "ip", "parent", "1:0",
+                        "prio", "1", "u32", "match", "ip", "protocol", "6", "0xff", "match", "u8",
+                        "0x02", "0x17", "at", "33", "flowid", "1:3",
+                    ],
+                )
+                .await?;
+            }
         }
 
         let metadata = serde_json::json!({
             "interface": interface,
             "mean_ms": mean_ms,
             "jitter_ms": jitter_ms,
-            "distribution": "normal"
+            "distribution": "normal",
+            "scope": self.config.scope,
         });
 
         Ok(InjectionHandle::new(
@@ -223,9 +487,9 @@ impl NetworkLatencyInjector {
             interface, mean_ms, jitter_ms
         );
 
-        // macOS: Use dnctl (dummynet control) for traffic shaping
-        // First create a pipe
-        let pipe_num = 1;
+        // macOS: Use dnctl (dummynet control) for traffic shaping, behind a
+        // pf anchor that's private to this injection - see `macos_pf`.
+        let pipe_num = macos_pf::alloc_pipe();
         let output = Command::new("sudo")
             .args(&[
                 "dnctl",
@@ -244,32 +508,27 @@ impl NetworkLatencyInjector {
             warn!("dnctl pipe creation warning: {}", stderr);
         }
 
-        // Add pfctl rule to use the pipe
+        let injection_id = uuid::Uuid::new_v4().to_string();
         let pfctl_rule = format!("dummynet out proto tcp from any to any pipe {}", pipe_num);
-        let output = Command::new("sudo")
-            .args(&["pfctl", "-a", "chaos", "-f", "-"])
-            .output()
-            .await
-            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run pfctl: {}", e)))?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            info!("pfctl note: {}", stderr);
-        }
+        let rule_file = macos_pf::load_rule(&injection_id, pipe_num, &pfctl_rule).await?;
 
         let metadata = serde_json::json!({
             "interface": interface,
             "mean_ms": mean_ms,
             "jitter_ms": jitter_ms,
             "pipe_num": pipe_num,
+            "anchor": macos_pf::anchor_for(&injection_id),
+            "rule_file": rule_file.to_string_lossy(),
             "platform": "macos"
         });
 
-        Ok(InjectionHandle::new(
-            "network_latency",
-            target.clone(),
-            metadata,
-        ))
+        let mut handle = InjectionHandle::new("network_latency", target.clone(), metadata);
+        // The anchor/rule file above are keyed on `injection_id`, not
+        // whatever id `InjectionHandle::new` happened to generate - adopt
+        // it as the handle's own id so `remove_linux` can find them again
+        // from `handle.id` alone.
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
@@ -279,16 +538,15 @@ impl NetworkLatencyInjector {
         ))
     }
 
-    #[allow(dead_code)]
+    #[cfg(target_os = "linux")]
     async fn get_interface_for_target(&self, target: &Target) -> Result<String> {
-        match target {
-            Target::Network { address: _ } => {
-                // Simplified: use default interface
-                // In production, resolve actual interface for the route to address
-                Ok("eth0".to_string())
-            }
-            _ => Ok("eth0".to_string()),
-        }
+        resolve_interface(target, self.config.interface.as_deref()).await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[allow(dead_code)]
+    async fn get_interface_for_target(&self, _target: &Target) -> Result<String> {
+        Ok(self.config.interface.clone().unwrap_or_else(|| "eth0".to_string()))
     }
 
     #[cfg(target_os = "linux")]
@@ -301,15 +559,8 @@ impl NetworkLatencyInjector {
 
         info!("Removing network latency from {}", interface);
 
-        let output = Command::new("tc")
-            .args(&["qdisc", "del", "dev", interface, "root"])
-            .output()
-            .await
-            .map_err(|e| ChaosError::CleanupFailed(format!("Failed to run tc: {}", e)))?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            info!("tc cleanup note (may be already removed): {}", stderr);
+        if let Err(e) = run_tc(&handle.target, &["qdisc", "del", "dev", interface, "root"]).await {
+            info!("tc cleanup note (may be already removed): {}", e);
         }
 
         Ok(())
@@ -361,21 +612,11 @@ impl NetworkLatencyInjector {
             .metadata
             .get("pipe_num")
             .and_then(|v| v.as_u64())
-            .unwrap_or(1);
+            .unwrap_or(1) as u32;
+        let rule_file = handle.metadata.get("rule_file").and_then(|v| v.as_str());
 
         info!("Removing network latency from macOS (pipe {})", pipe_num);
-
-        // Remove pfctl rules
-        let _output = Command::new("sudo")
-            .args(&["pfctl", "-a", "chaos", "-F", "all"])
-            .output()
-            .await;
-
-        // Remove dummynet pipe
-        let _output = Command::new("sudo")
-            .args(&["dnctl", "pipe", &pipe_num.to_string(), "delete"])
-            .output()
-            .await;
+        macos_pf::cleanup(&handle.id, Some(pipe_num), rule_file).await;
 
         Ok(())
     }
@@ -396,6 +637,16 @@ impl Injector for NetworkLatencyInjector {
         self.remove_linux(&handle).await
     }
 
+    #[cfg(target_os = "linux")]
+    async fn verify(&self, handle: &InjectionHandle) -> Result<bool> {
+        let Some(interface) = handle.metadata.get("interface").and_then(|v| v.as_str()) else {
+            return Ok(true);
+        };
+
+        let output = run_networked(&handle.target, "tc", &["qdisc", "show", "dev", interface]).await?;
+        Ok(String::from_utf8_lossy(&output.stdout).contains("netem"))
+    }
+
     fn name(&self) -> &str {
         "network_latency"
     }
@@ -403,6 +654,53 @@ impl Injector for NetworkLatencyInjector {
     fn required_capabilities(&self) -> Vec<String> {
         vec!["CAP_NET_ADMIN".to_string()]
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(NetworkLatencyInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::new("mean", ParameterKind::Duration),
+            ParameterSpec::new("jitter", ParameterKind::Duration),
+            ParameterSpec::new("distribution", ParameterKind::Enum),
+            ParameterSpec::bounded("correlation", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::new("scope", ParameterKind::Enum),
+            ParameterSpec::new("interface", ParameterKind::String),
+        ]
+    }
+
+    // The interface is resolved from `target` with an async lookup
+    // (`get_interface_for_target`), which `describe_dry_run` can't call, so
+    // this stands in for the real interface name. Only modeled for the
+    // Linux `tc` path - other platforms fall back to the trait default.
+    #[cfg(target_os = "linux")]
+    fn describe_dry_run(&self, _target: &Target) -> Vec<String> {
+        let mean_ms = self.config.mean.as_millis();
+        let jitter_ms = self.config.jitter.as_millis();
+        let correlation = (self.config.correlation * 100.0) as u32;
+        let iface = "<interface resolved for target>";
+
+        match self.config.scope {
+            LatencyScope::AllTraffic => vec![format!(
+                "tc qdisc add dev {} root netem delay {}ms {}ms {}% distribution normal",
+                iface, mean_ms, jitter_ms, correlation
+            )],
+            LatencyScope::ConnectOnly => vec![
+                format!("tc qdisc add dev {} root handle 1: prio", iface),
+                format!(
+                    "tc qdisc add dev {} parent 1:3 handle 30: netem delay {}ms {}ms {}% distribution normal",
+                    iface, mean_ms, jitter_ms, correlation
+                ),
+                format!(
+                    "tc filter add dev {} protocol ip parent 1:0 prio 1 u32 match ip protocol 6 0xff match u8 0x02 0x17 at 33 flowid 1:3",
+                    iface
+                ),
+            ],
+        }
+    }
 }
 
 #[derive(Default)]
@@ -411,6 +709,8 @@ pub struct NetworkLatencyBuilder {
     jitter: Option<Duration>,
     distribution: Option<LatencyDistribution>,
     correlation: Option<f64>,
+    scope: Option<LatencyScope>,
+    interface: Option<String>,
 }
 
 impl NetworkLatencyBuilder {
@@ -434,6 +734,19 @@ impl NetworkLatencyBuilder {
         self
     }
 
+    /// Restricts the delay to connection establishment (SYN / SYN-ACK)
+    /// instead of every packet. See [`LatencyScope::ConnectOnly`].
+    pub fn scope(mut self, scope: LatencyScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Overrides routing-table interface resolution with an explicit name.
+    pub fn interface(mut self, interface: impl Into<String>) -> Self {
+        self.interface = Some(interface.into());
+        self
+    }
+
     pub fn build(self) -> NetworkLatencyInjector {
         let mean = self.mean.unwrap_or(Duration::from_millis(100));
         let jitter = self.jitter.unwrap_or(Duration::from_millis(20));
@@ -449,6 +762,8 @@ impl NetworkLatencyBuilder {
                     std_dev: jitter_ms,
                 }),
                 correlation: self.correlation.unwrap_or(0.0),
+                scope: self.scope.unwrap_or_default(),
+                interface: self.interface,
             },
         }
     }
@@ -456,9 +771,14 @@ impl NetworkLatencyBuilder {
 
 // Packet Loss Injector
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PacketLossConfig {
     pub rate: f64, // 0.0 - 1.0
     pub correlation: f64,
+    /// Explicit egress interface, bypassing routing-table resolution. See
+    /// `NetworkLatencyConfig::interface`.
+    #[serde(default)]
+    pub interface: Option<String>,
 }
 
 impl Default for PacketLossConfig {
@@ -466,29 +786,24 @@ impl Default for PacketLossConfig {
         Self {
             rate: 0.01, // 1% loss
             correlation: 0.0,
+            interface: None,
         }
     }
 }
 
+#[derive(Default)]
 pub struct PacketLossInjector {
     #[allow(dead_code)]
     config: PacketLossConfig,
 }
 
-impl Default for PacketLossInjector {
-    fn default() -> Self {
-        Self {
-            config: PacketLossConfig::default(),
-        }
-    }
-}
-
 impl PacketLossInjector {
     pub fn new(rate: f64) -> Self {
         Self {
             config: PacketLossConfig {
                 rate,
                 correlation: 0.0,
+                interface: None,
             },
         }
     }
@@ -504,8 +819,10 @@ impl PacketLossInjector {
             interface, loss_percent
         );
 
-        let output = Command::new("tc")
-            .args(&[
+        let output = run_networked(
+            target,
+            "tc",
+            &[
                 "qdisc",
                 "add",
                 "dev",
@@ -515,10 +832,9 @@ impl PacketLossInjector {
                 "loss",
                 &format!("{}%", loss_percent),
                 &format!("{}%", correlation),
-            ])
-            .output()
-            .await
-            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run tc: {}", e)))?;
+            ],
+        )
+        .await?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -575,8 +891,9 @@ impl PacketLossInjector {
             loss_percent
         );
 
-        // macOS: Use dnctl with loss parameter
-        let pipe_num = 2; // Different pipe from latency
+        // macOS: Use dnctl with loss parameter, behind a pf anchor private
+        // to this injection - see `macos_pf`.
+        let pipe_num = macos_pf::alloc_pipe();
         let output = Command::new("sudo")
             .args(&[
                 "dnctl",
@@ -595,17 +912,21 @@ impl PacketLossInjector {
             info!("dnctl note: {}", stderr);
         }
 
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let pfctl_rule = format!("dummynet out proto ip from any to any pipe {}", pipe_num);
+        let rule_file = macos_pf::load_rule(&injection_id, pipe_num, &pfctl_rule).await?;
+
         let metadata = serde_json::json!({
             "loss_percent": loss_percent,
             "pipe_num": pipe_num,
+            "anchor": macos_pf::anchor_for(&injection_id),
+            "rule_file": rule_file.to_string_lossy(),
             "platform": "macos"
         });
 
-        Ok(InjectionHandle::new(
-            "packet_loss",
-            _target.clone(),
-            metadata,
-        ))
+        let mut handle = InjectionHandle::new("packet_loss", _target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
@@ -615,9 +936,15 @@ impl PacketLossInjector {
         ))
     }
 
+    #[cfg(target_os = "linux")]
+    async fn get_interface_for_target(&self, target: &Target) -> Result<String> {
+        resolve_interface(target, self.config.interface.as_deref()).await
+    }
+
+    #[cfg(not(target_os = "linux"))]
     #[allow(dead_code)]
     async fn get_interface_for_target(&self, _target: &Target) -> Result<String> {
-        Ok("eth0".to_string())
+        Ok(self.config.interface.clone().unwrap_or_else(|| "eth0".to_string()))
     }
 }
 
@@ -640,11 +967,9 @@ impl Injector for PacketLossInjector {
 
             info!("Removing packet loss from {}", interface);
 
-            let output = Command::new("tc")
-                .args(&["qdisc", "del", "dev", interface, "root"])
-                .output()
+            let output = run_networked(&_handle.target, "tc", &["qdisc", "del", "dev", interface, "root"])
                 .await
-                .map_err(|e| ChaosError::CleanupFailed(format!("Failed to run tc: {}", e)))?;
+                .map_err(|e| ChaosError::CleanupFailed(e.to_string()))?;
 
             if !output.status.success() {
                 info!("tc cleanup note (may be already removed)");
@@ -663,19 +988,26 @@ impl Injector for PacketLossInjector {
                 .metadata
                 .get("pipe_num")
                 .and_then(|v| v.as_u64())
-                .unwrap_or(2);
+                .unwrap_or(2) as u32;
+            let rule_file = _handle.metadata.get("rule_file").and_then(|v| v.as_str());
 
             info!("Removing packet loss from macOS (pipe {})", pipe_num);
-
-            let _output = Command::new("sudo")
-                .args(&["dnctl", "pipe", &pipe_num.to_string(), "delete"])
-                .output()
-                .await;
+            macos_pf::cleanup(&_handle.id, Some(pipe_num), rule_file).await;
         }
 
         Ok(())
     }
 
+    #[cfg(target_os = "linux")]
+    async fn verify(&self, handle: &InjectionHandle) -> Result<bool> {
+        let Some(interface) = handle.metadata.get("interface").and_then(|v| v.as_str()) else {
+            return Ok(true);
+        };
+
+        let output = run_networked(&handle.target, "tc", &["qdisc", "show", "dev", interface]).await?;
+        Ok(String::from_utf8_lossy(&output.stdout).contains("loss"))
+    }
+
     fn name(&self) -> &str {
         "packet_loss"
     }
@@ -683,8 +1015,32 @@ impl Injector for PacketLossInjector {
     fn required_capabilities(&self) -> Vec<String> {
         vec!["CAP_NET_ADMIN".to_string()]
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(Arc::new(PacketLossInjector { config }))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::bounded("rate", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::bounded("correlation", ParameterKind::Float, 0.0, 1.0),
+            ParameterSpec::new("interface", ParameterKind::String),
+        ]
+    }
+
+    fn ramp_parameter(&self) -> Option<&'static str> {
+        Some("rate")
+    }
 }
 
+/// Tags every iptables rule [`TcpResetInjector`] adds with an
+/// iptables-native comment, so `chaos cleanup` (see [`crate::cleanup`]) can
+/// tell "a rule this tool added" apart from arbitrary rules already on the
+/// host when no executor state survived to say so.
+pub(crate) const CHAOS_IPTABLES_COMMENT: &str = "chaos_tcp_reset";
+
 // TCP Reset Injector
 #[derive(Debug, Clone)]
 pub struct TcpResetInjector {
@@ -713,10 +1069,18 @@ impl TcpResetInjector {
 
         info!("Injecting TCP resets for {}", address);
 
-        // Use iptables to inject RST packets
+        // Use iptables to inject RST packets. The comment embeds this
+        // injection's own ID (not just the fixed marker) so `chaos cleanup`
+        // can still find it via the marker prefix, while `remove` can
+        // target this exact rule rather than any chaos_tcp_reset rule on
+        // the same port left by a concurrent run.
         let port = address.port();
-        let output = Command::new("iptables")
-            .args(&[
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let comment = format!("{}_{}", CHAOS_IPTABLES_COMMENT, injection_id);
+        let output = run_networked(
+            target,
+            "iptables",
+            &[
                 "-A",
                 "OUTPUT",
                 "-p",
@@ -727,10 +1091,13 @@ impl TcpResetInjector {
                 "REJECT",
                 "--reject-with",
                 "tcp-reset",
-            ])
-            .output()
-            .await
-            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run iptables: {}", e)))?;
+                "-m",
+                "comment",
+                "--comment",
+                &comment,
+            ],
+        )
+        .await?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -743,9 +1110,12 @@ impl TcpResetInjector {
         let metadata = serde_json::json!({
             "port": port,
             "address": address.to_string(),
+            "comment": comment,
         });
 
-        Ok(InjectionHandle::new("tcp_reset", target.clone(), metadata))
+        let mut handle = InjectionHandle::new("tcp_reset", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(target_os = "windows")]
@@ -784,20 +1154,21 @@ impl TcpResetInjector {
         info!("Injecting TCP resets for {} on macOS", address);
 
         let port = address.port();
-        // macOS: Use pfctl to block/reset TCP connections
-        // Note: pfctl rules would be configured here
-        let _output = Command::new("sudo")
-            .args(&["pfctl", "-a", "chaos", "-f", "-"])
-            .output()
-            .await;
+        let injection_id = uuid::Uuid::new_v4().to_string();
+        let pf_rule = format!("block return-rst out proto tcp from any to any port {}", port);
+        let rule_file = macos_pf::load_anchor_rule(&injection_id, &pf_rule).await?;
 
         let metadata = serde_json::json!({
             "port": port,
             "address": address.to_string(),
+            "anchor": macos_pf::anchor_for(&injection_id),
+            "rule_file": rule_file.to_string_lossy(),
             "platform": "macos"
         });
 
-        Ok(InjectionHandle::new("tcp_reset", target.clone(), metadata))
+        let mut handle = InjectionHandle::new("tcp_reset", target.clone(), metadata);
+        handle.id = injection_id;
+        Ok(handle)
     }
 
     #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
@@ -822,11 +1193,18 @@ impl Injector for TcpResetInjector {
                 .get("port")
                 .and_then(|v| v.as_u64())
                 .ok_or_else(|| ChaosError::CleanupFailed("Missing port metadata".to_string()))?;
+            let comment = _handle
+                .metadata
+                .get("comment")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ChaosError::CleanupFailed("Missing comment metadata".to_string()))?;
 
             info!("Removing TCP reset rule for port {}", port);
 
-            let output = Command::new("iptables")
-                .args(&[
+            let output = run_networked(
+                &_handle.target,
+                "iptables",
+                &[
                     "-D",
                     "OUTPUT",
                     "-p",
@@ -837,10 +1215,14 @@ impl Injector for TcpResetInjector {
                     "REJECT",
                     "--reject-with",
                     "tcp-reset",
-                ])
-                .output()
-                .await
-                .map_err(|e| ChaosError::CleanupFailed(format!("Failed to run iptables: {}", e)))?;
+                    "-m",
+                    "comment",
+                    "--comment",
+                    comment,
+                ],
+            )
+            .await
+            .map_err(|e| ChaosError::CleanupFailed(e.to_string()))?;
 
             if !output.status.success() {
                 info!("iptables cleanup note (may be already removed)");
@@ -855,11 +1237,9 @@ impl Injector for TcpResetInjector {
 
         #[cfg(target_os = "macos")]
         {
+            let rule_file = _handle.metadata.get("rule_file").and_then(|v| v.as_str());
             info!("Removing TCP reset rules on macOS");
-            let _output = Command::new("sudo")
-                .args(&["pfctl", "-a", "chaos", "-F", "all"])
-                .output()
-                .await;
+            macos_pf::cleanup(&_handle.id, None, rule_file).await;
         }
 
         Ok(())
@@ -872,6 +1252,32 @@ impl Injector for TcpResetInjector {
     fn required_capabilities(&self) -> Vec<String> {
         vec!["CAP_NET_ADMIN".to_string()]
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<DynInjector> {
+        let rate = params
+            .get("rate")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(self.rate);
+        Ok(Arc::new(TcpResetInjector::new(rate)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![ParameterSpec::bounded("rate", ParameterKind::Float, 0.0, 1.0)]
+    }
+
+    #[cfg(target_os = "linux")]
+    fn describe_dry_run(&self, target: &Target) -> Vec<String> {
+        let Target::Network { address } = target else {
+            return vec!["tcp_reset requires a Network target".to_string()];
+        };
+
+        vec![format!(
+            "iptables -A OUTPUT -p tcp --dport {} -j REJECT --reject-with tcp-reset -m comment --comment {}_<injection id>",
+            address.port(),
+            CHAOS_IPTABLES_COMMENT
+        )]
+    }
 }
 
 #[cfg(test)]
@@ -903,4 +1309,62 @@ mod tests {
         assert_eq!(injector.config.mean, Duration::from_millis(50));
         assert_eq!(injector.config.jitter, Duration::from_millis(10));
     }
+
+    #[test]
+    fn test_network_latency_builder_defaults_to_all_traffic_scope() {
+        let injector = NetworkLatencyInjector::builder().build();
+        assert_eq!(injector.config.scope, LatencyScope::AllTraffic);
+    }
+
+    #[test]
+    fn test_network_latency_builder_sets_connect_only_scope() {
+        let injector = NetworkLatencyInjector::builder()
+            .scope(LatencyScope::ConnectOnly)
+            .build();
+
+        assert_eq!(injector.config.scope, LatencyScope::ConnectOnly);
+    }
+
+    #[test]
+    fn test_network_latency_configure_accepts_partial_params() {
+        let injector = NetworkLatencyInjector::default();
+        let params = serde_json::json!({ "correlation": 0.5 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "network_latency");
+    }
+
+    #[test]
+    fn test_packet_loss_configure_accepts_rate_param() {
+        let injector = PacketLossInjector::default();
+        let params = serde_json::json!({ "rate": 0.5 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "packet_loss");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_route_get_device_extracts_dev_field() {
+        let line = "10.0.0.5 via 10.0.0.1 dev eth1 src 10.0.0.2 uid 0 \n    cache";
+        assert_eq!(parse_route_get_device(line), Some("eth1".to_string()));
+        assert_eq!(parse_route_get_device("unreachable"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_resolve_interface_prefers_explicit_override() {
+        let target = Target::network("127.0.0.1:8080".parse().unwrap());
+        let iface = resolve_interface(&target, Some("vnet0")).await.unwrap();
+        assert_eq!(iface, "vnet0");
+    }
+
+    #[test]
+    fn test_tcp_reset_configure_accepts_rate_param() {
+        let injector = TcpResetInjector::default();
+        let params = serde_json::json!({ "rate": 0.9 });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "tcp_reset");
+    }
 }
@@ -1,6 +1,16 @@
-use crate::{error::*, handle::InjectionHandle, injectors::Injector, target::Target};
+use crate::{
+    error::*,
+    handle::InjectionHandle,
+    injectors::Injector,
+    system_backend::{RealSystemBackend, SystemBackend},
+    target::Target,
+};
 use async_trait::async_trait;
+#[cfg(target_os = "linux")]
+use libc;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
 use tracing::{info, warn};
@@ -26,7 +36,7 @@ impl Signal {
         }
     }
 
-    fn as_str(&self) -> &str {
+    pub(crate) fn as_str(&self) -> &str {
         match self {
             Signal::SIGTERM => "SIGTERM",
             Signal::SIGKILL => "SIGKILL",
@@ -45,12 +55,32 @@ pub enum RestartMode {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ProcessKillConfig {
     pub signal: Signal,
     pub restart_delay: Duration,
     pub restart_mode: RestartMode,
     pub restart_command: Option<String>,
     pub health_check_url: Option<String>,
+    /// If set, wait this long after `signal` for the process to die, then
+    /// escalate to SIGKILL. Only meaningful when `signal` is not already
+    /// SIGKILL or SIGSTOP.
+    pub escalation_timeout: Option<Duration>,
+    /// How many matching processes to hit when the target is a
+    /// `Target::ProcessPattern`. Ignored for `Target::Process`.
+    pub pattern_selection: PatternSelection,
+    /// Reuse the shared, connection-pooled HTTP client across
+    /// `health_check_url` polls. Disable for scenarios that want each poll
+    /// to pay fresh DNS/TCP/TLS setup, matching a real client hitting the
+    /// target cold rather than a warmed-up one.
+    pub reuse_health_check_connections: bool,
+    /// Environment variables to set on `restart_command`, on top of this
+    /// process's own environment. Lets a phase restart the target with a
+    /// mutated configuration (smaller pool sizes, debug logging, a
+    /// different feature flag) as part of the experiment, rather than only
+    /// varying fault injection - so config chaos and fault chaos can be
+    /// combined in one scenario run.
+    pub restart_env: std::collections::HashMap<String, String>,
 }
 
 impl Default for ProcessKillConfig {
@@ -61,63 +91,129 @@ impl Default for ProcessKillConfig {
             restart_mode: RestartMode::None,
             restart_command: None,
             health_check_url: None,
+            escalation_timeout: None,
+            pattern_selection: PatternSelection::All,
+            reuse_health_check_connections: true,
+            restart_env: std::collections::HashMap::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum PatternSelection {
+    /// Signal every matching process
+    All,
+    /// Signal a random subset of this size (capped at the number of matches)
+    Count(usize),
+    /// Signal a random fraction (0.0 - 1.0) of matching processes
+    Percentage(f64),
+}
+
+struct KillOutcome {
+    original_pid: u32,
+    new_pid: Option<u32>,
+    escalated: bool,
+}
+
+static HEALTH_CHECK_CLIENTS_CREATED: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+static HEALTH_CHECK_REQUESTS_SENT: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
+/// The shared, connection-pooled HTTP client used for `health_check_url`
+/// polls, so a 30-attempt poll loop reuses one warm connection instead of
+/// paying fresh DNS/TCP/TLS setup on every attempt - which would otherwise
+/// dominate the measured time-to-healthy instead of reflecting the
+/// target's own recovery time.
+fn health_check_client() -> &'static reqwest::Client {
+    static CLIENT: std::sync::OnceLock<reqwest::Client> = std::sync::OnceLock::new();
+    CLIENT.get_or_init(|| {
+        HEALTH_CHECK_CLIENTS_CREATED.fetch_add(1, Ordering::Relaxed);
+        reqwest::Client::builder()
+            .build()
+            .expect("building the shared health-check HTTP client should never fail")
+    })
+}
+
+/// A client with connection reuse disabled, for scenarios that opt out of
+/// pooling via `ProcessKillConfig::reuse_health_check_connections` to keep
+/// each poll's DNS/TCP/TLS cost representative of a real cold client.
+fn health_check_one_shot_client() -> reqwest::Client {
+    HEALTH_CHECK_CLIENTS_CREATED.fetch_add(1, Ordering::Relaxed);
+    reqwest::Client::builder()
+        .pool_max_idle_per_host(0)
+        .build()
+        .expect("building a one-shot health-check HTTP client should never fail")
+}
+
+/// Connection-reuse stats for the shared health-check client, so operators
+/// can confirm polls are actually pooling rather than dialing fresh each
+/// time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HealthCheckPoolStats {
+    pub clients_created: usize,
+    pub requests_sent: usize,
+}
+
+pub fn health_check_pool_stats() -> HealthCheckPoolStats {
+    HealthCheckPoolStats {
+        clients_created: HEALTH_CHECK_CLIENTS_CREATED.load(Ordering::Relaxed),
+        requests_sent: HEALTH_CHECK_REQUESTS_SENT.load(Ordering::Relaxed),
+    }
+}
+
 pub struct ProcessKillInjector {
     config: ProcessKillConfig,
+    backend: Arc<dyn SystemBackend>,
 }
 
 impl Default for ProcessKillInjector {
     fn default() -> Self {
         Self {
             config: ProcessKillConfig::default(),
+            backend: Arc::new(RealSystemBackend),
         }
     }
 }
 
 impl ProcessKillInjector {
     pub fn new(config: ProcessKillConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            backend: Arc::new(RealSystemBackend),
+        }
     }
 
     pub fn builder() -> ProcessKillBuilder {
         ProcessKillBuilder::default()
     }
 
+    /// Overrides the [`SystemBackend`] used to send signals and run the
+    /// restart command, e.g. with a [`RecordingSystemBackend`] in tests.
+    pub fn with_backend(mut self, backend: Arc<dyn SystemBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
     async fn send_signal(&self, pid: u32) -> Result<()> {
-        info!("Sending {} to PID {}", self.config.signal.as_str(), pid);
+        self.send_signal_to(pid, self.config.signal).await
+    }
+
+    async fn send_signal_to(&self, pid: u32, signal: Signal) -> Result<()> {
+        info!("Sending {} to PID {}", signal.as_str(), pid);
 
         #[cfg(unix)]
         {
-            use nix::sys::signal;
-            use nix::unistd::Pid;
-
-            let signal = match self.config.signal {
-                Signal::SIGTERM => signal::Signal::SIGTERM,
-                Signal::SIGKILL => signal::Signal::SIGKILL,
-                Signal::SIGSTOP => signal::Signal::SIGSTOP,
-                Signal::SIGCONT => signal::Signal::SIGCONT,
-                Signal::SIGHUP => signal::Signal::SIGHUP,
-            };
-
-            signal::kill(Pid::from_raw(pid as i32), signal).map_err(|e| {
-                ChaosError::ProcessError(format!("Failed to send signal: {}", e))
-            })?;
+            self.backend.send_signal(pid, signal)?;
         }
 
         #[cfg(windows)]
         {
             // Windows doesn't have Unix signals, use TerminateProcess
-            if matches!(self.config.signal, Signal::SIGKILL) {
-                Command::new("taskkill")
-                    .args(&["/F", "/PID", &pid.to_string()])
-                    .output()
-                    .await
-                    .map_err(|e| {
-                        ChaosError::ProcessError(format!("Failed to kill process: {}", e))
-                    })?;
+            if matches!(signal, Signal::SIGKILL) {
+                self.backend
+                    .run_command("taskkill", &["/F", "/PID", &pid.to_string()])
+                    .await?;
             } else {
                 return Err(ChaosError::SystemError(
                     "Only SIGKILL supported on Windows".to_string(),
@@ -129,19 +225,28 @@ impl ProcessKillInjector {
     }
 
     async fn wait_for_process_death(&self, pid: u32, timeout: Duration) -> Result<()> {
+        if self.wait_for_death(pid, timeout).await {
+            Ok(())
+        } else {
+            warn!("Process {} did not terminate within timeout", pid);
+            Ok(())
+        }
+    }
+
+    /// Returns true if the process died before `timeout` elapsed.
+    async fn wait_for_death(&self, pid: u32, timeout: Duration) -> bool {
         let start = tokio::time::Instant::now();
-        
+
         while start.elapsed() < timeout {
             let target = Target::process(pid);
             if !target.exists().await {
                 info!("Process {} terminated", pid);
-                return Ok(());
+                return true;
             }
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
 
-        warn!("Process {} did not terminate within timeout", pid);
-        Ok(())
+        false
     }
 
     async fn restart_process(&self) -> Result<u32> {
@@ -156,9 +261,10 @@ impl ProcessKillInjector {
         })?;
 
         info!(
-            "Restarting process after {} seconds (mode: {:?})",
+            "Restarting process after {} seconds (mode: {:?}, env overrides: {:?})",
             self.config.restart_delay.as_secs(),
-            self.config.restart_mode
+            self.config.restart_mode,
+            self.config.restart_env.keys().collect::<Vec<_>>()
         );
 
         tokio::time::sleep(self.config.restart_delay).await;
@@ -167,6 +273,7 @@ impl ProcessKillInjector {
         let output = Command::new("sh")
             .arg("-c")
             .arg(command)
+            .envs(&self.config.restart_env)
             .spawn()
             .map_err(|e| ChaosError::ProcessError(format!("Failed to restart process: {}", e)))?;
 
@@ -184,11 +291,101 @@ impl ProcessKillInjector {
         Ok(pid)
     }
 
+    async fn kill_one(&self, pid: u32) -> Result<KillOutcome> {
+        self.send_signal(pid).await?;
+
+        // Wait for the process to die if not SIGSTOP - for exactly
+        // escalation_timeout if one is configured, 10s otherwise - and
+        // escalate to SIGKILL if it outlives that wait and the original
+        // signal was SIGTERM/SIGHUP. The wait itself always happens
+        // regardless of whether escalation_timeout is set: setting it
+        // alongside SIGKILL/SIGCONT only means there's nothing to escalate
+        // to, not that `restart_process` below should stop waiting for the
+        // original signal to take effect.
+        let mut escalated = false;
+        if !matches!(self.config.signal, Signal::SIGSTOP) {
+            let wait_timeout = self
+                .config
+                .escalation_timeout
+                .unwrap_or(Duration::from_secs(10));
+            let died = self.wait_for_death(pid, wait_timeout).await;
+            if !died {
+                if matches!(self.config.signal, Signal::SIGTERM | Signal::SIGHUP) {
+                    warn!(
+                        "PID {} survived {:?} after {}, escalating to SIGKILL",
+                        pid,
+                        wait_timeout,
+                        self.config.signal.as_str()
+                    );
+                    self.send_signal_to(pid, Signal::SIGKILL).await?;
+                    escalated = true;
+                    self.wait_for_process_death(pid, Duration::from_secs(10))
+                        .await?;
+                } else {
+                    warn!("Process {} did not terminate within timeout", pid);
+                }
+            }
+        }
+
+        // Restart if configured
+        let new_pid = if self.config.restart_mode != RestartMode::None {
+            Some(self.restart_process().await?)
+        } else {
+            None
+        };
+
+        Ok(KillOutcome {
+            original_pid: pid,
+            new_pid,
+            escalated,
+        })
+    }
+
+    async fn resolve_pattern(&self, pattern: &str) -> Result<Vec<u32>> {
+        use sysinfo::System;
+
+        let mut sys = System::new_all();
+        sys.refresh_processes();
+
+        Ok(sys
+            .processes()
+            .values()
+            .filter(|p| p.name().contains(pattern))
+            .map(|p| p.pid().as_u32())
+            .collect())
+    }
+
+    fn select_pids(&self, mut pids: Vec<u32>) -> Vec<u32> {
+        use rand::seq::SliceRandom;
+
+        match self.config.pattern_selection {
+            PatternSelection::All => pids,
+            PatternSelection::Count(count) => {
+                let mut rng = rand::thread_rng();
+                pids.shuffle(&mut rng);
+                pids.into_iter().take(count).collect()
+            }
+            PatternSelection::Percentage(fraction) => {
+                let count = ((pids.len() as f64) * fraction.clamp(0.0, 1.0)).round() as usize;
+                let mut rng = rand::thread_rng();
+                pids.shuffle(&mut rng);
+                pids.into_iter().take(count).collect()
+            }
+        }
+    }
+
     async fn wait_for_health(&self, url: &str) -> Result<()> {
         info!("Waiting for health check: {}", url);
 
         for attempt in 1..=30 {
-            match reqwest::get(url).await {
+            let response = if self.config.reuse_health_check_connections {
+                health_check_client().get(url).send().await
+            } else {
+                health_check_one_shot_client().get(url).send().await
+            };
+            HEALTH_CHECK_REQUESTS_SENT.fetch_add(1, Ordering::Relaxed);
+
+            match response {
                 Ok(response) if response.status().is_success() => {
                     info!("Health check passed");
                     return Ok(());
@@ -211,40 +408,56 @@ impl ProcessKillInjector {
 #[async_trait]
 impl Injector for ProcessKillInjector {
     async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
-        let Target::Process { pid } = target else {
-            return Err(ChaosError::InvalidConfig(
-                "Process kill requires Process target".to_string(),
-            ));
-        };
-
-        let original_pid = *pid;
-        self.send_signal(*pid).await?;
+        match target {
+            Target::Process { pid } => {
+                let outcome = self.kill_one(*pid).await?;
+
+                let metadata = serde_json::json!({
+                    "original_pid": outcome.original_pid,
+                    "new_pid": outcome.new_pid,
+                    "signal": self.config.signal.as_str(),
+                    "escalated_to_sigkill": outcome.escalated,
+                    "restart_mode": format!("{:?}", self.config.restart_mode),
+                    "restart_command": self.config.restart_command,
+                    "restart_env": self.config.restart_env,
+                });
+
+                Ok(InjectionHandle::new("process_kill", target.clone(), metadata))
+            }
+            Target::ProcessPattern { pattern } => {
+                let pids = self.resolve_pattern(pattern).await?;
+                let selected = self.select_pids(pids);
+
+                info!(
+                    "Fanning out process_kill to {} PIDs matching pattern '{}'",
+                    selected.len(),
+                    pattern
+                );
+
+                let mut outcomes = Vec::new();
+                for pid in &selected {
+                    match self.kill_one(*pid).await {
+                        Ok(outcome) => outcomes.push(outcome),
+                        Err(e) => warn!("Failed to kill PID {} (pattern '{}'): {}", pid, pattern, e),
+                    }
+                }
 
-        // Wait for process to die if not SIGSTOP
-        if !matches!(self.config.signal, Signal::SIGSTOP) {
-            self.wait_for_process_death(*pid, Duration::from_secs(10))
-                .await?;
+                let metadata = serde_json::json!({
+                    "pattern": pattern,
+                    "affected_pids": selected,
+                    "escalated_pids": outcomes.iter().filter(|o| o.escalated).map(|o| o.original_pid).collect::<Vec<_>>(),
+                    "signal": self.config.signal.as_str(),
+                    "restart_mode": format!("{:?}", self.config.restart_mode),
+                    "restart_command": self.config.restart_command,
+                    "restart_env": self.config.restart_env,
+                });
+
+                Ok(InjectionHandle::new("process_kill", target.clone(), metadata))
+            }
+            _ => Err(ChaosError::InvalidConfig(
+                "Process kill requires a Process or ProcessPattern target".to_string(),
+            )),
         }
-
-        // Restart if configured
-        let new_pid = if self.config.restart_mode != RestartMode::None {
-            Some(self.restart_process().await?)
-        } else {
-            None
-        };
-
-        let metadata = serde_json::json!({
-            "original_pid": original_pid,
-            "new_pid": new_pid,
-            "signal": self.config.signal.as_str(),
-            "restart_mode": format!("{:?}", self.config.restart_mode),
-        });
-
-        Ok(InjectionHandle::new(
-            "process_kill",
-            target.clone(),
-            metadata,
-        ))
     }
 
     async fn remove(&self, _handle: InjectionHandle) -> Result<()> {
@@ -259,6 +472,68 @@ impl Injector for ProcessKillInjector {
     fn required_capabilities(&self) -> Vec<String> {
         vec!["CAP_KILL".to_string()]
     }
+
+    fn configure(&self, params: &serde_json::Value) -> Result<crate::injectors::DynInjector> {
+        let config = crate::injectors::configure_from_params(params)?;
+        Ok(std::sync::Arc::new(ProcessKillInjector::new(config)))
+    }
+
+    fn parameter_schema(&self) -> Vec<crate::injectors::ParameterSpec> {
+        use crate::injectors::{ParameterKind, ParameterSpec};
+        vec![
+            ParameterSpec::new("signal", ParameterKind::Enum),
+            ParameterSpec::new("restart_delay", ParameterKind::Duration),
+            ParameterSpec::new("restart_mode", ParameterKind::Enum),
+            ParameterSpec::new("restart_command", ParameterKind::String),
+            ParameterSpec::new("health_check_url", ParameterKind::String),
+            ParameterSpec::new("escalation_timeout", ParameterKind::Duration),
+            ParameterSpec::new("pattern_selection", ParameterKind::Enum),
+            ParameterSpec::new("reuse_health_check_connections", ParameterKind::Bool),
+            ParameterSpec::new("restart_env", ParameterKind::String),
+        ]
+    }
+
+    fn describe_dry_run(&self, target: &Target) -> Vec<String> {
+        let mut lines = match target {
+            Target::Process { pid } => vec![format!(
+                "send {} to PID {}",
+                self.config.signal.as_str(),
+                pid
+            )],
+            Target::ProcessPattern { pattern } => vec![format!(
+                "resolve PIDs matching pattern '{}' and send {} to each (subject to pattern_selection: {:?})",
+                pattern, self.config.signal.as_str(), self.config.pattern_selection
+            )],
+            _ => {
+                return vec![
+                    "process_kill requires a Process or ProcessPattern target".to_string(),
+                ]
+            }
+        };
+
+        let escalates = matches!(self.config.signal, Signal::SIGTERM | Signal::SIGHUP);
+        if let Some(timeout) = self.config.escalation_timeout.filter(|_| escalates) {
+            lines.push(format!(
+                "if still alive after {:?}, escalate to SIGKILL",
+                timeout
+            ));
+        }
+
+        if self.config.restart_mode != RestartMode::None {
+            lines.push(format!(
+                "restart via {:?} after {:?}{}",
+                self.config.restart_mode,
+                self.config.restart_delay,
+                self.config
+                    .restart_command
+                    .as_deref()
+                    .map(|cmd| format!(" running '{}'", cmd))
+                    .unwrap_or_default()
+            ));
+        }
+
+        lines
+    }
 }
 
 #[derive(Default)]
@@ -268,6 +543,10 @@ pub struct ProcessKillBuilder {
     restart_mode: Option<RestartMode>,
     restart_command: Option<String>,
     health_check_url: Option<String>,
+    escalation_timeout: Option<Duration>,
+    pattern_selection: Option<PatternSelection>,
+    reuse_health_check_connections: Option<bool>,
+    restart_env: std::collections::HashMap<String, String>,
 }
 
 impl ProcessKillBuilder {
@@ -276,6 +555,16 @@ impl ProcessKillBuilder {
         self
     }
 
+    pub fn escalation_timeout(mut self, timeout: Duration) -> Self {
+        self.escalation_timeout = Some(timeout);
+        self
+    }
+
+    pub fn pattern_selection(mut self, selection: PatternSelection) -> Self {
+        self.pattern_selection = Some(selection);
+        self
+    }
+
     pub fn restart_delay(mut self, delay: Duration) -> Self {
         self.restart_delay = Some(delay);
         self
@@ -296,6 +585,18 @@ impl ProcessKillBuilder {
         self
     }
 
+    pub fn reuse_health_check_connections(mut self, reuse: bool) -> Self {
+        self.reuse_health_check_connections = Some(reuse);
+        self
+    }
+
+    /// Adds a single environment variable to set on `restart_command` when
+    /// the process is restarted, on top of any already added.
+    pub fn restart_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.restart_env.insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> ProcessKillInjector {
         ProcessKillInjector {
             config: ProcessKillConfig {
@@ -304,7 +605,12 @@ impl ProcessKillBuilder {
                 restart_mode: self.restart_mode.unwrap_or(RestartMode::None),
                 restart_command: self.restart_command,
                 health_check_url: self.health_check_url,
+                escalation_timeout: self.escalation_timeout,
+                pattern_selection: self.pattern_selection.unwrap_or(PatternSelection::All),
+                reuse_health_check_connections: self.reuse_health_check_connections.unwrap_or(true),
+                restart_env: self.restart_env,
             },
+            backend: Arc::new(RealSystemBackend),
         }
     }
 }
@@ -380,9 +686,560 @@ impl Injector for ProcessSuspendInjector {
     }
 }
 
+// Cgroup Freezer-based Pause Injector
+//
+// Uses the cgroup v2 freezer (cgroup.freeze) to pause a process and all of
+// its threads/children atomically, unlike SIGSTOP which only stops the
+// targeted task and can be intercepted by ptrace.
+//
+// Resolves the cgroup through the target process's own `/proc/<pid>/cgroup`
+// rather than a fixed root-owned path, so it keeps working unprivileged
+// when that subtree was delegated to the caller - e.g. a systemd user
+// session's `user@<uid>.service` slice with cgroup v2 delegation enabled.
+// No `required_capabilities` are declared for that reason; `cgroup.freeze`
+// just fails with a permission error, surfaced as `ChaosError::ProcessError`
+// below, if the process doing the freezing doesn't own that cgroup.
+#[derive(Clone)]
+pub struct CgroupFreezeInjector {
+    duration: Option<Duration>,
+    backend: Arc<dyn SystemBackend>,
+}
+
+impl Default for CgroupFreezeInjector {
+    fn default() -> Self {
+        Self {
+            duration: None,
+            backend: Arc::new(RealSystemBackend),
+        }
+    }
+}
+
+impl CgroupFreezeInjector {
+    pub fn new(duration: Option<Duration>) -> Self {
+        Self {
+            duration,
+            backend: Arc::new(RealSystemBackend),
+        }
+    }
+
+    /// Overrides the [`SystemBackend`] used to write `cgroup.freeze`, e.g.
+    /// with a [`RecordingSystemBackend`] in tests.
+    pub fn with_backend(mut self, backend: Arc<dyn SystemBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn find_cgroup_path(pid: u32) -> Result<String> {
+        let cgroup_file = format!("/proc/{}/cgroup", pid);
+        let contents = tokio::fs::read_to_string(&cgroup_file).await.map_err(|e| {
+            ChaosError::ProcessError(format!("Failed to read {}: {}", cgroup_file, e))
+        })?;
+
+        // cgroup v2 has a single line: "0::<path>"
+        let relative = contents
+            .lines()
+            .find_map(|line| line.strip_prefix("0::"))
+            .ok_or_else(|| {
+                ChaosError::SystemError(
+                    "Process is not in a cgroup v2 hierarchy".to_string(),
+                )
+            })?;
+
+        Ok(format!("/sys/fs/cgroup{}", relative))
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn set_frozen(&self, cgroup_path: &str, frozen: bool) -> Result<()> {
+        let freeze_file = format!("{}/cgroup.freeze", cgroup_path);
+        self.backend
+            .write_file(&freeze_file, if frozen { "1" } else { "0" })
+            .await
+    }
+}
+
+#[async_trait]
+impl Injector for CgroupFreezeInjector {
+    async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+        let Target::Process { pid } = target else {
+            return Err(ChaosError::InvalidConfig(
+                "Cgroup freeze requires Process target".to_string(),
+            ));
+        };
+
+        #[cfg(target_os = "linux")]
+        {
+            let cgroup_path = Self::find_cgroup_path(*pid).await?;
+            info!("Freezing cgroup {} (PID {})", cgroup_path, pid);
+            self.set_frozen(&cgroup_path, true).await?;
+
+            let metadata = serde_json::json!({
+                "pid": pid,
+                "cgroup_path": cgroup_path,
+            });
+
+            let mut handle = InjectionHandle::new("cgroup_freeze", target.clone(), metadata);
+            if let Some(duration) = self.duration {
+                // Set the handle's TTL instead of blocking here so the
+                // freeze is visible to `chaos active`/`chaos top` and
+                // removable by cancellation for as long as it's actually in
+                // effect, rather than the executor only learning about it
+                // after it's already over. `Executor::spawn_auto_expiry`
+                // thaws it via `remove` once the TTL elapses.
+                handle = handle.with_ttl(duration);
+            }
+
+            Ok(handle)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = pid;
+            Err(ChaosError::SystemError(
+                "Cgroup freezer is only available on Linux".to_string(),
+            ))
+        }
+    }
+
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            let cgroup_path = handle
+                .metadata
+                .get("cgroup_path")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    ChaosError::CleanupFailed("Missing cgroup_path metadata".to_string())
+                })?;
+
+            info!("Thawing cgroup {}", cgroup_path);
+            self.set_frozen(cgroup_path, false).await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn verify(&self, handle: &InjectionHandle) -> Result<bool> {
+        let Some(cgroup_path) = handle.metadata.get("cgroup_path").and_then(|v| v.as_str()) else {
+            return Ok(true);
+        };
+
+        let freeze_file = format!("{}/cgroup.freeze", cgroup_path);
+        let contents = tokio::fs::read_to_string(&freeze_file).await.map_err(|e| {
+            ChaosError::SystemError(format!("Failed to read {}: {}", freeze_file, e))
+        })?;
+
+        Ok(contents.trim() == "1")
+    }
+
+    fn name(&self) -> &str {
+        "cgroup_freeze"
+    }
+
+    fn describe_dry_run(&self, target: &Target) -> Vec<String> {
+        let Target::Process { pid } = target else {
+            return vec!["cgroup_freeze requires a Process target".to_string()];
+        };
+
+        let mut lines = vec![format!(
+            "resolve the cgroup v2 path for PID {} and write 1 to its cgroup.freeze",
+            pid
+        )];
+
+        if let Some(duration) = self.duration {
+            lines.push(format!(
+                "after {:?}, write 0 to the same cgroup.freeze to thaw it automatically",
+                duration
+            ));
+        }
+
+        lines
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SchedulingClass {
+    Batch,
+    Idle,
+}
+
+impl SchedulingClass {
+    #[cfg(target_os = "linux")]
+    fn as_policy(&self) -> libc::c_int {
+        match self {
+            SchedulingClass::Batch => libc::SCHED_BATCH,
+            SchedulingClass::Idle => libc::SCHED_IDLE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SchedulingChaosConfig {
+    /// New `nice` value (-20 to 19), None to leave unchanged
+    pub nice: Option<i32>,
+    pub sched_class: Option<SchedulingClass>,
+    pub ionice_idle: bool,
+    pub duration: Duration,
+}
+
+impl Default for SchedulingChaosConfig {
+    fn default() -> Self {
+        Self {
+            nice: Some(19),
+            sched_class: None,
+            ionice_idle: true,
+            duration: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Degrades a process's CPU and I/O scheduling priority for the duration of
+/// the injection, restoring its original nice value/class/ionice on removal.
+#[derive(Default)]
+pub struct SchedulingChaosInjector {
+    config: SchedulingChaosConfig,
+}
+
+impl SchedulingChaosInjector {
+    pub fn new(config: SchedulingChaosConfig) -> Self {
+        Self { config }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn get_nice(pid: u32) -> Result<i32> {
+        errno_clear();
+        let value = unsafe { libc::getpriority(libc::PRIO_PROCESS, pid) };
+        if value == -1 && errno_is_set() {
+            return Err(ChaosError::ProcessError("Failed to read nice value".to_string()));
+        }
+        Ok(value)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_nice(pid: u32, nice: i32) -> Result<()> {
+        let rc = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) };
+        if rc != 0 {
+            return Err(ChaosError::ProcessError(format!(
+                "Failed to set nice value {} on PID {}",
+                nice, pid
+            )));
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_sched_class(pid: u32, class: SchedulingClass) -> Result<()> {
+        let param = libc::sched_param { sched_priority: 0 };
+        let rc = unsafe {
+            libc::sched_setscheduler(pid as libc::pid_t, class.as_policy(), &param)
+        };
+        if rc != 0 {
+            return Err(ChaosError::ProcessError(format!(
+                "Failed to set scheduling class on PID {}",
+                pid
+            )));
+        }
+        Ok(())
+    }
+
+    async fn set_ionice_idle(pid: u32) -> Result<()> {
+        // Class 3 = idle, priority ignored for idle class
+        Command::new("ionice")
+            .args(["-c", "3", "-p", &pid.to_string()])
+            .output()
+            .await
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to run ionice: {}", e)))?;
+        Ok(())
+    }
+
+    async fn restore_ionice(pid: u32, class: u32, priority: u32) -> Result<()> {
+        Command::new("ionice")
+            .args([
+                "-c",
+                &class.to_string(),
+                "-n",
+                &priority.to_string(),
+                "-p",
+                &pid.to_string(),
+            ])
+            .output()
+            .await
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to restore ionice: {}", e)))?;
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn errno_clear() {
+    unsafe { *libc::__errno_location() = 0 };
+}
+
+#[cfg(target_os = "linux")]
+fn errno_is_set() -> bool {
+    unsafe { *libc::__errno_location() != 0 }
+}
+
+#[async_trait]
+impl Injector for SchedulingChaosInjector {
+    async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+        let Target::Process { pid } = target else {
+            return Err(ChaosError::InvalidConfig(
+                "Scheduling chaos requires Process target".to_string(),
+            ));
+        };
+        let pid = *pid;
+
+        #[cfg(target_os = "linux")]
+        {
+            let original_nice = Self::get_nice(pid)?;
+
+            if let Some(nice) = self.config.nice {
+                info!("Renicing PID {} to {}", pid, nice);
+                Self::set_nice(pid, nice)?;
+            }
+
+            if let Some(class) = self.config.sched_class {
+                info!("Setting PID {} scheduling class to {:?}", pid, class);
+                Self::set_sched_class(pid, class)?;
+            }
+
+            if self.config.ionice_idle {
+                info!("Setting PID {} ionice to idle", pid);
+                Self::set_ionice_idle(pid).await?;
+            }
+
+            let metadata = serde_json::json!({
+                "pid": pid,
+                "original_nice": original_nice,
+                "applied_nice": self.config.nice,
+                "sched_class": self.config.sched_class,
+                "ionice_idle": self.config.ionice_idle,
+            });
+
+            Ok(InjectionHandle::new("scheduling_chaos", target.clone(), metadata))
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            Err(ChaosError::SystemError(
+                "Scheduling chaos is only supported on Linux".to_string(),
+            ))
+        }
+    }
+
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            let pid = handle
+                .metadata
+                .get("pid")
+                .and_then(|v| v.as_u64())
+                .ok_or_else(|| ChaosError::CleanupFailed("Missing pid metadata".to_string()))?
+                as u32;
+
+            if let Some(original_nice) = handle.metadata.get("original_nice").and_then(|v| v.as_i64()) {
+                info!("Restoring nice value {} on PID {}", original_nice, pid);
+                Self::set_nice(pid, original_nice as i32)?;
+            }
+
+            if self.config.sched_class.is_some() {
+                // SCHED_OTHER is the normal (non-realtime, non-batch/idle) Linux class.
+                let param = libc::sched_param { sched_priority: 0 };
+                unsafe {
+                    libc::sched_setscheduler(pid as libc::pid_t, libc::SCHED_OTHER, &param);
+                }
+            }
+
+            if self.config.ionice_idle {
+                // Best-effort restore to "best effort" class at default priority.
+                Self::restore_ionice(pid, 2, 4).await.ok();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "scheduling_chaos"
+    }
+
+    fn required_capabilities(&self) -> Vec<String> {
+        vec!["CAP_SYS_NICE".to_string()]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ZombieOrphanConfig {
+    /// Number of children to leave as unreaped zombies.
+    pub zombie_count: usize,
+    /// Number of children to orphan by having their immediate parent exit,
+    /// forcing a reparent to PID 1 (or the nearest subreaper).
+    pub orphan_count: usize,
+    /// How long each orphaned process sleeps before exiting on its own.
+    pub orphan_lifetime: Duration,
+}
+
+impl Default for ZombieOrphanConfig {
+    fn default() -> Self {
+        Self {
+            zombie_count: 5,
+            orphan_count: 5,
+            orphan_lifetime: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Spawns zombie and orphan child processes to exercise PID-1 reaping and
+/// process-table pressure in containers.
+///
+/// Children are created under the chaos agent's own process tree, so the
+/// agent must share the target's PID namespace (e.g. run as a sidecar with
+/// `pid: container`) for this to stress the target's own reaper.
+pub struct ZombieOrphanInjector {
+    config: ZombieOrphanConfig,
+}
+
+impl ZombieOrphanInjector {
+    pub fn new(config: ZombieOrphanConfig) -> Self {
+        Self { config }
+    }
+
+    /// Spawn a child that exits immediately and is deliberately left
+    /// unreaped, turning it into a zombie until `remove` reaps it.
+    async fn spawn_zombie() -> Result<u32> {
+        tokio::task::spawn_blocking(|| {
+            std::process::Command::new("true")
+                .spawn()
+                .map(|child| child.id())
+                .map_err(|e| ChaosError::ProcessError(format!("Failed to spawn zombie: {}", e)))
+        })
+        .await
+        .map_err(|e| ChaosError::ProcessError(format!("Zombie spawn task panicked: {}", e)))?
+    }
+
+    /// Background a `sleep` under an intermediate shell that exits right
+    /// away, so the sleep is reparented to PID 1 / the nearest subreaper.
+    async fn spawn_orphan(&self, lifetime: Duration) -> Result<u32> {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(format!("sleep {} & echo $!", lifetime.as_secs().max(1)))
+            .output()
+            .await
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to spawn orphan: {}", e)))?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<u32>()
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to parse orphan PID: {}", e)))
+    }
+}
+
+#[async_trait]
+impl Injector for ZombieOrphanInjector {
+    async fn inject(&self, target: &Target) -> Result<InjectionHandle> {
+        let Target::Process { pid } = target else {
+            return Err(ChaosError::InvalidConfig(
+                "Zombie/orphan injection requires a Process target".to_string(),
+            ));
+        };
+
+        let mut zombie_pids = Vec::with_capacity(self.config.zombie_count);
+        for _ in 0..self.config.zombie_count {
+            zombie_pids.push(Self::spawn_zombie().await?);
+        }
+
+        let mut orphan_pids = Vec::with_capacity(self.config.orphan_count);
+        for _ in 0..self.config.orphan_count {
+            orphan_pids.push(self.spawn_orphan(self.config.orphan_lifetime).await?);
+        }
+
+        info!(
+            "Spawned {} zombies and {} orphans near target PID {}",
+            zombie_pids.len(),
+            orphan_pids.len(),
+            pid
+        );
+
+        let metadata = serde_json::json!({
+            "target_pid": pid,
+            "zombie_pids": zombie_pids,
+            "orphan_pids": orphan_pids,
+        });
+
+        Ok(InjectionHandle::new("zombie_orphan", target.clone(), metadata))
+    }
+
+    async fn remove(&self, handle: InjectionHandle) -> Result<()> {
+        if let Some(zombie_pids) = handle.metadata.get("zombie_pids").and_then(|v| v.as_array()) {
+            for pid in zombie_pids.iter().filter_map(|v| v.as_u64()) {
+                reap_zombie(pid as u32);
+            }
+        }
+
+        if let Some(orphan_pids) = handle.metadata.get("orphan_pids").and_then(|v| v.as_array()) {
+            for pid in orphan_pids.iter().filter_map(|v| v.as_u64()) {
+                kill_orphan(pid as u32);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "zombie_orphan"
+    }
+}
+
+#[cfg(unix)]
+fn reap_zombie(pid: u32) {
+    use nix::sys::wait::{waitpid, WaitPidFlag};
+    use nix::unistd::Pid;
+
+    let _ = waitpid(Pid::from_raw(pid as i32), Some(WaitPidFlag::WNOHANG));
+}
+
+#[cfg(not(unix))]
+fn reap_zombie(_pid: u32) {}
+
+#[cfg(unix)]
+fn kill_orphan(pid: u32) {
+    use nix::sys::signal::{self, Signal};
+    use nix::unistd::Pid;
+
+    let _ = signal::kill(Pid::from_raw(pid as i32), Signal::SIGKILL);
+}
+
+#[cfg(not(unix))]
+fn kill_orphan(_pid: u32) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::system_backend::{RecordedCall, RecordingSystemBackend};
+
+    #[tokio::test]
+    async fn test_inject_sends_configured_signal_through_backend() {
+        let backend = Arc::new(RecordingSystemBackend::new());
+        // SIGSTOP skips kill_one's wait-for-death loop, keeping this test
+        // synchronous without needing a real process to watch.
+        let injector = ProcessKillInjector::builder()
+            .signal(Signal::SIGSTOP)
+            .build()
+            .with_backend(backend.clone());
+
+        injector.inject(&Target::process(999_999)).await.unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec![RecordedCall::SendSignal {
+                pid: 999_999,
+                signal: "SIGSTOP".to_string(),
+            }]
+        );
+    }
 
     #[test]
     fn test_signal_conversion() {
@@ -402,4 +1259,127 @@ mod tests {
         assert!(matches!(injector.config.signal, Signal::SIGKILL));
         assert_eq!(injector.config.restart_delay, Duration::from_secs(10));
     }
+
+    #[test]
+    fn test_process_kill_builder_escalation_timeout() {
+        let injector = ProcessKillInjector::builder()
+            .signal(Signal::SIGTERM)
+            .escalation_timeout(Duration::from_secs(5))
+            .build();
+
+        assert_eq!(injector.config.escalation_timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_process_kill_builder_defaults_to_reusing_connections() {
+        let injector = ProcessKillInjector::builder().build();
+        assert!(injector.config.reuse_health_check_connections);
+    }
+
+    #[test]
+    fn test_health_check_client_is_reused_across_calls() {
+        let _ = health_check_client();
+        let created_after_first_call = health_check_pool_stats().clients_created;
+        let _ = health_check_client();
+
+        assert_eq!(
+            health_check_pool_stats().clients_created,
+            created_after_first_call
+        );
+    }
+
+    #[test]
+    fn test_process_kill_builder_accumulates_restart_env() {
+        let injector = ProcessKillInjector::builder()
+            .restart_env("POOL_SIZE", "2")
+            .restart_env("LOG_LEVEL", "debug")
+            .build();
+
+        assert_eq!(
+            injector.config.restart_env.get("POOL_SIZE").map(String::as_str),
+            Some("2")
+        );
+        assert_eq!(
+            injector.config.restart_env.get("LOG_LEVEL").map(String::as_str),
+            Some("debug")
+        );
+    }
+
+    #[test]
+    fn test_process_kill_configure_accepts_signal_param() {
+        let injector = ProcessKillInjector::default();
+        let params = serde_json::json!({ "signal": "SIGKILL" });
+
+        let configured = injector.configure(&params).unwrap();
+        assert_eq!(configured.name(), "process_kill");
+    }
+
+    #[test]
+    fn test_process_kill_select_pids_count() {
+        let injector = ProcessKillInjector::new(ProcessKillConfig {
+            pattern_selection: PatternSelection::Count(2),
+            ..ProcessKillConfig::default()
+        });
+
+        let selected = injector.select_pids(vec![1, 2, 3, 4, 5]);
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn test_scheduling_chaos_default_config() {
+        let config = SchedulingChaosConfig::default();
+        assert_eq!(config.nice, Some(19));
+        assert!(config.ionice_idle);
+        assert!(config.sched_class.is_none());
+    }
+
+    #[test]
+    fn test_zombie_orphan_default_config() {
+        let config = ZombieOrphanConfig::default();
+        assert_eq!(config.zombie_count, 5);
+        assert_eq!(config.orphan_count, 5);
+        assert_eq!(config.orphan_lifetime, Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_zombie_orphan_inject_rejects_non_process_target() {
+        let injector = ZombieOrphanInjector::new(ZombieOrphanConfig::default());
+        let result = injector
+            .inject(&Target::Network {
+                address: "127.0.0.1:0".parse().unwrap(),
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_cgroup_freeze_writes_cgroup_freeze_file_through_backend() {
+        let backend = Arc::new(RecordingSystemBackend::new());
+        let injector = CgroupFreezeInjector::new(None).with_backend(backend.clone());
+
+        injector
+            .set_frozen("/sys/fs/cgroup/chaos/test", true)
+            .await
+            .unwrap();
+        injector
+            .set_frozen("/sys/fs/cgroup/chaos/test", false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec![
+                RecordedCall::WriteFile {
+                    path: "/sys/fs/cgroup/chaos/test/cgroup.freeze".to_string(),
+                    contents: "1".to_string(),
+                },
+                RecordedCall::WriteFile {
+                    path: "/sys/fs/cgroup/chaos/test/cgroup.freeze".to_string(),
+                    contents: "0".to_string(),
+                },
+            ]
+        );
+    }
 }
@@ -1,14 +1,43 @@
+pub mod abort;
+#[cfg(feature = "agent")]
+pub mod agent;
+pub mod capabilities;
+pub mod cleanup;
+pub mod discovery;
+pub mod events;
 pub mod injectors;
 pub mod target;
 pub mod executor;
 pub mod error;
+pub mod error_budget;
 pub mod handle;
+pub mod policy;
+pub mod preflight;
+pub mod remote;
+pub mod state_file;
+pub mod system_backend;
 
+pub use abort::{AbortConditions, AbortMetricsSource, AbortMonitor};
+#[cfg(feature = "agent")]
+pub use agent::{serve, serve_with_cancellation, AgentClient, AgentClientConfig, AgentServerConfig};
+pub use capabilities::Capability;
+pub use cleanup::OrphanArtifact;
+pub use discovery::{DiscoveredContainer, DiscoveredProcess, DiscoveredSocket};
+pub use events::ExecutorEvent;
 pub use injectors::*;
 pub use target::Target;
-pub use executor::Executor;
+pub use executor::{Executor, InjectionGuard};
 pub use error::{ChaosError, Result};
+pub use policy::{SafetyPolicy, TimeWindow};
+pub use error_budget::{ErrorBudgetPolicy, ErrorBudgetSource};
 pub use handle::InjectionHandle;
+pub use preflight::{
+    BinaryCheck, CapabilityCheck, InjectorReadiness, KernelModuleCheck, Preflight,
+    PreflightReport,
+};
+pub use remote::{HostKeyPolicy, SshConfig, SshPool};
+pub use state_file::StateFile;
+pub use system_backend::{RealSystemBackend, RecordedCall, RecordingSystemBackend, SystemBackend};
 
 // Re-export commonly used types
 pub use async_trait::async_trait;
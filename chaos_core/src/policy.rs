@@ -0,0 +1,338 @@
+use crate::error::{ChaosError, Result};
+use crate::target::Target;
+use chrono::Timelike;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::path::Path;
+
+/// An hour-of-day range, in UTC, during which injections are permitted.
+/// `end_hour` may be less than `start_hour` to express a window crossing
+/// midnight (e.g. `22 - 6` for "overnight only").
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// First hour of the window, inclusive. 0-23.
+    pub start_hour: u8,
+    /// First hour after the window, exclusive. 0-23.
+    pub end_hour: u8,
+}
+
+impl TimeWindow {
+    fn contains(&self, hour: u8) -> bool {
+        if self.start_hour <= self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// Blast-radius guardrails the [`crate::executor::Executor`] checks before
+/// every injection, independent of whatever a scenario itself asks for.
+/// Attach one with `Executor::with_policy` so a bad scenario file - or a
+/// scenario author who didn't think about where it would run - can't reach
+/// production PIDs or networks just because nothing else stopped it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SafetyPolicy {
+    /// PIDs that may never be targeted, regardless of what a scenario asks
+    /// for. PID 1 is the canonical example of something no injector should
+    /// ever be allowed to touch.
+    #[serde(default)]
+    pub deny_pids: Vec<u32>,
+
+    /// CIDR blocks (e.g. `"10.0.0.0/8"`) that a `Target::Network` may never
+    /// fall inside - production ranges, management networks, and the like.
+    /// Malformed entries are ignored rather than rejected at load time, so
+    /// one typo'd line doesn't take down the whole policy.
+    #[serde(default)]
+    pub deny_cidrs: Vec<String>,
+
+    /// Hard cap on how many injections this executor may have active at
+    /// once, across every injector and target.
+    #[serde(default)]
+    pub max_concurrent_injections: Option<usize>,
+
+    /// Upper bound on the `"intensity"` parameter of any injector that
+    /// exposes one (`cpu_starvation`, ...). Injectors without an
+    /// `"intensity"` parameter are unaffected.
+    #[serde(default)]
+    pub max_intensity: Option<f64>,
+
+    /// If non-empty, injections are only permitted while the current UTC
+    /// hour falls inside at least one of these windows; outside all of
+    /// them, every injection is denied. Empty means "no restriction".
+    #[serde(default)]
+    pub allowed_windows: Vec<TimeWindow>,
+}
+
+impl SafetyPolicy {
+    /// Loads a policy from a YAML file. There's deliberately no TOML/JSON
+    /// dispatch here the way `MonkeyConfig`/`SuiteFile` have - a safety
+    /// policy is hand-edited and reviewed like a Kubernetes manifest, and
+    /// YAML is what this repo uses for that kind of file everywhere else.
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path).await?;
+        serde_yaml::from_str(&contents).map_err(|e| {
+            ChaosError::InvalidConfig(format!(
+                "failed to parse safety policy {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Checks a prospective injection against every guardrail this policy
+    /// declares, returning the first violation found. `active_count` is the
+    /// number of injections already active, not counting the one about to
+    /// be made.
+    pub(crate) fn check(
+        &self,
+        injector_name: &str,
+        target: &Target,
+        params: &serde_json::Value,
+        active_count: usize,
+    ) -> Result<()> {
+        self.check_target(target)?;
+
+        if let Some(max) = self.max_concurrent_injections {
+            if active_count >= max {
+                return Err(ChaosError::PolicyViolation(format!(
+                    "policy caps concurrent injections at {}, {} are already active",
+                    max, active_count
+                )));
+            }
+        }
+
+        if let Some(max) = self.max_intensity {
+            if let Some(intensity) = params.get("intensity").and_then(|v| v.as_f64()) {
+                if intensity > max {
+                    return Err(ChaosError::PolicyViolation(format!(
+                        "policy caps '{}' intensity at {}, got {}",
+                        injector_name, max, intensity
+                    )));
+                }
+            }
+        }
+
+        if !self.allowed_windows.is_empty() {
+            let hour = chrono::Utc::now().hour() as u8;
+            if !self.allowed_windows.iter().any(|w| w.contains(hour)) {
+                return Err(ChaosError::PolicyViolation(format!(
+                    "policy only allows injections during configured time windows, \
+                     current UTC hour is {}",
+                    hour
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks `deny_pids`/`deny_cidrs` against `target`, recursing through
+    /// `Target::Group`/`Target::Remote`/`Target::Agent`/`Target::NetNamespace`
+    /// the same way `Target::exists` does - otherwise a denied pid or CIDR
+    /// sails straight past the policy just by wrapping it in a fan-out,
+    /// cross-host, or namespaced target.
+    fn check_target(&self, target: &Target) -> Result<()> {
+        match target {
+            Target::Group(members) => {
+                for member in members {
+                    self.check_target(member)?;
+                }
+                Ok(())
+            }
+            Target::Remote { inner, .. }
+            | Target::Agent { inner, .. }
+            | Target::NetNamespace { inner, .. } => self.check_target(inner),
+            Target::Process { pid } | Target::Thread { tid: pid } => {
+                if self.deny_pids.contains(pid) {
+                    return Err(ChaosError::PolicyViolation(format!(
+                        "policy denies targeting pid {}",
+                        pid
+                    )));
+                }
+                Ok(())
+            }
+            Target::Network { address } => {
+                if let Some(cidr) = self
+                    .deny_cidrs
+                    .iter()
+                    .find(|cidr| cidr_contains(cidr, address.ip()))
+                {
+                    return Err(ChaosError::PolicyViolation(format!(
+                        "policy denies targeting {} (inside denied range {})",
+                        address, cidr
+                    )));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether `ip` falls inside `cidr` (e.g. `"10.0.0.0/8"`). Malformed input
+/// is treated as a non-match rather than an error, so a malformed
+/// `deny_cidrs` entry denies nothing; entries are not validated at load
+/// time.
+fn cidr_contains(cidr: &str, ip: IpAddr) -> bool {
+    let Some((network, prefix)) = cidr.split_once('/') else {
+        return false;
+    };
+    let Ok(network) = network.parse::<IpAddr>() else {
+        return false;
+    };
+    let Ok(prefix) = prefix.parse::<u32>() else {
+        return false;
+    };
+
+    match (network, ip) {
+        (IpAddr::V4(net), IpAddr::V4(addr)) if prefix <= 32 => {
+            let mask = if prefix == 0 {
+                0
+            } else {
+                u32::MAX << (32 - prefix)
+            };
+            u32::from(net) & mask == u32::from(addr) & mask
+        }
+        (IpAddr::V6(net), IpAddr::V6(addr)) if prefix <= 128 => {
+            let mask = if prefix == 0 {
+                0
+            } else {
+                u128::MAX << (128 - prefix)
+            };
+            u128::from(net) & mask == u128::from(addr) & mask
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cidr_contains_matches_within_range() {
+        assert!(cidr_contains("10.0.0.0/8", "10.1.2.3".parse().unwrap()));
+        assert!(!cidr_contains("10.0.0.0/8", "11.1.2.3".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_cidr_contains_rejects_malformed_entries() {
+        assert!(!cidr_contains("not-a-cidr", "10.1.2.3".parse().unwrap()));
+        assert!(!cidr_contains("10.0.0.0/99", "10.1.2.3".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_time_window_handles_midnight_wraparound() {
+        let overnight = TimeWindow {
+            start_hour: 22,
+            end_hour: 6,
+        };
+        assert!(overnight.contains(23));
+        assert!(overnight.contains(2));
+        assert!(!overnight.contains(12));
+    }
+
+    #[test]
+    fn test_check_denies_listed_pid() {
+        let policy = SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+
+        let err = policy
+            .check(
+                "process_kill",
+                &Target::Process { pid: 1 },
+                &serde_json::Value::Null,
+                0,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ChaosError::PolicyViolation(_)));
+    }
+
+    #[test]
+    fn test_check_denies_over_max_intensity() {
+        let policy = SafetyPolicy {
+            max_intensity: Some(0.5),
+            ..Default::default()
+        };
+
+        let err = policy
+            .check(
+                "cpu_starvation",
+                &Target::Process { pid: 42 },
+                &serde_json::json!({ "intensity": 0.9 }),
+                0,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ChaosError::PolicyViolation(_)));
+    }
+
+    #[test]
+    fn test_check_denies_listed_pid_wrapped_in_group() {
+        let policy = SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+
+        let err = policy
+            .check(
+                "process_kill",
+                &Target::group([Target::process(2), Target::process(1)]),
+                &serde_json::Value::Null,
+                0,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ChaosError::PolicyViolation(_)));
+    }
+
+    #[test]
+    fn test_check_denies_listed_pid_wrapped_in_remote_and_agent() {
+        let policy = SafetyPolicy {
+            deny_pids: vec![1],
+            ..Default::default()
+        };
+
+        assert!(policy
+            .check(
+                "process_kill",
+                &Target::remote("prod-db", Target::process(1)),
+                &serde_json::Value::Null,
+                0,
+            )
+            .is_err());
+
+        assert!(policy
+            .check(
+                "process_kill",
+                &Target::agent("prod-db", 9091, Target::process(1)),
+                &serde_json::Value::Null,
+                0,
+            )
+            .is_err());
+
+        assert!(policy
+            .check(
+                "process_kill",
+                &Target::net_namespace("/var/run/netns/app", Target::process(1)),
+                &serde_json::Value::Null,
+                0,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_check_allows_when_nothing_configured() {
+        let policy = SafetyPolicy::default();
+        policy
+            .check(
+                "cpu_starvation",
+                &Target::Process { pid: 42 },
+                &serde_json::json!({ "intensity": 1.0 }),
+                1000,
+            )
+            .unwrap();
+    }
+}
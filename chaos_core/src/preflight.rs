@@ -0,0 +1,322 @@
+use crate::injectors::InjectorRegistry;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// One binary the base injectors shell out to (`tc`, `iptables`, `dnctl`),
+/// and whether it was found on `PATH`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BinaryCheck {
+    pub name: &'static str,
+    pub found: bool,
+    pub path: Option<PathBuf>,
+}
+
+/// A kernel module an injector depends on. Linux-only - `Preflight::run`
+/// returns an empty list elsewhere, since there's nothing equivalent to
+/// check on other platforms.
+#[derive(Debug, Clone, Serialize)]
+pub struct KernelModuleCheck {
+    pub name: &'static str,
+    pub loaded: bool,
+}
+
+/// A Linux capability the process's effective set needs for some injector
+/// to work. Linux-only, same as [`KernelModuleCheck`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CapabilityCheck {
+    pub name: &'static str,
+    pub held: bool,
+}
+
+/// Result of calling [`crate::injectors::Injector::validate`] on one
+/// registered injector.
+#[derive(Debug, Clone, Serialize)]
+pub struct InjectorReadiness {
+    pub name: String,
+    pub ready: bool,
+    pub detail: Option<String>,
+}
+
+/// Full preflight report: everything [`Preflight::run`] checked about this
+/// host before any injector is actually invoked for real.
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightReport {
+    pub binaries: Vec<BinaryCheck>,
+    pub kernel_modules: Vec<KernelModuleCheck>,
+    pub capabilities: Vec<CapabilityCheck>,
+    pub cgroup_version: Option<u8>,
+    pub injectors: Vec<InjectorReadiness>,
+    /// Whether this process is running as root (euid 0). `false` puts the
+    /// host in rootless/degraded mode: injectors with no
+    /// `required_capabilities` (e.g. `memory_pressure`), or whose only
+    /// declared capabilities are ones [`has_capability`] can't check (e.g.
+    /// `cpu_starvation`'s `CAP_SYS_NICE`), still report ready, and cgroup-based injectors work if the process's own
+    /// cgroup subtree was delegated to it (common under a systemd user
+    /// session), but anything needing `CAP_NET_ADMIN`/`CAP_SYS_ADMIN` and
+    /// not separately granted via `setcap` reports not-ready below with a
+    /// reason, instead of failing deep inside a `tc`/`iptables` call.
+    pub privileged: bool,
+}
+
+impl PreflightReport {
+    /// Whether every check that applies to this platform passed. Checks
+    /// that don't apply (e.g. kernel modules on non-Linux) are vacuously
+    /// satisfied rather than counted as failures.
+    pub fn all_passed(&self) -> bool {
+        self.binaries.iter().all(|b| b.found)
+            && self.kernel_modules.iter().all(|m| m.loaded)
+            && self.capabilities.iter().all(|c| c.held)
+            && self.injectors.iter().all(|i| i.ready)
+    }
+}
+
+/// Checks the binaries, kernel modules, capabilities, and cgroup version
+/// the base injectors rely on, behind `chaos doctor`. `Injector::validate`
+/// existed on the trait but nothing previously called it - this is what
+/// finally wires it up, running it once per registered injector.
+pub struct Preflight;
+
+impl Preflight {
+    pub async fn run(registry: &InjectorRegistry) -> PreflightReport {
+        let mut injectors = Vec::new();
+        for name in registry.list() {
+            if let Some(injector) = registry.get(&name) {
+                let readiness = match injector.validate().await {
+                    Err(e) => InjectorReadiness {
+                        name: name.clone(),
+                        ready: false,
+                        detail: Some(e.to_string()),
+                    },
+                    Ok(()) => match injector
+                        .required_capabilities()
+                        .into_iter()
+                        .find(|capability| !has_capability(capability))
+                    {
+                        Some(missing) => InjectorReadiness {
+                            name: name.clone(),
+                            ready: false,
+                            detail: Some(format!(
+                                "requires capability '{}', which this process does not hold{} - \
+                                 run as root or grant it with setcap",
+                                missing,
+                                if is_privileged() { "" } else { " (running unprivileged)" }
+                            )),
+                        },
+                        None => InjectorReadiness {
+                            name: name.clone(),
+                            ready: true,
+                            detail: None,
+                        },
+                    },
+                };
+                injectors.push(readiness);
+            }
+        }
+        injectors.sort_by(|a, b| a.name.cmp(&b.name));
+
+        PreflightReport {
+            binaries: check_binaries(),
+            kernel_modules: check_kernel_modules(),
+            capabilities: check_capabilities(),
+            cgroup_version: detect_cgroup_version(),
+            injectors,
+            privileged: is_privileged(),
+        }
+    }
+}
+
+/// Whether this process is running as root (euid 0). The degraded/rootless
+/// mode the rest of this module's readiness checks describe is whatever's
+/// left once this is `false`: injectors that declare no
+/// `required_capabilities` keep working unmodified, and the rest report
+/// their own missing capability by name instead of this function gating
+/// anything directly.
+#[cfg(unix)]
+pub fn is_privileged() -> bool {
+    nix::unistd::Uid::effective().is_root()
+}
+
+#[cfg(not(unix))]
+pub fn is_privileged() -> bool {
+    false
+}
+
+const REQUIRED_BINARIES: [&str; 3] = ["tc", "iptables", "dnctl"];
+
+fn check_binaries() -> Vec<BinaryCheck> {
+    REQUIRED_BINARIES
+        .iter()
+        .map(|&name| {
+            let path = find_on_path(name);
+            BinaryCheck {
+                name,
+                found: path.is_some(),
+                path,
+            }
+        })
+        .collect()
+}
+
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(target_os = "linux")]
+const REQUIRED_KERNEL_MODULES: [&str; 1] = ["sch_netem"];
+
+#[cfg(target_os = "linux")]
+fn check_kernel_modules() -> Vec<KernelModuleCheck> {
+    // sch_netem can also be compiled directly into the kernel rather than
+    // loaded as a module, in which case it never shows up here even though
+    // `tc ... netem` still works - this only reports what /proc/modules
+    // says, not whether netem is actually usable.
+    let modules = std::fs::read_to_string("/proc/modules").unwrap_or_default();
+
+    REQUIRED_KERNEL_MODULES
+        .iter()
+        .map(|&name| {
+            let loaded = modules
+                .lines()
+                .any(|line| line.split_whitespace().next() == Some(name));
+            KernelModuleCheck { name, loaded }
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_kernel_modules() -> Vec<KernelModuleCheck> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+const REQUIRED_CAPABILITIES: [&str; 3] = ["CAP_NET_ADMIN", "CAP_SYS_ADMIN", "CAP_KILL"];
+
+#[cfg(target_os = "linux")]
+fn check_capabilities() -> Vec<CapabilityCheck> {
+    REQUIRED_CAPABILITIES
+        .iter()
+        .map(|&name| CapabilityCheck {
+            name,
+            held: has_capability(name),
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_capabilities() -> Vec<CapabilityCheck> {
+    Vec::new()
+}
+
+/// Maps a `capability(7)` name (as returned by
+/// [`crate::injectors::Injector::required_capabilities`]) to its bit number
+/// in the `CapEff`/`CapBnd` bitmasks. Only covers `CAP_CHOWN`, `CAP_KILL`,
+/// `CAP_NET_ADMIN` and `CAP_SYS_ADMIN`; any other name (including the
+/// `CAP_SYS_NICE` the CPU and scheduling injectors declare) falls through to
+/// [`has_capability`] treating it as unverifiable rather than denied.
+#[cfg(target_os = "linux")]
+fn capability_bit(name: &str) -> Option<u64> {
+    match name {
+        "CAP_CHOWN" => Some(0),
+        "CAP_KILL" => Some(5),
+        "CAP_NET_ADMIN" => Some(12),
+        "CAP_SYS_ADMIN" => Some(21),
+        _ => None,
+    }
+}
+
+/// Whether the current process's effective capability set holds `name`.
+/// Used both by [`Preflight::run`]'s report and by `Executor::inject` to
+/// fail fast with [`crate::error::ChaosError::PermissionDenied`] before
+/// handing off to an injector that would otherwise fail deep inside its own
+/// `tc`/`iptables`/cgroup invocation. A capability this function doesn't
+/// recognize is treated as held, since we have no way to check it and
+/// shouldn't block an injector over our own blind spot.
+#[cfg(target_os = "linux")]
+pub(crate) fn has_capability(name: &str) -> bool {
+    let Some(bit) = capability_bit(name) else {
+        return true;
+    };
+
+    let status = std::fs::read_to_string("/proc/self/status").unwrap_or_default();
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|hex| u64::from_str_radix(hex.trim(), 16).ok())
+        .map(|mask| mask & (1 << bit) != 0)
+        .unwrap_or(false)
+}
+
+/// Non-Linux platforms have no equivalent capability model, so there's
+/// nothing to deny an injector over - `Preflight::run` reports an empty
+/// capability list here too.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn has_capability(_name: &str) -> bool {
+    true
+}
+
+#[cfg(target_os = "linux")]
+fn detect_cgroup_version() -> Option<u8> {
+    if std::path::Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+        Some(2)
+    } else if std::path::Path::new("/sys/fs/cgroup/memory").exists() {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_cgroup_version() -> Option<u8> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_on_path_locates_a_real_binary() {
+        // `sh` is about as safe an assumption as exists for a Unix CI box.
+        if cfg!(unix) {
+            assert!(find_on_path("sh").is_some());
+        }
+    }
+
+    #[test]
+    fn test_find_on_path_reports_missing_for_bogus_name() {
+        assert!(find_on_path("definitely-not-a-real-binary-xyz").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_one_entry_per_registered_injector() {
+        let registry = InjectorRegistry::with_defaults();
+        let report = Preflight::run(&registry).await;
+
+        assert_eq!(report.injectors.len(), registry.list().len());
+    }
+
+    #[test]
+    fn test_is_privileged_matches_euid() {
+        assert_eq!(is_privileged(), nix::unistd::geteuid().is_root());
+    }
+
+    #[tokio::test]
+    async fn test_injectors_with_no_required_capabilities_are_always_ready() {
+        // memory_pressure declares no required_capabilities, so it must
+        // report ready regardless of whether this test process is root -
+        // the rootless/degraded mode case the report.privileged field
+        // documents.
+        let registry = InjectorRegistry::with_defaults();
+        let report = Preflight::run(&registry).await;
+
+        let memory_pressure = report
+            .injectors
+            .iter()
+            .find(|i| i.name == "memory_pressure")
+            .expect("memory_pressure is a default injector");
+        assert!(memory_pressure.ready);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::error::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::info;
+
+/// How strictly a [`SshPool`] verifies a remote host's identity before
+/// connecting. `AcceptNew` is the default - it protects against a host
+/// silently changing key (a signal something's wrong) without requiring an
+/// operator to pre-seed `known_hosts` for every fleet member before a
+/// scenario can run against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum HostKeyPolicy {
+    /// Refuse to connect unless the host is already in `known_hosts`.
+    Strict,
+    /// Accept and remember a host's key the first time it's seen; reject if
+    /// a previously-seen host's key changes.
+    #[default]
+    AcceptNew,
+    /// Accept any host key without recording it. Only for throwaway/lab
+    /// environments - defeats the point of host key checking.
+    Insecure,
+}
+
+impl HostKeyPolicy {
+    fn ssh_option_value(&self) -> &'static str {
+        match self {
+            HostKeyPolicy::Strict => "yes",
+            HostKeyPolicy::AcceptNew => "accept-new",
+            HostKeyPolicy::Insecure => "no",
+        }
+    }
+}
+
+/// Connection settings shared by every host a [`SshPool`] talks to. Applies
+/// uniformly across the pool rather than per-host, matching how the rest of
+/// the framework configures an injector once and points it at many targets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SshConfig {
+    /// Remote login user. Defaults to whatever `ssh` itself would use (the
+    /// local user, or `~/.ssh/config`'s `User` directive) when unset.
+    pub user: Option<String>,
+    pub port: u16,
+    /// Private key to authenticate with, if not relying on an agent or
+    /// `~/.ssh/config`.
+    pub identity_file: Option<PathBuf>,
+    pub host_key_policy: HostKeyPolicy,
+    pub connect_timeout: Duration,
+}
+
+impl Default for SshConfig {
+    fn default() -> Self {
+        Self {
+            user: None,
+            port: 22,
+            identity_file: None,
+            host_key_policy: HostKeyPolicy::default(),
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl SshConfig {
+    fn destination(&self, host: &str) -> String {
+        match &self.user {
+            Some(user) => format!("{}@{}", user, host),
+            None => host.to_string(),
+        }
+    }
+
+    fn common_args(&self, control_path: &std::path::Path) -> Vec<String> {
+        let mut args = vec![
+            "-p".to_string(),
+            self.port.to_string(),
+            "-o".to_string(),
+            format!("StrictHostKeyChecking={}", self.host_key_policy.ssh_option_value()),
+            "-o".to_string(),
+            format!("ConnectTimeout={}", self.connect_timeout.as_secs()),
+            "-o".to_string(),
+            format!("ControlPath={}", control_path.display()),
+        ];
+        if let Some(identity_file) = &self.identity_file {
+            args.push("-i".to_string());
+            args.push(identity_file.display().to_string());
+        }
+        args
+    }
+}
+
+/// Runs shell-out commands (`tc`, `iptables`, ...) against
+/// remote hosts on behalf of [`crate::Target::Remote`], reusing one
+/// authenticated connection per host via OpenSSH's `ControlMaster` rather
+/// than paying a fresh TCP/auth handshake for every command an injector
+/// issues - the same reuse-a-warm-connection reasoning as the process
+/// injector's shared health-check HTTP client.
+///
+/// Shells out to the system `ssh` binary instead of vendoring an SSH client
+/// library, matching how [`crate::discovery`] shells out to `docker`/`ss`
+/// rather than adding a dependency for a single CLI round trip.
+pub struct SshPool {
+    config: SshConfig,
+    control_dir: PathBuf,
+    masters: Arc<Mutex<HashSet<String>>>,
+}
+
+impl SshPool {
+    pub fn new(config: SshConfig) -> Self {
+        Self {
+            config,
+            control_dir: std::env::temp_dir().join("chaos_ssh_control"),
+            masters: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    fn control_path(&self, host: &str) -> PathBuf {
+        self.control_dir.join(format!("{}.sock", host.replace('/', "_")))
+    }
+
+    /// Starts a backgrounded `ssh -M` master connection for `host` if one
+    /// isn't already tracked. Idempotent per pool instance.
+    async fn ensure_master(&self, host: &str) -> Result<()> {
+        let mut masters = self.masters.lock().await;
+        if masters.contains(host) {
+            return Ok(());
+        }
+
+        tokio::fs::create_dir_all(&self.control_dir)
+            .await
+            .map_err(|e| ChaosError::SystemError(format!("Failed to create SSH control dir: {}", e)))?;
+
+        let control_path = self.control_path(host);
+        let mut args = self.config.common_args(&control_path);
+        args.extend([
+            "-N".to_string(),
+            "-f".to_string(),
+            "-M".to_string(),
+            "-o".to_string(),
+            "ControlPersist=10m".to_string(),
+            self.config.destination(host),
+        ]);
+
+        info!("Opening pooled SSH connection to {}", host);
+        let output = Command::new("ssh")
+            .args(&args)
+            .output()
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to start ssh master: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ChaosError::InjectionFailed(format!(
+                "Failed to open SSH connection to {}: {}",
+                host, stderr
+            )));
+        }
+
+        masters.insert(host.to_string());
+        Ok(())
+    }
+
+    /// Runs `program args...` on `host` over the pooled connection,
+    /// returning its raw output so callers can interpret exit status the
+    /// same way they already do for local `tc`/`iptables` invocations.
+    pub async fn run(&self, host: &str, program: &str, args: &[&str]) -> Result<std::process::Output> {
+        self.ensure_master(host).await?;
+
+        let control_path = self.control_path(host);
+        let mut ssh_args = self.config.common_args(&control_path);
+        ssh_args.push(self.config.destination(host));
+        ssh_args.push(program.to_string());
+        ssh_args.extend(args.iter().map(|a| a.to_string()));
+
+        Command::new("ssh")
+            .args(&ssh_args)
+            .output()
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("Failed to run '{}' on {}: {}", program, host, e)))
+    }
+}
+
+/// The default pool used by injectors that don't need a custom
+/// [`SshConfig`] - one shared, lazily-started set of `ControlMaster`
+/// connections per process, the same "warm shared client, created once"
+/// pattern the health-check HTTP client uses.
+pub fn default_pool() -> &'static SshPool {
+    static POOL: std::sync::OnceLock<SshPool> = std::sync::OnceLock::new();
+    POOL.get_or_init(|| SshPool::new(SshConfig::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_key_policy_maps_to_ssh_option() {
+        assert_eq!(HostKeyPolicy::Strict.ssh_option_value(), "yes");
+        assert_eq!(HostKeyPolicy::AcceptNew.ssh_option_value(), "accept-new");
+        assert_eq!(HostKeyPolicy::Insecure.ssh_option_value(), "no");
+    }
+
+    #[test]
+    fn test_ssh_config_destination_includes_user_when_set() {
+        let mut config = SshConfig::default();
+        assert_eq!(config.destination("host1"), "host1");
+
+        config.user = Some("chaos".to_string());
+        assert_eq!(config.destination("host1"), "chaos@host1");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::Result;
+use crate::handle::InjectionHandle;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// On-disk record of injections that are (or, before a crash, were) active.
+///
+/// The `Executor` keeps this file in sync with `active_injections` as
+/// injections are applied and removed, so a crashed process still leaves
+/// behind enough information for `chaos recover` to find and tear down
+/// orphaned tc/iptables/cgroup artifacts, instead of leaving the host
+/// degraded with no record of what was injected.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StateFile {
+    pub injections: HashMap<String, InjectionHandle>,
+}
+
+impl StateFile {
+    /// Default location, overridable via `CHAOS_STATE_FILE` so multiple
+    /// instances on the same host (or a test run) don't collide.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("CHAOS_STATE_FILE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("/run/chaos/state.json"))
+    }
+
+    /// Loads the state file at `path`, or an empty one if it doesn't exist
+    /// yet (e.g. nothing has ever been injected on this host).
+    pub async fn load(path: &Path) -> Result<Self> {
+        if !tokio::fs::try_exists(path).await.unwrap_or(false) {
+            return Ok(Self::default());
+        }
+
+        let contents = tokio::fs::read_to_string(path).await?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, json).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::target::Target;
+
+    #[tokio::test]
+    async fn test_load_missing_state_file_is_empty() {
+        let path = std::env::temp_dir().join(format!("chaos-state-test-{}.json", uuid::Uuid::new_v4()));
+
+        let state = StateFile::load(&path).await.unwrap();
+        assert!(state.injections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_then_load_round_trips() {
+        let path = std::env::temp_dir().join(format!("chaos-state-test-{}.json", uuid::Uuid::new_v4()));
+
+        let handle = InjectionHandle::new(
+            "cpu_starvation",
+            Target::Process { pid: 1 },
+            serde_json::json!({}),
+        );
+
+        let mut state = StateFile::default();
+        state.injections.insert(handle.id.clone(), handle.clone());
+        state.save(&path).await.unwrap();
+
+        let loaded = StateFile::load(&path).await.unwrap();
+        assert_eq!(loaded.injections.len(), 1);
+        assert!(loaded.injections.contains_key(&handle.id));
+
+        tokio::fs::remove_file(&path).await.ok();
+    }
+}
@@ -0,0 +1,200 @@
+use crate::error::{ChaosError, Result};
+use crate::injectors::Signal;
+use async_trait::async_trait;
+use std::process::Output;
+
+/// Abstracts the three kinds of real-world side effects injectors perform -
+/// running a command (`tc`, `iptables`, `dnctl`, a restart script, ...),
+/// writing a sysfs/cgroupfs file (cgroup freezer, CPU quota, ...), and
+/// sending a Unix signal to a process - behind a trait, so inject/remove
+/// logic can be unit-tested against [`RecordingSystemBackend`] in CI instead
+/// of requiring root and a real Linux host to exercise.
+///
+/// Injectors that adopt this default to [`RealSystemBackend`] and accept an
+/// override through a `with_backend` setter, the same optional-override
+/// setter pattern as `NetworkLatencyBuilder::interface`.
+#[async_trait]
+pub trait SystemBackend: Send + Sync {
+    /// Runs `program` with `args` to completion and returns its output,
+    /// analogous to `tokio::process::Command::output`.
+    async fn run_command(&self, program: &str, args: &[&str]) -> Result<Output>;
+
+    /// Overwrites `path` with `contents`, analogous to `tokio::fs::write`.
+    async fn write_file(&self, path: &str, contents: &str) -> Result<()>;
+
+    /// Sends `signal` to `pid`.
+    fn send_signal(&self, pid: u32, signal: Signal) -> Result<()>;
+}
+
+/// The [`SystemBackend`] every injector uses outside of tests: shells out to
+/// the real OS via `tokio::process::Command`, `tokio::fs::write`, and
+/// `nix::sys::signal::kill`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealSystemBackend;
+
+#[async_trait]
+impl SystemBackend for RealSystemBackend {
+    async fn run_command(&self, program: &str, args: &[&str]) -> Result<Output> {
+        tokio::process::Command::new(program)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| ChaosError::SystemError(format!("failed to run {}: {}", program, e)))
+    }
+
+    async fn write_file(&self, path: &str, contents: &str) -> Result<()> {
+        tokio::fs::write(path, contents)
+            .await
+            .map_err(|e| ChaosError::InjectionFailed(format!("failed to write {}: {}", path, e)))
+    }
+
+    #[cfg(unix)]
+    fn send_signal(&self, pid: u32, signal: Signal) -> Result<()> {
+        use nix::sys::signal as nix_signal;
+        use nix::unistd::Pid;
+
+        let nix_signal = match signal {
+            Signal::SIGTERM => nix_signal::Signal::SIGTERM,
+            Signal::SIGKILL => nix_signal::Signal::SIGKILL,
+            Signal::SIGSTOP => nix_signal::Signal::SIGSTOP,
+            Signal::SIGCONT => nix_signal::Signal::SIGCONT,
+            Signal::SIGHUP => nix_signal::Signal::SIGHUP,
+        };
+
+        nix_signal::kill(Pid::from_raw(pid as i32), nix_signal)
+            .map_err(|e| ChaosError::ProcessError(format!("Failed to send signal: {}", e)))
+    }
+
+    #[cfg(not(unix))]
+    fn send_signal(&self, _pid: u32, _signal: Signal) -> Result<()> {
+        Err(ChaosError::SystemError(
+            "sending Unix signals is not supported on this platform".to_string(),
+        ))
+    }
+}
+
+/// One call an injector made through a [`SystemBackend`], as captured by
+/// [`RecordingSystemBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedCall {
+    RunCommand { program: String, args: Vec<String> },
+    WriteFile { path: String, contents: String },
+    SendSignal { pid: u32, signal: String },
+}
+
+/// A [`SystemBackend`] that never touches the real system: it records every
+/// call it receives (in order, in an internal `Mutex`-guarded `Vec`) and
+/// returns canned results, so injector `inject`/`remove` logic can be
+/// unit-tested for *which* commands it issues and in *what* order without
+/// root or a real Linux host.
+///
+/// Command calls succeed with empty, successful output by default; call
+/// [`RecordingSystemBackend::fail_commands`] to make `run_command` return an
+/// error instead, for testing an injector's error handling.
+#[derive(Debug, Default)]
+pub struct RecordingSystemBackend {
+    calls: std::sync::Mutex<Vec<RecordedCall>>,
+    fail_commands: std::sync::atomic::AtomicBool,
+}
+
+impl RecordingSystemBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes subsequent `run_command` calls return an error, instead of the
+    /// default successful empty output.
+    pub fn fail_commands(&self) {
+        self.fail_commands
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Returns every call recorded so far, in the order they happened.
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl SystemBackend for RecordingSystemBackend {
+    async fn run_command(&self, program: &str, args: &[&str]) -> Result<Output> {
+        self.calls.lock().unwrap().push(RecordedCall::RunCommand {
+            program: program.to_string(),
+            args: args.iter().map(|s| s.to_string()).collect(),
+        });
+
+        if self.fail_commands.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(ChaosError::SystemError(format!(
+                "{} failed (RecordingSystemBackend configured to fail commands)",
+                program
+            )));
+        }
+
+        #[cfg(unix)]
+        use std::os::unix::process::ExitStatusExt;
+        #[cfg(windows)]
+        use std::os::windows::process::ExitStatusExt;
+
+        Ok(Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        })
+    }
+
+    async fn write_file(&self, path: &str, contents: &str) -> Result<()> {
+        self.calls.lock().unwrap().push(RecordedCall::WriteFile {
+            path: path.to_string(),
+            contents: contents.to_string(),
+        });
+        Ok(())
+    }
+
+    fn send_signal(&self, pid: u32, signal: Signal) -> Result<()> {
+        self.calls.lock().unwrap().push(RecordedCall::SendSignal {
+            pid,
+            signal: signal.as_str().to_string(),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_recording_backend_captures_calls_in_order() {
+        let backend = RecordingSystemBackend::new();
+
+        backend.run_command("tc", &["qdisc", "add"]).await.unwrap();
+        backend.send_signal(42, Signal::SIGTERM).unwrap();
+        backend.write_file("/sys/fs/cgroup/x/cgroup.freeze", "1").await.unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec![
+                RecordedCall::RunCommand {
+                    program: "tc".to_string(),
+                    args: vec!["qdisc".to_string(), "add".to_string()],
+                },
+                RecordedCall::SendSignal {
+                    pid: 42,
+                    signal: "SIGTERM".to_string(),
+                },
+                RecordedCall::WriteFile {
+                    path: "/sys/fs/cgroup/x/cgroup.freeze".to_string(),
+                    contents: "1".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recording_backend_fail_commands_errors_run_command() {
+        let backend = RecordingSystemBackend::new();
+        backend.fail_commands();
+
+        assert!(backend.run_command("tc", &[]).await.is_err());
+    }
+}
@@ -17,6 +17,38 @@ pub enum Target {
     
     /// Target all processes matching a pattern
     ProcessPattern { pattern: String },
+
+    /// Fan out to every member target independently, e.g. all replica
+    /// addresses of a service or every PID matched by a pattern at
+    /// selection time. The executor applies the injection to each member
+    /// separately and bundles the resulting handles under one parent.
+    Group(Vec<Target>),
+
+    /// `inner` lives on `host` rather than the local machine. The network
+    /// injectors (tc, iptables) run their commands over
+    /// `chaos_core::remote::SshPool` instead of a local `Command` when they
+    /// see this wrapper, so one operator box can coordinate network faults
+    /// across a fleet without an agent installed on every member; other
+    /// injectors do not handle this wrapper.
+    Remote { host: String, inner: Box<Target> },
+
+    /// `inner` lives on a host running `chaos agent` (`chaos_core::agent`),
+    /// reached over its REST API rather than SSH. Unlike `Remote`, which
+    /// forwards individual shell commands, the `Executor` forwards the
+    /// *whole* injection to the agent's own `Executor`, so the agent host
+    /// needs no SSH access from the operator box - just the agent binary
+    /// running with a shared token.
+    Agent { host: String, port: u16, inner: Box<Target> },
+
+    /// `inner` lives inside the network namespace at `path` (a bind-mounted
+    /// namespace file such as `/var/run/netns/foo`, or a container's own
+    /// `/proc/<pid>/ns/net`) instead of the default namespace. Injectors
+    /// that shell out to `tc`/`iptables` enter the namespace with `nsenter
+    /// --net=path` before running their command, so faults land on that
+    /// namespace's interfaces without touching the host's - the piece
+    /// needed to inject faults into containerized workloads without an
+    /// agent or SSH access inside the container.
+    NetNamespace { path: String, inner: Box<Target> },
 }
 
 impl Target {
@@ -40,6 +72,32 @@ impl Target {
         Self::ProcessPattern { pattern: pattern.into() }
     }
 
+    pub fn group(members: impl IntoIterator<Item = Target>) -> Self {
+        Self::Group(members.into_iter().collect())
+    }
+
+    pub fn remote(host: impl Into<String>, inner: Target) -> Self {
+        Self::Remote {
+            host: host.into(),
+            inner: Box::new(inner),
+        }
+    }
+
+    pub fn agent(host: impl Into<String>, port: u16, inner: Target) -> Self {
+        Self::Agent {
+            host: host.into(),
+            port,
+            inner: Box::new(inner),
+        }
+    }
+
+    pub fn net_namespace(path: impl Into<String>, inner: Target) -> Self {
+        Self::NetNamespace {
+            path: path.into(),
+            inner: Box::new(inner),
+        }
+    }
+
     pub fn description(&self) -> String {
         match self {
             Target::Process { pid } => format!("Process PID {}", pid),
@@ -47,55 +105,163 @@ impl Target {
             Target::Container { id } => format!("Container {}", id),
             Target::Thread { tid } => format!("Thread TID {}", tid),
             Target::ProcessPattern { pattern } => format!("Process pattern '{}'", pattern),
+            Target::Group(members) => format!(
+                "Group of {} targets [{}]",
+                members.len(),
+                members
+                    .iter()
+                    .map(Target::description)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Target::Remote { host, inner } => format!("{} on remote host {}", inner.description(), host),
+            Target::Agent { host, port, inner } => {
+                format!("{} via chaos agent at {}:{}", inner.description(), host, port)
+            }
+            Target::NetNamespace { path, inner } => {
+                format!("{} in netns {}", inner.description(), path)
+            }
         }
     }
 
-    pub async fn exists(&self) -> bool {
-        match self {
-            Target::Process { pid } => {
-                #[cfg(unix)]
-                {
-                    use nix::sys::signal;
-                    use nix::unistd::Pid;
-                    signal::kill(Pid::from_raw(*pid as i32), None).is_ok()
+    /// Whether this target is currently live. For a `Group`, true if *any*
+    /// member still exists - a group is only worth tearing down once every
+    /// member has disappeared.
+    ///
+    /// Returns a boxed future rather than being an `async fn` because
+    /// `Group` recurses into its members' own `exists()`, and an `async fn`
+    /// can't call itself without boxing anyway.
+    pub fn exists(&self) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + '_>> {
+        Box::pin(async move {
+            match self {
+                Target::Process { pid } => {
+                    #[cfg(unix)]
+                    {
+                        use nix::sys::signal;
+                        use nix::unistd::Pid;
+                        signal::kill(Pid::from_raw(*pid as i32), None).is_ok()
+                    }
+                    #[cfg(not(unix))]
+                    {
+                        use sysinfo::System;
+                        let mut sys = System::new_all();
+                        sys.refresh_processes();
+                        sys.process(sysinfo::Pid::from(*pid as usize)).is_some()
+                    }
                 }
-                #[cfg(not(unix))]
-                {
+                Target::Network { address } => {
+                    // Check if address is reachable
+                    tokio::net::TcpStream::connect(address).await.is_ok()
+                }
+                Target::Container { id } => {
+                    // Check if container exists (simplified)
+                    std::path::Path::new(&format!("/sys/fs/cgroup/docker/{}", id)).exists()
+                }
+                Target::Thread { tid: _ } => {
+                    #[cfg(unix)]
+                    {
+                        // Thread validation would require checking /proc/<tid>
+                        true
+                    }
+                    #[cfg(not(unix))]
+                    {
+                        false
+                    }
+                }
+                Target::ProcessPattern { pattern } => {
                     use sysinfo::System;
                     let mut sys = System::new_all();
                     sys.refresh_processes();
-                    sys.process(sysinfo::Pid::from(*pid as usize)).is_some()
+                    sys.processes().values().any(|p| {
+                        p.name().contains(pattern)
+                    })
+                }
+                Target::Group(members) => {
+                    for member in members {
+                        if member.exists().await {
+                            return true;
+                        }
+                    }
+                    false
+                }
+                Target::Remote { host, inner } => remote_exists(host, inner).await,
+                Target::Agent { host, port, inner } => {
+                    #[cfg(feature = "agent")]
+                    {
+                        crate::agent::default_client().exists(host, *port, inner).await
+                    }
+                    #[cfg(not(feature = "agent"))]
+                    {
+                        let _ = (host, port, inner);
+                        false
+                    }
+                }
+                Target::NetNamespace { path, inner } => {
+                    std::path::Path::new(path).exists() && inner.exists().await
                 }
             }
-            Target::Network { address } => {
-                // Check if address is reachable
-                tokio::net::TcpStream::connect(address).await.is_ok()
-            }
-            Target::Container { id } => {
-                // Check if container exists (simplified)
-                std::path::Path::new(&format!("/sys/fs/cgroup/docker/{}", id)).exists()
+        })
+    }
+}
+
+/// Best-effort existence check for a [`Target::Remote`]'s `inner` target,
+/// run over the shared [`crate::remote::SshPool`] rather than the local
+/// syscalls/filesystem `Target::exists` otherwise uses. `Network` and
+/// `Thread` targets have no cheap remote-safe check (probing a port from
+/// the operator box says nothing about the target host's own view of it,
+/// and thread liveness needs `/proc` access on the remote host), so those
+/// optimistically report as existing rather than risk an operator killing
+/// a scenario they can't actually verify one way or the other.
+fn remote_exists<'a>(
+    host: &'a str,
+    target: &'a Target,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + 'a>> {
+    Box::pin(async move {
+        let pool = crate::remote::default_pool();
+        match target {
+            Target::Process { pid } => pool
+                .run(host, "kill", &["-0", &pid.to_string()])
+                .await
+                .map(|output| output.status.success())
+                .unwrap_or(false),
+            Target::Container { id } => pool
+                .run(host, "test", &["-e", &format!("/sys/fs/cgroup/docker/{}", id)])
+                .await
+                .map(|output| output.status.success())
+                .unwrap_or(false),
+            Target::ProcessPattern { pattern } => pool
+                .run(host, "pgrep", &[pattern])
+                .await
+                .map(|output| output.status.success())
+                .unwrap_or(false),
+            Target::Group(members) => {
+                for member in members {
+                    if remote_exists(host, member).await {
+                        return true;
+                    }
+                }
+                false
             }
-            Target::Thread { tid: _ } => {
-                #[cfg(unix)]
+            Target::Remote { host: inner_host, inner } => remote_exists(inner_host, inner).await,
+            Target::Agent {
+                host: agent_host,
+                port,
+                inner,
+            } => {
+                #[cfg(feature = "agent")]
                 {
-                    // Thread validation would require checking /proc/<tid>
-                    true
+                    crate::agent::default_client().exists(agent_host, *port, inner).await
                 }
-                #[cfg(not(unix))]
+                #[cfg(not(feature = "agent"))]
                 {
-                    false
+                    let _ = (agent_host, port, inner);
+                    true
                 }
             }
-            Target::ProcessPattern { pattern } => {
-                use sysinfo::System;
-                let mut sys = System::new_all();
-                sys.refresh_processes();
-                sys.processes().values().any(|p| {
-                    p.name().contains(pattern)
-                })
-            }
+            Target::NetNamespace { inner, .. } => remote_exists(host, inner).await,
+            Target::Network { .. } | Target::Thread { .. } => true,
         }
-    }
+    })
 }
 
 #[cfg(test)]
@@ -122,4 +288,46 @@ mod tests {
         let target = Target::process(999999);
         assert!(!target.exists().await);
     }
+
+    #[tokio::test]
+    async fn test_group_exists_if_any_member_exists() {
+        let group = Target::group([Target::process(std::process::id()), Target::process(999999)]);
+        assert!(group.exists().await);
+
+        let all_missing = Target::group([Target::process(999998), Target::process(999999)]);
+        assert!(!all_missing.exists().await);
+    }
+
+    #[test]
+    fn test_group_description_lists_members() {
+        let group = Target::group([Target::process(1), Target::process(2)]);
+        assert_eq!(group.description(), "Group of 2 targets [Process PID 1, Process PID 2]");
+    }
+
+    #[test]
+    fn test_remote_description_names_host_and_inner() {
+        let target = Target::remote("db-1.internal", Target::process(1234));
+        assert_eq!(target.description(), "Process PID 1234 on remote host db-1.internal");
+    }
+
+    #[test]
+    fn test_net_namespace_description_names_path_and_inner() {
+        let target = Target::net_namespace("/var/run/netns/app", Target::network("127.0.0.1:8080".parse().unwrap()));
+        assert_eq!(target.description(), "Network 127.0.0.1:8080 in netns /var/run/netns/app");
+    }
+
+    #[tokio::test]
+    async fn test_net_namespace_missing_path_does_not_exist() {
+        let target = Target::net_namespace("/no/such/netns", Target::process(std::process::id()));
+        assert!(!target.exists().await);
+    }
+
+    #[test]
+    fn test_agent_description_names_host_port_and_inner() {
+        let target = Target::agent("db-1.internal", 9090, Target::process(1234));
+        assert_eq!(
+            target.description(),
+            "Process PID 1234 via chaos agent at db-1.internal:9090"
+        );
+    }
 }
@@ -1,161 +1,217 @@
-use crate::collector::{Metric, MetricType};
-use serde::{Deserialize, Serialize};
-use std::time::Duration;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AggregatedMetrics {
-    pub total_requests: usize,
-    pub successful_requests: usize,
-    pub failed_requests: usize,
-    pub error_rate: f64,
-    pub latency_p50: Duration,
-    pub latency_p95: Duration,
-    pub latency_p99: Duration,
-    pub latency_p999: Duration,
-    pub average_latency: Duration,
-    pub min_latency: Duration,
-    pub max_latency: Duration,
-    pub average_recovery_time: Duration,
-}
-
-pub struct MetricsAggregator;
-
-impl MetricsAggregator {
-    pub fn aggregate(metrics: &[Metric]) -> AggregatedMetrics {
-        let mut latencies: Vec<Duration> = Vec::new();
-        let mut recovery_times: Vec<Duration> = Vec::new();
-        let mut success_count = 0;
-        let mut error_count = 0;
-
-        for metric in metrics {
-            match &metric.metric_type {
-                MetricType::Latency(duration) => {
-                    latencies.push(*duration);
-                }
-                MetricType::Success => {
-                    success_count += 1;
-                }
-                MetricType::Error { .. } => {
-                    error_count += 1;
-                }
-                MetricType::Recovery { time } => {
-                    recovery_times.push(*time);
-                }
-                MetricType::Custom { .. } => {}
-            }
-        }
-
-        // Sort latencies for percentile calculation
-        latencies.sort();
-
-        let total_requests = success_count + error_count;
-        let error_rate = if total_requests > 0 {
-            error_count as f64 / total_requests as f64
-        } else {
-            0.0
-        };
-
-        let (p50, p95, p99, p999, avg, min, max) = if !latencies.is_empty() {
-            (
-                Self::percentile(&latencies, 0.50),
-                Self::percentile(&latencies, 0.95),
-                Self::percentile(&latencies, 0.99),
-                Self::percentile(&latencies, 0.999),
-                Self::average(&latencies),
-                *latencies.first().unwrap(),
-                *latencies.last().unwrap(),
-            )
-        } else {
-            (
-                Duration::ZERO,
-                Duration::ZERO,
-                Duration::ZERO,
-                Duration::ZERO,
-                Duration::ZERO,
-                Duration::ZERO,
-                Duration::ZERO,
-            )
-        };
-
-        let avg_recovery = if !recovery_times.is_empty() {
-            Self::average(&recovery_times)
-        } else {
-            Duration::ZERO
-        };
-
-        AggregatedMetrics {
-            total_requests,
-            successful_requests: success_count,
-            failed_requests: error_count,
-            error_rate,
-            latency_p50: p50,
-            latency_p95: p95,
-            latency_p99: p99,
-            latency_p999: p999,
-            average_latency: avg,
-            min_latency: min,
-            max_latency: max,
-            average_recovery_time: avg_recovery,
-        }
-    }
-
-    fn percentile(sorted: &[Duration], percentile: f64) -> Duration {
-        if sorted.is_empty() {
-            return Duration::ZERO;
-        }
-
-        let index = ((sorted.len() as f64) * percentile) as usize;
-        let index = index.min(sorted.len() - 1);
-        sorted[index]
-    }
-
-    fn average(durations: &[Duration]) -> Duration {
-        if durations.is_empty() {
-            return Duration::ZERO;
-        }
-
-        let sum: Duration = durations.iter().sum();
-        sum / durations.len() as u32
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::Utc;
-
-    #[test]
-    fn test_aggregation() {
-        let metrics = vec![
-            Metric {
-                metric_type: MetricType::Latency(Duration::from_millis(100)),
-                timestamp: Utc::now(),
-                labels: Default::default(),
-            },
-            Metric {
-                metric_type: MetricType::Latency(Duration::from_millis(200)),
-                timestamp: Utc::now(),
-                labels: Default::default(),
-            },
-            Metric {
-                metric_type: MetricType::Success,
-                timestamp: Utc::now(),
-                labels: Default::default(),
-            },
-            Metric {
-                metric_type: MetricType::Error {
-                    error_type: "timeout".to_string(),
-                },
-                timestamp: Utc::now(),
-                labels: Default::default(),
-            },
-        ];
-
-        let aggregated = MetricsAggregator::aggregate(&metrics);
-
-        assert_eq!(aggregated.total_requests, 2);
-        assert_eq!(aggregated.successful_requests, 1);
-        assert_eq!(aggregated.failed_requests, 1);
-        assert_eq!(aggregated.error_rate, 0.5);
-    }
-}
+use crate::collector::{Metric, MetricType};
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default significant-figure precision for the latency histogram - 3
+/// keeps sub-millisecond error under 0.1% while staying cheap to build,
+/// which is plenty for chaos-run latencies.
+const DEFAULT_SIGFIG: u8 = 3;
+
+/// Widest latency we'll track precisely, in nanoseconds. Anything beyond
+/// this (an hour) gets clamped rather than rejected - a chaos run stalling
+/// that long is already a finding, and the exact tail value doesn't matter.
+pub(crate) const MAX_LATENCY_NANOS: u64 = 3_600_000_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedMetrics {
+    pub total_requests: usize,
+    pub successful_requests: usize,
+    pub failed_requests: usize,
+    pub error_rate: f64,
+    pub latency_p50: Duration,
+    pub latency_p95: Duration,
+    pub latency_p99: Duration,
+    pub latency_p999: Duration,
+    pub average_latency: Duration,
+    pub min_latency: Duration,
+    pub max_latency: Duration,
+    pub average_recovery_time: Duration,
+}
+
+pub struct MetricsAggregator;
+
+impl MetricsAggregator {
+    /// Aggregates with the default histogram precision - see
+    /// [`Self::aggregate_with_precision`] for runs that need tighter or
+    /// looser percentile accuracy.
+    pub fn aggregate(metrics: &[Metric]) -> AggregatedMetrics {
+        Self::aggregate_with_precision(metrics, DEFAULT_SIGFIG)
+    }
+
+    /// Same as [`Self::aggregate`], but lets the caller trade histogram
+    /// memory for percentile accuracy (1-5 significant decimal digits).
+    /// A long soak test with millions of samples may want `sigfig = 2` to
+    /// keep the histogram small; a short, latency-sensitive run can afford
+    /// `sigfig = 5`.
+    pub fn aggregate_with_precision(metrics: &[Metric], sigfig: u8) -> AggregatedMetrics {
+        let mut latency_histogram = new_latency_histogram(sigfig);
+        let mut recovery_times: Vec<Duration> = Vec::new();
+        let mut success_count = 0;
+        let mut error_count = 0;
+
+        for metric in metrics {
+            match &metric.metric_type {
+                MetricType::Latency(duration) => record_latency_nanos(&mut latency_histogram, *duration),
+                MetricType::Success => {
+                    success_count += 1;
+                }
+                MetricType::Error { .. } => {
+                    error_count += 1;
+                }
+                MetricType::Recovery { time } => {
+                    recovery_times.push(*time);
+                }
+                MetricType::Custom { .. } => {}
+            }
+        }
+
+        let average_recovery_time = if !recovery_times.is_empty() {
+            Self::average(&recovery_times)
+        } else {
+            Duration::ZERO
+        };
+
+        summarize(&latency_histogram, success_count, error_count, average_recovery_time)
+    }
+
+    fn average(durations: &[Duration]) -> Duration {
+        if durations.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let sum: Duration = durations.iter().sum();
+        sum / durations.len() as u32
+    }
+}
+
+/// Builds an empty latency histogram at `sigfig` significant figures,
+/// bounded to [`MAX_LATENCY_NANOS`] - the one place both this module's
+/// ad-hoc aggregation and [`crate::MetricsCollector`]'s long-lived exact
+/// histogram get their bounds from, so widening the range only needs
+/// changing once.
+pub(crate) fn new_latency_histogram(sigfig: u8) -> Histogram<u64> {
+    Histogram::<u64>::new_with_bounds(1, MAX_LATENCY_NANOS, sigfig)
+        .expect("static latency bounds are always valid for hdrhistogram")
+}
+
+/// Clamps `duration` into the histogram's configured range and records
+/// it - values beyond an hour are rare enough (and already a finding on
+/// their own) that losing precision on them is an acceptable tradeoff.
+pub(crate) fn record_latency_nanos(histogram: &mut Histogram<u64>, duration: Duration) {
+    let nanos = (duration.as_nanos() as u64).clamp(1, MAX_LATENCY_NANOS);
+    histogram
+        .record(nanos)
+        .expect("value is clamped within the histogram's configured bounds");
+}
+
+/// Turns an exact histogram plus request counts into the same
+/// [`AggregatedMetrics`] shape `aggregate_with_precision` builds from a
+/// raw sample list - shared so [`crate::MetricsCollector::summary`]'s
+/// always-exact counters don't need their own copy of the percentile math.
+pub(crate) fn summarize(
+    latency_histogram: &Histogram<u64>,
+    successful_requests: usize,
+    failed_requests: usize,
+    average_recovery_time: Duration,
+) -> AggregatedMetrics {
+    let total_requests = successful_requests + failed_requests;
+    let error_rate = if total_requests > 0 {
+        failed_requests as f64 / total_requests as f64
+    } else {
+        0.0
+    };
+
+    let (latency_p50, latency_p95, latency_p99, latency_p999, average_latency, min_latency, max_latency) =
+        if !latency_histogram.is_empty() {
+            (
+                Duration::from_nanos(latency_histogram.value_at_percentile(50.0)),
+                Duration::from_nanos(latency_histogram.value_at_percentile(95.0)),
+                Duration::from_nanos(latency_histogram.value_at_percentile(99.0)),
+                Duration::from_nanos(latency_histogram.value_at_percentile(99.9)),
+                Duration::from_nanos(latency_histogram.mean() as u64),
+                Duration::from_nanos(latency_histogram.min()),
+                Duration::from_nanos(latency_histogram.max()),
+            )
+        } else {
+            (
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+            )
+        };
+
+    AggregatedMetrics {
+        total_requests,
+        successful_requests,
+        failed_requests,
+        error_rate,
+        latency_p50,
+        latency_p95,
+        latency_p99,
+        latency_p999,
+        average_latency,
+        min_latency,
+        max_latency,
+        average_recovery_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_aggregation() {
+        let metrics = vec![
+            Metric {
+                metric_type: MetricType::Latency(Duration::from_millis(100)),
+                timestamp: Utc::now(),
+                labels: Default::default(),
+            },
+            Metric {
+                metric_type: MetricType::Latency(Duration::from_millis(200)),
+                timestamp: Utc::now(),
+                labels: Default::default(),
+            },
+            Metric {
+                metric_type: MetricType::Success,
+                timestamp: Utc::now(),
+                labels: Default::default(),
+            },
+            Metric {
+                metric_type: MetricType::Error {
+                    error_type: "timeout".to_string(),
+                },
+                timestamp: Utc::now(),
+                labels: Default::default(),
+            },
+        ];
+
+        let aggregated = MetricsAggregator::aggregate(&metrics);
+
+        assert_eq!(aggregated.total_requests, 2);
+        assert_eq!(aggregated.successful_requests, 1);
+        assert_eq!(aggregated.failed_requests, 1);
+        assert_eq!(aggregated.error_rate, 0.5);
+    }
+
+    #[test]
+    fn test_aggregate_with_precision_matches_default() {
+        let metrics = vec![Metric {
+            metric_type: MetricType::Latency(Duration::from_millis(50)),
+            timestamp: Utc::now(),
+            labels: Default::default(),
+        }];
+
+        let default = MetricsAggregator::aggregate(&metrics);
+        let explicit = MetricsAggregator::aggregate_with_precision(&metrics, DEFAULT_SIGFIG);
+
+        assert_eq!(default.latency_p50, explicit.latency_p50);
+    }
+}
@@ -1,89 +1,274 @@
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::RwLock;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum MetricType {
-    Latency(Duration),
-    Error { error_type: String },
-    Success,
-    Recovery { time: Duration },
-    Custom { name: String, value: f64 },
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Metric {
-    pub metric_type: MetricType,
-    pub timestamp: DateTime<Utc>,
-    pub labels: std::collections::HashMap<String, String>,
-}
-
-pub struct MetricsCollector {
-    metrics: Arc<RwLock<Vec<Metric>>>,
-}
-
-impl MetricsCollector {
-    pub fn new() -> Self {
-        Self {
-            metrics: Arc::new(RwLock::new(Vec::new())),
-        }
-    }
-
-    pub async fn record(&self, metric: Metric) {
-        self.metrics.write().await.push(metric);
-    }
-
-    pub async fn record_latency(&self, latency: Duration) {
-        self.record(Metric {
-            metric_type: MetricType::Latency(latency),
-            timestamp: Utc::now(),
-            labels: std::collections::HashMap::new(),
-        })
-        .await;
-    }
-
-    pub async fn record_error(&self, error_type: impl Into<String>) {
-        self.record(Metric {
-            metric_type: MetricType::Error {
-                error_type: error_type.into(),
-            },
-            timestamp: Utc::now(),
-            labels: std::collections::HashMap::new(),
-        })
-        .await;
-    }
-
-    pub async fn record_success(&self) {
-        self.record(Metric {
-            metric_type: MetricType::Success,
-            timestamp: Utc::now(),
-            labels: std::collections::HashMap::new(),
-        })
-        .await;
-    }
-
-    pub async fn record_recovery(&self, time: Duration) {
-        self.record(Metric {
-            metric_type: MetricType::Recovery { time },
-            timestamp: Utc::now(),
-            labels: std::collections::HashMap::new(),
-        })
-        .await;
-    }
-
-    pub async fn get_metrics(&self) -> Vec<Metric> {
-        self.metrics.read().await.clone()
-    }
-
-    pub async fn clear(&self) {
-        self.metrics.write().await.clear();
-    }
-}
-
-impl Default for MetricsCollector {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+use crate::aggregator::{new_latency_histogram, record_latency_nanos, summarize, AggregatedMetrics};
+use chrono::{DateTime, Utc};
+use hdrhistogram::Histogram;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MetricType {
+    Latency(Duration),
+    Error { error_type: String },
+    Success,
+    Recovery { time: Duration },
+    Custom { name: String, value: f64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Metric {
+    pub metric_type: MetricType,
+    pub timestamp: DateTime<Utc>,
+    pub labels: std::collections::HashMap<String, String>,
+}
+
+/// Default reservoir size - generous enough that a typical run's timeline
+/// chart never notices it's sampled, small enough that a multi-hour soak
+/// test doesn't grow `MetricsCollector` without bound.
+const DEFAULT_SAMPLE_CAPACITY: usize = 10_000;
+
+/// Significant-figure precision for the collector's own long-lived
+/// histogram - matches `MetricsAggregator`'s default, since both are
+/// summarizing the same kind of latency data.
+const HISTOGRAM_SIGFIG: u8 = 3;
+
+struct Totals {
+    success_count: usize,
+    error_count: usize,
+    latency_histogram: Histogram<u64>,
+    recovery_count: usize,
+    recovery_sum_nanos: u128,
+}
+
+impl Totals {
+    fn new() -> Self {
+        Self {
+            success_count: 0,
+            error_count: 0,
+            latency_histogram: new_latency_histogram(HISTOGRAM_SIGFIG),
+            recovery_count: 0,
+            recovery_sum_nanos: 0,
+        }
+    }
+}
+
+struct State {
+    samples: VecDeque<Metric>,
+    sample_capacity: usize,
+    seen: usize,
+    totals: Totals,
+}
+
+impl State {
+    fn apply(&mut self, metric: Metric) {
+        match &metric.metric_type {
+            MetricType::Latency(duration) => record_latency_nanos(&mut self.totals.latency_histogram, *duration),
+            MetricType::Success => self.totals.success_count += 1,
+            MetricType::Error { .. } => self.totals.error_count += 1,
+            MetricType::Recovery { time } => {
+                self.totals.recovery_count += 1;
+                self.totals.recovery_sum_nanos += time.as_nanos();
+            }
+            MetricType::Custom { .. } => {}
+        }
+
+        self.reservoir_insert(metric);
+    }
+
+    /// Classic Algorithm R: the first `sample_capacity` samples are kept
+    /// outright, and each one after that replaces a uniformly-random
+    /// existing slot with probability `sample_capacity / seen` - so the
+    /// reservoir stays a representative sample of the whole run rather
+    /// than just its earliest slice.
+    fn reservoir_insert(&mut self, metric: Metric) {
+        if self.sample_capacity == 0 {
+            return;
+        }
+
+        self.seen += 1;
+        if self.samples.len() < self.sample_capacity {
+            self.samples.push_back(metric);
+            return;
+        }
+
+        let slot = rand::thread_rng().gen_range(0..self.seen);
+        if slot < self.sample_capacity {
+            self.samples[slot] = metric;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.samples.clear();
+        self.seen = 0;
+        self.totals = Totals::new();
+    }
+}
+
+/// A `record*` call and a `clear()` call, funneled through the same
+/// channel so the background aggregator applies them in the order
+/// callers issued them - a `clear` processed separately from the channel
+/// could otherwise race a still-in-flight `record` and silently "undo" it.
+enum Command {
+    Record(Metric),
+    Clear,
+}
+
+/// Collects metrics for the duration of a run. `record*` calls are
+/// wait-free: they push onto an unbounded channel to a single background
+/// task that owns the actual state, rather than taking a lock themselves.
+/// This matters once a load generator is recording hundreds of thousands
+/// of samples per second across many tasks - a shared `RwLock` taken on
+/// every sample becomes the bottleneck, while a channel send only ever
+/// contends with other senders, never with the one task doing the
+/// (comparatively rare) aggregation work.
+///
+/// Raw samples are kept in a bounded reservoir rather than an
+/// ever-growing `Vec`, so a multi-hour soak scenario doesn't exhaust
+/// memory - but counts, error rate, and latency percentiles stay exact
+/// regardless of `sample_capacity`, since they're tracked in running
+/// counters and an HDR histogram alongside the reservoir, not derived
+/// from it. [`Self::get_metrics`] returns the (possibly sampled) raw
+/// timeline; [`Self::summary`] returns the exact aggregate - both read a
+/// shared state snapshot, so they may lag slightly behind a `record*`
+/// call still sitting in the channel.
+pub struct MetricsCollector {
+    commands: mpsc::UnboundedSender<Command>,
+    state: Arc<RwLock<State>>,
+}
+
+impl MetricsCollector {
+    /// Must be called from within a Tokio runtime - it spawns the
+    /// background aggregator task that owns `State`.
+    pub fn new() -> Self {
+        Self::with_sample_capacity(DEFAULT_SAMPLE_CAPACITY)
+    }
+
+    /// Same as [`Self::new`], but with a caller-chosen reservoir size.
+    /// A long soak scenario that only cares about [`Self::summary`], not
+    /// the raw sample timeline, can shrink this well below the default.
+    pub fn with_sample_capacity(sample_capacity: usize) -> Self {
+        let state = Arc::new(RwLock::new(State {
+            samples: VecDeque::with_capacity(sample_capacity.min(1024)),
+            sample_capacity,
+            seen: 0,
+            totals: Totals::new(),
+        }));
+
+        let (commands, mut receiver) = mpsc::unbounded_channel::<Command>();
+        let worker_state = state.clone();
+        tokio::spawn(async move {
+            while let Some(command) = receiver.recv().await {
+                let mut state = worker_state.write().await;
+                match command {
+                    Command::Record(metric) => state.apply(metric),
+                    Command::Clear => state.reset(),
+                }
+            }
+        });
+
+        Self { commands, state }
+    }
+
+    pub async fn record(&self, metric: Metric) {
+        // A send only fails once the background task has shut down,
+        // which only happens once every `MetricsCollector` handle (and
+        // thus every sender) has already been dropped - nothing would be
+        // left to record for anyway.
+        let _ = self.commands.send(Command::Record(metric));
+    }
+
+    pub async fn record_latency(&self, latency: Duration) {
+        self.record(Metric {
+            metric_type: MetricType::Latency(latency),
+            timestamp: Utc::now(),
+            labels: std::collections::HashMap::new(),
+        })
+        .await;
+    }
+
+    pub async fn record_error(&self, error_type: impl Into<String>) {
+        self.record(Metric {
+            metric_type: MetricType::Error {
+                error_type: error_type.into(),
+            },
+            timestamp: Utc::now(),
+            labels: std::collections::HashMap::new(),
+        })
+        .await;
+    }
+
+    pub async fn record_success(&self) {
+        self.record(Metric {
+            metric_type: MetricType::Success,
+            timestamp: Utc::now(),
+            labels: std::collections::HashMap::new(),
+        })
+        .await;
+    }
+
+    pub async fn record_recovery(&self, time: Duration) {
+        self.record(Metric {
+            metric_type: MetricType::Recovery { time },
+            timestamp: Utc::now(),
+            labels: std::collections::HashMap::new(),
+        })
+        .await;
+    }
+
+    /// Records a driver- or injector-defined measurement that doesn't fit
+    /// the built-in variants - e.g. a workload driver's message gap or
+    /// staleness reading.
+    pub async fn record_custom(&self, name: impl Into<String>, value: f64) {
+        self.record(Metric {
+            metric_type: MetricType::Custom {
+                name: name.into(),
+                value,
+            },
+            timestamp: Utc::now(),
+            labels: std::collections::HashMap::new(),
+        })
+        .await;
+    }
+
+    /// The reservoir-sampled raw metrics recorded so far - representative
+    /// of the whole run, but not exhaustive once more than
+    /// `sample_capacity` metrics have been recorded. Good enough for
+    /// timeline charts; use [`Self::summary`] for exact totals and
+    /// percentiles.
+    pub async fn get_metrics(&self) -> Vec<Metric> {
+        self.state.read().await.samples.iter().cloned().collect()
+    }
+
+    /// Exact summary built from running counters and the long-lived
+    /// latency histogram. Unlike
+    /// `MetricsAggregator::aggregate(&collector.get_metrics().await)`,
+    /// this isn't affected by reservoir eviction, so it's the right
+    /// choice for anything reporting a run's actual totals.
+    pub async fn summary(&self) -> AggregatedMetrics {
+        let state = self.state.read().await;
+        let average_recovery_time = if state.totals.recovery_count > 0 {
+            Duration::from_nanos((state.totals.recovery_sum_nanos / state.totals.recovery_count as u128) as u64)
+        } else {
+            Duration::ZERO
+        };
+
+        summarize(
+            &state.totals.latency_histogram,
+            state.totals.success_count,
+            state.totals.error_count,
+            average_recovery_time,
+        )
+    }
+
+    pub async fn clear(&self) {
+        let _ = self.commands.send(Command::Clear);
+    }
+}
+
+impl Default for MetricsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
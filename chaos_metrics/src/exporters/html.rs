@@ -0,0 +1,194 @@
+use crate::aggregator::MetricsAggregator;
+use crate::collector::Metric;
+use anyhow::Result;
+use chaos_scenarios::runner::PhaseResult;
+use std::path::Path;
+
+/// One second's worth of samples, aggregated down to the handful of
+/// figures the timeline chart actually plots - the same shape
+/// `MetricsAggregator::aggregate` produces for the whole run, just
+/// computed per-bucket instead of once.
+struct Bucket {
+    offset_secs: i64,
+    latency_p50_ms: f64,
+    latency_p95_ms: f64,
+    error_rate: f64,
+}
+
+pub struct HtmlExporter;
+
+impl HtmlExporter {
+    pub async fn export(metrics: &[Metric], phases: &[PhaseResult], path: impl AsRef<Path>) -> Result<()> {
+        let html = Self::format(metrics, phases);
+        tokio::fs::write(path, html).await?;
+        Ok(())
+    }
+
+    pub fn format(metrics: &[Metric], phases: &[PhaseResult]) -> String {
+        let summary = MetricsAggregator::aggregate(metrics);
+        let buckets = Self::bucket_by_second(metrics);
+        let phase_boundaries = Self::phase_boundaries(phases);
+        let chart = Self::render_chart(&buckets, &phase_boundaries);
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Chaos Engineering Test Report</title>
+<style>
+  body {{ font-family: system-ui, sans-serif; margin: 2rem; color: #222; }}
+  h1 {{ font-size: 1.5rem; }}
+  h2 {{ font-size: 1.1rem; margin-top: 2rem; }}
+  table {{ border-collapse: collapse; }}
+  th, td {{ text-align: left; padding: 0.3rem 0.8rem; border-bottom: 1px solid #ddd; }}
+  .chart {{ border: 1px solid #ddd; }}
+  .phase-label {{ font-size: 10px; fill: #666; }}
+</style>
+</head>
+<body>
+  <h1>Chaos Engineering Test Report</h1>
+
+  <h2>Summary</h2>
+  <table>
+    <tr><th>Total Requests</th><td>{total_requests}</td></tr>
+    <tr><th>Failed Requests</th><td>{failed_requests}</td></tr>
+    <tr><th>Error Rate</th><td>{error_rate_pct:.2}%</td></tr>
+    <tr><th>P50 Latency</th><td>{p50:?}</td></tr>
+    <tr><th>P95 Latency</th><td>{p95:?}</td></tr>
+    <tr><th>P99 Latency</th><td>{p99:?}</td></tr>
+  </table>
+
+  <h2>Latency &amp; Error Rate Timeline</h2>
+  {chart}
+</body>
+</html>
+"#,
+            total_requests = summary.total_requests,
+            failed_requests = summary.failed_requests,
+            error_rate_pct = summary.error_rate * 100.0,
+            p50 = summary.latency_p50,
+            p95 = summary.latency_p95,
+            p99 = summary.latency_p99,
+        )
+    }
+
+    /// Groups samples by the whole second they landed in, so the timeline
+    /// has one point per second regardless of how bursty injection/removal
+    /// traffic was within it.
+    fn bucket_by_second(metrics: &[Metric]) -> Vec<Bucket> {
+        if metrics.is_empty() {
+            return Vec::new();
+        }
+
+        let start = metrics.iter().map(|m| m.timestamp).min().unwrap();
+        let mut by_second: std::collections::BTreeMap<i64, Vec<&Metric>> = std::collections::BTreeMap::new();
+        for metric in metrics {
+            let offset = (metric.timestamp - start).num_seconds();
+            by_second.entry(offset).or_default().push(metric);
+        }
+
+        by_second
+            .into_iter()
+            .map(|(offset_secs, bucket_metrics)| {
+                let owned: Vec<Metric> = bucket_metrics.into_iter().cloned().collect();
+                let aggregated = MetricsAggregator::aggregate(&owned);
+                Bucket {
+                    offset_secs,
+                    latency_p50_ms: aggregated.latency_p50.as_secs_f64() * 1000.0,
+                    latency_p95_ms: aggregated.latency_p95.as_secs_f64() * 1000.0,
+                    error_rate: aggregated.error_rate,
+                }
+            })
+            .collect()
+    }
+
+    /// Cumulative start offset of each phase, derived from phase durations
+    /// in order - `PhaseResult` doesn't carry its own start time, since
+    /// phases run back-to-back with no gap between them.
+    fn phase_boundaries(phases: &[PhaseResult]) -> Vec<(String, f64)> {
+        let mut offset = 0.0;
+        phases
+            .iter()
+            .map(|phase| {
+                let start = offset;
+                offset += phase.duration.as_secs_f64();
+                (phase.name.clone(), start)
+            })
+            .collect()
+    }
+
+    /// Hand-rolled SVG line chart - latency percentiles on the left axis,
+    /// error rate as a shaded area, phase boundaries as dashed vertical
+    /// lines labelled with the phase name. No JS charting library is a
+    /// workspace dependency, and a report this size doesn't need one.
+    fn render_chart(buckets: &[Bucket], phase_boundaries: &[(String, f64)]) -> String {
+        if buckets.is_empty() {
+            return "<p>No samples were recorded during this run.</p>".to_string();
+        }
+
+        const WIDTH: f64 = 800.0;
+        const HEIGHT: f64 = 300.0;
+        const PADDING: f64 = 40.0;
+
+        let max_offset = buckets.last().map(|b| b.offset_secs as f64).unwrap_or(1.0).max(1.0);
+        let max_latency = buckets
+            .iter()
+            .fold(0.0_f64, |acc, b| acc.max(b.latency_p50_ms).max(b.latency_p95_ms))
+            .max(1.0);
+
+        let x_of = |offset_secs: f64| PADDING + (offset_secs / max_offset) * (WIDTH - 2.0 * PADDING);
+        let y_of_latency = |ms: f64| HEIGHT - PADDING - (ms / max_latency) * (HEIGHT - 2.0 * PADDING);
+        let y_of_error_rate = |rate: f64| HEIGHT - PADDING - rate * (HEIGHT - 2.0 * PADDING);
+
+        let p50_points: String = buckets
+            .iter()
+            .map(|b| format!("{:.1},{:.1}", x_of(b.offset_secs as f64), y_of_latency(b.latency_p50_ms)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let p95_points: String = buckets
+            .iter()
+            .map(|b| format!("{:.1},{:.1}", x_of(b.offset_secs as f64), y_of_latency(b.latency_p95_ms)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let error_points: String = buckets
+            .iter()
+            .map(|b| format!("{:.1},{:.1}", x_of(b.offset_secs as f64), y_of_error_rate(b.error_rate)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let phase_markers: String = phase_boundaries
+            .iter()
+            .map(|(name, start_secs)| {
+                let x = x_of(*start_secs);
+                format!(
+                    r##"<line x1="{x:.1}" y1="{pad}" x2="{x:.1}" y2="{bottom:.1}" stroke="#999" stroke-dasharray="4,3" />
+<text x="{label_x:.1}" y="{label_y}" class="phase-label">{name}</text>"##,
+                    x = x,
+                    pad = PADDING,
+                    bottom = HEIGHT - PADDING,
+                    label_x = x + 2.0,
+                    label_y = PADDING - 4.0,
+                    name = name,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r##"<svg class="chart" width="{width}" height="{height}" viewBox="0 0 {width} {height}">
+  <rect x="0" y="0" width="{width}" height="{height}" fill="white" />
+  {phase_markers}
+  <polyline points="{error_points}" fill="none" stroke="#f59e0b" stroke-width="1.5" />
+  <polyline points="{p95_points}" fill="none" stroke="#dc2626" stroke-width="1.5" />
+  <polyline points="{p50_points}" fill="none" stroke="#2563eb" stroke-width="1.5" />
+  <text x="{pad}" y="14" font-size="11" fill="#2563eb">P50 latency (ms)</text>
+  <text x="{pad}" y="28" font-size="11" fill="#dc2626">P95 latency (ms)</text>
+  <text x="{pad}" y="42" font-size="11" fill="#f59e0b">Error rate</text>
+</svg>"##,
+            width = WIDTH,
+            height = HEIGHT,
+            pad = PADDING,
+        )
+    }
+}
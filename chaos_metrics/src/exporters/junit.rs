@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chaos_scenarios::runner::ScenarioResult;
+use std::path::Path;
+
+/// Renders a [`ScenarioResult`] as a JUnit XML report - one `<testcase>`
+/// per phase, plus synthetic testcases for injection failures and an
+/// early abort. This is the format Jenkins and GitLab already parse into
+/// a pass/fail summary, so a chaos run shows up as a test result instead
+/// of only in its own logs.
+pub struct JunitExporter;
+
+impl JunitExporter {
+    pub async fn export(result: &ScenarioResult, path: impl AsRef<Path>) -> Result<()> {
+        let xml = Self::format(result);
+        tokio::fs::write(path, xml).await?;
+        Ok(())
+    }
+
+    pub fn format(result: &ScenarioResult) -> String {
+        let classname = escape(&result.scenario_name);
+
+        let mut testcases = String::new();
+        for phase in &result.phase_results {
+            testcases.push_str(&format!(
+                "    <testcase classname=\"{classname}\" name=\"{name}\" time=\"{time:.3}\"/>\n",
+                classname = classname,
+                name = escape(&phase.name),
+                time = phase.duration.as_secs_f64(),
+            ));
+        }
+
+        if result.failed_injections > 0 {
+            testcases.push_str(&format!(
+                "    <testcase classname=\"{classname}\" name=\"injections\" time=\"0\">\n      <failure message=\"{count} injection(s) failed to apply\"/>\n    </testcase>\n",
+                classname = classname,
+                count = result.failed_injections,
+            ));
+        }
+
+        if let Some(reason) = &result.aborted_reason {
+            testcases.push_str(&format!(
+                "    <testcase classname=\"{classname}\" name=\"completion\" time=\"0\">\n      <failure message=\"scenario aborted before all phases ran\">{reason}</failure>\n    </testcase>\n",
+                classname = classname,
+                reason = escape(reason),
+            ));
+        }
+
+        let tests = result.phase_results.len()
+            + usize::from(result.failed_injections > 0)
+            + usize::from(result.aborted_reason.is_some());
+        let failures =
+            usize::from(result.failed_injections > 0) + usize::from(result.aborted_reason.is_some());
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"{classname}\" tests=\"{tests}\" failures=\"{failures}\" time=\"{time:.3}\">\n{testcases}</testsuite>\n",
+            classname = classname,
+            tests = tests,
+            failures = failures,
+            time = result.total_duration.as_secs_f64(),
+            testcases = testcases,
+        )
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
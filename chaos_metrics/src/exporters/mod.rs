@@ -1,7 +1,15 @@
+pub mod html;
 pub mod json;
+pub mod junit;
 pub mod prometheus;
 pub mod markdown;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 
+pub use html::HtmlExporter;
 pub use json::JsonExporter;
+pub use junit::JunitExporter;
 pub use prometheus::PrometheusExporter;
 pub use markdown::MarkdownExporter;
+#[cfg(feature = "otlp")]
+pub use otlp::{OtlpConfig, OtlpExporter};
@@ -0,0 +1,169 @@
+//! Pushes a chaos run's metrics and per-injection spans to an OTLP
+//! collector over HTTP, so a run shows up in the same tracing backend as
+//! the service it's testing instead of only in `chaos`'s own reports.
+//! Gated behind the `otlp` feature - most builds never talk to a tracing
+//! backend and shouldn't pay for the OTel SDK.
+use crate::aggregator::AggregatedMetrics;
+use anyhow::{Context, Result};
+use chaos_core::InjectionHandle;
+use opentelemetry::metrics::MeterProvider as _;
+use opentelemetry::trace::{Span, Tracer, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::trace::{SdkTracerProvider, Span as SdkSpan, Tracer as SdkTracer};
+use opentelemetry_sdk::Resource;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Where to push OTLP telemetry for a run, and what to label it with so it
+/// shows up alongside the rest of this service's telemetry rather than as
+/// an unrelated stream.
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    pub endpoint: String,
+    pub service_name: String,
+}
+
+impl OtlpConfig {
+    pub fn new(endpoint: impl Into<String>, service_name: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            service_name: service_name.into(),
+        }
+    }
+}
+
+/// Spans are opened on `InjectionApplied` and closed on `InjectionRemoved`/
+/// `CleanupFailed`, so they're held here keyed by injection ID between the
+/// two events rather than built in one shot like the other exporters'
+/// single-pass `format`/`export`.
+pub struct OtlpExporter {
+    tracer_provider: SdkTracerProvider,
+    meter_provider: SdkMeterProvider,
+    tracer: SdkTracer,
+    spans: Mutex<HashMap<String, SdkSpan>>,
+}
+
+impl OtlpExporter {
+    pub fn new(config: OtlpConfig) -> Result<Self> {
+        let resource = Resource::builder()
+            .with_service_name(config.service_name)
+            .build();
+
+        let span_exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/traces", config.endpoint))
+            .build()
+            .context("failed to build OTLP span exporter")?;
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_resource(resource.clone())
+            .with_batch_exporter(span_exporter)
+            .build();
+        let tracer = tracer_provider.tracer("chaos_metrics");
+
+        let metric_exporter = MetricExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/metrics", config.endpoint))
+            .build()
+            .context("failed to build OTLP metric exporter")?;
+        let meter_provider = SdkMeterProvider::builder()
+            .with_resource(resource)
+            .with_periodic_exporter(metric_exporter)
+            .build();
+
+        Ok(Self {
+            tracer_provider,
+            meter_provider,
+            tracer,
+            spans: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Opens a span for a newly-applied injection, named after the
+    /// injector so spans for different fault types are easy to tell apart
+    /// in the backend's trace view.
+    pub fn record_injection_applied(&self, handle: &InjectionHandle) {
+        let mut span = self
+            .tracer
+            .start(format!("chaos.injection.{}", handle.injector_name));
+        span.set_attribute(KeyValue::new("chaos.injection.id", handle.id.clone()));
+        span.set_attribute(KeyValue::new("chaos.target", handle.target.description()));
+        span.add_event("applied", vec![]);
+        self.spans.lock().unwrap().insert(handle.id.clone(), span);
+    }
+
+    /// Closes the span opened by `record_injection_applied` for `handle`,
+    /// if one is still open - a failed or duplicate removal that never had
+    /// a matching apply event is a no-op rather than an error here.
+    pub fn record_injection_removed(&self, handle: &InjectionHandle) {
+        if let Some(mut span) = self.spans.lock().unwrap().remove(&handle.id) {
+            span.add_event("removed", vec![]);
+            span.end();
+        }
+    }
+
+    /// Records a cleanup failure as a span event rather than dropping the
+    /// span silently, then closes it - there's nothing further to wait for
+    /// once the executor has given up on tearing it down.
+    pub fn record_cleanup_failed(&self, handle: &InjectionHandle, error: &str) {
+        if let Some(mut span) = self.spans.lock().unwrap().remove(&handle.id) {
+            span.add_event(
+                "cleanup_failed",
+                vec![KeyValue::new("error", error.to_string())],
+            );
+            span.end();
+        }
+    }
+
+    /// Pushes a run's aggregated metrics as a one-shot gauge snapshot.
+    /// Re-recording the same gauges on every call (rather than accumulating
+    /// counters) matches `AggregatedMetrics` itself being a full
+    /// re-aggregation, not an incremental delta.
+    pub fn export_metrics(&self, metrics: &AggregatedMetrics) {
+        let meter = self.meter_provider.meter("chaos_metrics");
+
+        meter
+            .u64_gauge("chaos_total_requests")
+            .build()
+            .record(metrics.total_requests as u64, &[]);
+        meter
+            .u64_gauge("chaos_failed_requests")
+            .build()
+            .record(metrics.failed_requests as u64, &[]);
+        meter
+            .f64_gauge("chaos_error_rate")
+            .build()
+            .record(metrics.error_rate, &[]);
+        meter
+            .f64_gauge("chaos_latency_p50")
+            .build()
+            .record(metrics.latency_p50.as_secs_f64(), &[]);
+        meter
+            .f64_gauge("chaos_latency_p95")
+            .build()
+            .record(metrics.latency_p95.as_secs_f64(), &[]);
+        meter
+            .f64_gauge("chaos_latency_p99")
+            .build()
+            .record(metrics.latency_p99.as_secs_f64(), &[]);
+        meter
+            .f64_gauge("chaos_avg_latency")
+            .build()
+            .record(metrics.average_latency.as_secs_f64(), &[]);
+    }
+
+    /// Flushes buffered spans and metrics before the process exits - the
+    /// batch span processor and periodic metric reader otherwise only
+    /// flush on their own timers, which a short `chaos run` may not live
+    /// long enough to hit.
+    pub fn shutdown(&self) -> Result<()> {
+        self.tracer_provider
+            .shutdown()
+            .context("failed to shut down OTLP tracer provider")?;
+        self.meter_provider
+            .shutdown()
+            .context("failed to shut down OTLP meter provider")?;
+        Ok(())
+    }
+}
@@ -1,4 +1,16 @@
 use crate::aggregator::AggregatedMetrics;
+use crate::collector::MetricsCollector;
+use crate::streaming::{StreamingAggregator, Window};
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
 
 pub struct PrometheusExporter;
 
@@ -42,4 +54,105 @@ chaos_avg_latency {}
             metrics.average_latency.as_secs_f64(),
         )
     }
+
+    /// Renders each `(window, metrics)` pair as Prometheus gauges labelled
+    /// by window size, so a scrape can chart "last 1s" alongside "last 1m"
+    /// without the scraper having to run its own rate() over the run-total
+    /// counters above.
+    fn format_windowed(windows: &[(Window, AggregatedMetrics)]) -> String {
+        if windows.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        out.push_str(
+            "\n# HELP chaos_windowed_error_rate Error rate over a rolling window\n\
+             # TYPE chaos_windowed_error_rate gauge\n",
+        );
+        for (window, metrics) in windows {
+            out.push_str(&format!(
+                "chaos_windowed_error_rate{{window=\"{}\"}} {}\n",
+                window.label(),
+                metrics.error_rate
+            ));
+        }
+
+        out.push_str(
+            "\n# HELP chaos_windowed_latency_p50 50th percentile latency in seconds over a rolling window\n\
+             # TYPE chaos_windowed_latency_p50 gauge\n",
+        );
+        for (window, metrics) in windows {
+            out.push_str(&format!(
+                "chaos_windowed_latency_p50{{window=\"{}\"}} {}\n",
+                window.label(),
+                metrics.latency_p50.as_secs_f64()
+            ));
+        }
+
+        out.push_str(
+            "\n# HELP chaos_windowed_latency_p99 99th percentile latency in seconds over a rolling window\n\
+             # TYPE chaos_windowed_latency_p99 gauge\n",
+        );
+        for (window, metrics) in windows {
+            out.push_str(&format!(
+                "chaos_windowed_latency_p99{{window=\"{}\"}} {}\n",
+                window.label(),
+                metrics.latency_p99.as_secs_f64()
+            ));
+        }
+
+        out
+    }
+}
+
+#[derive(Clone)]
+struct PrometheusState {
+    collector: Arc<MetricsCollector>,
+    streaming: Option<Arc<StreamingAggregator>>,
+}
+
+/// Serves a Prometheus-scrapeable `GET /metrics` over `collector`'s running
+/// totals until `cancel` fires - the same graceful-shutdown shape as
+/// `chaos_core::agent::serve_with_cancellation`, so `chaos run
+/// --prometheus-port` can be stopped by the same Ctrl-C/SIGTERM handling as
+/// everything else that process manages.
+///
+/// Metrics are re-aggregated from `collector`'s full history on every
+/// scrape rather than cached, so there's no separate refresh interval to
+/// configure or go stale. When `streaming` is set, the scrape also
+/// includes windowed gauges from its rolling 1s/10s/1m buffers.
+pub async fn serve(
+    collector: Arc<MetricsCollector>,
+    streaming: Option<Arc<StreamingAggregator>>,
+    bind_addr: SocketAddr,
+    cancel: CancellationToken,
+) -> Result<()> {
+    let app = Router::new()
+        .route("/metrics", get(handle_metrics))
+        .with_state(PrometheusState { collector, streaming });
+
+    info!("Prometheus metrics listening on {}", bind_addr);
+    let listener = tokio::net::TcpListener::bind(bind_addr)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to bind Prometheus exporter on {}: {}", bind_addr, e))?;
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move { cancel.cancelled().await })
+        .await
+        .map_err(|e| anyhow::anyhow!("Prometheus exporter server error: {}", e))
+}
+
+async fn handle_metrics(State(state): State<PrometheusState>) -> impl IntoResponse {
+    let aggregated = state.collector.summary().await;
+    let mut body = PrometheusExporter::format(&aggregated);
+
+    if let Some(streaming) = &state.streaming {
+        let mut windows = Vec::with_capacity(Window::ALL.len());
+        for window in Window::ALL {
+            windows.push((window, streaming.aggregate(window).await));
+        }
+        body.push_str(&PrometheusExporter::format_windowed(&windows));
+    }
+
+    ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body)
 }
@@ -0,0 +1,450 @@
+use chaos_scenarios::runner::ScenarioResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Retention policy for the on-disk run history store: raw per-run results
+/// are kept for `raw_days`, after which they are compacted into a single
+/// per-scenario aggregate and the raw files are deleted. Aggregates
+/// themselves are pruned once older than `aggregate_months`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub raw_days: i64,
+    pub aggregate_months: i64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            raw_days: 30,
+            aggregate_months: 12,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub recorded_at: DateTime<Utc>,
+    pub result: ScenarioResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedHistoryEntry {
+    pub scenario_name: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub run_count: usize,
+    pub average_success_rate: f64,
+}
+
+/// A directory-backed store of past scenario runs, used for trend analysis
+/// and regression detection across hosts and time.
+pub struct HistoryStore {
+    root: PathBuf,
+    policy: RetentionPolicy,
+}
+
+impl HistoryStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            policy: RetentionPolicy::default(),
+        }
+    }
+
+    pub fn with_policy(root: impl Into<PathBuf>, policy: RetentionPolicy) -> Self {
+        Self {
+            root: root.into(),
+            policy,
+        }
+    }
+
+    pub async fn record(&self, result: &ScenarioResult) -> anyhow::Result<PathBuf> {
+        tokio::fs::create_dir_all(&self.root).await?;
+
+        let entry = HistoryEntry {
+            recorded_at: Utc::now(),
+            result: result.clone(),
+        };
+
+        let file_name = format!(
+            "{}_{}.json",
+            sanitize(&result.scenario_name),
+            uuid::Uuid::new_v4()
+        );
+        let path = self.root.join(file_name);
+
+        let json = serde_json::to_string_pretty(&entry)?;
+        tokio::fs::write(&path, json).await?;
+
+        Ok(path)
+    }
+
+    pub async fn load_all(&self) -> anyhow::Result<Vec<HistoryEntry>> {
+        load_entries(&self.root).await
+    }
+
+    pub async fn load_for_scenario(&self, scenario_name: &str) -> anyhow::Result<Vec<HistoryEntry>> {
+        let mut entries = self.load_all().await?;
+        entries.retain(|e| e.result.scenario_name == scenario_name);
+        Ok(entries)
+    }
+
+    /// Finds the most recently recorded baseline run of `scenario_name`, if
+    /// any - i.e. the latest stored entry whose scenario was built via
+    /// `Scenario::baseline` (or otherwise labeled `chaos.baseline`). This is
+    /// what `--baseline` comparisons read from, so recording a baseline is
+    /// just running that scenario through [`HistoryStore::record`] like any
+    /// other run.
+    pub async fn load_baseline(&self, scenario_name: &str) -> anyhow::Result<Option<HistoryEntry>> {
+        let mut entries = self.load_for_scenario(scenario_name).await?;
+        entries.retain(|e| e.result.is_baseline());
+        entries.sort_by_key(|e| e.recorded_at);
+        Ok(entries.pop())
+    }
+
+    /// Build a chronological series of `metric`'s values across all stored
+    /// runs of `scenario_name`, oldest first.
+    pub async fn trend(&self, scenario_name: &str, metric: &str) -> anyhow::Result<TrendSeries> {
+        let metric = TrendMetric::parse(metric)?;
+
+        let mut entries = self.load_for_scenario(scenario_name).await?;
+        entries.sort_by_key(|e| e.recorded_at);
+
+        let points = entries
+            .iter()
+            .map(|e| TrendPoint {
+                recorded_at: e.recorded_at,
+                value: metric.extract(&e.result),
+            })
+            .collect();
+
+        Ok(TrendSeries {
+            scenario_name: scenario_name.to_string(),
+            metric: format!("{metric:?}"),
+            points,
+        })
+    }
+
+    /// Roll raw entries older than `raw_days` up into per-scenario aggregates,
+    /// then delete the raw files that were rolled up. Aggregates older than
+    /// `aggregate_months` are discarded entirely.
+    pub async fn compact(&self) -> anyhow::Result<CompactionSummary> {
+        let now = Utc::now();
+        let raw_cutoff = now - chrono::Duration::days(self.policy.raw_days);
+        let aggregate_cutoff = now - chrono::Duration::days(self.policy.aggregate_months * 30);
+
+        let mut stale_paths = Vec::new();
+        for (path, entry) in entries_with_paths(&self.root).await? {
+            if entry.recorded_at < raw_cutoff {
+                stale_paths.push((path, entry));
+            }
+        }
+
+        let aggregates_dir = self.root.join("aggregates");
+        tokio::fs::create_dir_all(&aggregates_dir).await?;
+
+        let mut aggregated_scenarios = 0;
+        let mut grouped: std::collections::HashMap<String, Vec<&HistoryEntry>> =
+            std::collections::HashMap::new();
+        for (_, entry) in &stale_paths {
+            grouped.entry(entry.result.scenario_name.clone()).or_default().push(entry);
+        }
+
+        for (scenario_name, group) in grouped {
+            if group.is_empty() {
+                continue;
+            }
+            let run_count = group.len();
+            let average_success_rate =
+                group.iter().map(|e| e.result.success_rate()).sum::<f64>() / run_count as f64;
+            let period_start = group.iter().map(|e| e.recorded_at).min().unwrap();
+            let period_end = group.iter().map(|e| e.recorded_at).max().unwrap();
+
+            let aggregate = AggregatedHistoryEntry {
+                scenario_name: scenario_name.clone(),
+                period_start,
+                period_end,
+                run_count,
+                average_success_rate,
+            };
+
+            let aggregate_path = aggregates_dir.join(format!(
+                "{}_{}.json",
+                sanitize(&scenario_name),
+                uuid::Uuid::new_v4()
+            ));
+            tokio::fs::write(&aggregate_path, serde_json::to_string_pretty(&aggregate)?).await?;
+            aggregated_scenarios += 1;
+        }
+
+        let raw_removed = stale_paths.len();
+        for (path, _) in stale_paths {
+            tokio::fs::remove_file(path).await.ok();
+        }
+
+        // Prune aggregates older than the aggregate retention window.
+        let mut aggregates_removed = 0;
+        let mut dir = tokio::fs::read_dir(&aggregates_dir).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Ok(contents) = tokio::fs::read_to_string(&path).await {
+                if let Ok(aggregate) = serde_json::from_str::<AggregatedHistoryEntry>(&contents) {
+                    if aggregate.period_end < aggregate_cutoff {
+                        tokio::fs::remove_file(&path).await.ok();
+                        aggregates_removed += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(CompactionSummary {
+            raw_entries_compacted: raw_removed,
+            scenarios_aggregated: aggregated_scenarios,
+            aggregates_pruned: aggregates_removed,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompactionSummary {
+    pub raw_entries_compacted: usize,
+    pub scenarios_aggregated: usize,
+    pub aggregates_pruned: usize,
+}
+
+/// A metric that can be tracked across stored runs of the same scenario.
+///
+/// `ScenarioResult` does not yet carry per-run latency percentiles, so the
+/// metrics exposed here are derived from the fields it does carry. `p50`,
+/// `p95`, `p99` and similar latency percentiles aren't available until
+/// scenario runs start recording `AggregatedMetrics` alongside their result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendMetric {
+    SuccessRate,
+    TotalDurationSecs,
+    TotalInjections,
+    AvgPhaseDurationSecs,
+}
+
+impl TrendMetric {
+    pub fn parse(name: &str) -> anyhow::Result<Self> {
+        match name {
+            "success_rate" => Ok(Self::SuccessRate),
+            "total_duration" | "duration" => Ok(Self::TotalDurationSecs),
+            "total_injections" | "injections" => Ok(Self::TotalInjections),
+            "avg_phase_duration" => Ok(Self::AvgPhaseDurationSecs),
+            other => Err(anyhow::anyhow!(
+                "unknown metric '{other}' (expected one of: success_rate, total_duration, total_injections, avg_phase_duration)"
+            )),
+        }
+    }
+
+    fn extract(&self, result: &ScenarioResult) -> f64 {
+        match self {
+            Self::SuccessRate => result.success_rate(),
+            Self::TotalDurationSecs => result.total_duration.as_secs_f64(),
+            Self::TotalInjections => result.total_injections as f64,
+            Self::AvgPhaseDurationSecs => result.average_phase_duration().as_secs_f64(),
+        }
+    }
+
+    /// Compares `current` against a single `baseline` run for this metric,
+    /// reusing [`TrendSeries::detect_regression`]'s window/threshold logic
+    /// with a window of 1 - the baseline run stands in for a one-point
+    /// trailing history instead of a stored series. Returns `None` if the
+    /// two runs don't diverge by more than `threshold`.
+    pub fn compare(&self, baseline: &ScenarioResult, current: &ScenarioResult, threshold: f64) -> Option<RegressionAlert> {
+        let series = TrendSeries {
+            scenario_name: current.scenario_name.clone(),
+            metric: format!("{self:?}"),
+            points: vec![
+                TrendPoint {
+                    recorded_at: Utc::now(),
+                    value: self.extract(baseline),
+                },
+                TrendPoint {
+                    recorded_at: Utc::now(),
+                    value: self.extract(current),
+                },
+            ],
+        };
+        series.detect_regression(1, threshold)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendPoint {
+    pub recorded_at: DateTime<Utc>,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendSeries {
+    pub scenario_name: String,
+    pub metric: String,
+    pub points: Vec<TrendPoint>,
+}
+
+/// Raised by [`TrendSeries::detect_regression`] when the latest run drifts
+/// from the trailing baseline by more than the configured threshold.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RegressionAlert {
+    pub baseline: f64,
+    pub latest: f64,
+    /// Fractional deviation of `latest` from `baseline` (e.g. 0.2 == 20%).
+    pub deviation: f64,
+}
+
+impl TrendSeries {
+    /// Flags a regression when the latest point deviates from the mean of
+    /// the preceding `window` points by more than `threshold` (a fraction,
+    /// e.g. 0.2 for 20%). Returns `None` if there isn't enough history yet.
+    pub fn detect_regression(&self, window: usize, threshold: f64) -> Option<RegressionAlert> {
+        if self.points.len() < window + 1 {
+            return None;
+        }
+
+        let latest = self.points.last()?.value;
+        let baseline_slice = &self.points[self.points.len() - 1 - window..self.points.len() - 1];
+        let baseline = baseline_slice.iter().map(|p| p.value).sum::<f64>() / window as f64;
+
+        if baseline == 0.0 {
+            return None;
+        }
+
+        let deviation = (latest - baseline) / baseline;
+        if deviation.abs() > threshold {
+            Some(RegressionAlert {
+                baseline,
+                latest,
+                deviation,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+async fn load_entries(root: &Path) -> anyhow::Result<Vec<HistoryEntry>> {
+    Ok(entries_with_paths(root)
+        .await?
+        .into_iter()
+        .map(|(_, e)| e)
+        .collect())
+}
+
+async fn entries_with_paths(root: &Path) -> anyhow::Result<Vec<(PathBuf, HistoryEntry)>> {
+    let mut results = Vec::new();
+
+    if !root.exists() {
+        return Ok(results);
+    }
+
+    let mut dir = tokio::fs::read_dir(root).await?;
+    while let Some(entry) = dir.next_entry().await? {
+        let path = entry.path();
+        if path.is_dir() || path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        if let Ok(contents) = tokio::fs::read_to_string(&path).await {
+            if let Ok(parsed) = serde_json::from_str::<HistoryEntry>(&contents) {
+                results.push((path, parsed));
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(value: f64) -> TrendPoint {
+        TrendPoint {
+            recorded_at: Utc::now(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_detect_regression_flags_drift() {
+        let series = TrendSeries {
+            scenario_name: "checkout".to_string(),
+            metric: "SuccessRate".to_string(),
+            points: vec![
+                point(1.0),
+                point(1.0),
+                point(1.0),
+                point(1.0),
+                point(1.0),
+                point(0.5),
+            ],
+        };
+
+        let alert = series.detect_regression(5, 0.2).expect("expected a regression alert");
+        assert!((alert.baseline - 1.0).abs() < f64::EPSILON);
+        assert!((alert.latest - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_detect_regression_ignores_small_drift() {
+        let series = TrendSeries {
+            scenario_name: "checkout".to_string(),
+            metric: "SuccessRate".to_string(),
+            points: vec![point(1.0), point(1.0), point(1.0), point(0.95)],
+        };
+
+        assert!(series.detect_regression(3, 0.2).is_none());
+    }
+
+    #[test]
+    fn test_trend_metric_compare_flags_baseline_regression() {
+        use chaos_scenarios::runner::ScenarioResult;
+
+        fn result(injections: usize) -> ScenarioResult {
+            ScenarioResult {
+                scenario_name: "checkout".to_string(),
+                total_duration: std::time::Duration::from_secs(10),
+                phase_results: vec![],
+                total_injections: injections,
+                failed_injections: 0,
+                aborted_reason: None,
+                host: chaos_scenarios::host::HostFingerprint::capture(),
+                labels: Default::default(),
+                hook_results: Vec::new(),
+                resolved_seed: None,
+            }
+        }
+
+        let baseline = result(10);
+        let current = result(20);
+
+        let alert = TrendMetric::TotalInjections
+            .compare(&baseline, &current, 0.2)
+            .expect("expected a regression alert");
+        assert!((alert.baseline - 10.0).abs() < f64::EPSILON);
+        assert!((alert.latest - 20.0).abs() < f64::EPSILON);
+
+        assert!(TrendMetric::TotalInjections.compare(&baseline, &current, 2.0).is_none());
+    }
+
+    #[test]
+    fn test_trend_metric_parse_rejects_unknown() {
+        assert!(TrendMetric::parse("p99").is_err());
+        assert!(matches!(
+            TrendMetric::parse("success_rate"),
+            Ok(TrendMetric::SuccessRate)
+        ));
+    }
+}
@@ -1,8 +1,16 @@
 pub mod collector;
 pub mod aggregator;
 pub mod exporters;
+pub mod history;
 pub mod slo;
+pub mod streaming;
+pub mod workload;
 
 pub use collector::{MetricsCollector, Metric, MetricType};
 pub use aggregator::{MetricsAggregator, AggregatedMetrics};
-pub use slo::{SloTracker, SloViolation};
+pub use history::{HistoryEntry, HistoryStore, RetentionPolicy, TrendMetric, TrendSeries};
+pub use slo::{ErrorBudget, ErrorBudgetReport, SloTracker, SloViolation};
+pub use streaming::{StreamingAggregator, Window};
+#[cfg(feature = "grpc")]
+pub use workload::{GrpcWorkloadDriver, RpcMode};
+pub use workload::{TcpWorkloadDriver, WebSocketWorkloadDriver};
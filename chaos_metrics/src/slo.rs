@@ -69,3 +69,108 @@ impl Default for SloTracker {
         Self::new()
     }
 }
+
+/// An SLO target measured over a rolling window (e.g. 99.9% over 30 days),
+/// used to work out how much of that window's error budget a single chaos
+/// run consumed and how fast it burned it - the question "is this
+/// experiment safe to run again today, or did it already spend next week's
+/// budget."
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ErrorBudget {
+    /// Target success rate, e.g. `0.999` for "three nines".
+    pub target: f64,
+    /// The window `target` is measured over, e.g. 30 days.
+    pub window: Duration,
+}
+
+impl ErrorBudget {
+    pub fn new(target: f64, window: Duration) -> Self {
+        Self { target, window }
+    }
+
+    /// Fraction of requests allowed to fail across the whole window
+    /// without breaching `target`.
+    pub fn allowed_error_rate(&self) -> f64 {
+        1.0 - self.target
+    }
+
+    /// Evaluates this budget against a run's error rate and elapsed
+    /// duration. `error_rate` and `elapsed` describe the chaos run alone,
+    /// not the whole window - the run is one slice of it.
+    pub fn evaluate(&self, error_rate: f64, elapsed: Duration) -> ErrorBudgetReport {
+        let allowed_error_rate = self.allowed_error_rate();
+        let burn_rate = if allowed_error_rate > 0.0 {
+            error_rate / allowed_error_rate
+        } else if error_rate > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+
+        let window_fraction = elapsed.as_secs_f64() / self.window.as_secs_f64().max(f64::EPSILON);
+        let consumed_fraction = burn_rate * window_fraction;
+
+        ErrorBudgetReport {
+            target: self.target,
+            window: self.window,
+            error_rate,
+            elapsed,
+            burn_rate,
+            consumed_fraction,
+        }
+    }
+}
+
+/// The result of evaluating an [`ErrorBudget`] against a single run.
+/// `consumed_fraction` isn't capped at 1.0 - a run that blows through more
+/// than the whole window's budget on its own is exactly the case worth
+/// surfacing loudest.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ErrorBudgetReport {
+    pub target: f64,
+    pub window: Duration,
+    pub error_rate: f64,
+    pub elapsed: Duration,
+    /// Error rate as a multiple of the sustainable rate - 1.0 means errors
+    /// are coming in exactly fast enough to exhaust the budget precisely at
+    /// the end of the window.
+    pub burn_rate: f64,
+    pub consumed_fraction: f64,
+}
+
+impl ErrorBudgetReport {
+    /// The one-line sentence call sites report in run summaries, e.g.
+    /// "this experiment consumed 12% of the 30-day error budget".
+    pub fn summary(&self) -> String {
+        format!(
+            "this experiment consumed {:.1}% of the {:?} error budget (burn rate {:.2}x)",
+            self.consumed_fraction * 100.0,
+            self.window,
+            self.burn_rate
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_budget_consumed_fraction() {
+        let budget = ErrorBudget::new(0.999, Duration::from_secs(30 * 24 * 3600));
+        // 1% error rate is 10x the 0.1% allowed rate, sustained for 1% of
+        // the 30-day window - 10x burn rate over 1% of the window is 10% of
+        // the budget.
+        let report = budget.evaluate(0.01, Duration::from_secs(30 * 24 * 36));
+        assert!((report.burn_rate - 10.0).abs() < 1e-9);
+        assert!((report.consumed_fraction - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_error_budget_zero_errors_consumes_nothing() {
+        let budget = ErrorBudget::new(0.999, Duration::from_secs(30 * 24 * 3600));
+        let report = budget.evaluate(0.0, Duration::from_secs(3600));
+        assert_eq!(report.burn_rate, 0.0);
+        assert_eq!(report.consumed_fraction, 0.0);
+    }
+}
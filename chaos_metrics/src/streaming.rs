@@ -0,0 +1,123 @@
+use crate::aggregator::{AggregatedMetrics, MetricsAggregator};
+use crate::collector::Metric;
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Rolling window a [`StreamingAggregator`] can report on. Three sizes
+/// cover the cadences its consumers actually need: the live progress bar
+/// wants something that reacts within a second, the Prometheus endpoint
+/// and abort-condition checks want enough samples to not be noise-prone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Window {
+    OneSecond,
+    TenSeconds,
+    OneMinute,
+}
+
+impl Window {
+    pub fn duration(self) -> Duration {
+        match self {
+            Window::OneSecond => Duration::from_secs(1),
+            Window::TenSeconds => Duration::from_secs(10),
+            Window::OneMinute => Duration::from_secs(60),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Window::OneSecond => "1s",
+            Window::TenSeconds => "10s",
+            Window::OneMinute => "1m",
+        }
+    }
+
+    pub const ALL: [Window; 3] = [Window::OneSecond, Window::TenSeconds, Window::OneMinute];
+}
+
+/// Maintains a rolling buffer of recent [`Metric`]s and aggregates them
+/// per-[`Window`] on demand, so a run in progress can be queried for
+/// "what does the last 10s look like" without waiting for completion.
+/// Samples older than the largest window are dropped eagerly on
+/// `record`, keeping memory bounded regardless of run length - unlike
+/// [`crate::MetricsCollector`], which keeps exact totals and a sampled
+/// reservoir covering the whole run for the final report.
+pub struct StreamingAggregator {
+    samples: Arc<RwLock<VecDeque<Metric>>>,
+}
+
+impl StreamingAggregator {
+    pub fn new() -> Self {
+        Self {
+            samples: Arc::new(RwLock::new(VecDeque::new())),
+        }
+    }
+
+    pub async fn record(&self, metric: Metric) {
+        let mut samples = self.samples.write().await;
+        samples.push_back(metric);
+        Self::prune(&mut samples);
+    }
+
+    /// Aggregates the samples still within `window` as of now.
+    pub async fn aggregate(&self, window: Window) -> AggregatedMetrics {
+        let samples = self.samples.read().await;
+        let cutoff = Utc::now() - chrono::Duration::from_std(window.duration()).unwrap_or_default();
+        let windowed: Vec<Metric> = samples
+            .iter()
+            .filter(|metric| metric.timestamp >= cutoff)
+            .cloned()
+            .collect();
+        MetricsAggregator::aggregate(&windowed)
+    }
+
+    /// Drops samples older than the widest window - there's never a
+    /// reason to hold onto them, since no window will ever look that far
+    /// back again.
+    fn prune(samples: &mut VecDeque<Metric>) {
+        let widest = Window::ALL.iter().map(|w| w.duration()).max().unwrap_or_default();
+        let cutoff = Utc::now() - chrono::Duration::from_std(widest).unwrap_or_default();
+        while samples.front().map(|metric| metric.timestamp < cutoff).unwrap_or(false) {
+            samples.pop_front();
+        }
+    }
+}
+
+impl Default for StreamingAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::MetricType;
+
+    #[tokio::test]
+    async fn test_window_excludes_stale_samples() {
+        let streaming = StreamingAggregator::new();
+        streaming
+            .record(Metric {
+                metric_type: MetricType::Success,
+                timestamp: Utc::now() - chrono::Duration::seconds(30),
+                labels: Default::default(),
+            })
+            .await;
+        streaming
+            .record(Metric {
+                metric_type: MetricType::Success,
+                timestamp: Utc::now(),
+                labels: Default::default(),
+            })
+            .await;
+
+        let one_second = streaming.aggregate(Window::OneSecond).await;
+        assert_eq!(one_second.total_requests, 1);
+
+        let one_minute = streaming.aggregate(Window::OneMinute).await;
+        assert_eq!(one_minute.total_requests, 2);
+    }
+}
@@ -0,0 +1,15 @@
+//! Background workload drivers: real protocol clients run alongside a
+//! scenario's injections so a target's actual behavior under fault
+//! injection - not just whether the injections applied cleanly - shows up
+//! in the run's metrics. One file per protocol, mirroring
+//! `crate::exporters`.
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod tcp;
+pub mod websocket;
+
+#[cfg(feature = "grpc")]
+pub use grpc::{GrpcWorkloadDriver, RpcMode};
+pub use tcp::TcpWorkloadDriver;
+pub use websocket::WebSocketWorkloadDriver;
@@ -0,0 +1,119 @@
+use crate::collector::MetricsCollector;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+/// How long to wait for an unprompted greeting after connecting, e.g.
+/// `chaos_targets`' `tcp_echo_server` banner, before starting the
+/// request/echo loop. Generic enough to also just time out immediately
+/// against a server that never sends one.
+const GREETING_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Drives a steady rate of request/echo round-trips against a raw TCP
+/// server (e.g. `chaos_targets`' `tcp_echo_server` target) for the
+/// duration of a chaos run, so an L4 injector's effect on connection
+/// health and round-trip time - not just whether the scenario's
+/// injections applied cleanly - shows up in the run's metrics.
+///
+/// Reconnects on every disconnect with a fixed backoff rather than
+/// returning an error, the same as [`crate::WebSocketWorkloadDriver`]: a
+/// connection dropping mid-injection is exactly the behavior a chaos
+/// scenario wants to observe, not a driver failure.
+pub struct TcpWorkloadDriver {
+    addr: String,
+    payload: Vec<u8>,
+    interval: Duration,
+    reconnect_backoff: Duration,
+}
+
+impl TcpWorkloadDriver {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            payload: b"ping".to_vec(),
+            interval: Duration::from_secs(1),
+            reconnect_backoff: Duration::from_secs(1),
+        }
+    }
+
+    pub fn payload(mut self, payload: Vec<u8>) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn reconnect_backoff(mut self, backoff: Duration) -> Self {
+        self.reconnect_backoff = backoff;
+        self
+    }
+
+    /// Connects, round-trips, and reconnects until `stop` is set to
+    /// `true`. Intended to be driven from a `tokio::spawn`ed task
+    /// alongside the [`MetricsCollector`] used to record the rest of the
+    /// run's metrics.
+    pub async fn run(&self, collector: Arc<MetricsCollector>, mut stop: watch::Receiver<bool>) {
+        while !*stop.borrow() {
+            match TcpStream::connect(&self.addr).await {
+                Ok(mut stream) => {
+                    let mut greeting = [0u8; 1024];
+                    let _ = tokio::time::timeout(GREETING_TIMEOUT, stream.read(&mut greeting)).await;
+                    self.drive_connection(stream, &collector, &mut stop).await;
+                }
+                Err(e) => {
+                    warn!("Failed to connect to workload target {}: {}", self.addr, e);
+                    collector.record_error("tcp_connect_error").await;
+                }
+            }
+
+            if *stop.borrow() {
+                return;
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(self.reconnect_backoff) => {}
+                _ = stop.changed() => {}
+            }
+        }
+    }
+
+    async fn drive_connection(&self, mut stream: TcpStream, collector: &MetricsCollector, stop: &mut watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(self.interval);
+        let mut echo = vec![0u8; self.payload.len()];
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = stop.changed() => {
+                    if *stop.borrow() {
+                        return;
+                    }
+                }
+                _ = ticker.tick() => {
+                    let start = Instant::now();
+                    if let Err(e) = stream.write_all(&self.payload).await {
+                        warn!("Write to workload target {} failed: {}", self.addr, e);
+                        collector.record_error("tcp_write_error").await;
+                        return;
+                    }
+                    match stream.read_exact(&mut echo).await {
+                        Ok(_) => {
+                            collector.record_latency(start.elapsed()).await;
+                            collector.record_success().await;
+                        }
+                        Err(e) => {
+                            info!("Workload target {} disconnected: {}", self.addr, e);
+                            collector.record_error("tcp_read_error").await;
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
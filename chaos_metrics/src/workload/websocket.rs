@@ -0,0 +1,138 @@
+use crate::collector::MetricsCollector;
+use futures::StreamExt;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{info, warn};
+
+/// `MetricType::Custom` name under which [`WebSocketWorkloadDriver`] records
+/// the gap between consecutive messages.
+pub const GAP_METRIC_NAME: &str = "ws_message_gap_ms";
+/// `MetricType::Custom` name under which the driver records how long it's
+/// been since the last message, sampled once per [`WebSocketWorkloadDriver::staleness_interval`]
+/// even when nothing has arrived.
+pub const STALENESS_METRIC_NAME: &str = "ws_staleness_ms";
+/// `MetricType::Custom` name under which the driver records how long a
+/// reconnect took, after the first successful connection.
+pub const RECONNECT_METRIC_NAME: &str = "ws_reconnect_ms";
+
+/// Drives a steady stream of reads against a WebSocket endpoint (e.g.
+/// `chaos_targets`' `websocket_feed` binary) for the duration of a chaos
+/// run, so the feed's own behavior under fault injection - not just
+/// whether the scenario's injections applied cleanly - shows up in the
+/// run's metrics: inter-arrival gap, staleness, and reconnect time.
+///
+/// Reconnects on every disconnect with a fixed backoff rather than
+/// returning an error - a feed dropping mid-injection is exactly the
+/// behavior a chaos scenario wants to observe, not a driver failure.
+pub struct WebSocketWorkloadDriver {
+    url: String,
+    reconnect_backoff: Duration,
+    staleness_interval: Duration,
+}
+
+impl WebSocketWorkloadDriver {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            reconnect_backoff: Duration::from_secs(1),
+            staleness_interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn reconnect_backoff(mut self, backoff: Duration) -> Self {
+        self.reconnect_backoff = backoff;
+        self
+    }
+
+    pub fn staleness_interval(mut self, interval: Duration) -> Self {
+        self.staleness_interval = interval;
+        self
+    }
+
+    /// Connects, reads, and reconnects until `stop` is set to `true`.
+    /// Intended to be driven from a `tokio::spawn`ed task for the
+    /// duration of a scenario run, alongside the [`MetricsCollector`]
+    /// used to record the rest of the run's metrics.
+    pub async fn run(&self, collector: std::sync::Arc<MetricsCollector>, mut stop: watch::Receiver<bool>) {
+        let mut first_connection = true;
+
+        while !*stop.borrow() {
+            let connect_start = Instant::now();
+            match tokio_tungstenite::connect_async(&self.url).await {
+                Ok((stream, _response)) => {
+                    if !first_connection {
+                        let reconnect_time = connect_start.elapsed();
+                        info!("Reconnected to workload feed {} in {:?}", self.url, reconnect_time);
+                        collector
+                            .record_custom(RECONNECT_METRIC_NAME, reconnect_time.as_secs_f64() * 1000.0)
+                            .await;
+                    }
+                    first_connection = false;
+                    self.drive_connection(stream, &collector, &mut stop).await;
+                }
+                Err(e) => {
+                    warn!("Failed to connect to workload feed {}: {}", self.url, e);
+                    collector.record_error("ws_connect_error").await;
+                }
+            }
+
+            if *stop.borrow() {
+                return;
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(self.reconnect_backoff) => {}
+                _ = stop.changed() => {}
+            }
+        }
+    }
+
+    async fn drive_connection(
+        &self,
+        stream: tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+        collector: &MetricsCollector,
+        stop: &mut watch::Receiver<bool>,
+    ) {
+        let (_write, mut read) = stream.split();
+        let mut last_message_at = Instant::now();
+        let mut staleness_ticker = tokio::time::interval(self.staleness_interval);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = stop.changed() => {
+                    if *stop.borrow() {
+                        return;
+                    }
+                }
+                _ = staleness_ticker.tick() => {
+                    let staleness = last_message_at.elapsed();
+                    collector.record_custom(STALENESS_METRIC_NAME, staleness.as_secs_f64() * 1000.0).await;
+                }
+                message = read.next() => {
+                    match message {
+                        Some(Ok(Message::Text(_))) | Some(Ok(Message::Binary(_))) => {
+                            let now = Instant::now();
+                            let gap = now.duration_since(last_message_at);
+                            collector.record_custom(GAP_METRIC_NAME, gap.as_secs_f64() * 1000.0).await;
+                            last_message_at = now;
+                            collector.record_success().await;
+                        }
+                        Some(Ok(Message::Close(_))) | None => {
+                            info!("Workload feed {} disconnected", self.url);
+                            return;
+                        }
+                        Some(Ok(_)) => {
+                            // Ping/Pong/Frame control messages don't count as data.
+                        }
+                        Some(Err(e)) => {
+                            warn!("Read error from workload feed {}: {}", self.url, e);
+                            collector.record_error("ws_read_error").await;
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::config::Scenario;
+use std::collections::HashMap;
+
+/// Prefix a `chaos run`/`chaos validate` scenario-file argument uses to name
+/// a [`BuiltinScenario`] instead of a path on disk, e.g.
+/// `chaos run builtin:network-partition --set target=my-service`.
+pub const PREFIX: &str = "builtin:";
+
+/// A curated scenario embedded directly into this binary at compile time,
+/// so a team can start chaos testing with `chaos run builtin:<name>`
+/// before writing any YAML. Listed by `chaos scenarios list`; looked up by
+/// [`find`].
+pub struct BuiltinScenario {
+    pub name: &'static str,
+    pub summary: &'static str,
+    yaml: &'static str,
+}
+
+impl BuiltinScenario {
+    /// Parses this builtin's embedded YAML into a [`Scenario`], substituting
+    /// `overrides` the same way a `--set` override would for a file-backed
+    /// scenario - so `--set target=my-service` fills in the `${target}`
+    /// placeholder every builtin declares for its injection target.
+    pub fn load(&self, overrides: &HashMap<String, String>) -> anyhow::Result<Scenario> {
+        crate::parser::parse_scenario_from_str_with_vars(self.yaml, "yaml", overrides)
+    }
+}
+
+/// Every scenario shipped with this binary.
+pub const BUILTIN_SCENARIOS: &[BuiltinScenario] = &[
+    BuiltinScenario {
+        name: "network-partition",
+        summary: "Cuts all network traffic to a target to rehearse a full network partition",
+        yaml: include_str!("../scenarios/builtin/network-partition.yaml"),
+    },
+    BuiltinScenario {
+        name: "dependency-latency-sweep",
+        summary: "Steps a dependency's latency up through increasing delays to find where it starts to hurt",
+        yaml: include_str!("../scenarios/builtin/dependency-latency-sweep.yaml"),
+    },
+    BuiltinScenario {
+        name: "restart-storm",
+        summary: "Kills and restarts a target repeatedly to rehearse a crash-loop",
+        yaml: include_str!("../scenarios/builtin/restart-storm.yaml"),
+    },
+    BuiltinScenario {
+        name: "disk-full",
+        summary: "Approximates a full disk via heavy I/O latency, since the disk-fill injector isn't in the default registry",
+        yaml: include_str!("../scenarios/builtin/disk-full.yaml"),
+    },
+];
+
+/// Looks up a builtin scenario by name (the part after [`PREFIX`] in a
+/// scenario-file argument).
+pub fn find(name: &str) -> Option<&'static BuiltinScenario> {
+    BUILTIN_SCENARIOS.iter().find(|s| s.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_builtin_scenario_parses_and_finds_by_name() {
+        for builtin in BUILTIN_SCENARIOS {
+            let mut overrides = HashMap::new();
+            overrides.insert("target".to_string(), "test-service".to_string());
+
+            let scenario = builtin
+                .load(&overrides)
+                .unwrap_or_else(|e| panic!("builtin '{}' failed to parse: {}", builtin.name, e));
+            assert!(!scenario.phases.is_empty());
+
+            assert!(find(builtin.name).is_some());
+        }
+    }
+
+    #[test]
+    fn test_find_unknown_name_returns_none() {
+        assert!(find("not-a-real-builtin").is_none());
+    }
+
+    #[test]
+    fn test_builtin_scenarios_validate_against_the_default_registry() {
+        let registry = chaos_core::InjectorRegistry::with_defaults();
+        let mut overrides = HashMap::new();
+        overrides.insert("target".to_string(), "test-service".to_string());
+
+        for builtin in BUILTIN_SCENARIOS {
+            let scenario = builtin.load(&overrides).unwrap();
+            scenario.validate().unwrap_or_else(|e| panic!("builtin '{}' is structurally invalid: {}", builtin.name, e));
+            scenario
+                .validate_against_registry(&registry)
+                .unwrap_or_else(|e| panic!("builtin '{}' fails registry validation: {}", builtin.name, e));
+        }
+    }
+}
@@ -1,310 +1,1205 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::time::Duration;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Scenario {
-    pub name: String,
-    #[serde(default)]
-    pub description: Option<String>,
-    #[serde(default)]
-    pub seed: Option<u64>,
-    #[serde(with = "humantime_serde")]
-    pub duration: Duration,
-    #[serde(with = "humantime_serde_option", default)]
-    pub ramp_up: Option<Duration>,
-    #[serde(default)]
-    pub phases: Vec<Phase>,
-    #[serde(default)]
-    pub labels: HashMap<String, String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Phase {
-    pub name: String,
-    #[serde(with = "humantime_serde")]
-    pub duration: Duration,
-    #[serde(default)]
-    pub injections: Vec<InjectionConfig>,
-    #[serde(default)]
-    pub parallel: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InjectionConfig {
-    pub r#type: String,
-    #[serde(default)]
-    pub target: TargetConfig,
-    #[serde(flatten)]
-    pub parameters: HashMap<String, serde_json::Value>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct TargetConfig {
-    #[serde(default)]
-    pub pid: Option<u32>,
-    #[serde(default)]
-    pub address: Option<String>,
-    #[serde(default)]
-    pub container_id: Option<String>,
-    #[serde(default)]
-    pub pattern: Option<String>,
-}
-
-impl TargetConfig {
-    pub fn to_target(&self) -> Result<chaos_core::Target, String> {
-        if let Some(pid) = self.pid {
-            Ok(chaos_core::Target::process(pid))
-        } else if let Some(addr) = &self.address {
-            let socket_addr = addr
-                .parse()
-                .map_err(|e| format!("Invalid address '{}': {}", addr, e))?;
-            Ok(chaos_core::Target::network(socket_addr))
-        } else if let Some(id) = &self.container_id {
-            Ok(chaos_core::Target::container(id.clone()))
-        } else if let Some(pattern) = &self.pattern {
-            Ok(chaos_core::Target::process_pattern(pattern.clone()))
-        } else {
-            Err("No target specified".to_string())
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScenarioConfig {
-    pub scenario: Scenario,
-}
-
-impl Scenario {
-    pub fn builder() -> ScenarioBuilder {
-        ScenarioBuilder::default()
-    }
-
-    pub fn total_duration(&self) -> Duration {
-        self.phases.iter().map(|p| p.duration).sum()
-    }
-
-    pub fn validate(&self) -> Result<(), String> {
-        if self.name.is_empty() {
-            return Err("Scenario name cannot be empty".to_string());
-        }
-
-        if self.phases.is_empty() {
-            return Err("Scenario must have at least one phase".to_string());
-        }
-
-        for (i, phase) in self.phases.iter().enumerate() {
-            if phase.name.is_empty() {
-                return Err(format!("Phase {} name cannot be empty", i));
-            }
-
-            if phase.duration.is_zero() {
-                return Err(format!("Phase '{}' duration must be > 0", phase.name));
-            }
-
-            for (j, injection) in phase.injections.iter().enumerate() {
-                if injection.r#type.is_empty() {
-                    return Err(format!(
-                        "Injection {} in phase '{}' must have a type",
-                        j, phase.name
-                    ));
-                }
-            }
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Default)]
-pub struct ScenarioBuilder {
-    name: Option<String>,
-    description: Option<String>,
-    seed: Option<u64>,
-    duration: Option<Duration>,
-    ramp_up: Option<Duration>,
-    phases: Vec<Phase>,
-    labels: HashMap<String, String>,
-}
-
-impl ScenarioBuilder {
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
-        self
-    }
-
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        self.description = Some(description.into());
-        self
-    }
-
-    pub fn seed(mut self, seed: u64) -> Self {
-        self.seed = Some(seed);
-        self
-    }
-
-    pub fn duration(mut self, duration: Duration) -> Self {
-        self.duration = Some(duration);
-        self
-    }
-
-    pub fn ramp_up(mut self, ramp_up: Duration) -> Self {
-        self.ramp_up = Some(ramp_up);
-        self
-    }
-
-    pub fn add_phase(mut self, phase: Phase) -> Self {
-        self.phases.push(phase);
-        self
-    }
-
-    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.labels.insert(key.into(), value.into());
-        self
-    }
-
-    pub fn build(self) -> Scenario {
-        let duration = self.duration.unwrap_or_else(|| {
-            self.phases.iter().map(|p| p.duration).sum()
-        });
-
-        Scenario {
-            name: self.name.unwrap_or_else(|| "unnamed".to_string()),
-            description: self.description,
-            seed: self.seed,
-            duration,
-            ramp_up: self.ramp_up,
-            phases: self.phases,
-            labels: self.labels,
-        }
-    }
-}
-
-impl Phase {
-    pub fn builder() -> PhaseBuilder {
-        PhaseBuilder::default()
-    }
-}
-
-#[derive(Default)]
-pub struct PhaseBuilder {
-    name: Option<String>,
-    duration: Option<Duration>,
-    injections: Vec<InjectionConfig>,
-    parallel: bool,
-}
-
-impl PhaseBuilder {
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
-        self
-    }
-
-    pub fn duration(mut self, duration: Duration) -> Self {
-        self.duration = Some(duration);
-        self
-    }
-
-    pub fn add_injection(mut self, injection: InjectionConfig) -> Self {
-        self.injections.push(injection);
-        self
-    }
-
-    pub fn parallel(mut self, parallel: bool) -> Self {
-        self.parallel = parallel;
-        self
-    }
-
-    pub fn build(self) -> Phase {
-        Phase {
-            name: self.name.unwrap_or_else(|| "unnamed".to_string()),
-            duration: self.duration.unwrap_or(Duration::from_secs(60)),
-            injections: self.injections,
-            parallel: self.parallel,
-        }
-    }
-}
-
-mod humantime_serde {
-    use serde::{Deserialize, Deserializer, Serializer};
-    use std::time::Duration;
-
-    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&humantime::format_duration(*duration).to_string())
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        humantime::parse_duration(&s).map_err(serde::de::Error::custom)
-    }
-}
-
-mod humantime_serde_option {
-    use serde::{Deserialize, Deserializer, Serializer};
-    use std::time::Duration;
-
-    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match duration {
-            Some(d) => serializer.serialize_some(&humantime::format_duration(*d).to_string()),
-            None => serializer.serialize_none(),
-        }
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let opt = Option::<String>::deserialize(deserializer)?;
-        opt.map(|s| humantime::parse_duration(&s).map_err(serde::de::Error::custom))
-            .transpose()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_scenario_builder() {
-        let scenario = Scenario::builder()
-            .name("test")
-            .duration(Duration::from_secs(120))
-            .add_phase(
-                Phase::builder()
-                    .name("phase1")
-                    .duration(Duration::from_secs(60))
-                    .build(),
-            )
-            .build();
-
-        assert_eq!(scenario.name, "test");
-        assert_eq!(scenario.phases.len(), 1);
-    }
-
-    #[test]
-    fn test_scenario_validation() {
-        let scenario = Scenario::builder()
-            .name("valid")
-            .add_phase(
-                Phase::builder()
-                    .name("phase1")
-                    .duration(Duration::from_secs(60))
-                    .build(),
-            )
-            .build();
-
-        assert!(scenario.validate().is_ok());
-
-        let invalid = Scenario::builder().build();
-        assert!(invalid.validate().is_err());
-    }
-}
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+    #[serde(with = "humantime_serde_option", default)]
+    pub ramp_up: Option<Duration>,
+    #[serde(default)]
+    pub phases: Vec<Phase>,
+    #[serde(default)]
+    pub background: Vec<InjectionConfig>,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    /// Gate on the target's remaining error budget: if set, the scenario
+    /// refuses to start (and aborts between phases) once the budget drops
+    /// below the policy's minimum.
+    #[serde(default)]
+    pub error_budget: Option<chaos_core::ErrorBudgetPolicy>,
+    /// Conditions that, once breached, stop remaining phases and remove
+    /// every active injection: an error rate or P99 latency threshold, or
+    /// a health check that's been failing too long. Checked on the same
+    /// cadence as `error_budget`.
+    #[serde(default)]
+    pub abort_conditions: Option<chaos_core::AbortConditions>,
+    /// Background protocol clients (WebSocket, gRPC, ...) to run for the
+    /// scenario's duration, alongside its injections, so a target's real
+    /// behavior under fault - not just whether the injections applied
+    /// cleanly - shows up in the run's metrics. Interpreted by `chaos_cli`,
+    /// which owns the concrete driver implementations: this crate can't
+    /// depend on `chaos_metrics`, where they live, without a cycle.
+    #[serde(default)]
+    pub workloads: Vec<WorkloadConfig>,
+    /// Health checks run on their own interval for the scenario's
+    /// duration, so any existing monitoring script - not just this
+    /// framework's own injectors and drivers - can feed the run's metrics
+    /// and, via `abort_below`/`abort_above`, its abort conditions.
+    #[serde(default)]
+    pub probes: Vec<ProbeConfig>,
+    /// Default quiet period after every phase during which no injections
+    /// are active, before the next phase starts - so probes/metrics can
+    /// keep running and reports can quantify how the target actually
+    /// recovers, instead of one fault rolling straight into the next.
+    /// Overridden per-phase by [`Phase::recovery_period`].
+    #[serde(with = "humantime_serde_option", default)]
+    pub recovery_period: Option<Duration>,
+    /// What to do when an injection fails to apply. Defaults to logging a
+    /// warning and continuing, the long-standing behavior.
+    #[serde(default)]
+    pub injection_failure_policy: InjectionFailurePolicy,
+    /// Run once before the first phase starts (after validation and the
+    /// error budget/abort condition checks), e.g. to warm a cache or notify
+    /// on-call that a run is starting.
+    #[serde(default)]
+    pub before: Vec<HookConfig>,
+    /// Run once after every phase has finished (or the scenario was
+    /// cancelled/aborted) and background load has been torn down, e.g. to
+    /// trigger a failover back or post a completion notice.
+    #[serde(default)]
+    pub after: Vec<HookConfig>,
+    /// Where to post run-event notifications (scenario start, phase
+    /// transitions, phase failures, aborts, completion) - see
+    /// [`crate::runner::NotificationEvent`] for exactly what's sent and
+    /// when.
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+}
+
+/// What [`crate::runner::ScenarioRunner`] does when an injection fails to
+/// apply, instead of always just logging a warning and moving on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum InjectionFailurePolicy {
+    /// Log a warning and keep going - other injections in the same phase,
+    /// and later phases, are unaffected.
+    #[default]
+    Continue,
+    /// Stop applying further injections in whichever phase hit the
+    /// failure, but still run remaining phases.
+    FailFast,
+    /// Stop the whole scenario immediately, the same as a tripped abort
+    /// condition.
+    AbortScenario,
+}
+
+/// A command or HTTP request run before/after a scenario or a phase, for
+/// side effects no injector models: flushing a cache, triggering a
+/// failover, paging a human. Unlike [`ProbeConfig`]/[`WorkloadConfig`], this
+/// crate runs the hook itself instead of leaving it to `chaos_cli` - a
+/// process spawn or an HTTP call needs nothing from `chaos_metrics`, so
+/// there's no dependency cycle to route around.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    /// Label this hook's result is recorded under in the run's artifacts.
+    pub name: String,
+    #[serde(flatten)]
+    pub action: HookAction,
+    /// How long to wait for the hook before treating it as failed.
+    #[serde(with = "humantime_serde", default = "HookConfig::default_timeout")]
+    pub timeout: Duration,
+}
+
+impl HookConfig {
+    fn default_timeout() -> Duration {
+        Duration::from_secs(30)
+    }
+}
+
+/// What a [`HookConfig`] actually does when it runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum HookAction {
+    /// Runs `command` with `args` as a child process. Non-zero exit is
+    /// treated as failure.
+    Command {
+        command: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    /// Sends an HTTP request to `url`. Any non-2xx status is treated as
+    /// failure.
+    Http {
+        url: String,
+        #[serde(default = "HookAction::default_method")]
+        method: String,
+    },
+}
+
+impl HookAction {
+    fn default_method() -> String {
+        "POST".to_string()
+    }
+}
+
+/// Where [`crate::runner::ScenarioRunner`] posts run-event notifications.
+/// Like [`HookConfig`], this crate sends them itself - a webhook POST or a
+/// Slack API call needs nothing from `chaos_metrics`. Both are optional and
+/// independent: a scenario can configure either, both, or neither.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    /// Plain HTTP(S) endpoints that each receive a JSON POST of every
+    /// event.
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+    /// Slack channel that receives a formatted message for every event, via
+    /// the Slack Web API's `chat.postMessage`.
+    #[serde(default)]
+    pub slack: Option<SlackConfig>,
+}
+
+/// Slack Web API credentials for [`NotificationConfig::slack`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackConfig {
+    /// Bot token with `chat:write` scope.
+    pub token: String,
+    /// Channel ID or name to post to, e.g. `"#chaos-oncall"`.
+    pub channel: String,
+}
+
+/// One entry in [`Scenario::workloads`]. `r#type` selects the driver
+/// (`"websocket"`, `"grpc"`); `parameters` is whatever that driver needs,
+/// left untyped here the same way [`InjectionConfig::parameters`] is -
+/// this crate has no reason to know the shape of every driver's config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadConfig {
+    pub r#type: String,
+    #[serde(flatten)]
+    pub parameters: HashMap<String, serde_json::Value>,
+}
+
+/// A command-line health check, run by `chaos_cli` on `interval` for the
+/// scenario's duration and recorded as a custom metric named `name` -
+/// integration with whatever health tooling a target already has, rather
+/// than requiring a purpose-built injector or driver for every case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbeConfig {
+    /// Custom metric name the probe's result is recorded under.
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(with = "humantime_serde")]
+    pub interval: Duration,
+    #[serde(default)]
+    pub parse: ProbeParse,
+    /// Abort the scenario once this probe's parsed numeric value drops
+    /// below this threshold. Only meaningful with `parse: stdout-numeric`.
+    #[serde(default)]
+    pub abort_below: Option<f64>,
+    /// Abort the scenario once this probe's parsed numeric value rises
+    /// above this threshold.
+    #[serde(default)]
+    pub abort_above: Option<f64>,
+}
+
+/// How a [`ProbeConfig`] turns a completed command into a metric value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProbeParse {
+    /// Record `1.0` on exit code `0`, `0.0` otherwise. `abort_below`/
+    /// `abort_above` have no effect in this mode.
+    #[default]
+    ExitCode,
+    /// Record the `f64` parsed from trimmed stdout, in addition to
+    /// treating a non-zero exit code as a probe failure.
+    StdoutNumeric,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Phase {
+    pub name: String,
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+    #[serde(default)]
+    pub injections: Vec<InjectionConfig>,
+    #[serde(default)]
+    pub parallel: bool,
+    /// Gates this phase on an earlier one, e.g. `phases.warmup.slo_passed`
+    /// or its negation `!phases.warmup.slo_passed`, so an escalation
+    /// scenario only proceeds to harsher faults once the system has
+    /// survived the previous level. `slo_passed` is
+    /// [`PhaseResult::success`][crate::runner::PhaseResult::success] - the
+    /// closest thing this crate has to an SLO verdict without depending on
+    /// `chaos_metrics`. A condition naming a phase that hasn't run yet
+    /// (skipped by an earlier `run_if`, or scheduled later) is treated as
+    /// not met.
+    #[serde(default)]
+    pub run_if: Option<String>,
+    /// Instead of applying every entry in `injections`, picks a weighted
+    /// random subset of them - a probabilistic fault mix rather than a
+    /// fixed one. `None` keeps the existing "apply all" behavior.
+    #[serde(default)]
+    pub injection_selection: Option<InjectionSelection>,
+    /// Delays this phase's start by a random amount in `[0, jitter]`,
+    /// seeded by [`Scenario::seed`] for reproducibility, instead of always
+    /// starting at the exact same instant relative to the scenario clock -
+    /// so repeated runs don't all land on the identical wall-clock offset.
+    /// The phase's own duration is unaffected, so a jittered phase can
+    /// overlap slightly with whatever follows it.
+    #[serde(with = "humantime_serde_option", default)]
+    pub jitter: Option<Duration>,
+    /// Overrides [`Scenario::recovery_period`] for the quiet period after
+    /// just this phase. `None` falls back to the scenario's default (which
+    /// may itself be unset, meaning no recovery period).
+    #[serde(with = "humantime_serde_option", default)]
+    pub recovery_period: Option<Duration>,
+    /// Run once right before this phase's injections are applied (after its
+    /// start delay), e.g. to flush a cache immediately ahead of a fault.
+    #[serde(default)]
+    pub before: Vec<HookConfig>,
+    /// Run once right after this phase's injections are removed, before its
+    /// recovery period, e.g. to trigger a failover back.
+    #[serde(default)]
+    pub after: Vec<HookConfig>,
+}
+
+/// Picks `count` entries out of a phase's `injections` at random, weighted
+/// by `weights` and seeded by [`Scenario::seed`] for reproducibility - the
+/// same seeding convention [`TargetConfig::to_target_with_seed`]'s sampling
+/// uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InjectionSelection {
+    /// How many injections to apply out of the phase's list. Clamped to
+    /// the number of injections available.
+    #[serde(default = "default_selection_count")]
+    pub count: usize,
+    /// Per-injection weight, aligned by index with the phase's
+    /// `injections`. A short or empty list defaults the missing entries to
+    /// a weight of `1.0` (uniform selection).
+    #[serde(default)]
+    pub weights: Vec<f64>,
+}
+
+fn default_selection_count() -> usize {
+    1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InjectionConfig {
+    pub r#type: String,
+    #[serde(default)]
+    pub target: TargetConfig,
+    #[serde(flatten)]
+    pub parameters: HashMap<String, serde_json::Value>,
+    /// Drives one of `parameters` from `from` to `to` over `ramp_duration`
+    /// instead of applying it as a step function, for injectors whose
+    /// `Injector::ramp_parameter()` names the same field.
+    #[serde(default)]
+    pub ramp: Option<RampConfig>,
+    /// Delays this injection's application until this far into the phase,
+    /// instead of applying it as soon as the phase starts - so a phase can
+    /// stagger faults (latency at t+10s, a process kill at t+40s) without
+    /// splitting into artificial phases just to offset one injection.
+    #[serde(with = "humantime_serde_option", default)]
+    pub start_after: Option<Duration>,
+    /// Removes this injection this long after it was applied, instead of
+    /// leaving it active for the rest of the phase. Has no effect if it
+    /// would fall after the phase already ends.
+    #[serde(with = "humantime_serde_option", default)]
+    pub duration: Option<Duration>,
+    /// Adds a random amount in `[0, jitter]` on top of `start_after`
+    /// (default zero), seeded by [`Scenario::seed`], so a fleet of
+    /// otherwise-identical injections doesn't all fire at the exact same
+    /// instant.
+    #[serde(with = "humantime_serde_option", default)]
+    pub jitter: Option<Duration>,
+}
+
+/// Gradually drives one numeric injection parameter (e.g. `intensity` on
+/// `cpu_starvation`, `rate` on `packet_loss`) from `from` to `to` over
+/// `ramp_duration`, by re-applying the injection at `steps` evenly-spaced
+/// points instead of jumping straight to the target value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RampConfig {
+    /// Name of the injector parameter to ramp. Must match the injector's
+    /// `Injector::ramp_parameter()`.
+    pub parameter: String,
+    pub from: f64,
+    pub to: f64,
+    #[serde(with = "humantime_serde")]
+    pub ramp_duration: Duration,
+    /// Number of re-applications across `ramp_duration`, including the
+    /// first (`from`) and last (`to`). Clamped to at least 2.
+    #[serde(default = "RampConfig::default_steps")]
+    pub steps: u32,
+}
+
+impl RampConfig {
+    fn default_steps() -> u32 {
+        10
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TargetConfig {
+    #[serde(default)]
+    pub pid: Option<u32>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub container_id: Option<String>,
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Fan out to every target in this list instead of a single one, e.g.
+    /// a fixed set of replica addresses. Takes precedence over the other
+    /// fields when present.
+    #[serde(default)]
+    pub members: Option<Vec<TargetConfig>>,
+    /// Instead of fanning out to every `members` entry or every live
+    /// process matching `pattern`, affect only a reproducible sample of
+    /// them. Ignored by `pid`/`address`/`container_id`, which only ever
+    /// resolve to one target anyway.
+    #[serde(default)]
+    pub sample: Option<SampleConfig>,
+}
+
+/// Down-samples a selector that matched many targets (a `members` list or a
+/// live `pattern` match) to a fraction of them, chosen with
+/// [`Scenario::seed`] so the same scenario run always picks the same
+/// subset - the same guarantee `Scheduler` and the chaos monkey already
+/// give their own random choices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampleConfig {
+    /// Fraction of matches to affect, e.g. `0.3` for "30% of matches".
+    /// Must be in `(0.0, 1.0]`.
+    pub percent: f64,
+}
+
+impl TargetConfig {
+    pub fn to_target(&self) -> Result<chaos_core::Target, String> {
+        self.to_target_with_seed(None)
+    }
+
+    /// Resolves this config into a concrete [`chaos_core::Target`], sampling
+    /// `members`/`pattern` matches down via `self.sample` if set. `seed`
+    /// should be the owning [`Scenario::seed`] - passing the same seed
+    /// against the same matches always produces the same subset.
+    pub fn to_target_with_seed(&self, seed: Option<u64>) -> Result<chaos_core::Target, String> {
+        if let Some(members) = &self.members {
+            if members.is_empty() {
+                return Err("Group target must have at least one member".to_string());
+            }
+            let targets = members
+                .iter()
+                .map(|member| member.to_target_with_seed(seed))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(chaos_core::Target::group(sample_targets(
+                targets,
+                self.sample.as_ref(),
+                seed,
+            )))
+        } else if let Some(pid) = self.pid {
+            Ok(chaos_core::Target::process(pid))
+        } else if let Some(addr) = &self.address {
+            let socket_addr = addr
+                .parse()
+                .map_err(|e| format!("Invalid address '{}': {}", addr, e))?;
+            Ok(chaos_core::Target::network(socket_addr))
+        } else if let Some(id) = &self.container_id {
+            Ok(chaos_core::Target::container(id.clone()))
+        } else if let Some(pattern) = &self.pattern {
+            match &self.sample {
+                None => Ok(chaos_core::Target::process_pattern(pattern.clone())),
+                Some(sample) => {
+                    let matches = chaos_core::discovery::discover_processes(pattern);
+                    if matches.is_empty() {
+                        return Err(format!("No live process matched pattern '{}'", pattern));
+                    }
+                    let targets = matches
+                        .into_iter()
+                        .map(|process| chaos_core::Target::process(process.pid))
+                        .collect();
+                    Ok(chaos_core::Target::group(sample_targets(
+                        targets,
+                        Some(sample),
+                        seed,
+                    )))
+                }
+            }
+        } else {
+            Err("No target specified".to_string())
+        }
+    }
+}
+
+/// Checks that `target.sample`, if set, has a sane percentage and is only
+/// attached to a selector that can actually match more than one target -
+/// sampling a single `pid`/`address`/`container_id` doesn't mean anything.
+/// Recurses into `members` so a nested group target's own sample settings
+/// are caught too.
+fn validate_target_config(target: &TargetConfig) -> Result<(), String> {
+    if let Some(sample) = &target.sample {
+        if !(sample.percent > 0.0 && sample.percent <= 1.0) {
+            return Err(format!(
+                "Target sample percent must be in (0.0, 1.0], got {}",
+                sample.percent
+            ));
+        }
+        if target.members.is_none() && target.pattern.is_none() {
+            return Err(
+                "Target sample only applies to a 'members' list or a 'pattern' match".to_string(),
+            );
+        }
+    }
+
+    if let Some(members) = &target.members {
+        for member in members {
+            validate_target_config(member)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Shuffles `targets` with a seeded RNG (or an unseeded one, if `seed` is
+/// `None`) and truncates to `sample.percent` of them, rounding up so a
+/// small or oddly-sized match set never samples down to zero targets.
+/// Returns `targets` unchanged if `sample` isn't set.
+fn sample_targets(
+    mut targets: Vec<chaos_core::Target>,
+    sample: Option<&SampleConfig>,
+    seed: Option<u64>,
+) -> Vec<chaos_core::Target> {
+    let Some(sample) = sample else {
+        return targets;
+    };
+
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    targets.shuffle(&mut rng);
+
+    let percent = sample.percent.clamp(0.0, 1.0);
+    let count = ((targets.len() as f64) * percent).ceil() as usize;
+    targets.truncate(count.clamp(1, targets.len()));
+    targets
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioConfig {
+    pub scenario: Scenario,
+}
+
+/// Label used to mark a scenario (and, once run, its stored history entry)
+/// as a baseline recording rather than a fault-injection experiment. Set by
+/// [`Scenario::baseline`], or by hand on any scenario via
+/// [`ScenarioBuilder::label`].
+pub const BASELINE_LABEL: &str = "chaos.baseline";
+
+impl Scenario {
+    pub fn builder() -> ScenarioBuilder {
+        ScenarioBuilder::default()
+    }
+
+    /// Starts building a built-in "no-op observation" scenario: a single
+    /// phase of `duration` with no injections, labeled so the run it
+    /// produces is recognized as a baseline by `--baseline` comparisons.
+    /// Callers that want load during the observation window can still
+    /// `add_background` on the returned builder, the same as any other
+    /// scenario - only fault injection is excluded.
+    ///
+    /// This replaces hand-writing a scenario with an empty phase just to
+    /// get an observation-only run.
+    pub fn baseline(name: impl Into<String>, duration: Duration) -> ScenarioBuilder {
+        Scenario::builder()
+            .name(name)
+            .duration(duration)
+            .add_phase(
+                Phase::builder()
+                    .name("observe")
+                    .duration(duration)
+                    .build(),
+            )
+            .label(BASELINE_LABEL, "true")
+    }
+
+    /// Whether this scenario is tagged as a baseline recording (see
+    /// [`Scenario::baseline`]), rather than a fault-injection experiment.
+    pub fn is_baseline(&self) -> bool {
+        self.labels.get(BASELINE_LABEL).map(String::as_str) == Some("true")
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.phases.iter().map(|p| p.duration).sum()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Scenario name cannot be empty".to_string());
+        }
+
+        if self.phases.is_empty() {
+            return Err("Scenario must have at least one phase".to_string());
+        }
+
+        for (i, phase) in self.phases.iter().enumerate() {
+            if phase.name.is_empty() {
+                return Err(format!("Phase {} name cannot be empty", i));
+            }
+
+            if phase.duration.is_zero() {
+                return Err(format!("Phase '{}' duration must be > 0", phase.name));
+            }
+
+            for (j, injection) in phase.injections.iter().enumerate() {
+                if injection.r#type.is_empty() {
+                    return Err(format!(
+                        "Injection {} in phase '{}' must have a type",
+                        j, phase.name
+                    ));
+                }
+                validate_target_config(&injection.target)?;
+            }
+
+            if let Some(selection) = &phase.injection_selection {
+                if phase.injections.is_empty() {
+                    return Err(format!(
+                        "Phase '{}' has injection_selection but no injections",
+                        phase.name
+                    ));
+                }
+                if !selection.weights.is_empty() && selection.weights.iter().any(|w| *w <= 0.0) {
+                    return Err(format!(
+                        "Phase '{}' injection_selection weights must all be > 0",
+                        phase.name
+                    ));
+                }
+            }
+        }
+
+        for (i, injection) in self.background.iter().enumerate() {
+            if injection.r#type.is_empty() {
+                return Err(format!("Background injection {} must have a type", i));
+            }
+            validate_target_config(&injection.target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks every injection's type and parameters against `registry`, so
+    /// an unknown injector or an out-of-range parameter is caught before
+    /// `run`/`run_with_cancellation` ever starts a phase, rather than
+    /// failing partway through the scenario. Complements [`Scenario::validate`],
+    /// which only checks structural non-emptiness and has no registry to
+    /// check injector names or parameters against.
+    pub fn validate_against_registry(
+        &self,
+        registry: &chaos_core::InjectorRegistry,
+    ) -> Result<(), String> {
+        let check_injection = |injection: &InjectionConfig| -> Result<(), String> {
+            let injector = registry
+                .get(&injection.r#type)
+                .ok_or_else(|| format!("Unknown injector type '{}'", injection.r#type))?;
+
+            let params = serde_json::to_value(&injection.parameters)
+                .map_err(|e| format!("Failed to serialize parameters for '{}': {}", injection.r#type, e))?;
+
+            injector
+                .validate_params(&params)
+                .map_err(|e| format!("Invalid parameters for '{}': {}", injection.r#type, e))?;
+
+            if let Some(ramp) = &injection.ramp {
+                match injector.ramp_parameter() {
+                    Some(name) if name == ramp.parameter => {}
+                    Some(name) => {
+                        return Err(format!(
+                            "Injector '{}' only supports ramping '{}', not '{}'",
+                            injection.r#type, name, ramp.parameter
+                        ))
+                    }
+                    None => {
+                        return Err(format!(
+                            "Injector '{}' does not support ramping",
+                            injection.r#type
+                        ))
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+        for phase in &self.phases {
+            for injection in &phase.injections {
+                check_injection(injection)?;
+            }
+        }
+
+        for injection in &self.background {
+            check_injection(injection)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct ScenarioBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    seed: Option<u64>,
+    duration: Option<Duration>,
+    ramp_up: Option<Duration>,
+    phases: Vec<Phase>,
+    background: Vec<InjectionConfig>,
+    labels: HashMap<String, String>,
+    error_budget: Option<chaos_core::ErrorBudgetPolicy>,
+    abort_conditions: Option<chaos_core::AbortConditions>,
+    workloads: Vec<WorkloadConfig>,
+    probes: Vec<ProbeConfig>,
+    recovery_period: Option<Duration>,
+    injection_failure_policy: InjectionFailurePolicy,
+    before: Vec<HookConfig>,
+    after: Vec<HookConfig>,
+    notifications: NotificationConfig,
+}
+
+impl ScenarioBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn ramp_up(mut self, ramp_up: Duration) -> Self {
+        self.ramp_up = Some(ramp_up);
+        self
+    }
+
+    pub fn add_phase(mut self, phase: Phase) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    pub fn add_background(mut self, injection: InjectionConfig) -> Self {
+        self.background.push(injection);
+        self
+    }
+
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn error_budget(mut self, policy: chaos_core::ErrorBudgetPolicy) -> Self {
+        self.error_budget = Some(policy);
+        self
+    }
+
+    pub fn abort_conditions(mut self, conditions: chaos_core::AbortConditions) -> Self {
+        self.abort_conditions = Some(conditions);
+        self
+    }
+
+    pub fn add_workload(mut self, workload: WorkloadConfig) -> Self {
+        self.workloads.push(workload);
+        self
+    }
+
+    pub fn add_probe(mut self, probe: ProbeConfig) -> Self {
+        self.probes.push(probe);
+        self
+    }
+
+    pub fn recovery_period(mut self, recovery_period: Duration) -> Self {
+        self.recovery_period = Some(recovery_period);
+        self
+    }
+
+    pub fn injection_failure_policy(mut self, policy: InjectionFailurePolicy) -> Self {
+        self.injection_failure_policy = policy;
+        self
+    }
+
+    pub fn add_before_hook(mut self, hook: HookConfig) -> Self {
+        self.before.push(hook);
+        self
+    }
+
+    pub fn add_after_hook(mut self, hook: HookConfig) -> Self {
+        self.after.push(hook);
+        self
+    }
+
+    pub fn notifications(mut self, notifications: NotificationConfig) -> Self {
+        self.notifications = notifications;
+        self
+    }
+
+    pub fn build(self) -> Scenario {
+        let duration = self.duration.unwrap_or_else(|| {
+            self.phases.iter().map(|p| p.duration).sum()
+        });
+
+        Scenario {
+            name: self.name.unwrap_or_else(|| "unnamed".to_string()),
+            description: self.description,
+            seed: self.seed,
+            duration,
+            ramp_up: self.ramp_up,
+            phases: self.phases,
+            background: self.background,
+            labels: self.labels,
+            error_budget: self.error_budget,
+            abort_conditions: self.abort_conditions,
+            workloads: self.workloads,
+            probes: self.probes,
+            recovery_period: self.recovery_period,
+            injection_failure_policy: self.injection_failure_policy,
+            before: self.before,
+            after: self.after,
+            notifications: self.notifications,
+        }
+    }
+}
+
+impl Phase {
+    pub fn builder() -> PhaseBuilder {
+        PhaseBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct PhaseBuilder {
+    name: Option<String>,
+    duration: Option<Duration>,
+    injections: Vec<InjectionConfig>,
+    parallel: bool,
+    run_if: Option<String>,
+    injection_selection: Option<InjectionSelection>,
+    jitter: Option<Duration>,
+    recovery_period: Option<Duration>,
+    before: Vec<HookConfig>,
+    after: Vec<HookConfig>,
+}
+
+impl PhaseBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn add_injection(mut self, injection: InjectionConfig) -> Self {
+        self.injections.push(injection);
+        self
+    }
+
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    pub fn run_if(mut self, condition: impl Into<String>) -> Self {
+        self.run_if = Some(condition.into());
+        self
+    }
+
+    pub fn injection_selection(mut self, selection: InjectionSelection) -> Self {
+        self.injection_selection = Some(selection);
+        self
+    }
+
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    pub fn recovery_period(mut self, recovery_period: Duration) -> Self {
+        self.recovery_period = Some(recovery_period);
+        self
+    }
+
+    pub fn add_before_hook(mut self, hook: HookConfig) -> Self {
+        self.before.push(hook);
+        self
+    }
+
+    pub fn add_after_hook(mut self, hook: HookConfig) -> Self {
+        self.after.push(hook);
+        self
+    }
+
+    pub fn build(self) -> Phase {
+        Phase {
+            name: self.name.unwrap_or_else(|| "unnamed".to_string()),
+            duration: self.duration.unwrap_or(Duration::from_secs(60)),
+            injections: self.injections,
+            parallel: self.parallel,
+            run_if: self.run_if,
+            injection_selection: self.injection_selection,
+            jitter: self.jitter,
+            recovery_period: self.recovery_period,
+            before: self.before,
+            after: self.after,
+        }
+    }
+}
+
+pub(crate) mod humantime_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&humantime::format_duration(*duration).to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        humantime::parse_duration(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+mod humantime_serde_option {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(d) => serializer.serialize_some(&humantime::format_duration(*d).to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let opt = Option::<String>::deserialize(deserializer)?;
+        opt.map(|s| humantime::parse_duration(&s).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenario_builder() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(120))
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(scenario.name, "test");
+        assert_eq!(scenario.phases.len(), 1);
+    }
+
+    #[test]
+    fn test_scenario_validation() {
+        let scenario = Scenario::builder()
+            .name("valid")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .build();
+
+        assert!(scenario.validate().is_ok());
+
+        let invalid = Scenario::builder().build();
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_background_load_validation() {
+        let scenario = Scenario::builder()
+            .name("with-background")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .add_background(InjectionConfig {
+                r#type: "cpu_starvation".to_string(),
+                target: TargetConfig::default(),
+                parameters: HashMap::new(),
+                ramp: None,
+                start_after: None,
+                duration: None,
+                jitter: None,
+            })
+            .build();
+
+        assert_eq!(scenario.background.len(), 1);
+        assert!(scenario.validate().is_ok());
+
+        let invalid = Scenario::builder()
+            .name("bad-background")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .add_background(InjectionConfig {
+                r#type: String::new(),
+                target: TargetConfig::default(),
+                parameters: HashMap::new(),
+                ramp: None,
+                start_after: None,
+                duration: None,
+                jitter: None,
+            })
+            .build();
+
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_against_registry_rejects_unknown_injector() {
+        let scenario = Scenario::builder()
+            .name("unknown-injector")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .add_injection(InjectionConfig {
+                        r#type: "not_a_real_injector".to_string(),
+                        target: TargetConfig::default(),
+                        parameters: HashMap::new(),
+                        ramp: None,
+                        start_after: None,
+                        duration: None,
+                        jitter: None,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let registry = chaos_core::InjectorRegistry::with_defaults();
+        let err = scenario.validate_against_registry(&registry).unwrap_err();
+        assert!(err.contains("not_a_real_injector"));
+    }
+
+    #[test]
+    fn test_validate_against_registry_rejects_out_of_range_parameter() {
+        let mut parameters = HashMap::new();
+        parameters.insert("intensity".to_string(), serde_json::json!(5.0));
+
+        let scenario = Scenario::builder()
+            .name("bad-parameter")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .add_injection(InjectionConfig {
+                        r#type: "cpu_starvation".to_string(),
+                        target: TargetConfig::default(),
+                        parameters,
+                        ramp: None,
+                        start_after: None,
+                        duration: None,
+                        jitter: None,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let registry = chaos_core::InjectorRegistry::with_defaults();
+        assert!(scenario.validate_against_registry(&registry).is_err());
+    }
+
+    #[test]
+    fn test_validate_against_registry_accepts_known_injector_in_range() {
+        let scenario = Scenario::builder()
+            .name("with-background")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .add_background(InjectionConfig {
+                r#type: "cpu_starvation".to_string(),
+                target: TargetConfig::default(),
+                parameters: HashMap::new(),
+                ramp: None,
+                start_after: None,
+                duration: None,
+                jitter: None,
+            })
+            .build();
+
+        let registry = chaos_core::InjectorRegistry::with_defaults();
+        assert!(scenario.validate_against_registry(&registry).is_ok());
+    }
+
+    #[test]
+    fn test_baseline_scenario_is_labeled_and_has_no_injections() {
+        let scenario = Scenario::baseline("prod-west-baseline", Duration::from_secs(300)).build();
+
+        assert!(scenario.is_baseline());
+        assert_eq!(scenario.phases.len(), 1);
+        assert!(scenario.phases[0].injections.is_empty());
+        assert!(scenario.validate().is_ok());
+    }
+
+    #[test]
+    fn test_non_baseline_scenario_is_not_baseline() {
+        let scenario = Scenario::builder()
+            .name("regular")
+            .add_phase(
+                Phase::builder()
+                    .name("phase1")
+                    .duration(Duration::from_secs(60))
+                    .build(),
+            )
+            .build();
+
+        assert!(!scenario.is_baseline());
+    }
+
+    #[test]
+    fn test_target_config_group_fans_out_to_members() {
+        let target = TargetConfig {
+            members: Some(vec![
+                TargetConfig {
+                    pid: Some(1),
+                    ..Default::default()
+                },
+                TargetConfig {
+                    pid: Some(2),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }
+        .to_target()
+        .unwrap();
+
+        assert_eq!(
+            target,
+            chaos_core::Target::group([chaos_core::Target::process(1), chaos_core::Target::process(2)])
+        );
+    }
+
+    #[test]
+    fn test_target_config_group_rejects_empty_members() {
+        let target = TargetConfig {
+            members: Some(vec![]),
+            ..Default::default()
+        }
+        .to_target();
+
+        assert!(target.is_err());
+    }
+
+    #[test]
+    fn test_target_config_sample_picks_same_members_for_same_seed() {
+        let make_config = || TargetConfig {
+            members: Some(
+                (1..=10)
+                    .map(|pid| TargetConfig {
+                        pid: Some(pid),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            sample: Some(SampleConfig { percent: 0.3 }),
+            ..Default::default()
+        };
+
+        let first = make_config().to_target_with_seed(Some(42)).unwrap();
+        let second = make_config().to_target_with_seed(Some(42)).unwrap();
+
+        assert_eq!(first, second);
+        match first {
+            chaos_core::Target::Group(members) => assert_eq!(members.len(), 3),
+            other => panic!("expected a group target, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_target_config_sample_never_rounds_down_to_zero() {
+        let target = TargetConfig {
+            members: Some(vec![TargetConfig {
+                pid: Some(1),
+                ..Default::default()
+            }]),
+            sample: Some(SampleConfig { percent: 0.1 }),
+            ..Default::default()
+        }
+        .to_target_with_seed(Some(7))
+        .unwrap();
+
+        match target {
+            chaos_core::Target::Group(members) => assert_eq!(members.len(), 1),
+            other => panic!("expected a group target, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_target_config_rejects_out_of_range_percent() {
+        let target = TargetConfig {
+            members: Some(vec![TargetConfig {
+                pid: Some(1),
+                ..Default::default()
+            }]),
+            sample: Some(SampleConfig { percent: 1.5 }),
+            ..Default::default()
+        };
+
+        assert!(validate_target_config(&target).is_err());
+    }
+
+    #[test]
+    fn test_validate_target_config_rejects_sample_without_members_or_pattern() {
+        let target = TargetConfig {
+            pid: Some(1),
+            sample: Some(SampleConfig { percent: 0.5 }),
+            ..Default::default()
+        };
+
+        assert!(validate_target_config(&target).is_err());
+    }
+}
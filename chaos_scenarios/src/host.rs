@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// A snapshot of the host a scenario ran on, captured so results from
+/// different machines aren't compared as if they were apples-to-apples -
+/// especially important for latency-sensitive experiments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HostFingerprint {
+    pub os: String,
+    pub kernel_version: Option<String>,
+    pub cpu_model: Option<String>,
+    pub cpu_cores: usize,
+    pub total_memory_bytes: u64,
+    pub cgroup_version: Option<String>,
+    pub virtualization: Option<String>,
+    pub sysctls: std::collections::HashMap<String, String>,
+}
+
+const RELEVANT_SYSCTLS: &[&str] = &[
+    "/proc/sys/kernel/sched_latency_ns",
+    "/proc/sys/vm/swappiness",
+    "/proc/sys/vm/overcommit_memory",
+];
+
+impl HostFingerprint {
+    pub fn capture() -> Self {
+        use sysinfo::System;
+
+        let mut sys = System::new_all();
+        sys.refresh_all();
+
+        Self {
+            os: format!(
+                "{} {}",
+                System::name().unwrap_or_else(|| "unknown".to_string()),
+                System::os_version().unwrap_or_default()
+            ),
+            kernel_version: System::kernel_version(),
+            cpu_model: sys.cpus().first().map(|c| c.brand().to_string()),
+            cpu_cores: sys.cpus().len(),
+            total_memory_bytes: sys.total_memory(),
+            cgroup_version: detect_cgroup_version(),
+            virtualization: detect_virtualization(),
+            sysctls: read_sysctls(),
+        }
+    }
+}
+
+fn detect_cgroup_version() -> Option<String> {
+    if std::path::Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+        Some("v2".to_string())
+    } else if std::path::Path::new("/sys/fs/cgroup/memory").exists() {
+        Some("v1".to_string())
+    } else {
+        None
+    }
+}
+
+fn detect_virtualization() -> Option<String> {
+    std::fs::read_to_string("/sys/class/dmi/id/product_name")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn read_sysctls() -> std::collections::HashMap<String, String> {
+    let mut map = std::collections::HashMap::new();
+    for path in RELEVANT_SYSCTLS {
+        if let Ok(value) = std::fs::read_to_string(path) {
+            let key = path.rsplit('/').next().unwrap_or(path).to_string();
+            map.insert(key, value.trim().to_string());
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_populates_basic_fields() {
+        let fingerprint = HostFingerprint::capture();
+        assert!(fingerprint.cpu_cores > 0);
+        assert!(!fingerprint.os.is_empty());
+    }
+}
@@ -1,11 +1,32 @@
+pub mod builtin;
 pub mod config;
+pub mod host;
+pub mod lint;
+pub mod monkey;
+pub mod package;
 pub mod parser;
+pub mod plan;
+pub mod replay;
+pub mod schedule;
 pub mod scheduler;
 pub mod phase;
 pub mod runner;
+pub mod suite;
 
+pub use builtin::BuiltinScenario;
 pub use config::{Scenario, ScenarioConfig};
-pub use parser::{parse_scenario_from_file, parse_scenario_from_str};
+pub use host::HostFingerprint;
+pub use lint::{lint, LintFinding, LintSeverity};
+pub use monkey::{MonkeyAction, MonkeyConfig, MonkeyRunner, MonkeyStatus};
+pub use package::{PackageManifest, ScenarioPackage};
+pub use parser::{
+    load_scenario_matrix, parse_scenario_from_file, parse_scenario_from_file_with_vars,
+    parse_scenario_from_str, parse_scenario_from_str_with_vars, serialize_scenario,
+};
+pub use plan::{ImpactEstimate, ScenarioPlan};
+pub use replay::ExecutionRecord;
+pub use schedule::{ScheduleEntry, ScheduleFile};
 pub use scheduler::{Scheduler, SchedulingMode};
 pub use phase::Phase;
 pub use runner::{run_scenario, ScenarioRunner};
+pub use suite::{SuiteEntry, SuiteEntryResult, SuiteFile, SuiteMode, SuiteResult};
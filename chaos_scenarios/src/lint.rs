@@ -0,0 +1,354 @@
+//! Static "smell" checks for scenarios that parse and validate cleanly but
+//! are still probably not what the author meant - as opposed to
+//! [`crate::config::Scenario::validate`] and `validate_against_registry`,
+//! which reject scenarios that are outright broken, everything here is a
+//! [`LintFinding`] the author should look at but that doesn't block a run.
+
+use crate::config::{InjectionConfig, Phase, Scenario, TargetConfig};
+use std::time::Duration;
+
+/// How serious a [`LintFinding`] is. Lint never fails a scenario by itself -
+/// this only changes how loudly `chaos lint` reports it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintSeverity {
+    Info,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct LintFinding {
+    pub severity: LintSeverity,
+    /// Phase this finding is about, or `"scenario"`/`"background"` for
+    /// findings that aren't scoped to a single phase.
+    pub phase: String,
+    pub message: String,
+}
+
+/// Injectors that fight over the same network interface if applied to the
+/// same target at once - e.g. a latency injection and a packet-loss
+/// injection both racing to reconfigure the same `tc qdisc`.
+const NETWORK_INJECTORS: &[&str] = &["network_latency", "packet_loss", "tcp_reset"];
+
+/// Injectors that fight over the same process's lifecycle or memory if
+/// applied to the same target at once.
+const PROCESS_INJECTORS: &[&str] = &["process_kill", "memory_leak", "memory_pressure", "oom_killer"];
+
+/// Rough, hardcoded lower bound on how long an injector needs to actually
+/// take effect and be observed before it's torn down again. Not derived
+/// from anything measured on the host - `Injector` has no notion of setup
+/// time, so this is a conservative heuristic, not a guarantee.
+fn min_setup_time(injector_type: &str) -> Duration {
+    match injector_type {
+        "cpu_starvation" | "memory_pressure" | "oom_killer" => Duration::from_secs(5),
+        "disk_slow" | "memory_leak" => Duration::from_secs(3),
+        _ => Duration::from_secs(1),
+    }
+}
+
+/// Runs every check below against `scenario` and returns what it found, in
+/// no particular priority order - `chaos lint` decides how to present them.
+pub fn lint(scenario: &Scenario) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    check_empty_phases(scenario, &mut findings);
+    check_targetless_injections(scenario, &mut findings);
+    check_conflicting_faults(scenario, &mut findings);
+    check_short_durations(scenario, &mut findings);
+    check_missing_seed(scenario, &mut findings);
+
+    findings
+}
+
+fn check_empty_phases(scenario: &Scenario, findings: &mut Vec<LintFinding>) {
+    for phase in &scenario.phases {
+        if phase.injections.is_empty() {
+            findings.push(LintFinding {
+                severity: LintSeverity::Warning,
+                phase: phase.name.clone(),
+                message: "phase has no injections - it will just wait out its duration".to_string(),
+            });
+        }
+    }
+}
+
+fn is_targetless(target: &TargetConfig) -> bool {
+    target.pid.is_none()
+        && target.address.is_none()
+        && target.container_id.is_none()
+        && target.pattern.is_none()
+        && target.members.is_none()
+}
+
+fn check_targetless_injections(scenario: &Scenario, findings: &mut Vec<LintFinding>) {
+    let check_one = |phase_name: &str, injection: &InjectionConfig, findings: &mut Vec<LintFinding>| {
+        if is_targetless(&injection.target) {
+            findings.push(LintFinding {
+                severity: LintSeverity::Warning,
+                phase: phase_name.to_string(),
+                message: format!(
+                    "'{}' has no pid/address/container_id/pattern/members set - it will fail to resolve a target at run time",
+                    injection.r#type
+                ),
+            });
+        }
+    };
+
+    for phase in &scenario.phases {
+        for injection in &phase.injections {
+            check_one(&phase.name, injection, findings);
+        }
+    }
+    for injection in &scenario.background {
+        check_one("background", injection, findings);
+    }
+}
+
+/// A stable, best-effort key for "is this the same target" without actually
+/// resolving anything - two injections both naming the same `pid`,
+/// `address`, `container_id`, or `pattern` are treated as overlapping.
+/// `members`/`sample` targets aren't compared; a false negative there is
+/// safer than guessing which members a sampled group actually picks.
+fn target_key(target: &TargetConfig) -> Option<String> {
+    if target.sample.is_some() {
+        return None;
+    }
+    target
+        .pid
+        .map(|pid| format!("pid:{}", pid))
+        .or_else(|| target.address.clone().map(|a| format!("address:{}", a)))
+        .or_else(|| target.container_id.clone().map(|c| format!("container:{}", c)))
+        .or_else(|| target.pattern.clone().map(|p| format!("pattern:{}", p)))
+}
+
+fn fault_category(injector_type: &str) -> Option<&'static str> {
+    if NETWORK_INJECTORS.contains(&injector_type) {
+        Some("network")
+    } else if PROCESS_INJECTORS.contains(&injector_type) {
+        Some("process")
+    } else {
+        None
+    }
+}
+
+fn check_conflicting_faults(scenario: &Scenario, findings: &mut Vec<LintFinding>) {
+    for phase in &scenario.phases {
+        check_conflicting_faults_in(&phase.name, &phase.injections, findings);
+    }
+    check_conflicting_faults_in("background", &scenario.background, findings);
+}
+
+fn check_conflicting_faults_in(phase_name: &str, injections: &[InjectionConfig], findings: &mut Vec<LintFinding>) {
+    for (i, a) in injections.iter().enumerate() {
+        let (Some(key_a), Some(category_a)) = (target_key(&a.target), fault_category(&a.r#type)) else {
+            continue;
+        };
+        for b in &injections[i + 1..] {
+            let (Some(key_b), Some(category_b)) = (target_key(&b.target), fault_category(&b.r#type)) else {
+                continue;
+            };
+            if key_a == key_b && category_a == category_b && a.r#type != b.r#type {
+                findings.push(LintFinding {
+                    severity: LintSeverity::Warning,
+                    phase: phase_name.to_string(),
+                    message: format!(
+                        "'{}' and '{}' both target {} at once - conflicting {} faults",
+                        a.r#type, b.r#type, key_a, category_a
+                    ),
+                });
+            }
+        }
+    }
+}
+
+fn check_short_durations(scenario: &Scenario, findings: &mut Vec<LintFinding>) {
+    for phase in &scenario.phases {
+        for injection in &phase.injections {
+            let min = min_setup_time(&injection.r#type);
+            if phase.duration < min {
+                findings.push(LintFinding {
+                    severity: LintSeverity::Warning,
+                    phase: phase.name.clone(),
+                    message: format!(
+                        "phase duration {:?} is shorter than '{}''s typical setup time ({:?}) - it may barely take effect before the phase ends",
+                        phase.duration, injection.r#type, min
+                    ),
+                });
+            }
+        }
+    }
+}
+
+fn phase_is_randomized(phase: &Phase) -> bool {
+    phase.injection_selection.is_some()
+        || phase.jitter.is_some()
+        || phase.injections.iter().any(|i| i.target.sample.is_some())
+}
+
+fn check_missing_seed(scenario: &Scenario, findings: &mut Vec<LintFinding>) {
+    if scenario.seed.is_some() {
+        return;
+    }
+    for phase in &scenario.phases {
+        if phase_is_randomized(phase) {
+            findings.push(LintFinding {
+                severity: LintSeverity::Warning,
+                phase: phase.name.clone(),
+                message: "phase uses randomized injection_selection/jitter/sample but the scenario has no seed - runs won't be reproducible".to_string(),
+            });
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::InjectionSelection;
+    use std::collections::HashMap;
+
+    fn injection(r#type: &str, target: TargetConfig) -> InjectionConfig {
+        InjectionConfig {
+            r#type: r#type.to_string(),
+            target,
+            parameters: HashMap::new(),
+            ramp: None,
+            start_after: None,
+            duration: None,
+            jitter: None,
+        }
+    }
+
+    fn pid_target(pid: u32) -> TargetConfig {
+        TargetConfig {
+            pid: Some(pid),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_empty_phase() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_phase(Phase::builder().name("quiet").duration(Duration::from_secs(30)).build())
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("no injections")));
+    }
+
+    #[test]
+    fn flags_targetless_injection() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_background(injection("process_kill", TargetConfig::default()))
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("no pid/address")));
+    }
+
+    #[test]
+    fn flags_conflicting_network_faults_on_same_target() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_phase(
+                Phase::builder()
+                    .name("storm")
+                    .duration(Duration::from_secs(60))
+                    .parallel(true)
+                    .add_injection(injection("network_latency", pid_target(42)))
+                    .add_injection(injection("packet_loss", pid_target(42)))
+                    .build(),
+            )
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("conflicting network faults")));
+    }
+
+    #[test]
+    fn flags_short_duration_for_slow_setup_injector() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_phase(
+                Phase::builder()
+                    .name("blip")
+                    .duration(Duration::from_millis(500))
+                    .add_injection(injection("oom_killer", pid_target(1)))
+                    .build(),
+            )
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("typical setup time")));
+    }
+
+    #[test]
+    fn flags_missing_seed_for_randomized_selection() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_phase(
+                Phase::builder()
+                    .name("mixed")
+                    .duration(Duration::from_secs(60))
+                    .add_injection(injection("process_kill", pid_target(1)))
+                    .injection_selection(InjectionSelection {
+                        count: 1,
+                        weights: Vec::new(),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("no seed")));
+    }
+
+    #[test]
+    fn clean_scenario_has_no_findings() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .seed(7)
+            .duration(Duration::from_secs(60))
+            .add_phase(
+                Phase::builder()
+                    .name("steady")
+                    .duration(Duration::from_secs(60))
+                    .add_injection(injection("cpu_starvation", pid_target(1)))
+                    .build(),
+            )
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.is_empty(), "unexpected findings: {:?}", findings);
+    }
+
+    #[test]
+    fn same_pattern_target_still_key_matches() {
+        let target = TargetConfig {
+            pattern: Some("worker".to_string()),
+            ..Default::default()
+        };
+        let scenario = Scenario::builder()
+            .name("test")
+            .duration(Duration::from_secs(60))
+            .add_phase(
+                Phase::builder()
+                    .name("sampled")
+                    .duration(Duration::from_secs(60))
+                    .parallel(true)
+                    .add_injection(injection("network_latency", target.clone()))
+                    .add_injection(injection("packet_loss", target))
+                    .build(),
+            )
+            .build();
+
+        let findings = lint(&scenario);
+        assert!(findings.iter().any(|f| f.message.contains("conflicting network faults")));
+    }
+}
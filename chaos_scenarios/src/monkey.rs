@@ -0,0 +1,411 @@
+use crate::config::TargetConfig;
+use chaos_core::Executor;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use tracing::{info, warn};
+
+fn default_injection_ttl() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn default_max_concurrent() -> usize {
+    1
+}
+
+/// Policy limits for a chaos monkey run: which injectors it's allowed to
+/// pick from, which targets it's allowed to pick against, how often it
+/// acts, and how long any one fault is allowed to stay active before it's
+/// considered expired. Unlike a [`crate::config::Scenario`], a monkey has
+/// no phases or fixed duration - it runs until stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonkeyConfig {
+    pub name: String,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub targets: Vec<TargetConfig>,
+    pub allowed_injectors: Vec<String>,
+    #[serde(with = "crate::config::humantime_serde")]
+    pub min_interval: Duration,
+    #[serde(with = "crate::config::humantime_serde")]
+    pub max_interval: Duration,
+    /// Every fault the monkey applies carries this TTL, so a run that's
+    /// killed uncleanly can never leave more than one TTL's worth of blast
+    /// radius behind - the same safety net `Executor::inject_with_ttl`
+    /// gives any other caller.
+    #[serde(
+        with = "crate::config::humantime_serde",
+        default = "default_injection_ttl"
+    )]
+    pub injection_ttl: Duration,
+    /// Maximum number of faults the monkey will have active at once.
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent: usize,
+    /// Varies how long each fault is intentionally kept active, instead of
+    /// every fault living for the fixed `injection_ttl`. Still capped by
+    /// `injection_ttl` - a monkey never intentionally outlives its own
+    /// safety net.
+    #[serde(default)]
+    pub duration: Option<DurationRange>,
+    /// Varies one numeric config field of whichever injector gets picked,
+    /// instead of every fault using that injector's default intensity.
+    /// Ignored by an injector that doesn't have `parameter` as a field.
+    #[serde(default)]
+    pub intensity: Option<IntensityRange>,
+}
+
+/// Inclusive bounds a chaos monkey samples a fault's active duration from,
+/// uniformly, using the same seeded RNG as its injector/target choices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationRange {
+    #[serde(with = "crate::config::humantime_serde")]
+    pub min: Duration,
+    #[serde(with = "crate::config::humantime_serde")]
+    pub max: Duration,
+}
+
+/// Inclusive bounds a chaos monkey samples a fault's numeric intensity
+/// parameter from, uniformly. `parameter` names the injector config field
+/// this drives (e.g. `intensity` for `cpu_starvation`, `rate` for
+/// `packet_loss`) - the same convention `RampConfig::parameter` uses for a
+/// scenario file's own ramps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntensityRange {
+    pub parameter: String,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl MonkeyConfig {
+    pub async fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path).await?;
+        let extension = path.extension().and_then(|s| s.to_str());
+
+        let config: Self = match extension {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&contents)?,
+            Some("toml") => toml::from_str(&contents)?,
+            Some("json") => serde_json::from_str(&contents)?,
+            _ => anyhow::bail!("Unsupported file format. Use .yaml, .yml, .toml, or .json"),
+        };
+
+        if config.targets.is_empty() {
+            anyhow::bail!("Monkey config '{}' has no targets", config.name);
+        }
+        if config.allowed_injectors.is_empty() {
+            anyhow::bail!("Monkey config '{}' has no allowed_injectors", config.name);
+        }
+
+        Ok(config)
+    }
+}
+
+/// One fault the monkey has applied, kept around for status reporting.
+#[derive(Debug, Clone, Serialize)]
+pub struct MonkeyAction {
+    pub injector: String,
+    pub target: String,
+    pub injection_id: String,
+    pub applied_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Point-in-time snapshot of a running monkey. Every action is already
+/// logged through the same tracing setup `chaos run` uses - console plus a
+/// per-run JSON file under `--log-dir` - so that log is today's audit
+/// trail. `MonkeyRunner::status` exists so a future daemon mode can serve
+/// this same snapshot over an HTTP status endpoint instead of requiring
+/// operators to tail the log file; no such server exists yet.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct MonkeyStatus {
+    pub actions_taken: u64,
+    pub active: Vec<MonkeyAction>,
+    pub last_action: Option<MonkeyAction>,
+}
+
+/// Runs a [`MonkeyConfig`] indefinitely: picks a random (seeded) allowed
+/// injector and target, applies it with the policy's TTL, waits a random
+/// interval, and repeats until cancelled.
+pub struct MonkeyRunner {
+    config: MonkeyConfig,
+    executor: Executor,
+    rng: StdRng,
+    status: MonkeyStatus,
+}
+
+impl MonkeyRunner {
+    pub fn new(config: MonkeyConfig, executor: Executor) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Self {
+            config,
+            executor,
+            rng,
+            status: MonkeyStatus::default(),
+        }
+    }
+
+    pub fn status(&self) -> &MonkeyStatus {
+        &self.status
+    }
+
+    /// Runs until `cancel` fires. Never returns an error - a single failed
+    /// action (unresolvable target, injector rejection) is logged and the
+    /// monkey just tries again on the next tick, since a transient failure
+    /// picking one random fault shouldn't end the whole run.
+    pub async fn run(&mut self, cancel: tokio_util::sync::CancellationToken) {
+        loop {
+            if cancel.is_cancelled() {
+                break;
+            }
+
+            self.prune_expired();
+
+            if self.status.active.len() < self.config.max_concurrent {
+                if let Err(e) = self.act().await {
+                    warn!("Chaos monkey action failed: {}", e);
+                }
+            }
+
+            let interval = self.next_interval();
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = cancel.cancelled() => break,
+            }
+        }
+    }
+
+    fn next_interval(&mut self) -> Duration {
+        if self.config.max_interval <= self.config.min_interval {
+            return self.config.min_interval;
+        }
+
+        let span_ms = (self.config.max_interval - self.config.min_interval).as_millis() as u64;
+        self.config.min_interval + Duration::from_millis(self.rng.gen_range(0..=span_ms))
+    }
+
+    /// Drops faults whose TTL has elapsed from `active` so `max_concurrent`
+    /// reflects faults the executor still has live, not ones it's already
+    /// auto-removed in the background.
+    fn prune_expired(&mut self) {
+        let ttl = self.config.injection_ttl;
+        let now = chrono::Utc::now();
+
+        self.status.active.retain(|action| {
+            now.signed_duration_since(action.applied_at)
+                .to_std()
+                .map(|age| age < ttl)
+                .unwrap_or(true)
+        });
+    }
+
+    /// Samples this action's TTL from `config.duration`, if set, clamped to
+    /// `injection_ttl` so a wide range can never bypass the safety cap.
+    fn sample_duration(&mut self) -> Duration {
+        let Some(range) = &self.config.duration else {
+            return self.config.injection_ttl;
+        };
+
+        let sampled = if range.max <= range.min {
+            range.min
+        } else {
+            let span_ms = (range.max - range.min).as_millis() as u64;
+            range.min + Duration::from_millis(self.rng.gen_range(0..=span_ms))
+        };
+
+        sampled.min(self.config.injection_ttl)
+    }
+
+    /// Samples this action's intensity parameter from `config.intensity`,
+    /// if set, as the single-field params object `inject_with_params_and_ttl`
+    /// applies on top of the picked injector's default config.
+    fn sample_intensity_params(&mut self) -> serde_json::Value {
+        let Some(range) = &self.config.intensity else {
+            return serde_json::Value::Null;
+        };
+
+        let value = if range.max <= range.min {
+            range.min
+        } else {
+            self.rng.gen_range(range.min..=range.max)
+        };
+
+        serde_json::json!({ range.parameter.clone(): value })
+    }
+
+    async fn act(&mut self) -> chaos_core::Result<()> {
+        let injector_name = self
+            .config
+            .allowed_injectors
+            .choose(&mut self.rng)
+            .expect("allowed_injectors is non-empty, checked at load time")
+            .clone();
+
+        let target_config = self
+            .config
+            .targets
+            .choose(&mut self.rng)
+            .expect("targets is non-empty, checked at load time")
+            .clone();
+
+        let target = target_config
+            .to_target_with_seed(self.config.seed)
+            .map_err(chaos_core::ChaosError::InvalidConfig)?;
+
+        let ttl = self.sample_duration();
+        let params = self.sample_intensity_params();
+
+        let handle = self
+            .executor
+            .inject_with_params_and_ttl(&injector_name, &target, &params, ttl)
+            .await?;
+
+        info!(
+            injector = %injector_name,
+            target = %target.description(),
+            injection_id = %handle.id,
+            "chaos monkey applied a fault"
+        );
+
+        self.status.actions_taken += 1;
+        let action = MonkeyAction {
+            injector: injector_name,
+            target: target.description(),
+            injection_id: handle.id,
+            applied_at: handle.started_at,
+        };
+        self.status.active.push(action.clone());
+        self.status.last_action = Some(action);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(seed: u64) -> MonkeyConfig {
+        MonkeyConfig {
+            name: "test-monkey".to_string(),
+            seed: Some(seed),
+            targets: vec![TargetConfig {
+                pid: Some(1),
+                ..Default::default()
+            }],
+            allowed_injectors: vec!["cpu_starvation".to_string()],
+            min_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(1),
+            injection_ttl: Duration::from_secs(60),
+            max_concurrent: 1,
+            duration: None,
+            intensity: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_rejects_empty_targets() {
+        let mut config = config(1);
+        config.targets.clear();
+        let json = serde_json::to_string(&config).unwrap();
+        let path = std::env::temp_dir().join(format!("chaos-monkey-empty-targets-{}.json", std::process::id()));
+        tokio::fs::write(&path, json).await.unwrap();
+        let loaded = MonkeyConfig::load(&path).await;
+        let _ = tokio::fs::remove_file(&path).await;
+        assert!(loaded.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_act_records_the_applied_fault_as_active() {
+        let mut config = config(42);
+        config.injection_ttl = Duration::from_millis(10);
+        let executor = Executor::with_defaults();
+        let mut runner = MonkeyRunner::new(config, executor);
+
+        runner.act().await.unwrap();
+        assert_eq!(runner.status().active.len(), 1);
+        assert_eq!(runner.status().actions_taken, 1);
+
+        // Let the injection_ttl auto-expiry actually fire before the test's
+        // runtime is torn down, same as test_prune_expired_drops_old_actions
+        // below - otherwise the cpu_starvation burner it started never gets
+        // its stop signal and the test binary stalls tearing down the
+        // runtime's blocking threads.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    #[tokio::test]
+    async fn test_prune_expired_drops_old_actions() {
+        let mut config = config(7);
+        config.injection_ttl = Duration::from_millis(10);
+        let executor = Executor::with_defaults();
+        let mut runner = MonkeyRunner::new(config, executor);
+
+        runner.act().await.unwrap();
+        assert_eq!(runner.status().active.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        runner.prune_expired();
+        assert!(runner.status().active.is_empty());
+    }
+
+    #[test]
+    fn test_seeded_runs_pick_the_same_sequence() {
+        let mut a = StdRng::seed_from_u64(99);
+        let mut b = StdRng::seed_from_u64(99);
+        let injectors = vec!["network_latency".to_string(), "cpu_starvation".to_string()];
+
+        assert_eq!(injectors.choose(&mut a), injectors.choose(&mut b));
+    }
+
+    #[test]
+    fn test_sample_duration_is_clamped_to_injection_ttl() {
+        let mut config = config(1);
+        config.injection_ttl = Duration::from_secs(5);
+        config.duration = Some(DurationRange {
+            min: Duration::from_secs(1),
+            max: Duration::from_secs(100),
+        });
+        let mut runner = MonkeyRunner::new(config, Executor::with_defaults());
+
+        for _ in 0..20 {
+            let sampled = runner.sample_duration();
+            assert!(sampled >= Duration::from_secs(1) && sampled <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn test_sample_duration_defaults_to_injection_ttl_when_unset() {
+        let mut config = config(1);
+        config.injection_ttl = Duration::from_secs(30);
+        let mut runner = MonkeyRunner::new(config, Executor::with_defaults());
+
+        assert_eq!(runner.sample_duration(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_sample_intensity_params_is_null_when_unset() {
+        let mut runner = MonkeyRunner::new(config(1), Executor::with_defaults());
+        assert!(runner.sample_intensity_params().is_null());
+    }
+
+    #[test]
+    fn test_sample_intensity_params_stays_within_bounds() {
+        let mut config = config(1);
+        config.intensity = Some(IntensityRange {
+            parameter: "intensity".to_string(),
+            min: 0.2,
+            max: 0.8,
+        });
+        let mut runner = MonkeyRunner::new(config, Executor::with_defaults());
+
+        for _ in 0..20 {
+            let params = runner.sample_intensity_params();
+            let value = params["intensity"].as_f64().unwrap();
+            assert!((0.2..=0.8).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::config::Scenario;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Documentation front-matter carried alongside a packaged scenario.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PackageDocs {
+    pub summary: Option<String>,
+    pub owner: Option<String>,
+    pub runbook_url: Option<String>,
+}
+
+/// On-disk manifest for a `.chaospkg` scenario package (`chaospkg.toml`).
+///
+/// A package is a directory rooted at the manifest, bundling the scenario
+/// with the assets it references — templates, probe scripts, and policy
+/// exceptions — so it can be shared and installed as a single unit instead
+/// of a bare YAML file with dangling script paths.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageManifest {
+    pub name: String,
+    pub version: String,
+
+    /// Path to the scenario file, relative to the package root.
+    pub scenario: PathBuf,
+
+    /// Probe scripts invoked by scenario hooks, relative to the package root.
+    #[serde(default)]
+    pub probes: Vec<PathBuf>,
+
+    /// Approved blast-radius/policy overrides, relative to the package root.
+    #[serde(default)]
+    pub policy_exceptions: Vec<PathBuf>,
+
+    #[serde(default)]
+    pub docs: PackageDocs,
+}
+
+/// A loaded `.chaospkg` scenario package: its manifest, parsed scenario, and
+/// the root directory it was loaded from.
+pub struct ScenarioPackage {
+    pub manifest: PackageManifest,
+    pub scenario: Scenario,
+    pub root: PathBuf,
+}
+
+impl ScenarioPackage {
+    /// Load a `.chaospkg` directory. Probe scripts and policy exceptions
+    /// referenced in the manifest are checked for presence but not executed
+    /// here — running them is the scenario runner's responsibility once
+    /// hooks are wired up.
+    pub async fn load(root: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let root = root.into();
+        let manifest_path = root.join("chaospkg.toml");
+
+        let manifest_contents = tokio::fs::read_to_string(&manifest_path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", manifest_path.display(), e))?;
+        let manifest: PackageManifest = toml::from_str(&manifest_contents)?;
+
+        let scenario_path = root.join(&manifest.scenario);
+        let scenario = crate::parser::parse_scenario_from_file(&scenario_path).await?;
+
+        let package = Self {
+            manifest,
+            scenario,
+            root,
+        };
+        package.validate_assets()?;
+
+        Ok(package)
+    }
+
+    /// Validate that every asset the manifest references actually exists
+    /// relative to the package root, so a package can't be "installed" with
+    /// dangling probe/policy paths.
+    pub fn validate_assets(&self) -> anyhow::Result<()> {
+        for path in self
+            .manifest
+            .probes
+            .iter()
+            .chain(self.manifest.policy_exceptions.iter())
+        {
+            let full = self.root.join(path);
+            if !full.exists() {
+                return Err(anyhow::anyhow!(
+                    "Missing packaged asset: {}",
+                    full.display()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Install this package into a library directory by copying its root
+    /// into `library_dir/<name>-<version>`.
+    pub async fn install(&self, library_dir: impl Into<PathBuf>) -> anyhow::Result<PathBuf> {
+        let dest = library_dir
+            .into()
+            .join(format!("{}-{}", self.manifest.name, self.manifest.version));
+
+        copy_dir_recursive(&self.root, &dest).await?;
+
+        Ok(dest)
+    }
+}
+
+fn copy_dir_recursive<'a>(
+    src: &'a std::path::Path,
+    dest: &'a std::path::Path,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = anyhow::Result<()>> + 'a>> {
+    Box::pin(async move {
+        tokio::fs::create_dir_all(dest).await?;
+
+        let mut entries = tokio::fs::read_dir(src).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let src_path = entry.path();
+            let dest_path = dest.join(entry.file_name());
+
+            if entry.file_type().await?.is_dir() {
+                copy_dir_recursive(&src_path, &dest_path).await?;
+            } else {
+                tokio::fs::copy(&src_path, &dest_path).await?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_parses_with_defaults() {
+        let toml = r#"
+name = "checkout-outage-drill"
+version = "1.0.0"
+scenario = "scenario.yaml"
+
+[docs]
+summary = "Exercises checkout's dependency on the payments service"
+owner = "payments-team"
+"#;
+
+        let manifest: PackageManifest = toml::from_str(toml).unwrap();
+        assert_eq!(manifest.name, "checkout-outage-drill");
+        assert!(manifest.probes.is_empty());
+        assert!(manifest.policy_exceptions.is_empty());
+        assert_eq!(manifest.docs.owner, Some("payments-team".to_string()));
+    }
+
+    #[test]
+    fn test_manifest_carries_probes_and_policy_exceptions() {
+        let toml = r#"
+name = "checkout-outage-drill"
+version = "1.0.0"
+scenario = "scenario.yaml"
+probes = ["probes/check_latency.sh"]
+policy_exceptions = ["policy/payments-team-exception.yaml"]
+"#;
+
+        let manifest: PackageManifest = toml::from_str(toml).unwrap();
+        assert_eq!(manifest.probes, vec![PathBuf::from("probes/check_latency.sh")]);
+        assert_eq!(
+            manifest.policy_exceptions,
+            vec![PathBuf::from("policy/payments-team-exception.yaml")]
+        );
+    }
+}
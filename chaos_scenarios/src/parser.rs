@@ -1,13 +1,179 @@
 use crate::config::{Scenario, ScenarioConfig};
 use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Declares the `vars:` a scenario file can be templated with. Only the
+/// `vars` field is typed - everything else in the document (including
+/// fields that still hold unresolved `${NAME}` placeholders) is left alone,
+/// since it isn't valid to deserialize into `Scenario` yet.
+#[derive(Debug, Default, Deserialize)]
+struct RawVars {
+    #[serde(default)]
+    vars: HashMap<String, String>,
+}
+
+/// TOML wraps the whole scenario (and therefore its `vars:` table) under a
+/// top-level `[scenario]` table, matching `ScenarioConfig`.
+#[derive(Debug, Default, Deserialize)]
+struct RawVarsToml {
+    #[serde(default)]
+    scenario: RawVars,
+}
+
+/// Best-effort extraction of a scenario file's declared `vars:`. Parse
+/// failures are swallowed here - with an empty or unresolved document this
+/// just yields no vars, and the real parse error surfaces later from
+/// `parse_yaml`/`parse_toml`/`parse_json` once substitution has run.
+fn extract_vars(content: &str, extension: Option<&str>) -> HashMap<String, String> {
+    match extension {
+        Some("toml") => toml::from_str::<RawVarsToml>(content)
+            .map(|raw| raw.scenario.vars)
+            .unwrap_or_default(),
+        Some("json") => serde_json::from_str::<RawVars>(content)
+            .map(|raw| raw.vars)
+            .unwrap_or_default(),
+        _ => serde_yaml::from_str::<RawVars>(content)
+            .map(|raw| raw.vars)
+            .unwrap_or_default(),
+    }
+}
+
+/// Replaces every `${NAME}` placeholder in `content` with its value from
+/// `vars`, erroring on any placeholder left undefined so a typo in a
+/// variable name fails loudly instead of producing a scenario with a
+/// literal `${...}` in one of its fields.
+fn substitute_vars(content: &str, vars: &HashMap<String, String>) -> Result<String> {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            anyhow::bail!("Unterminated ${{...}} placeholder in scenario file");
+        };
+        let name = &rest[start + 2..start + end];
+        let value = vars
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Undefined scenario template variable: ${{{}}}", name))?;
+        result.push_str(value);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Merges a scenario file's own `vars:` section with CLI `--set` overrides
+/// (which win on conflict) and substitutes the result into `content`.
+fn resolve_vars(
+    content: &str,
+    extension: Option<&str>,
+    overrides: &HashMap<String, String>,
+) -> Result<String> {
+    let mut vars = extract_vars(content, extension);
+    vars.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+    substitute_vars(content, &vars)
+}
+
+/// Declares the `matrix:` axes a scenario file can be swept over, each
+/// resolved the same way a `${NAME}` template variable is.
+#[derive(Debug, Default, Deserialize)]
+struct RawMatrix {
+    #[serde(default)]
+    matrix: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawMatrixToml {
+    #[serde(default)]
+    scenario: RawMatrix,
+}
+
+/// Best-effort extraction of a scenario file's declared `matrix:`, mirroring
+/// [`extract_vars`].
+fn extract_matrix(content: &str, extension: Option<&str>) -> HashMap<String, Vec<String>> {
+    match extension {
+        Some("toml") => toml::from_str::<RawMatrixToml>(content)
+            .map(|raw| raw.scenario.matrix)
+            .unwrap_or_default(),
+        Some("json") => serde_json::from_str::<RawMatrix>(content)
+            .map(|raw| raw.matrix)
+            .unwrap_or_default(),
+        _ => serde_yaml::from_str::<RawMatrix>(content)
+            .map(|raw| raw.matrix)
+            .unwrap_or_default(),
+    }
+}
+
+/// Cartesian-expands a `matrix:` section into one vars-map per combination.
+/// An empty matrix expands to a single empty combination, so a file with no
+/// `matrix:` section is just a one-combination sweep.
+fn expand_matrix(matrix: &HashMap<String, Vec<String>>) -> Vec<HashMap<String, String>> {
+    let mut combinations = vec![HashMap::new()];
+    for (axis, values) in matrix {
+        let mut expanded = Vec::with_capacity(combinations.len() * values.len());
+        for combination in &combinations {
+            for value in values {
+                let mut next = combination.clone();
+                next.insert(axis.clone(), value.clone());
+                expanded.push(next);
+            }
+        }
+        combinations = expanded;
+    }
+    combinations
+}
+
+/// Expands a scenario file's `matrix:` section into one resolved `Scenario`
+/// per combination of axis values, alongside the axis values used to
+/// produce it (for labeling a comparative report). `overrides` (`--set`)
+/// win over the file's own `vars:`, and a combination's own axis values win
+/// over both, so a sweep axis can't silently be pinned by an unrelated
+/// override.
+pub async fn load_scenario_matrix(
+    path: impl AsRef<Path>,
+    overrides: &HashMap<String, String>,
+) -> Result<Vec<(HashMap<String, String>, Scenario)>> {
+    let path = path.as_ref();
+    let contents = tokio::fs::read_to_string(path).await?;
+    let extension = path.extension().and_then(|s| s.to_str());
+
+    let matrix = extract_matrix(&contents, extension);
+    let mut combinations = Vec::new();
+    for axis_values in expand_matrix(&matrix) {
+        let mut vars = overrides.clone();
+        vars.extend(axis_values.iter().map(|(k, v)| (k.clone(), v.clone())));
+        let resolved = resolve_vars(&contents, extension, &vars)?;
+
+        let scenario = match extension {
+            Some("yaml") | Some("yml") => parse_yaml(&resolved),
+            Some("toml") => parse_toml(&resolved),
+            Some("json") => parse_json(&resolved),
+            _ => Err(anyhow::anyhow!(
+                "Unsupported file format. Use .yaml, .yml, .toml, or .json"
+            )),
+        }?;
+
+        combinations.push((axis_values, scenario));
+    }
+
+    Ok(combinations)
+}
+
 pub async fn parse_scenario_from_file(path: impl AsRef<Path>) -> Result<Scenario> {
+    parse_scenario_from_file_with_vars(path, &HashMap::new()).await
+}
+
+pub async fn parse_scenario_from_file_with_vars(
+    path: impl AsRef<Path>,
+    overrides: &HashMap<String, String>,
+) -> Result<Scenario> {
     let path = path.as_ref();
     let contents = tokio::fs::read_to_string(path).await?;
-    
+
     let extension = path.extension().and_then(|s| s.to_str());
-    
+    let contents = resolve_vars(&contents, extension, overrides)?;
+
     match extension {
         Some("yaml") | Some("yml") => parse_yaml(&contents),
         Some("toml") => parse_toml(&contents),
@@ -19,14 +185,57 @@ pub async fn parse_scenario_from_file(path: impl AsRef<Path>) -> Result<Scenario
 }
 
 pub fn parse_scenario_from_str(content: &str, format: &str) -> Result<Scenario> {
+    parse_scenario_from_str_with_vars(content, format, &HashMap::new())
+}
+
+pub fn parse_scenario_from_str_with_vars(
+    content: &str,
+    format: &str,
+    overrides: &HashMap<String, String>,
+) -> Result<Scenario> {
+    let format = format.to_lowercase();
+    let extension = match format.as_str() {
+        "yml" => Some("yaml"),
+        other => Some(other),
+    };
+    let content = resolve_vars(content, extension, overrides)?;
+
+    match format.as_str() {
+        "yaml" | "yml" => parse_yaml(&content),
+        "toml" => parse_toml(&content),
+        "json" => parse_json(&content),
+        _ => Err(anyhow::anyhow!("Unsupported format: {}", format)),
+    }
+}
+
+/// Serialize a `Scenario` back into `format`'s on-disk representation, using
+/// the same canonical model `parse_scenario_from_str` reads from. Used by
+/// `chaos convert` to move scenarios between YAML, TOML, and JSON without
+/// hand-editing.
+pub fn serialize_scenario(scenario: &Scenario, format: &str) -> Result<String> {
     match format.to_lowercase().as_str() {
-        "yaml" | "yml" => parse_yaml(content),
-        "toml" => parse_toml(content),
-        "json" => parse_json(content),
+        "yaml" | "yml" => serialize_yaml(scenario),
+        "toml" => serialize_toml(scenario),
+        "json" => serialize_json(scenario),
         _ => Err(anyhow::anyhow!("Unsupported format: {}", format)),
     }
 }
 
+fn serialize_yaml(scenario: &Scenario) -> Result<String> {
+    Ok(serde_yaml::to_string(scenario)?)
+}
+
+fn serialize_toml(scenario: &Scenario) -> Result<String> {
+    let config = ScenarioConfig {
+        scenario: scenario.clone(),
+    };
+    Ok(toml::to_string_pretty(&config)?)
+}
+
+fn serialize_json(scenario: &Scenario) -> Result<String> {
+    Ok(serde_json::to_string_pretty(scenario)?)
+}
+
 fn parse_yaml(content: &str) -> Result<Scenario> {
     let scenario: Scenario = serde_yaml::from_str(content)?;
     scenario.validate().map_err(|e| anyhow::anyhow!(e))?;
@@ -101,4 +310,117 @@ injections = []
         let scenario = parse_json(json).unwrap();
         assert_eq!(scenario.name, "test_scenario");
     }
+
+    #[test]
+    fn test_convert_yaml_to_toml_roundtrip() {
+        let yaml = r#"
+name: "test_scenario"
+duration: 120s
+phases:
+  - name: "phase1"
+    duration: 60s
+    injections: []
+"#;
+
+        let scenario = parse_yaml(yaml).unwrap();
+        let toml = serialize_scenario(&scenario, "toml").unwrap();
+        let reparsed = parse_toml(&toml).unwrap();
+
+        assert_eq!(reparsed.name, scenario.name);
+        assert_eq!(reparsed.phases.len(), scenario.phases.len());
+    }
+
+    #[test]
+    fn test_convert_rejects_unknown_format() {
+        let scenario = parse_yaml(
+            "name: \"test\"\nduration: 10s\nphases:\n  - name: \"phase1\"\n    duration: 5s\n    injections: []\n",
+        )
+        .unwrap();
+        assert!(serialize_scenario(&scenario, "xml").is_err());
+    }
+
+    #[test]
+    fn test_parse_str_with_vars_substitutes_declared_and_override_vars() {
+        let yaml = r#"
+vars:
+  host: "localhost"
+name: "test_${host}_${env}"
+duration: 10s
+phases:
+  - name: "phase1"
+    duration: 5s
+    injections: []
+"#;
+        let mut overrides = HashMap::new();
+        overrides.insert("env".to_string(), "staging".to_string());
+
+        let scenario = parse_scenario_from_str_with_vars(yaml, "yaml", &overrides).unwrap();
+        assert_eq!(scenario.name, "test_localhost_staging");
+    }
+
+    #[test]
+    fn test_parse_str_with_vars_override_wins_over_declared_var() {
+        let yaml = r#"
+vars:
+  host: "localhost"
+name: "test_${host}"
+duration: 10s
+phases:
+  - name: "phase1"
+    duration: 5s
+    injections: []
+"#;
+        let mut overrides = HashMap::new();
+        overrides.insert("host".to_string(), "prod.internal".to_string());
+
+        let scenario = parse_scenario_from_str_with_vars(yaml, "yaml", &overrides).unwrap();
+        assert_eq!(scenario.name, "test_prod.internal");
+    }
+
+    #[test]
+    fn test_parse_str_with_vars_rejects_undefined_placeholder() {
+        let yaml = "name: \"test_${missing}\"\nduration: 10s\nphases: []\n";
+        assert!(parse_scenario_from_str_with_vars(yaml, "yaml", &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_expand_matrix_produces_the_cartesian_product() {
+        let mut matrix = HashMap::new();
+        matrix.insert("latency".to_string(), vec!["50ms".to_string(), "200ms".to_string()]);
+        matrix.insert("loss".to_string(), vec!["0%".to_string(), "5%".to_string()]);
+
+        let combinations = expand_matrix(&matrix);
+        assert_eq!(combinations.len(), 4);
+        assert!(combinations
+            .iter()
+            .all(|c| c.get("latency").is_some() && c.get("loss").is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_load_scenario_matrix_expands_axes_into_separate_scenarios() {
+        let yaml = r#"
+matrix:
+  latency: ["50ms", "200ms"]
+name: "sweep_${latency}"
+duration: 10s
+phases:
+  - name: "phase1"
+    duration: 5s
+    injections: []
+"#;
+        let path = std::env::temp_dir().join(format!(
+            "chaos_matrix_test_{}_{}.yaml",
+            std::process::id(),
+            "load_scenario_matrix_expands_axes"
+        ));
+        tokio::fs::write(&path, yaml).await.unwrap();
+
+        let combinations = load_scenario_matrix(&path, &HashMap::new()).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(combinations.len(), 2);
+        let mut names: Vec<_> = combinations.iter().map(|(_, s)| s.name.clone()).collect();
+        names.sort();
+        assert_eq!(names, vec!["sweep_200ms", "sweep_50ms"]);
+    }
 }
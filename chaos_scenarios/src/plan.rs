@@ -0,0 +1,258 @@
+use crate::{
+    config::{InjectionConfig, Scenario, TargetConfig},
+    host::HostFingerprint,
+};
+use chaos_core::PatternSelection;
+use serde::{Deserialize, Serialize};
+
+/// Estimated blast radius of a single configured injection, derived from its
+/// parameters and facts discovered about the host - without actually
+/// running it. Dimensions that don't apply to a given injection type are
+/// left `None` rather than guessed at.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImpactEstimate {
+    pub injection_type: String,
+    pub target: String,
+    pub added_latency_ms: Option<f64>,
+    pub traffic_affected_pct: Option<f64>,
+    pub processes_affected: Option<u64>,
+    pub disk_bytes_to_fill: Option<u64>,
+    /// Free-text caveats, e.g. why a dimension couldn't be estimated.
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhasePlan {
+    pub name: String,
+    #[serde(with = "crate::config::humantime_serde")]
+    pub duration: std::time::Duration,
+    pub estimates: Vec<ImpactEstimate>,
+}
+
+/// A dry-run impact summary for an entire scenario, built without applying
+/// any injection, so reviewers can assess risk before a real run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioPlan {
+    pub scenario_name: String,
+    pub host: HostFingerprint,
+    pub background: Vec<ImpactEstimate>,
+    pub phases: Vec<PhasePlan>,
+}
+
+impl ScenarioPlan {
+    /// Build a plan for `scenario` against `host`'s discovered facts (CPU
+    /// count, live process table, mounted disks). Never fails: injections
+    /// this can't model quantitatively just come back with `None` fields
+    /// and an explanatory note.
+    pub fn build(scenario: &Scenario, host: &HostFingerprint) -> Self {
+        Self {
+            scenario_name: scenario.name.clone(),
+            host: host.clone(),
+            background: scenario.background.iter().map(estimate_injection).collect(),
+            phases: scenario
+                .phases
+                .iter()
+                .map(|phase| PhasePlan {
+                    name: phase.name.clone(),
+                    duration: phase.duration,
+                    estimates: phase.injections.iter().map(estimate_injection).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn param_f64(injection: &InjectionConfig, key: &str) -> Option<f64> {
+    injection.parameters.get(key).and_then(|v| v.as_f64())
+}
+
+/// Deserialize a single flattened parameter as `T`, falling back to
+/// `default` if it's absent or doesn't match - the parameters map is
+/// untyped JSON, so a missing or malformed field should degrade gracefully
+/// rather than fail the whole plan.
+fn param_or<T: serde::de::DeserializeOwned>(injection: &InjectionConfig, key: &str, default: T) -> T {
+    injection
+        .parameters
+        .get(key)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or(default)
+}
+
+fn describe_target(target: &TargetConfig) -> String {
+    if let Some(pid) = target.pid {
+        format!("pid {}", pid)
+    } else if let Some(addr) = &target.address {
+        format!("address {}", addr)
+    } else if let Some(id) = &target.container_id {
+        format!("container {}", id)
+    } else if let Some(pattern) = &target.pattern {
+        format!("pattern '{}'", pattern)
+    } else {
+        "unspecified".to_string()
+    }
+}
+
+/// Count currently-running processes matching `pattern`, treating it the
+/// same way `Target::exists` does - a substring match against process
+/// names. Used to turn a fuzzy `ProcessPattern` target into a concrete
+/// "processes affected" number at plan time.
+fn count_matching_processes(pattern: &str) -> u64 {
+    use sysinfo::System;
+
+    let mut sys = System::new_all();
+    sys.refresh_processes();
+    sys.processes()
+        .values()
+        .filter(|p| p.name().contains(pattern))
+        .count() as u64
+}
+
+fn estimate_injection(injection: &InjectionConfig) -> ImpactEstimate {
+    let mut estimate = ImpactEstimate {
+        injection_type: injection.r#type.clone(),
+        target: describe_target(&injection.target),
+        ..Default::default()
+    };
+
+    match injection.r#type.as_str() {
+        "network_latency" => {
+            // Mirrors `NetworkLatencyConfig`'s defaults (mean=100ms, jitter=20ms).
+            let param_ms = |key: &str| {
+                injection
+                    .parameters
+                    .get(key)
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| humantime::parse_duration(s).ok())
+                    .map(|d| d.as_micros() as f64 / 1000.0)
+            };
+            let mean_ms = param_ms("mean").unwrap_or(100.0);
+            let jitter_ms = param_ms("jitter").unwrap_or(20.0);
+            estimate.added_latency_ms = Some(mean_ms + jitter_ms);
+            estimate.traffic_affected_pct = Some(100.0);
+            estimate
+                .notes
+                .push("affects all traffic on the interface serving this target".to_string());
+        }
+
+        "packet_loss" | "tcp_reset" => {
+            // Mirrors `PacketLossConfig`'s default rate of 1%.
+            let rate = param_f64(injection, "rate").unwrap_or(0.01);
+            estimate.traffic_affected_pct = Some(rate * 100.0);
+        }
+
+        "process_kill" => {
+            let selection = param_or(injection, "pattern_selection", PatternSelection::All);
+            estimate.processes_affected = Some(match (&injection.target.pattern, selection) {
+                (Some(pattern), PatternSelection::All) => count_matching_processes(pattern),
+                (Some(pattern), PatternSelection::Count(n)) => {
+                    count_matching_processes(pattern).min(n as u64)
+                }
+                (Some(pattern), PatternSelection::Percentage(pct)) => {
+                    (count_matching_processes(pattern) as f64 * pct).round() as u64
+                }
+                (None, _) => 1, // a single Target::Process
+            });
+        }
+
+        "disk_space" => {
+            // `DiskSpaceInjector::new` takes the target usage as a required
+            // argument; 90% is this estimate's own fallback when it's unset.
+            let target_usage = param_f64(injection, "target_usage").unwrap_or(0.90);
+            let path = injection
+                .parameters
+                .get("path")
+                .and_then(|v| v.as_str())
+                .unwrap_or("/tmp");
+
+            match bytes_to_fill(path, target_usage) {
+                Some(bytes) => estimate.disk_bytes_to_fill = Some(bytes),
+                None => estimate
+                    .notes
+                    .push(format!("could not stat mount for '{}'", path)),
+            }
+        }
+
+        other => {
+            estimate
+                .notes
+                .push(format!("no quantitative impact model for '{}' yet", other));
+        }
+    }
+
+    estimate
+}
+
+/// Find the disk whose mount point is the longest matching prefix of
+/// `path` and compute how many bytes filling it to `target_usage` would
+/// consume, mirroring `DiskSpaceInjector::calculate_bytes_to_fill`.
+fn bytes_to_fill(path: &str, target_usage: f64) -> Option<u64> {
+    use sysinfo::Disks;
+
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .iter()
+        .filter(|d| path.starts_with(&d.mount_point().to_string_lossy().to_string()))
+        .max_by_key(|d| d.mount_point().to_string_lossy().len())?;
+
+    let total = disk.total_space();
+    let free = disk.available_space();
+    let target_free = total as f64 * (1.0 - target_usage);
+    Some((free as f64 - target_free).max(0.0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn injection(r#type: &str, params: serde_json::Value) -> InjectionConfig {
+        InjectionConfig {
+            r#type: r#type.to_string(),
+            target: TargetConfig::default(),
+            parameters: serde_json::from_value::<HashMap<String, serde_json::Value>>(params)
+                .unwrap_or_default(),
+            ramp: None,
+            start_after: None,
+            duration: None,
+            jitter: None,
+        }
+    }
+
+    #[test]
+    fn test_network_latency_estimate_uses_configured_mean_and_jitter() {
+        let config = injection(
+            "network_latency",
+            serde_json::json!({"mean": "200ms", "jitter": "50ms"}),
+        );
+
+        let estimate = estimate_injection(&config);
+        assert_eq!(estimate.added_latency_ms, Some(250.0));
+        assert_eq!(estimate.traffic_affected_pct, Some(100.0));
+    }
+
+    #[test]
+    fn test_packet_loss_estimate_converts_rate_to_percentage() {
+        let config = injection("packet_loss", serde_json::json!({"rate": 0.25}));
+
+        let estimate = estimate_injection(&config);
+        assert_eq!(estimate.traffic_affected_pct, Some(25.0));
+    }
+
+    #[test]
+    fn test_process_kill_estimate_defaults_to_single_process_without_pattern() {
+        let config = injection("process_kill", serde_json::json!({}));
+
+        let estimate = estimate_injection(&config);
+        assert_eq!(estimate.processes_affected, Some(1));
+    }
+
+    #[test]
+    fn test_unmodeled_injection_type_leaves_fields_empty_with_a_note() {
+        let config = injection("cgroup_freeze", serde_json::json!({}));
+
+        let estimate = estimate_injection(&config);
+        assert!(estimate.added_latency_ms.is_none());
+        assert!(estimate.disk_bytes_to_fill.is_none());
+        assert_eq!(estimate.notes.len(), 1);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::config::{Scenario, TargetConfig};
+use crate::runner::ScenarioResult;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A recording of one actual scenario execution, captured right after
+/// [`crate::runner::ScenarioRunner::run`] returns: the scenario as it was
+/// declared and the result it actually produced, including the seed the
+/// run resolved to (see [`ScenarioResult::resolved_seed`]) even if the
+/// scenario itself declared none.
+///
+/// `chaos replay` rebuilds a scenario from this via [`Self::replay_scenario`]
+/// and reruns it, so an experiment that used randomness or live discovery
+/// reproduces the same phase order, targets, and parameters instead of
+/// re-rolling them. This lives here rather than alongside
+/// `chaos_metrics::HistoryStore` (which records results for trend analysis)
+/// because reconstructing a replayable [`Scenario`] needs `chaos_scenarios`
+/// types `chaos_metrics` doesn't depend on - the same split
+/// [`crate::schedule::ScheduleEntry`] follows for the same reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    pub scenario: Scenario,
+    pub result: ScenarioResult,
+}
+
+impl ExecutionRecord {
+    pub fn new(scenario: Scenario, result: ScenarioResult) -> Self {
+        Self {
+            recorded_at: chrono::Utc::now(),
+            scenario,
+            result,
+        }
+    }
+
+    /// Writes this recording to `path` as pretty JSON, mirroring
+    /// `chaos_metrics::HistoryStore::record`'s on-disk format - a replay
+    /// artifact is generated, not hand-authored, so there's no need to
+    /// support YAML/TOML the way scenario files do.
+    pub async fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path.as_ref(), json)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.as_ref().display(), e))?;
+        Ok(())
+    }
+
+    /// Loads a recording written by [`Self::save`].
+    pub async fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow::anyhow!("Failed to parse execution record {}: {}", path.display(), e))
+    }
+
+    /// Rebuilds [`Self::scenario`] into one that reruns exactly what
+    /// actually happened: [`ScenarioResult::resolved_seed`] in place of
+    /// whatever (or nothing) the scenario originally declared, and each
+    /// phase's injections narrowed to the ones [`Self::result`] recorded as
+    /// actually attempted, with their target and parameters pinned to the
+    /// resolved values it recorded instead of the original declaration -
+    /// so a `pattern`/`sample`/`members` target that could resolve
+    /// differently on a second discovery pass reapplies to the exact same
+    /// target as the recorded run.
+    ///
+    /// Pinning is best-effort: a target this crate's [`TargetConfig`] can't
+    /// represent directly (`Thread`, `Remote`, `Agent`, `NetNamespace`) or a
+    /// phase that ran no injections at all (skipped by `run_if`, or all
+    /// dropped by a `start_after` past the phase's end) replays with its
+    /// original declared injections unchanged, since there's nothing
+    /// resolved to pin.
+    pub fn replay_scenario(&self) -> Scenario {
+        let mut scenario = self.scenario.clone();
+        scenario.seed = self.result.resolved_seed.or(scenario.seed);
+
+        for (phase, phase_result) in scenario.phases.iter_mut().zip(self.result.phase_results.iter()) {
+            if phase_result.skipped || phase_result.injection_outcomes.is_empty() {
+                continue;
+            }
+
+            let mut replayed = Vec::new();
+            for outcome in &phase_result.injection_outcomes {
+                let Some(original) = phase
+                    .injections
+                    .iter()
+                    .find(|i| i.r#type == outcome.injection_type)
+                else {
+                    continue;
+                };
+
+                let mut injection = original.clone();
+                if let Some(target) = outcome.target.as_ref().and_then(pin_target) {
+                    injection.target = target;
+                }
+                if let Ok(parameters) = serde_json::from_value(outcome.applied_parameters.clone()) {
+                    injection.parameters = parameters;
+                }
+                // The resolved parameters already carry a ramp's final
+                // value (see `resolved_parameters`), and replaying the
+                // ramp itself would just retrace the same path to the same
+                // endpoint - so replay applies it as a step function.
+                injection.ramp = None;
+                replayed.push(injection);
+            }
+
+            phase.injections = replayed;
+            // The subset above already *is* the recorded selection outcome
+            // - reselecting from it would risk dropping some of it again.
+            phase.injection_selection = None;
+        }
+
+        scenario
+    }
+}
+
+/// Reconstructs a [`TargetConfig`] that resolves back to `target`, for the
+/// variants this crate's declarative format can represent directly.
+fn pin_target(target: &chaos_core::Target) -> Option<TargetConfig> {
+    use chaos_core::Target;
+    match target {
+        Target::Process { pid } => Some(TargetConfig {
+            pid: Some(*pid),
+            ..Default::default()
+        }),
+        Target::Network { address } => Some(TargetConfig {
+            address: Some(address.to_string()),
+            ..Default::default()
+        }),
+        Target::Container { id } => Some(TargetConfig {
+            container_id: Some(id.clone()),
+            ..Default::default()
+        }),
+        Target::Group(members) => {
+            let pinned = members.iter().map(pin_target).collect::<Option<Vec<_>>>()?;
+            Some(TargetConfig {
+                members: Some(pinned),
+                ..Default::default()
+            })
+        }
+        Target::Thread { .. } | Target::ProcessPattern { .. } | Target::Remote { .. } | Target::Agent { .. } | Target::NetNamespace { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{InjectionConfig, Phase, Scenario};
+    use crate::runner::{InjectionOutcome, InjectionStatus, PhaseResult, ScenarioResult};
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn scenario_with_pattern_target() -> Scenario {
+        Scenario {
+            name: "test".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(10),
+            ramp_up: None,
+            phases: vec![Phase {
+                name: "phase1".to_string(),
+                duration: Duration::from_secs(10),
+                parallel: false,
+                injections: vec![InjectionConfig {
+                    r#type: "cpu_starvation".to_string(),
+                    target: TargetConfig {
+                        pattern: Some("worker".to_string()),
+                        ..Default::default()
+                    },
+                    parameters: HashMap::new(),
+                    ramp: None,
+                    start_after: None,
+                    duration: None,
+                    jitter: None,
+                }],
+                injection_selection: None,
+                jitter: None,
+                recovery_period: None,
+                run_if: None,
+                before: Vec::new(),
+                after: Vec::new(),
+            }],
+            background: Vec::new(),
+            labels: HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: Default::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: Default::default(),
+        }
+    }
+
+    fn result_with_resolved_pid(pid: u32) -> ScenarioResult {
+        ScenarioResult {
+            scenario_name: "test".to_string(),
+            total_duration: Duration::from_secs(10),
+            phase_results: vec![PhaseResult {
+                name: "phase1".to_string(),
+                duration: Duration::from_secs(10),
+                injection_count: 1,
+                success: true,
+                skipped: false,
+                injection_outcomes: vec![InjectionOutcome {
+                    injection_type: "cpu_starvation".to_string(),
+                    status: InjectionStatus::Applied,
+                    target: Some(chaos_core::Target::process(pid)),
+                    applied_parameters: serde_json::json!({}),
+                    applied_at: Some(chrono::Utc::now()),
+                }],
+                hook_results: Vec::new(),
+            }],
+            total_injections: 1,
+            failed_injections: 0,
+            aborted_reason: None,
+            host: crate::host::HostFingerprint::capture(),
+            labels: HashMap::new(),
+            hook_results: Vec::new(),
+            resolved_seed: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_replay_scenario_pins_discovered_target_and_seed() {
+        let record = ExecutionRecord::new(scenario_with_pattern_target(), result_with_resolved_pid(1234));
+
+        let replayed = record.replay_scenario();
+
+        assert_eq!(replayed.seed, Some(42));
+        assert_eq!(replayed.phases[0].injections.len(), 1);
+        assert_eq!(replayed.phases[0].injections[0].target.pid, Some(1234));
+        assert_eq!(replayed.phases[0].injections[0].target.pattern, None);
+    }
+
+    #[tokio::test]
+    async fn test_execution_record_round_trips_through_disk() {
+        let record = ExecutionRecord::new(scenario_with_pattern_target(), result_with_resolved_pid(1234));
+        let path = std::env::temp_dir().join(format!("chaos_replay_test_{}.json", std::process::id()));
+
+        record.save(&path).await.unwrap();
+        let loaded = ExecutionRecord::load(&path).await.unwrap();
+
+        assert_eq!(loaded.scenario.name, record.scenario.name);
+        assert_eq!(loaded.result.resolved_seed, record.result.resolved_seed);
+
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+}
@@ -1,244 +1,2346 @@
-use crate::{
-    config::{InjectionConfig, Scenario},
-    scheduler::{Scheduler, SchedulingMode},
-};
-use chaos_core::{Executor, InjectionHandle};
-use std::time::Duration;
-use tokio::time::Instant;
-use tracing::{info, warn};
-
-pub struct ScenarioRunner {
-    executor: Executor,
-}
-
-impl ScenarioRunner {
-    pub fn new(executor: Executor) -> Self {
-        Self { executor }
-    }
-
-    pub fn with_defaults() -> Self {
-        Self::new(Executor::with_defaults())
-    }
-
-    pub async fn run(&self, scenario: &Scenario) -> anyhow::Result<ScenarioResult> {
-        info!("Starting scenario: {}", scenario.name);
-        scenario.validate().map_err(|e| anyhow::anyhow!(e))?;
-
-        let start_time = Instant::now();
-
-        // Create scheduler
-        let scheduling_mode = if scenario.phases.iter().any(|p| p.parallel) {
-            SchedulingMode::Parallel
-        } else {
-            SchedulingMode::Sequential
-        };
-
-        let mut scheduler = if let Some(seed) = scenario.seed {
-            Scheduler::new(scheduling_mode, Some(seed))
-        } else {
-            Scheduler::new(scheduling_mode, None)
-        };
-
-        let mut phases = scheduler.schedule_phases(scenario);
-
-        if let Some(ramp_up) = scenario.ramp_up {
-            scheduler.apply_ramp_up(&mut phases, ramp_up);
-        }
-
-        let mut phase_results = Vec::new();
-        let mut all_handles = Vec::new();
-
-        // Execute phases
-        for scheduled_phase in phases {
-            // Wait until phase start time
-            let elapsed = start_time.elapsed();
-            if let Some(delay) = scheduled_phase.delay_until_start(elapsed) {
-                info!(
-                    "Waiting {:?} before starting phase '{}'",
-                    delay,
-                    scheduled_phase.name()
-                );
-                tokio::time::sleep(delay).await;
-            }
-
-            info!(
-                "Starting phase '{}' (duration: {:?})",
-                scheduled_phase.name(),
-                scheduled_phase.duration()
-            );
-
-            let phase_start = Instant::now();
-            let mut handles = Vec::new();
-
-            // Apply injections
-            for injection in &scheduled_phase.phase.injections {
-                match self.apply_injection(injection).await {
-                    Ok(handle) => {
-                        info!("Applied injection: {}", injection.r#type);
-                        handles.push(handle);
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Failed to apply injection '{}': {}",
-                            injection.r#type, e
-                        );
-                    }
-                }
-            }
-
-            // Wait for phase duration
-            let phase_elapsed = phase_start.elapsed();
-            if phase_elapsed < scheduled_phase.duration() {
-                let remaining = scheduled_phase.duration() - phase_elapsed;
-                tokio::time::sleep(remaining).await;
-            }
-
-            // Remove injections
-            for handle in &handles {
-                if let Err(e) = self.executor.remove(handle.clone()).await {
-                    warn!("Failed to remove injection '{}': {}", handle.id, e);
-                }
-            }
-
-            let phase_duration = phase_start.elapsed();
-            info!(
-                "Completed phase '{}' in {:?}",
-                scheduled_phase.name(),
-                phase_duration
-            );
-
-            phase_results.push(PhaseResult {
-                name: scheduled_phase.name().to_string(),
-                duration: phase_duration,
-                injection_count: handles.len(),
-            });
-
-            all_handles.extend(handles);
-        }
-
-        let total_duration = start_time.elapsed();
-
-        info!(
-            "Scenario '{}' completed in {:?}",
-            scenario.name, total_duration
-        );
-
-        Ok(ScenarioResult {
-            scenario_name: scenario.name.clone(),
-            total_duration,
-            phase_results,
-            total_injections: all_handles.len(),
-        })
-    }
-
-    async fn apply_injection(
-        &self,
-        injection: &InjectionConfig,
-    ) -> anyhow::Result<InjectionHandle> {
-        let target = injection.target.to_target()
-            .map_err(|e| anyhow::anyhow!("Invalid target: {}", e))?;
-
-        let handle = self
-            .executor
-            .inject(&injection.r#type, &target)
-            .await
-            .map_err(|e| anyhow::anyhow!("Injection failed: {}", e))?;
-
-        Ok(handle)
-    }
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ScenarioResult {
-    pub scenario_name: String,
-    #[serde(with = "humantime_serde")]
-    pub total_duration: Duration,
-    pub phase_results: Vec<PhaseResult>,
-    pub total_injections: usize,
-}
-
-impl ScenarioResult {
-    pub fn success_rate(&self) -> f64 {
-        if self.phase_results.is_empty() {
-            return 0.0;
-        }
-        1.0 // Simplified - in reality would track failures
-    }
-
-    pub fn average_phase_duration(&self) -> Duration {
-        if self.phase_results.is_empty() {
-            return Duration::ZERO;
-        }
-
-        let total: Duration = self.phase_results.iter().map(|p| p.duration).sum();
-        total / self.phase_results.len() as u32
-    }
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct PhaseResult {
-    pub name: String,
-    #[serde(with = "humantime_serde")]
-    pub duration: Duration,
-    pub injection_count: usize,
-}
-
-pub async fn run_scenario(scenario: &Scenario) -> anyhow::Result<ScenarioResult> {
-    let runner = ScenarioRunner::with_defaults();
-    runner.run(scenario).await
-}
-
-mod humantime_serde {
-    use serde::{Deserialize, Deserializer, Serializer};
-    use std::time::Duration;
-
-    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&humantime::format_duration(*duration).to_string())
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        humantime::parse_duration(&s).map_err(serde::de::Error::custom)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_scenario_runner_creation() {
-        let _runner = ScenarioRunner::with_defaults();
-        assert!(true); // Runner created successfully
-    }
-
-    #[test]
-    fn test_scenario_result() {
-        let result = ScenarioResult {
-            scenario_name: "test".to_string(),
-            total_duration: Duration::from_secs(100),
-            phase_results: vec![
-                PhaseResult {
-                    name: "phase1".to_string(),
-                    duration: Duration::from_secs(50),
-                    injection_count: 2,
-                },
-                PhaseResult {
-                    name: "phase2".to_string(),
-                    duration: Duration::from_secs(50),
-                    injection_count: 1,
-                },
-            ],
-            total_injections: 3,
-        };
-
-        assert_eq!(result.success_rate(), 1.0);
-        assert_eq!(result.average_phase_duration(), Duration::from_secs(50));
-    }
-}
+use crate::{
+    config::{
+        HookAction, HookConfig, InjectionConfig, InjectionFailurePolicy, InjectionSelection,
+        NotificationConfig, Scenario,
+    },
+    host::HostFingerprint,
+    scheduler::{ScheduledPhase, Scheduler, SchedulingMode},
+};
+use chaos_core::{AbortMonitor, Executor, InjectionHandle};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Current pause state of a [`ScenarioRunner`], broadcast to its run loop
+/// over a `watch` channel so `pause`/`resume` can be called from a
+/// different task (a signal handler, an embedder's UI) than the one
+/// actually running the scenario.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PauseState {
+    Running,
+    Paused { lift_injections: bool },
+}
+
+#[derive(Clone)]
+pub struct ScenarioRunner {
+    executor: Executor,
+    pause_tx: watch::Sender<PauseState>,
+}
+
+impl ScenarioRunner {
+    pub fn new(executor: Executor) -> Self {
+        let (pause_tx, _) = watch::channel(PauseState::Running);
+        Self { executor, pause_tx }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self::new(Executor::with_defaults())
+    }
+
+    /// Freezes the phase clock: phase-start delays and phase-duration
+    /// waits stop counting down until `resume` is called. If
+    /// `lift_injections` is set, every injection active at the moment the
+    /// pause takes effect is removed, and re-applied once `resume` is
+    /// called - so an operator investigating an unexpected production
+    /// signal can ask "does this go away without the fault?" without
+    /// losing the scenario's place when they're done looking.
+    ///
+    /// Can be called from a different task than the one running the
+    /// scenario (a signal handler, an embedder's UI) since a cloned
+    /// `ScenarioRunner` shares the same pause state.
+    pub fn pause(&self, lift_injections: bool) {
+        let _ = self.pause_tx.send(PauseState::Paused { lift_injections });
+    }
+
+    /// Resumes a scenario frozen by [`Self::pause`]. A no-op if it wasn't
+    /// paused.
+    pub fn resume(&self) {
+        let _ = self.pause_tx.send(PauseState::Running);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        matches!(*self.pause_tx.borrow(), PauseState::Paused { .. })
+    }
+
+    /// Runs `scenario` to completion, or until `cancel` is triggered.
+    ///
+    /// Cancellation is checked at every `await` point that can block for a
+    /// meaningful stretch of wall-clock time (phase-start delays, phase
+    /// duration waits, and injection application), so Ctrl-C or an abort
+    /// request takes effect within roughly a second rather than at the next
+    /// phase boundary. Injections and background load already applied at
+    /// the point of cancellation are still torn down before returning.
+    ///
+    /// `scenario.error_budget` and `scenario.abort_conditions` are checked
+    /// once before the first phase and again at every phase boundary; a
+    /// breach stops remaining phases the same way cancellation does, and
+    /// the same unconditional teardown below still removes everything
+    /// that's active.
+    ///
+    /// [`Self::pause`]/[`Self::resume`] freeze and unfreeze the phase-start
+    /// delay and phase-duration waits; a pause taken with `lift_injections`
+    /// removes every active injection for the duration of the pause and
+    /// re-applies it on resume.
+    pub async fn run(&self, scenario: &Scenario) -> anyhow::Result<ScenarioResult> {
+        self.run_with_cancellation(scenario, CancellationToken::new())
+            .await
+    }
+
+    pub async fn run_with_cancellation(
+        &self,
+        scenario: &Scenario,
+        cancel: CancellationToken,
+    ) -> anyhow::Result<ScenarioResult> {
+        info!("Starting scenario: {}", scenario.name);
+        scenario.validate().map_err(|e| anyhow::anyhow!(e))?;
+        scenario
+            .validate_against_registry(self.executor.registry())
+            .map_err(|e| anyhow::anyhow!(e))?;
+        if let Some(policy) = &scenario.error_budget {
+            policy.check().await?;
+        }
+
+        // Pin down a seed for this run even if the scenario didn't declare
+        // one, so every random choice below (phase scheduling, jitter,
+        // sampling, injection selection) is reproducible from the resulting
+        // `ScenarioResult` - the whole point of `crate::replay`, which reruns
+        // exactly this resolved scenario rather than re-rolling it.
+        let mut scenario = scenario.clone();
+        scenario.seed.get_or_insert_with(rand::random);
+        let scenario = &scenario;
+
+        let mut abort_monitor = scenario.abort_conditions.clone().map(AbortMonitor::new);
+        if let Some(monitor) = &mut abort_monitor {
+            monitor.check().await?;
+        }
+
+        notify(&scenario.notifications, &NotificationEvent::ScenarioStarted { scenario: &scenario.name }).await;
+
+        let mut hook_results = run_hooks(&scenario.before).await;
+
+        let mut pause_rx = self.pause_tx.subscribe();
+
+        let start_time = Instant::now();
+
+        // Create scheduler
+        let scheduling_mode = if scenario.phases.iter().any(|p| p.parallel) {
+            SchedulingMode::Parallel
+        } else {
+            SchedulingMode::Sequential
+        };
+
+        let mut scheduler = if let Some(seed) = scenario.seed {
+            Scheduler::new(scheduling_mode, Some(seed))
+        } else {
+            Scheduler::new(scheduling_mode, None)
+        };
+
+        let mut phases = scheduler.schedule_phases(scenario);
+
+        if let Some(ramp_up) = scenario.ramp_up {
+            scheduler.apply_ramp_up(&mut phases, ramp_up);
+        }
+
+        // Background load runs for the entire experiment, independent of phases,
+        // so faults are exercised under realistic baseline utilization.
+        let mut background_handles = Vec::new();
+        let mut failed_injections = 0usize;
+        for injection in &scenario.background {
+            if cancel.is_cancelled() {
+                break;
+            }
+            match self.apply_injection(injection, scenario.seed, &cancel).await {
+                Ok(handle) => {
+                    info!("Applied background load: {}", injection.r#type);
+                    background_handles.push(handle);
+                }
+                Err(e) => {
+                    warn!("Failed to apply background load '{}': {}", injection.r#type, e);
+                    failed_injections += 1;
+                }
+            }
+        }
+
+        let mut phase_results = Vec::new();
+        let mut all_handles = Vec::new();
+        let mut aborted_reason = None;
+
+        // A `Parallel`-scheduled batch (every phase starting at the same
+        // `start_time`) is only truly concurrent if we actually run the
+        // phases as concurrent tasks - the sequential loop below would
+        // otherwise still finish one phase before starting the next despite
+        // their identical nominal start times. A batch where any phase has a
+        // `run_if` doesn't take this path and falls back to the sequential
+        // loop, since `run_if` reads `phase_results` for phases that haven't
+        // necessarily finished (or started) yet once phases stop completing
+        // in a defined order.
+        if scheduling_mode == SchedulingMode::Parallel
+            && phases.len() > 1
+            && !phases.iter().any(|p| p.phase.run_if.is_some())
+        {
+            let (results, handles, concurrent_failed, concurrent_breach) = self
+                .run_phases_concurrently(scenario, phases, start_time, &cancel)
+                .await;
+            phase_results = results;
+            all_handles = handles;
+            failed_injections += concurrent_failed;
+            aborted_reason = concurrent_breach;
+        } else {
+            for scheduled_phase in phases {
+                if cancel.is_cancelled() {
+                    info!("Cancellation requested, aborting scenario before next phase");
+                    aborted_reason = Some("cancelled before next phase".to_string());
+                    break;
+                }
+
+                // Re-check the error budget at every phase boundary, not just at
+                // start, so a scenario that was affordable when it began still
+                // aborts if the target burns through its budget mid-run.
+                if let Some(policy) = &scenario.error_budget {
+                    if let Err(e) = policy.check().await {
+                        warn!("Aborting scenario '{}': {}", scenario.name, e);
+                        aborted_reason = Some(format!("error budget exhausted: {}", e));
+                        break;
+                    }
+                }
+
+                // Same idea as the error budget check above, but for error
+                // rate / latency / health-check thresholds declared directly
+                // on the scenario rather than derived from a budget.
+                if let Some(monitor) = &mut abort_monitor {
+                    if let Err(e) = monitor.check().await {
+                        warn!("Aborting scenario '{}': {}", scenario.name, e);
+                        aborted_reason = Some(format!("abort condition triggered: {}", e));
+                        break;
+                    }
+                }
+
+                if matches!(*pause_rx.borrow(), PauseState::Paused { .. }) {
+                    self.lift_and_wait_for_resume(&mut pause_rx, &cancel, &mut [&mut background_handles])
+                        .await;
+                    if cancel.is_cancelled() {
+                        break;
+                    }
+                }
+
+                if let Some(condition) = &scheduled_phase.phase.run_if {
+                    if !evaluate_run_if(condition, &phase_results) {
+                        info!(
+                            "Skipping phase '{}': run_if '{}' not met",
+                            scheduled_phase.name(),
+                            condition
+                        );
+                        phase_results.push(PhaseResult {
+                            name: scheduled_phase.name().to_string(),
+                            duration: Duration::default(),
+                            injection_count: 0,
+                            success: true,
+                            skipped: true,
+                            injection_outcomes: Vec::new(),
+                            hook_results: Vec::new(),
+                        });
+                        continue;
+                    }
+                }
+
+                match self
+                    .run_single_phase(
+                        scenario,
+                        &scheduled_phase,
+                        start_time,
+                        &cancel,
+                        &mut pause_rx,
+                        &mut background_handles,
+                    )
+                    .await
+                {
+                    Some((result, handles, phase_failed_injections)) => {
+                        failed_injections += phase_failed_injections;
+                        phase_results.push(result);
+                        all_handles.extend(handles);
+                    }
+                    None => {
+                        info!("Cancellation requested, aborting scenario before next phase");
+                        aborted_reason = Some("cancelled before next phase".to_string());
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Tear down background load now that all phases have completed
+        // (or the scenario was cancelled).
+        for handle in background_handles {
+            if let Err(e) = self.executor.remove(handle.clone()).await {
+                warn!("Failed to remove background load '{}': {}", handle.id, e);
+            }
+        }
+
+        if cancel.is_cancelled() {
+            info!("Scenario '{}' cancelled after {:?}", scenario.name, start_time.elapsed());
+            aborted_reason.get_or_insert_with(|| "cancelled".to_string());
+        }
+
+        if let Some(reason) = &aborted_reason {
+            notify(
+                &scenario.notifications,
+                &NotificationEvent::ScenarioAborted { scenario: &scenario.name, reason },
+            )
+            .await;
+        }
+
+        hook_results.extend(run_hooks(&scenario.after).await);
+
+        let total_duration = start_time.elapsed();
+
+        info!(
+            "Scenario '{}' completed in {:?}",
+            scenario.name, total_duration
+        );
+
+        notify(
+            &scenario.notifications,
+            &NotificationEvent::ScenarioCompleted {
+                scenario: &scenario.name,
+                duration_secs: total_duration.as_secs_f64(),
+                failed_injections,
+            },
+        )
+        .await;
+
+        Ok(ScenarioResult {
+            scenario_name: scenario.name.clone(),
+            total_duration,
+            phase_results,
+            total_injections: all_handles.len(),
+            failed_injections,
+            aborted_reason,
+            host: HostFingerprint::capture(),
+            labels: scenario.labels.clone(),
+            hook_results,
+            resolved_seed: scenario.seed,
+        })
+    }
+
+    /// Runs every phase in `phases` as its own concurrent task rather than
+    /// one after another, so a [`SchedulingMode::Parallel`] batch actually
+    /// overlaps on the wall clock instead of just sharing a nominal
+    /// `start_time`. Each task gets its own pause-state receiver and an
+    /// empty local background-handle bucket, since there's no single
+    /// sequential point left to hand a shared one to; a pause during this
+    /// batch is still picked up by every task independently, same as any
+    /// other concurrent caller of the executor's active-injection list.
+    /// Results are returned in the phases' original (index) order, not
+    /// completion order.
+    ///
+    /// A concurrent batch has no discrete "phase boundary" to hang
+    /// [`run_with_cancellation`]'s error-budget/abort-condition checks off
+    /// of the way the sequential loop does, so instead this polls both on
+    /// a fixed cadence for as long as any phase task is still running and
+    /// cancels `cancel` the moment either one breaches - the same effect
+    /// as the sequential loop's per-boundary check, just re-timed for a
+    /// batch that doesn't have boundaries. The breach reason (if any) is
+    /// returned so the caller can record it as `aborted_reason`.
+    async fn run_phases_concurrently(
+        &self,
+        scenario: &Scenario,
+        phases: Vec<ScheduledPhase>,
+        start_time: Instant,
+        cancel: &CancellationToken,
+    ) -> (Vec<PhaseResult>, Vec<InjectionHandle>, usize, Option<String>) {
+        const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for scheduled_phase in phases {
+            let runner = self.clone();
+            let scenario = scenario.clone();
+            let cancel = cancel.clone();
+            tasks.spawn(async move {
+                let index = scheduled_phase.index;
+                let mut pause_rx = runner.pause_tx.subscribe();
+                let mut background_handles = Vec::new();
+                let outcome = runner
+                    .run_single_phase(
+                        &scenario,
+                        &scheduled_phase,
+                        start_time,
+                        &cancel,
+                        &mut pause_rx,
+                        &mut background_handles,
+                    )
+                    .await;
+                (index, outcome)
+            });
+        }
+
+        let error_budget_policy = scenario.error_budget.clone();
+        let mut abort_monitor = scenario.abort_conditions.clone().map(AbortMonitor::new);
+        let mut breach_reason = None;
+
+        let mut outcomes = Vec::new();
+        while !tasks.is_empty() {
+            tokio::select! {
+                joined = tasks.join_next() => {
+                    match joined {
+                        Some(Ok(outcome)) => outcomes.push(outcome),
+                        Some(Err(e)) => warn!("Concurrent phase task panicked: {}", e),
+                        None => break,
+                    }
+                }
+                _ = tokio::time::sleep(CHECK_INTERVAL), if !cancel.is_cancelled() => {
+                    if let Some(policy) = &error_budget_policy {
+                        if let Err(e) = policy.check().await {
+                            warn!("Aborting scenario '{}': {}", scenario.name, e);
+                            breach_reason.get_or_insert_with(|| format!("error budget exhausted: {}", e));
+                            cancel.cancel();
+                        }
+                    }
+                    if let Some(monitor) = &mut abort_monitor {
+                        if let Err(e) = monitor.check().await {
+                            warn!("Aborting scenario '{}': {}", scenario.name, e);
+                            breach_reason.get_or_insert_with(|| format!("abort condition triggered: {}", e));
+                            cancel.cancel();
+                        }
+                    }
+                }
+            }
+        }
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        let mut phase_results = Vec::new();
+        let mut all_handles = Vec::new();
+        let mut failed_injections = 0usize;
+        for (_, outcome) in outcomes {
+            if let Some((result, handles, failed)) = outcome {
+                phase_results.push(result);
+                all_handles.extend(handles);
+                failed_injections += failed;
+            }
+        }
+
+        (phase_results, all_handles, failed_injections, breach_reason)
+    }
+
+    /// Waits out `scheduled_phase`'s start delay, applies and removes its
+    /// injections via [`Self::run_phase_injections`], then sleeps out its
+    /// recovery period. Returns `None` if the start-delay wait was cut
+    /// short by cancellation, meaning the phase never ran and produced no
+    /// result - the caller should stop rather than record anything for it.
+    /// A cancellation during the injections or recovery wait still yields
+    /// `Some`, since the phase itself did run.
+    async fn run_single_phase(
+        &self,
+        scenario: &Scenario,
+        scheduled_phase: &ScheduledPhase,
+        start_time: Instant,
+        cancel: &CancellationToken,
+        pause_rx: &mut watch::Receiver<PauseState>,
+        background_handles: &mut Vec<InjectionHandle>,
+    ) -> Option<(PhaseResult, Vec<InjectionHandle>, usize)> {
+        let elapsed = start_time.elapsed();
+        if let Some(delay) = scheduled_phase.delay_until_start(elapsed) {
+            info!(
+                "Waiting {:?} before starting phase '{}'",
+                delay,
+                scheduled_phase.name()
+            );
+            self.sleep_while_running(delay, cancel, pause_rx, &mut [background_handles])
+                .await
+                .ok()?;
+        }
+
+        info!(
+            "Starting phase '{}' (duration: {:?})",
+            scheduled_phase.name(),
+            scheduled_phase.duration()
+        );
+
+        let phase_start = Instant::now();
+
+        notify(
+            &scenario.notifications,
+            &NotificationEvent::PhaseStarted { scenario: &scenario.name, phase: scheduled_phase.name() },
+        )
+        .await;
+
+        let mut hook_results = run_hooks(&scheduled_phase.phase.before).await;
+
+        let injections_to_apply = select_injections(
+            &scheduled_phase.phase.injections,
+            scheduled_phase.phase.injection_selection.as_ref(),
+            scenario.seed,
+            scheduled_phase.index,
+        );
+
+        let (handles, injection_outcomes, phase_failed_injections, abort_scenario) = self
+            .run_phase_injections(
+                &injections_to_apply,
+                scheduled_phase.duration(),
+                scenario.seed,
+                scheduled_phase.index,
+                scenario.injection_failure_policy,
+                cancel,
+                pause_rx,
+                background_handles,
+            )
+            .await;
+
+        if abort_scenario {
+            warn!(
+                "Aborting scenario '{}': an injection in phase '{}' failed under the abort_scenario failure policy",
+                scenario.name,
+                scheduled_phase.name()
+            );
+            cancel.cancel();
+        }
+
+        hook_results.extend(run_hooks(&scheduled_phase.phase.after).await);
+
+        let phase_duration = phase_start.elapsed();
+        info!(
+            "Completed phase '{}' in {:?}",
+            scheduled_phase.name(),
+            phase_duration
+        );
+
+        let success = phase_failed_injections == 0;
+
+        notify(
+            &scenario.notifications,
+            &NotificationEvent::PhaseCompleted { scenario: &scenario.name, phase: scheduled_phase.name(), success },
+        )
+        .await;
+        if !success {
+            notify(
+                &scenario.notifications,
+                &NotificationEvent::SloViolation { scenario: &scenario.name, phase: scheduled_phase.name() },
+            )
+            .await;
+        }
+
+        let result = PhaseResult {
+            name: scheduled_phase.name().to_string(),
+            duration: phase_duration,
+            injection_count: handles.len(),
+            success,
+            skipped: false,
+            injection_outcomes,
+            hook_results,
+        };
+
+        if !cancel.is_cancelled() {
+            let recovery_period = scheduled_phase
+                .phase
+                .recovery_period
+                .or(scenario.recovery_period);
+            if let Some(recovery_period) = recovery_period {
+                if !recovery_period.is_zero() {
+                    info!(
+                        "Recovering for {:?} after phase '{}' with no injections active",
+                        recovery_period,
+                        scheduled_phase.name()
+                    );
+                    let _ = self
+                        .sleep_while_running(recovery_period, cancel, pause_rx, &mut [background_handles])
+                        .await;
+                }
+            }
+        }
+
+        Some((result, handles, phase_failed_injections))
+    }
+
+    /// Applies `injections` and removes them, honoring each one's
+    /// `start_after`/`duration`/`jitter` instead of the simpler "apply all
+    /// at phase start, remove all at phase end" this replaced: an injection
+    /// with `start_after` isn't applied until that far into the phase (plus
+    /// up to `jitter` more, seeded by `seed` and `phase_index` for
+    /// reproducibility), and one with `duration` is removed that long after
+    /// it was applied rather than waiting for the phase to end. Anything
+    /// still active once `phase_duration` elapses (or `cancel` fires) is
+    /// removed before returning. Injections that fall due at the same
+    /// instant are applied concurrently rather than one at a time, so a
+    /// slow injector doesn't hold up the rest. Returns every handle that
+    /// was ever applied - including ones already removed early - so the
+    /// caller's counts match what it tracked before this per-injection
+    /// timing existed, plus how many failed to apply, plus one
+    /// [`InjectionOutcome`] per injection actually attempted (an injection
+    /// dropped by a `start_after` past `phase_duration`, or by
+    /// `policy` stopping this phase early, has none), plus whether
+    /// `policy` decided the whole scenario should stop.
+    async fn run_phase_injections(
+        &self,
+        injections: &[&InjectionConfig],
+        phase_duration: Duration,
+        seed: Option<u64>,
+        phase_index: usize,
+        policy: InjectionFailurePolicy,
+        cancel: &CancellationToken,
+        pause_rx: &mut watch::Receiver<PauseState>,
+        background_handles: &mut Vec<InjectionHandle>,
+    ) -> (Vec<InjectionHandle>, Vec<InjectionOutcome>, usize, bool) {
+        #[derive(Clone, Copy)]
+        enum Event {
+            Apply(usize),
+            Remove(usize),
+        }
+
+        let mut pending: Vec<(Duration, Event)> = injections
+            .iter()
+            .enumerate()
+            .map(|(i, injection)| {
+                let at = injection.start_after.unwrap_or(Duration::ZERO)
+                    + injection_jitter_offset(injection, seed, phase_index, i);
+                (at, Event::Apply(i))
+            })
+            .collect();
+        pending.sort_by_key(|(at, _)| *at);
+
+        // `active`/`active_indices` are kept in lockstep so a position in
+        // one always names the injection whose handle sits at the same
+        // position in the other, even after a pause's lift/reapply cycle
+        // replaces a handle's id in place inside `active` - the same
+        // convention `sleep_while_running`'s other trackers rely on.
+        let mut active: Vec<InjectionHandle> = Vec::new();
+        let mut active_indices: Vec<usize> = Vec::new();
+        let mut applied_handles = Vec::new();
+        // Indexed the same as `injections`; `None` means this injection was
+        // never attempted (its `start_after` never came due, or it was
+        // dropped by a fail-fast/abort policy after an earlier failure).
+        let mut outcomes: Vec<Option<InjectionOutcome>> = vec![None; injections.len()];
+        let mut failed = 0usize;
+        // Set once a failure under `FailFast`/`AbortScenario` means no more
+        // of this phase's injections should be applied, even ones already
+        // due - `AbortScenario` additionally stops the whole scenario via
+        // the returned `bool`.
+        let mut stop_applying = false;
+        let mut abort_scenario = false;
+        let phase_start = Instant::now();
+
+        loop {
+            let elapsed = phase_start.elapsed();
+            let next_event_at = pending.first().map(|(at, _)| *at);
+
+            let wait_until = match next_event_at {
+                Some(at) if at < phase_duration => at,
+                _ => phase_duration,
+            };
+
+            if wait_until > elapsed {
+                if self
+                    .sleep_while_running(
+                        wait_until - elapsed,
+                        cancel,
+                        pause_rx,
+                        &mut [background_handles, &mut active],
+                    )
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            } else if cancel.is_cancelled() {
+                break;
+            }
+
+            let elapsed = phase_start.elapsed();
+            let phase_over = elapsed >= phase_duration;
+
+            // Every event due by now is collected before anything is
+            // applied, so a batch of injections that all fall due together
+            // (the common case: no `start_after`/`jitter` at all) go through
+            // `apply_injection` concurrently below instead of one blocking
+            // the next - a slow injector (health-check waits, ramping) used
+            // to delay every other injection due at the same instant.
+            let mut due_applies = Vec::new();
+            while let Some(&(at, event)) = pending.first() {
+                if at > elapsed {
+                    break;
+                }
+                pending.remove(0);
+
+                match event {
+                    Event::Apply(i) if !phase_over && !stop_applying => due_applies.push(i),
+                    Event::Apply(_) => {}
+                    Event::Remove(i) => {
+                        if let Some(pos) = active_indices.iter().position(|&idx| idx == i) {
+                            let handle = active.remove(pos);
+                            active_indices.remove(pos);
+                            if let Err(e) = self.executor.remove(handle.clone()).await {
+                                warn!("Failed to remove injection '{}': {}", handle.id, e);
+                                if let Some(outcome) = outcomes[i].as_mut() {
+                                    outcome.status = InjectionStatus::CleanupFailed;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !due_applies.is_empty() {
+                let mut tasks = tokio::task::JoinSet::new();
+                for i in due_applies {
+                    let runner = self.clone();
+                    let injection = injections[i].clone();
+                    let cancel = cancel.clone();
+                    tasks.spawn(async move {
+                        let result = runner.apply_injection(&injection, seed, &cancel).await;
+                        (i, result)
+                    });
+                }
+
+                while let Some(joined) = tasks.join_next().await {
+                    let (i, result) = match joined {
+                        Ok(pair) => pair,
+                        Err(e) => {
+                            warn!("Injection apply task panicked: {}", e);
+                            failed += 1;
+                            continue;
+                        }
+                    };
+
+                    match result {
+                        Ok(handle) => {
+                            info!("Applied injection: {}", injections[i].r#type);
+                            if let Some(duration) = injections[i].duration {
+                                pending.push((elapsed + duration, Event::Remove(i)));
+                                pending.sort_by_key(|(at, _)| *at);
+                            }
+                            outcomes[i] = Some(InjectionOutcome {
+                                injection_type: injections[i].r#type.clone(),
+                                status: InjectionStatus::Applied,
+                                target: injections[i].target.to_target_with_seed(seed).ok(),
+                                applied_parameters: resolved_parameters(injections[i]),
+                                applied_at: Some(handle.started_at),
+                            });
+                            applied_handles.push(handle.clone());
+                            active.push(handle);
+                            active_indices.push(i);
+                        }
+                        Err(e) => {
+                            warn!("Failed to apply injection '{}': {}", injections[i].r#type, e);
+                            outcomes[i] = Some(InjectionOutcome {
+                                injection_type: injections[i].r#type.clone(),
+                                status: InjectionStatus::Failed,
+                                target: injections[i].target.to_target_with_seed(seed).ok(),
+                                applied_parameters: resolved_parameters(injections[i]),
+                                applied_at: None,
+                            });
+                            failed += 1;
+                            match policy {
+                                InjectionFailurePolicy::Continue => {}
+                                InjectionFailurePolicy::FailFast => stop_applying = true,
+                                InjectionFailurePolicy::AbortScenario => {
+                                    stop_applying = true;
+                                    abort_scenario = true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if phase_over || cancel.is_cancelled() {
+                break;
+            }
+        }
+
+        for (handle, &i) in active.iter().zip(active_indices.iter()) {
+            if let Err(e) = self.executor.remove(handle.clone()).await {
+                warn!("Failed to remove injection '{}': {}", handle.id, e);
+                if let Some(outcome) = outcomes[i].as_mut() {
+                    outcome.status = InjectionStatus::CleanupFailed;
+                }
+            }
+        }
+
+        let outcomes: Vec<InjectionOutcome> = outcomes.into_iter().flatten().collect();
+
+        (applied_handles, outcomes, failed, abort_scenario)
+    }
+
+    async fn apply_injection(
+        &self,
+        injection: &InjectionConfig,
+        seed: Option<u64>,
+        cancel: &CancellationToken,
+    ) -> anyhow::Result<InjectionHandle> {
+        let target = injection.target.to_target_with_seed(seed)
+            .map_err(|e| anyhow::anyhow!("Invalid target: {}", e))?;
+
+        if let Some(ramp) = &injection.ramp {
+            return self.apply_ramped_injection(injection, &target, ramp, cancel).await;
+        }
+
+        let params = serde_json::to_value(&injection.parameters)
+            .map_err(|e| anyhow::anyhow!("Invalid parameters for '{}': {}", injection.r#type, e))?;
+
+        if cancel.is_cancelled() {
+            return Err(anyhow::anyhow!("Injection '{}' aborted by cancellation", injection.r#type));
+        }
+
+        // Deliberately not raced against `cancel` in a `select!`: the
+        // injector's side effect (e.g. `tc qdisc add`) is already live on
+        // the host by the time `inject_with_params` returns, and the
+        // executor only records the handle in `active_injections` once that
+        // call completes. Dropping the future on cancellation would leave
+        // the fault applied but untracked, invisible to `chaos recover` and
+        // `chaos run`'s post-abort cleanup sweep alike.
+        let handle = self
+            .executor
+            .inject_with_params(&injection.r#type, &target, &params)
+            .await
+            .map_err(|e| anyhow::anyhow!("Injection failed: {}", e))?;
+
+        Ok(handle)
+    }
+
+    /// Drives `ramp.parameter` from `ramp.from` to `ramp.to` over
+    /// `ramp.ramp_duration`, by re-applying the injection at `ramp.steps`
+    /// evenly-spaced points rather than jumping straight to the target
+    /// value. Each intermediate step's handle is removed as soon as the
+    /// next one is applied; only the final handle is returned, so it's
+    /// torn down the same way a non-ramped injection's handle would be
+    /// once the phase ends. Time spent ramping counts against the phase's
+    /// duration the same way applying any other injection does.
+    async fn apply_ramped_injection(
+        &self,
+        injection: &InjectionConfig,
+        target: &chaos_core::Target,
+        ramp: &crate::config::RampConfig,
+        cancel: &CancellationToken,
+    ) -> anyhow::Result<InjectionHandle> {
+        let steps = ramp.steps.max(2);
+        let step_duration = ramp.ramp_duration / steps;
+        let mut current = None;
+
+        for step in 0..steps {
+            let fraction = step as f64 / (steps - 1) as f64;
+            let value = ramp.from + (ramp.to - ramp.from) * fraction;
+
+            let mut parameters = injection.parameters.clone();
+            parameters.insert(ramp.parameter.clone(), serde_json::json!(value));
+            let params = serde_json::to_value(&parameters).map_err(|e| {
+                anyhow::anyhow!("Invalid parameters for '{}': {}", injection.r#type, e)
+            })?;
+
+            if cancel.is_cancelled() {
+                return Err(anyhow::anyhow!("Injection '{}' aborted by cancellation", injection.r#type));
+            }
+
+            // See `apply_injection` for why this isn't raced against `cancel`.
+            let new_handle = self
+                .executor
+                .inject_with_params(&injection.r#type, target, &params)
+                .await
+                .map_err(|e| anyhow::anyhow!("Ramped injection failed: {}", e))?;
+
+            info!(
+                "Ramping '{}': {}={:.3} ({}/{})",
+                injection.r#type, ramp.parameter, value, step + 1, steps
+            );
+
+            if let Some(previous) = current.replace(new_handle) {
+                if let Err(e) = self.executor.remove(previous).await {
+                    warn!("Failed to remove intermediate ramp step for '{}': {}", injection.r#type, e);
+                }
+            }
+
+            if step + 1 < steps {
+                tokio::select! {
+                    _ = tokio::time::sleep(step_duration) => {}
+                    _ = cancel.cancelled() => break,
+                }
+            }
+        }
+
+        current.ok_or_else(|| anyhow::anyhow!("Ramp for '{}' produced no steps", injection.r#type))
+    }
+
+    /// Sleeps for `remaining`, returning early (`Err(())`) if `cancel`
+    /// fires. Also freezes the countdown while paused rather than letting
+    /// it elapse in the background - a paused scenario should come back
+    /// to exactly where it left off, not discover its phase timer ran out
+    /// while nobody was watching.
+    async fn sleep_while_running(
+        &self,
+        mut remaining: Duration,
+        cancel: &CancellationToken,
+        pause_rx: &mut watch::Receiver<PauseState>,
+        tracked: &mut [&mut Vec<InjectionHandle>],
+    ) -> Result<(), ()> {
+        loop {
+            if cancel.is_cancelled() {
+                return Err(());
+            }
+
+            if matches!(*pause_rx.borrow(), PauseState::Paused { .. }) {
+                self.lift_and_wait_for_resume(pause_rx, cancel, tracked).await;
+                continue;
+            }
+
+            if remaining.is_zero() {
+                return Ok(());
+            }
+
+            let started = Instant::now();
+            tokio::select! {
+                _ = tokio::time::sleep(remaining) => return Ok(()),
+                _ = cancel.cancelled() => return Err(()),
+                _ = pause_rx.changed() => {
+                    remaining = remaining.saturating_sub(started.elapsed());
+                }
+            }
+        }
+    }
+
+    /// Lifts (if requested) and blocks until [`Self::resume`] or `cancel`
+    /// fires, then re-applies whatever was lifted. `tracked` is every
+    /// handle bucket (background load, the current phase) the caller
+    /// still owns; a re-applied injection's new handle replaces its old
+    /// one wherever it's found, so later teardown doesn't try to remove an
+    /// id the executor already forgot about.
+    async fn lift_and_wait_for_resume(
+        &self,
+        pause_rx: &mut watch::Receiver<PauseState>,
+        cancel: &CancellationToken,
+        tracked: &mut [&mut Vec<InjectionHandle>],
+    ) {
+        let PauseState::Paused { lift_injections } = *pause_rx.borrow() else {
+            return;
+        };
+
+        info!("Scenario paused");
+
+        let lifted = if lift_injections {
+            let active = self.executor.list_active().await;
+            for handle in &active {
+                if let Err(e) = self.executor.remove(handle.clone()).await {
+                    warn!("Failed to lift injection '{}' for pause: {}", handle.id, e);
+                }
+            }
+            active
+        } else {
+            Vec::new()
+        };
+
+        while matches!(*pause_rx.borrow(), PauseState::Paused { .. }) {
+            tokio::select! {
+                _ = pause_rx.changed() => {}
+                _ = cancel.cancelled() => return,
+            }
+        }
+
+        info!("Scenario resumed");
+
+        if cancel.is_cancelled() {
+            return;
+        }
+
+        for old_handle in lifted {
+            match self
+                .executor
+                .inject_with_params(&old_handle.injector_name, &old_handle.target, &old_handle.metadata)
+                .await
+            {
+                Ok(new_handle) => {
+                    let replaced = tracked.iter_mut().any(|bucket| {
+                        bucket
+                            .iter_mut()
+                            .find(|h| h.id == old_handle.id)
+                            .map(|slot| *slot = new_handle.clone())
+                            .is_some()
+                    });
+                    if !replaced {
+                        warn!(
+                            "Re-applied injection '{}' after resume has no tracked slot; it won't be torn down automatically",
+                            new_handle.id
+                        );
+                    }
+                }
+                Err(e) => warn!(
+                    "Failed to re-apply injection '{}' after resume: {}",
+                    old_handle.injector_name, e
+                ),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScenarioResult {
+    pub scenario_name: String,
+    #[serde(with = "humantime_serde")]
+    pub total_duration: Duration,
+    pub phase_results: Vec<PhaseResult>,
+    pub total_injections: usize,
+    /// Injections (background or in-phase) that failed to apply. Under the
+    /// default `Continue` [`InjectionFailurePolicy`] these don't stop the
+    /// scenario (`FailFast` stops the rest of that phase's injections,
+    /// `AbortScenario` stops the scenario) - but a CI
+    /// pipeline gating on `chaos run --fail-on any-injection-failure`
+    /// needs to know about them.
+    #[serde(default)]
+    pub failed_injections: usize,
+    /// Set when the scenario stopped before running every scheduled
+    /// phase, e.g. cancellation, an exhausted error budget, or a tripped
+    /// abort condition. `None` means every phase ran to completion.
+    #[serde(default)]
+    pub aborted_reason: Option<String>,
+    pub host: HostFingerprint,
+    /// Copied from the scenario that produced this result, so labels (e.g.
+    /// [`crate::config::BASELINE_LABEL`]) survive into stored history.
+    #[serde(default)]
+    pub labels: std::collections::HashMap<String, String>,
+    /// Results of [`crate::config::Scenario::before`]/`after` hooks, in the
+    /// order they ran (every `before` hook, then every `after` hook).
+    /// Per-phase hook results live on [`PhaseResult::hook_results`] instead.
+    #[serde(default)]
+    pub hook_results: Vec<HookResult>,
+    /// The seed this run actually used, whether or not the scenario
+    /// declared one - `run` fills in a random one if it didn't, so this is
+    /// always `Some` for any result `run` produced. `crate::replay` needs
+    /// this to rerun the exact same random choices (phase order, jitter,
+    /// sampling, injection selection) the original run made.
+    #[serde(default)]
+    pub resolved_seed: Option<u64>,
+}
+
+impl ScenarioResult {
+    /// Fraction of attempted injections, across every phase, that applied
+    /// (and if applicable, cleaned up) without error. `1.0` if the
+    /// scenario attempted none, since nothing failed.
+    pub fn success_rate(&self) -> f64 {
+        let outcomes: Vec<&InjectionOutcome> = self
+            .phase_results
+            .iter()
+            .flat_map(|p| p.injection_outcomes.iter())
+            .collect();
+
+        if outcomes.is_empty() {
+            return 1.0;
+        }
+
+        let successful = outcomes
+            .iter()
+            .filter(|o| o.status == InjectionStatus::Applied)
+            .count();
+
+        successful as f64 / outcomes.len() as f64
+    }
+
+    pub fn average_phase_duration(&self) -> Duration {
+        if self.phase_results.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let total: Duration = self.phase_results.iter().map(|p| p.duration).sum();
+        total / self.phase_results.len() as u32
+    }
+
+    /// Whether the scenario stopped early, per [`Self::aborted_reason`].
+    pub fn is_aborted(&self) -> bool {
+        self.aborted_reason.is_some()
+    }
+
+    /// Whether the scenario that produced this result was tagged as a
+    /// baseline recording (see [`crate::config::Scenario::baseline`]).
+    pub fn is_baseline(&self) -> bool {
+        self.labels
+            .get(crate::config::BASELINE_LABEL)
+            .map(String::as_str)
+            == Some("true")
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PhaseResult {
+    pub name: String,
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+    pub injection_count: usize,
+    /// Whether every injection this phase attempted was applied
+    /// successfully - the proxy a later phase's `run_if:
+    /// phases.<name>.slo_passed` condition checks, in the absence of a
+    /// real per-phase SLO verdict in this crate. Always `true` for a
+    /// skipped phase, since "not run" isn't a fault survived or not.
+    pub success: bool,
+    /// Set when this phase's `run_if` condition wasn't met, so a report
+    /// can distinguish "ran with zero injections" from "skipped".
+    #[serde(default)]
+    pub skipped: bool,
+    /// One entry per injection this phase attempted, in the same order as
+    /// the phase's `injections` list (with an `injection_selection`, in the
+    /// order the subset was drawn instead) - the detail behind
+    /// `injection_count`/`success` above, and what
+    /// [`ScenarioResult::success_rate`] sums across phases instead of the
+    /// hardcoded ratio it used to return.
+    #[serde(default)]
+    pub injection_outcomes: Vec<InjectionOutcome>,
+    /// Results of this phase's [`crate::config::Phase::before`]/`after`
+    /// hooks, in the order they ran (every `before` hook, then every
+    /// `after` hook).
+    #[serde(default)]
+    pub hook_results: Vec<HookResult>,
+}
+
+/// What happened when a [`HookConfig`] ran, captured into the run's
+/// artifacts alongside injection outcomes - the detail behind a scenario or
+/// phase's `before`/`after` hooks, since neither runs anywhere a caller can
+/// otherwise observe.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HookResult {
+    pub name: String,
+    pub success: bool,
+    /// Captured stdout+stderr (a command hook) or response body (an HTTP
+    /// hook), regardless of `success` - a failed hook's output is often the
+    /// most useful part of the result.
+    pub output: String,
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+}
+
+/// Runs every hook in `hooks` in order, waiting for each to finish (or time
+/// out) before starting the next - hooks are usually ordered side effects
+/// (flush a cache, *then* trigger a failover), so running them concurrently
+/// would defeat the point. A failing or timed-out hook doesn't stop the
+/// rest; it's just recorded as `success: false` for the caller to notice.
+async fn run_hooks(hooks: &[HookConfig]) -> Vec<HookResult> {
+    let mut results = Vec::with_capacity(hooks.len());
+    for hook in hooks {
+        results.push(run_hook(hook).await);
+    }
+    results
+}
+
+async fn run_hook(hook: &HookConfig) -> HookResult {
+    let start = Instant::now();
+    let outcome = tokio::time::timeout(hook.timeout, run_hook_action(&hook.action)).await;
+
+    let (success, output) = match outcome {
+        Ok(Ok(output)) => (true, output),
+        Ok(Err(output)) => (false, output),
+        Err(_) => (false, format!("timed out after {:?}", hook.timeout)),
+    };
+
+    if !success {
+        warn!("Hook '{}' failed: {}", hook.name, output);
+    }
+
+    HookResult {
+        name: hook.name.clone(),
+        success,
+        output,
+        duration: start.elapsed(),
+    }
+}
+
+/// Runs one [`HookAction`] to completion. `Ok`/`Err` both carry the
+/// captured output - the only difference is whether the command exited
+/// zero (or the request came back 2xx).
+async fn run_hook_action(action: &HookAction) -> Result<String, String> {
+    match action {
+        HookAction::Command { command, args } => {
+            let output = tokio::process::Command::new(command)
+                .args(args)
+                .output()
+                .await
+                .map_err(|e| format!("failed to spawn '{}': {}", command, e))?;
+
+            let captured = format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+
+            if output.status.success() {
+                Ok(captured)
+            } else {
+                Err(format!("'{}' exited with {}: {}", command, output.status, captured))
+            }
+        }
+        HookAction::Http { url, method } => {
+            let method = reqwest::Method::from_bytes(method.as_bytes())
+                .map_err(|e| format!("invalid HTTP method '{}': {}", method, e))?;
+
+            let response = reqwest::Client::new()
+                .request(method, url)
+                .send()
+                .await
+                .map_err(|e| format!("request to '{}' failed: {}", url, e))?;
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+
+            if status.is_success() {
+                Ok(body)
+            } else {
+                Err(format!("'{}' returned {}: {}", url, status, body))
+            }
+        }
+    }
+}
+
+/// A run-lifecycle event a scenario's [`NotificationConfig`] fires on. Sent
+/// as a JSON POST to every configured webhook, and as a formatted message to
+/// the configured Slack channel if any - see [`Self::summary`] for the text
+/// used there.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "kebab-case", tag = "event")]
+pub enum NotificationEvent<'a> {
+    ScenarioStarted { scenario: &'a str },
+    PhaseStarted { scenario: &'a str, phase: &'a str },
+    PhaseCompleted { scenario: &'a str, phase: &'a str, success: bool },
+    /// A phase finished with a failed injection - the closest thing this
+    /// crate has to an SLO verdict without depending on `chaos_metrics`,
+    /// same proxy [`crate::config::Phase::run_if`] checks via
+    /// [`PhaseResult::success`].
+    SloViolation { scenario: &'a str, phase: &'a str },
+    ScenarioAborted { scenario: &'a str, reason: &'a str },
+    ScenarioCompleted {
+        scenario: &'a str,
+        duration_secs: f64,
+        failed_injections: usize,
+    },
+}
+
+impl NotificationEvent<'_> {
+    /// The one-line text posted to Slack; webhooks get the full JSON instead
+    /// since a webhook consumer can already parse the tagged event.
+    fn summary(&self) -> String {
+        match self {
+            Self::ScenarioStarted { scenario } => format!(":rocket: chaos scenario `{scenario}` started"),
+            Self::PhaseStarted { scenario, phase } => {
+                format!(":arrow_forward: `{scenario}`: phase `{phase}` started")
+            }
+            Self::PhaseCompleted { scenario, phase, success } => format!(
+                "{} `{scenario}`: phase `{phase}` completed ({})",
+                if *success { ":white_check_mark:" } else { ":warning:" },
+                if *success { "success" } else { "failed" }
+            ),
+            Self::SloViolation { scenario, phase } => {
+                format!(":rotating_light: `{scenario}`: phase `{phase}` violated its SLO")
+            }
+            Self::ScenarioAborted { scenario, reason } => {
+                format!(":octagonal_sign: chaos scenario `{scenario}` aborted: {reason}")
+            }
+            Self::ScenarioCompleted { scenario, duration_secs, failed_injections } => format!(
+                ":checkered_flag: chaos scenario `{scenario}` completed in {duration_secs:.1}s ({failed_injections} failed injection(s))"
+            ),
+        }
+    }
+}
+
+/// Posts `event` to every webhook and the Slack channel `config` names, if
+/// any. Best-effort: a delivery failure is logged and otherwise ignored,
+/// the same as a failing [`HookConfig`] - a notification going missing
+/// shouldn't be the reason a chaos run aborts.
+async fn notify(config: &NotificationConfig, event: &NotificationEvent<'_>) {
+    if config.webhooks.is_empty() && config.slack.is_none() {
+        return;
+    }
+
+    let body = serde_json::to_value(event).expect("NotificationEvent always serializes");
+    let client = reqwest::Client::new();
+
+    for webhook in &config.webhooks {
+        if let Err(e) = client.post(webhook).json(&body).send().await {
+            warn!("Failed to deliver notification to webhook '{}': {}", webhook, e);
+        }
+    }
+
+    if let Some(slack) = &config.slack {
+        let payload = serde_json::json!({
+            "channel": slack.channel,
+            "text": event.summary(),
+        });
+        let result = client
+            .post("https://slack.com/api/chat.postMessage")
+            .bearer_auth(&slack.token)
+            .json(&payload)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                warn!("Slack notification to '{}' returned {}", slack.channel, response.status());
+            }
+            Err(e) => warn!("Failed to deliver Slack notification to '{}': {}", slack.channel, e),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// What happened to a single injection a phase attempted, tracked so
+/// [`ScenarioResult::success_rate`] can report a real ratio, so a report
+/// can point at exactly which injection went wrong, and so `chaos replay`
+/// (see [`crate::replay`]) has the actual resolved target and parameters
+/// to reapply instead of re-resolving the scenario's declaration, which
+/// may not resolve to the same thing twice if it used discovery or
+/// sampling.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct InjectionOutcome {
+    pub injection_type: String,
+    pub status: InjectionStatus,
+    /// The concrete target this injection resolved to before being
+    /// applied. `None` only if target resolution itself failed before an
+    /// injector was ever called.
+    pub target: Option<chaos_core::Target>,
+    /// Parameters actually sent to the injector - the scenario's declared
+    /// `parameters`, with a `ramp`'s parameter overwritten by its final
+    /// (`to`) value if one was configured.
+    pub applied_parameters: serde_json::Value,
+    /// Wall-clock time this injection was applied. `None` if it was never
+    /// attempted or failed before an injector accepted it.
+    pub applied_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InjectionStatus {
+    /// Applied and, if it had a fixed `duration` or the phase ended
+    /// normally, removed without error.
+    Applied,
+    /// `Injector::inject` (or ramping to it) returned an error.
+    Failed,
+    /// Applied successfully, but `Injector::remove` failed during
+    /// teardown - the fault may still be active on the target.
+    CleanupFailed,
+}
+
+pub async fn run_scenario(scenario: &Scenario) -> anyhow::Result<ScenarioResult> {
+    let runner = ScenarioRunner::with_defaults();
+    runner.run(scenario).await
+}
+
+/// Evaluates a [`Phase::run_if`] condition against the phases that have run
+/// so far. Only `phases.<name>.slo_passed` and its negation `!phases.<name>
+/// .slo_passed` are understood; a condition naming a phase that hasn't run
+/// yet - never scheduled, or skipped by an earlier `run_if` - is not met.
+fn evaluate_run_if(condition: &str, phase_results: &[PhaseResult]) -> bool {
+    let (negate, expr) = match condition.trim().strip_prefix('!') {
+        Some(rest) => (true, rest.trim()),
+        None => (false, condition.trim()),
+    };
+
+    let met = expr
+        .strip_prefix("phases.")
+        .and_then(|rest| rest.strip_suffix(".slo_passed"))
+        .and_then(|phase_name| phase_results.iter().find(|p| p.name == phase_name))
+        .map(|p| p.success)
+        .unwrap_or(false);
+
+    if negate {
+        !met
+    } else {
+        met
+    }
+}
+
+/// The parameters actually sent to the injector for `injection`: its
+/// declared `parameters`, with a `ramp`'s parameter overwritten by its
+/// final (`to`) value if one is configured - matching what
+/// [`ScenarioRunner::apply_ramped_injection`]'s last step actually applies,
+/// rather than the starting value a scenario's `parameters` map declares.
+fn resolved_parameters(injection: &InjectionConfig) -> serde_json::Value {
+    let mut parameters = injection.parameters.clone();
+    if let Some(ramp) = &injection.ramp {
+        parameters.insert(ramp.parameter.clone(), serde_json::json!(ramp.to));
+    }
+    serde_json::to_value(&parameters).unwrap_or(serde_json::Value::Null)
+}
+
+/// Adds a random `[0, injection.jitter]` offset on top of `start_after`,
+/// seeded by mixing the scenario's seed with `phase_index` and
+/// `injection_index` - the same mixing convention [`select_injections`]
+/// uses - so a fleet of otherwise-identical injections doesn't all fire at
+/// the exact same instant, while staying reproducible for a given seed.
+fn injection_jitter_offset(
+    injection: &InjectionConfig,
+    seed: Option<u64>,
+    phase_index: usize,
+    injection_index: usize,
+) -> Duration {
+    let Some(jitter) = injection.jitter else {
+        return Duration::ZERO;
+    };
+    if jitter.is_zero() {
+        return Duration::ZERO;
+    }
+
+    let mut rng = match seed {
+        Some(seed) => {
+            StdRng::seed_from_u64(seed ^ (phase_index as u64) ^ (injection_index as u64))
+        }
+        None => StdRng::from_entropy(),
+    };
+    rng.gen_range(Duration::ZERO..=jitter)
+}
+
+/// Picks which of a phase's `injections` to apply this run. Without a
+/// [`crate::config::InjectionSelection`], every injection is applied, same
+/// as before this existed. With one, `count` injections are drawn without
+/// replacement, weighted by `weights` (index-aligned with `injections`; a
+/// missing or short entry defaults to `1.0`), seeded by mixing the
+/// scenario's seed with `phase_index` so distinct phases with a selection
+/// don't always draw the same subset.
+fn select_injections<'a>(
+    injections: &'a [InjectionConfig],
+    selection: Option<&InjectionSelection>,
+    seed: Option<u64>,
+    phase_index: usize,
+) -> Vec<&'a InjectionConfig> {
+    let Some(selection) = selection else {
+        return injections.iter().collect();
+    };
+
+    if injections.is_empty() {
+        return Vec::new();
+    }
+
+    let weight = |i: usize| selection.weights.get(i).copied().unwrap_or(1.0);
+    let count = selection.count.min(injections.len());
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed ^ (phase_index as u64)),
+        None => StdRng::from_entropy(),
+    };
+
+    injections
+        .choose_multiple_weighted(&mut rng, count, |item| {
+            let index = injections
+                .iter()
+                .position(|candidate| std::ptr::eq(candidate, item))
+                .unwrap_or(0);
+            weight(index)
+        })
+        .expect("weights are validated to be > 0 before a scenario runs")
+        .collect()
+}
+
+mod humantime_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&humantime::format_duration(*duration).to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        humantime::parse_duration(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Phase;
+
+    #[tokio::test]
+    async fn test_scenario_runner_creation() {
+        let _runner = ScenarioRunner::with_defaults();
+        assert!(true); // Runner created successfully
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_token_aborts_before_first_phase() {
+        let scenario = Scenario {
+            name: "cancel-me".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(60),
+            ramp_up: None,
+            phases: vec![Phase {
+                name: "phase1".to_string(),
+                duration: Duration::from_secs(60),
+                injections: Vec::new(),
+                parallel: false,
+                run_if: None,
+                injection_selection: None,
+                jitter: None,
+                recovery_period: None,
+                before: Vec::new(),
+                after: Vec::new(),
+            }],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let runner = ScenarioRunner::with_defaults();
+        let start = std::time::Instant::now();
+        let result = runner
+            .run_with_cancellation(&scenario, cancel)
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(result.phase_results.is_empty());
+        assert!(result.is_aborted());
+    }
+
+    #[tokio::test]
+    async fn test_abort_condition_rejects_scenario_before_first_phase() {
+        let scenario = Scenario {
+            name: "already-unhealthy".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(60),
+            ramp_up: None,
+            phases: vec![Phase {
+                name: "phase1".to_string(),
+                duration: Duration::from_secs(60),
+                injections: Vec::new(),
+                parallel: false,
+                run_if: None,
+                injection_selection: None,
+                jitter: None,
+                recovery_period: None,
+                before: Vec::new(),
+                after: Vec::new(),
+            }],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: Some(chaos_core::AbortConditions {
+                health_check_url: Some("http://127.0.0.1:0/healthz".to_string()),
+                health_check_grace: Some(Duration::ZERO),
+                ..Default::default()
+            }),
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let err = runner.run(&scenario).await.unwrap_err();
+        assert!(err.to_string().contains("health check"));
+    }
+
+    #[test]
+    fn test_evaluate_run_if_treats_a_phase_that_has_not_run_as_not_met() {
+        let phase_results = vec![PhaseResult {
+            name: "warmup".to_string(),
+            duration: Duration::from_secs(1),
+            injection_count: 0,
+            success: false,
+            skipped: false,
+            injection_outcomes: Vec::new(),
+            hook_results: Vec::new(),
+        }];
+
+        assert!(!evaluate_run_if("phases.warmup.slo_passed", &phase_results));
+        assert!(evaluate_run_if("!phases.warmup.slo_passed", &phase_results));
+        assert!(!evaluate_run_if("phases.never_ran.slo_passed", &phase_results));
+    }
+
+    #[tokio::test]
+    async fn test_run_if_skips_a_phase_when_the_earlier_phase_failed() {
+        let scenario = Scenario {
+            name: "escalation".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(1),
+            ramp_up: None,
+            phases: vec![
+                Phase {
+                    name: "warmup".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: vec![InjectionConfig {
+                        r#type: "network_latency".to_string(),
+                        target: crate::config::TargetConfig {
+                            members: Some(Vec::new()),
+                            ..Default::default()
+                        },
+                        parameters: std::collections::HashMap::new(),
+                        ramp: None,
+                        start_after: None,
+                        duration: None,
+                        jitter: None,
+                    }],
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+                Phase {
+                    name: "escalate".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: Some("phases.warmup.slo_passed".to_string()),
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+            ],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.phase_results.len(), 2);
+        assert!(!result.phase_results[0].success);
+        assert!(result.phase_results[1].skipped);
+    }
+
+    /// A `network_latency` injection whose target resolves to no members,
+    /// which fails to apply - the same trick [`test_run_if_skips_a_phase_when_the_earlier_phase_failed`]
+    /// uses to deterministically fail an injection without touching the
+    /// real host.
+    fn failing_injection() -> InjectionConfig {
+        failing_injection_after(None)
+    }
+
+    fn failing_injection_after(start_after: Option<Duration>) -> InjectionConfig {
+        InjectionConfig {
+            r#type: "network_latency".to_string(),
+            target: crate::config::TargetConfig {
+                members: Some(Vec::new()),
+                ..Default::default()
+            },
+            parameters: std::collections::HashMap::new(),
+            ramp: None,
+            start_after,
+            duration: None,
+            jitter: None,
+        }
+    }
+
+    fn scenario_with_two_phases(
+        first_injections: Vec<InjectionConfig>,
+        injection_failure_policy: InjectionFailurePolicy,
+    ) -> Scenario {
+        Scenario {
+            name: "policy-test".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(1),
+            ramp_up: None,
+            phases: vec![
+                Phase {
+                    name: "first".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: first_injections,
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+                Phase {
+                    name: "second".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+            ],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy,
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_continue_policy_runs_remaining_phases_after_a_failed_injection() {
+        let scenario = scenario_with_two_phases(
+            vec![failing_injection()],
+            InjectionFailurePolicy::Continue,
+        );
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.phase_results.len(), 2);
+        assert!(!result.phase_results[0].success);
+        assert!(!result.phase_results[1].skipped);
+        assert_eq!(
+            result.phase_results[0]
+                .injection_outcomes
+                .iter()
+                .map(|o| o.status)
+                .collect::<Vec<_>>(),
+            vec![InjectionStatus::Failed]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fail_fast_policy_stops_applying_further_injections_but_runs_remaining_phases() {
+        // Staggered `start_after` so the two injections fall due one at a
+        // time - if they were both due at once, they'd apply concurrently
+        // (see `run_phase_injections`'s per-instant batching) before
+        // `stop_applying` had a chance to take effect.
+        let mut scenario = scenario_with_two_phases(
+            vec![
+                failing_injection_after(None),
+                failing_injection_after(Some(Duration::from_millis(50))),
+            ],
+            InjectionFailurePolicy::FailFast,
+        );
+        scenario.phases[0].duration = Duration::from_millis(100);
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.phase_results.len(), 2);
+        assert_eq!(result.phase_results[0].injection_outcomes.len(), 1);
+        assert_eq!(result.phase_results[1].name, "second");
+        assert!(result.aborted_reason.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_abort_scenario_policy_stops_the_whole_scenario() {
+        let scenario = scenario_with_two_phases(
+            vec![failing_injection()],
+            InjectionFailurePolicy::AbortScenario,
+        );
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.phase_results.len(), 1);
+        assert!(result.aborted_reason.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_success_rate_reflects_actual_failed_injections() {
+        let scenario = scenario_with_two_phases(
+            vec![failing_injection()],
+            InjectionFailurePolicy::Continue,
+        );
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.success_rate(), 0.0);
+    }
+
+    /// A `CleanupFailed` outcome means the fault may still be active on
+    /// the target - the worst outcome a run can have, and one
+    /// `success_rate` used to (wrongly) count as a success because it
+    /// only excluded `Failed`. Constructed directly rather than through a
+    /// full run since triggering a real cleanup failure needs an injector
+    /// whose `remove` fails, which nothing in this crate's test fixtures
+    /// does.
+    #[test]
+    fn test_success_rate_counts_cleanup_failed_as_unsuccessful() {
+        let result = ScenarioResult {
+            scenario_name: "cleanup-failure".to_string(),
+            total_duration: Duration::from_secs(1),
+            phase_results: vec![PhaseResult {
+                name: "first".to_string(),
+                duration: Duration::from_secs(1),
+                injection_count: 2,
+                success: false,
+                skipped: false,
+                injection_outcomes: vec![
+                    InjectionOutcome {
+                        injection_type: "network_latency".to_string(),
+                        status: InjectionStatus::Applied,
+                        target: None,
+                        applied_parameters: serde_json::Value::Null,
+                        applied_at: None,
+                    },
+                    InjectionOutcome {
+                        injection_type: "process_kill".to_string(),
+                        status: InjectionStatus::CleanupFailed,
+                        target: None,
+                        applied_parameters: serde_json::Value::Null,
+                        applied_at: None,
+                    },
+                ],
+                hook_results: Vec::new(),
+            }],
+            total_injections: 2,
+            failed_injections: 0,
+            aborted_reason: None,
+            host: HostFingerprint::capture(),
+            labels: std::collections::HashMap::new(),
+            hook_results: Vec::new(),
+            resolved_seed: None,
+        };
+
+        assert_eq!(result.success_rate(), 0.5);
+    }
+
+    fn command_hook(name: &str, command: &str, args: &[&str]) -> HookConfig {
+        HookConfig {
+            name: name.to_string(),
+            action: HookAction::Command {
+                command: command.to_string(),
+                args: args.iter().map(|a| a.to_string()).collect(),
+            },
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scenario_before_and_after_hooks_run_and_capture_output() {
+        let mut scenario = scenario_with_two_phases(Vec::new(), InjectionFailurePolicy::default());
+        scenario.before = vec![command_hook("warm-cache", "echo", &["before"])];
+        scenario.after = vec![command_hook("notify", "echo", &["after"])];
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.hook_results.len(), 2);
+        assert!(result.hook_results[0].success);
+        assert_eq!(result.hook_results[0].output.trim(), "before");
+        assert!(result.hook_results[1].success);
+        assert_eq!(result.hook_results[1].output.trim(), "after");
+    }
+
+    #[tokio::test]
+    async fn test_a_failing_hook_is_recorded_but_does_not_abort_the_scenario() {
+        let mut scenario = scenario_with_two_phases(Vec::new(), InjectionFailurePolicy::default());
+        scenario.before = vec![command_hook("broken", "false", &[])];
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert!(!result.hook_results[0].success);
+        assert!(result.aborted_reason.is_none());
+        assert_eq!(result.phase_results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_phase_hooks_run_around_its_injections() {
+        let mut scenario = scenario_with_two_phases(Vec::new(), InjectionFailurePolicy::default());
+        scenario.phases[0].before = vec![command_hook("phase-before", "echo", &["p-before"])];
+        scenario.phases[0].after = vec![command_hook("phase-after", "echo", &["p-after"])];
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert_eq!(result.phase_results[0].hook_results.len(), 2);
+        assert_eq!(result.phase_results[0].hook_results[0].output.trim(), "p-before");
+        assert_eq!(result.phase_results[0].hook_results[1].output.trim(), "p-after");
+        assert!(result.phase_results[1].hook_results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scenario_runs_normally_when_notification_delivery_fails() {
+        // Port 0 is never a valid listener, so this webhook post fails
+        // immediately and deterministically without touching the network -
+        // the same trick `chaos_core::AbortMonitor`'s health-check test uses.
+        let mut scenario = scenario_with_two_phases(Vec::new(), InjectionFailurePolicy::default());
+        scenario.notifications = NotificationConfig {
+            webhooks: vec!["http://127.0.0.1:0/hook".to_string()],
+            slack: None,
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert!(result.aborted_reason.is_none());
+        assert_eq!(result.phase_results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_notify_is_a_no_op_with_no_configured_targets() {
+        // Nothing to send to - this must not attempt any network I/O, so it
+        // completes well within the timeout even off any network.
+        let result = tokio::time::timeout(
+            Duration::from_secs(1),
+            notify(&NotificationConfig::default(), &NotificationEvent::ScenarioStarted { scenario: "unused" }),
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_period_delays_progress_to_the_next_phase() {
+        let scenario = Scenario {
+            name: "with-recovery".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(1),
+            ramp_up: None,
+            phases: vec![
+                Phase {
+                    name: "p1".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: Some(Duration::from_millis(200)),
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+                Phase {
+                    name: "p2".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+            ],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let start = std::time::Instant::now();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(result.phase_results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_phase_recovery_period_overrides_scenario_default() {
+        let scenario = Scenario {
+            name: "override-recovery".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(1),
+            ramp_up: None,
+            phases: vec![
+                Phase {
+                    name: "p1".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    // No override: falls back to the scenario's default below.
+                    recovery_period: None,
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+                Phase {
+                    name: "p2".to_string(),
+                    duration: Duration::from_millis(1),
+                    injections: Vec::new(),
+                    parallel: false,
+                    run_if: None,
+                    injection_selection: None,
+                    jitter: None,
+                    // Overrides the scenario default down to nothing, so this
+                    // phase's recovery period should not add any wait time.
+                    recovery_period: Some(Duration::ZERO),
+                    before: Vec::new(),
+                    after: Vec::new(),
+                },
+            ],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: Some(Duration::from_millis(200)),
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let start = std::time::Instant::now();
+        let result = runner.run(&scenario).await.unwrap();
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200));
+        assert!(elapsed < Duration::from_secs(5));
+        assert_eq!(result.phase_results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_phases_run_concurrently_not_one_after_another() {
+        let make_phase = |name: &str| Phase {
+            name: name.to_string(),
+            duration: Duration::from_millis(1),
+            injections: Vec::new(),
+            parallel: true,
+            run_if: None,
+            injection_selection: None,
+            jitter: None,
+            // Each phase's own quiet period stands in for slow work a
+            // phase might do - if the phases genuinely run concurrently
+            // rather than one after another, the scenario as a whole only
+            // takes as long as the slowest one, not the sum of both.
+            recovery_period: Some(Duration::from_millis(300)),
+            before: Vec::new(),
+            after: Vec::new(),
+        };
+
+        let scenario = Scenario {
+            name: "parallel".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(1),
+            ramp_up: None,
+            phases: vec![make_phase("p1"), make_phase("p2")],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: None,
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let start = std::time::Instant::now();
+        let result = runner.run(&scenario).await.unwrap();
+
+        assert!(start.elapsed() < Duration::from_millis(550));
+        assert_eq!(result.phase_results.len(), 2);
+        assert_eq!(result.phase_results[0].name, "p1");
+        assert_eq!(result.phase_results[1].name, "p2");
+    }
+
+    /// `run_phases_concurrently` is called directly here (rather than
+    /// through `run`) so the already-exhausted error budget doesn't just
+    /// trip `run_with_cancellation`'s upfront pre-first-phase check - the
+    /// gap this test covers is the concurrent batch's own periodic
+    /// recheck, which is the only thing standing between a long-running
+    /// parallel phase and the exact "keeps burning no matter how badly
+    /// the target degrades" bug this was written to fix.
+    #[tokio::test]
+    async fn test_concurrent_phases_abort_when_error_budget_is_exhausted() {
+        let make_phase = |name: &str| Phase {
+            name: name.to_string(),
+            duration: Duration::from_secs(5),
+            injections: Vec::new(),
+            parallel: true,
+            run_if: None,
+            injection_selection: None,
+            jitter: None,
+            recovery_period: None,
+            before: Vec::new(),
+            after: Vec::new(),
+        };
+
+        let scenario = Scenario {
+            name: "parallel-over-budget".to_string(),
+            description: None,
+            seed: None,
+            duration: Duration::from_secs(10),
+            ramp_up: None,
+            phases: vec![make_phase("p1"), make_phase("p2")],
+            background: Vec::new(),
+            labels: std::collections::HashMap::new(),
+            error_budget: Some(chaos_core::ErrorBudgetPolicy {
+                source: chaos_core::ErrorBudgetSource::Static { remaining: 0.0 },
+                minimum: 0.5,
+            }),
+            abort_conditions: None,
+            workloads: Vec::new(),
+            probes: Vec::new(),
+            recovery_period: None,
+            injection_failure_policy: InjectionFailurePolicy::default(),
+            before: Vec::new(),
+            after: Vec::new(),
+            notifications: NotificationConfig::default(),
+        };
+
+        let runner = ScenarioRunner::with_defaults();
+        let cancel = CancellationToken::new();
+        let start = std::time::Instant::now();
+        let scheduled = vec![
+            ScheduledPhase {
+                phase: scenario.phases[0].clone(),
+                index: 0,
+                start_time: Duration::ZERO,
+                end_time: scenario.phases[0].duration,
+            },
+            ScheduledPhase {
+                phase: scenario.phases[1].clone(),
+                index: 1,
+                start_time: Duration::ZERO,
+                end_time: scenario.phases[1].duration,
+            },
+        ];
+        let (_, _, _, breach_reason) = runner
+            .run_phases_concurrently(&scenario, scheduled, tokio::time::Instant::now(), &cancel)
+            .await;
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(breach_reason.is_some());
+        assert!(cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_scenario_result() {
+        let result = ScenarioResult {
+            scenario_name: "test".to_string(),
+            total_duration: Duration::from_secs(100),
+            phase_results: vec![
+                PhaseResult {
+                    name: "phase1".to_string(),
+                    duration: Duration::from_secs(50),
+                    injection_count: 2,
+                    success: true,
+                    skipped: false,
+                    injection_outcomes: Vec::new(),
+                    hook_results: Vec::new(),
+                },
+                PhaseResult {
+                    name: "phase2".to_string(),
+                    duration: Duration::from_secs(50),
+                    injection_count: 1,
+                    success: true,
+                    skipped: false,
+                    injection_outcomes: Vec::new(),
+                    hook_results: Vec::new(),
+                },
+            ],
+            total_injections: 3,
+            failed_injections: 0,
+            aborted_reason: None,
+            host: HostFingerprint::capture(),
+            labels: std::collections::HashMap::new(),
+            hook_results: Vec::new(),
+            resolved_seed: None,
+        };
+
+        assert_eq!(result.success_rate(), 1.0);
+        assert_eq!(result.average_phase_duration(), Duration::from_secs(50));
+        assert!(!result.is_baseline());
+    }
+
+    fn injection(r#type: &str) -> InjectionConfig {
+        InjectionConfig {
+            r#type: r#type.to_string(),
+            target: Default::default(),
+            parameters: std::collections::HashMap::new(),
+            ramp: None,
+            start_after: None,
+            duration: None,
+            jitter: None,
+        }
+    }
+
+    #[test]
+    fn test_select_injections_returns_all_without_a_selection() {
+        let injections = vec![injection("a"), injection("b")];
+        let selected = select_injections(&injections, None, Some(1), 0);
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn test_select_injections_respects_count() {
+        let injections = vec![injection("a"), injection("b"), injection("c")];
+        let selection = InjectionSelection {
+            count: 2,
+            weights: Vec::new(),
+        };
+        let selected = select_injections(&injections, Some(&selection), Some(7), 0);
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn test_select_injections_clamps_count_to_available_injections() {
+        let injections = vec![injection("a")];
+        let selection = InjectionSelection {
+            count: 5,
+            weights: Vec::new(),
+        };
+        let selected = select_injections(&injections, Some(&selection), Some(7), 0);
+        assert_eq!(selected.len(), 1);
+    }
+
+    #[test]
+    fn test_select_injections_is_reproducible_for_the_same_seed_and_phase() {
+        let injections = vec![injection("a"), injection("b"), injection("c")];
+        let selection = InjectionSelection {
+            count: 1,
+            weights: vec![1.0, 100.0, 1.0],
+        };
+
+        let first = select_injections(&injections, Some(&selection), Some(42), 3);
+        let second = select_injections(&injections, Some(&selection), Some(42), 3);
+        assert_eq!(first[0].r#type, second[0].r#type);
+        // A weight of 100 vs 1 should make "b" overwhelmingly likely.
+        assert_eq!(first[0].r#type, "b");
+    }
+
+    fn cpu_burn_injection(duration: Option<Duration>, start_after: Option<Duration>) -> InjectionConfig {
+        let mut parameters = std::collections::HashMap::new();
+        parameters.insert("intensity".to_string(), serde_json::json!(0.1));
+        parameters.insert("threads".to_string(), serde_json::json!([0]));
+
+        InjectionConfig {
+            r#type: "cpu_starvation".to_string(),
+            target: crate::config::TargetConfig {
+                pid: Some(1),
+                ..Default::default()
+            },
+            parameters,
+            ramp: None,
+            start_after,
+            duration,
+            jitter: None,
+        }
+    }
+
+    async fn run_injections(
+        runner: &ScenarioRunner,
+        injections: &[&InjectionConfig],
+        phase_duration: Duration,
+    ) -> (Vec<InjectionHandle>, Vec<InjectionOutcome>, usize, bool) {
+        let (_pause_tx, mut pause_rx) = watch::channel(PauseState::Running);
+        let cancel = CancellationToken::new();
+        let mut background_handles = Vec::new();
+        runner
+            .run_phase_injections(
+                injections,
+                phase_duration,
+                None,
+                0,
+                InjectionFailurePolicy::default(),
+                &cancel,
+                &mut pause_rx,
+                &mut background_handles,
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_run_phase_injections_never_applies_a_start_after_past_phase_end() {
+        let runner = ScenarioRunner::with_defaults();
+        let injection = cpu_burn_injection(None, Some(Duration::from_secs(60)));
+
+        let (handles, outcomes, failed, abort_scenario) =
+            run_injections(&runner, &[&injection], Duration::from_millis(20)).await;
+
+        assert!(handles.is_empty());
+        assert!(outcomes.is_empty());
+        assert_eq!(failed, 0);
+        assert!(!abort_scenario);
+    }
+
+    #[tokio::test]
+    async fn test_run_phase_injections_removes_early_once_its_duration_elapses() {
+        let runner = ScenarioRunner::with_defaults();
+        let injection = cpu_burn_injection(Some(Duration::from_millis(10)), None);
+
+        let (handles, outcomes, failed, abort_scenario) =
+            run_injections(&runner, &[&injection], Duration::from_millis(200)).await;
+
+        assert_eq!(handles.len(), 1);
+        assert_eq!(
+            outcomes.iter().map(|o| o.status).collect::<Vec<_>>(),
+            vec![InjectionStatus::Applied]
+        );
+        assert_eq!(failed, 0);
+        assert!(!abort_scenario);
+    }
+
+    #[tokio::test]
+    async fn test_run_phase_injections_applies_all_immediately_without_timing() {
+        let runner = ScenarioRunner::with_defaults();
+        let a = cpu_burn_injection(None, None);
+        let b = cpu_burn_injection(None, None);
+
+        let (handles, outcomes, failed, abort_scenario) =
+            run_injections(&runner, &[&a, &b], Duration::from_millis(20)).await;
+
+        assert_eq!(handles.len(), 2);
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(failed, 0);
+        assert!(!abort_scenario);
+    }
+
+    #[test]
+    fn test_injection_jitter_offset_is_reproducible_and_within_bounds() {
+        let mut injection = injection("a");
+        injection.jitter = Some(Duration::from_secs(5));
+
+        let first = injection_jitter_offset(&injection, Some(42), 1, 0);
+        let second = injection_jitter_offset(&injection, Some(42), 1, 0);
+
+        assert_eq!(first, second);
+        assert!(first <= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_injection_jitter_offset_is_zero_without_jitter_configured() {
+        let injection = injection("a");
+        assert_eq!(
+            injection_jitter_offset(&injection, Some(42), 0, 0),
+            Duration::ZERO
+        );
+    }
+}
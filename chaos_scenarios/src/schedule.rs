@@ -0,0 +1,171 @@
+use crate::{config::Scenario, parser::parse_scenario_from_file};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// One registered scenario in a [`ScheduleFile`]: run automatically
+/// whenever `cron` next comes due, instead of only via an explicit `chaos
+/// run`. `scenario_file` is relative to the schedule file's own directory,
+/// the same convention [`crate::suite::SuiteEntry::scenario_file`] uses.
+///
+/// Actually driving a clock against `cron`, preventing overlapping runs of
+/// the same entry, and recording each result to the run store is
+/// `chaos_cli`'s job (`chaos schedule`) - the same split as
+/// [`crate::config::ProbeConfig`]/[`crate::config::WorkloadConfig`], since
+/// recording to history needs `chaos_metrics`, which this crate can't
+/// depend on without a cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    pub name: String,
+    pub scenario_file: PathBuf,
+    /// Standard 6-field cron expression (seconds minutes hours
+    /// day-of-month month day-of-week), understood by the `cron` crate.
+    pub cron: String,
+    /// Fires the schedule when true (the default). Set to false to keep an
+    /// entry registered without running it, e.g. while investigating a
+    /// finicky target, without having to delete and re-add it later.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ScheduleEntry {
+    /// Parses [`Self::cron`] into a queryable [`cron::Schedule`], so a
+    /// malformed expression is caught at load time rather than silently
+    /// never firing.
+    pub fn parsed_cron(&self) -> anyhow::Result<cron::Schedule> {
+        cron::Schedule::from_str(&self.cron).map_err(|e| {
+            anyhow::anyhow!("invalid cron expression '{}' for schedule '{}': {}", self.cron, self.name, e)
+        })
+    }
+
+    /// Loads the scenario this entry runs, resolving [`Self::scenario_file`]
+    /// relative to `base_dir` and applying [`Self::seed`] if set - the same
+    /// override [`crate::suite::SuiteEntry`] applies.
+    pub async fn load_scenario(&self, base_dir: impl AsRef<Path>) -> anyhow::Result<Scenario> {
+        let path = base_dir.as_ref().join(&self.scenario_file);
+        let mut scenario: Scenario = parse_scenario_from_file(&path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load '{}': {}", path.display(), e))?;
+
+        if let Some(seed) = self.seed {
+            scenario.seed = Some(seed);
+        }
+
+        Ok(scenario)
+    }
+}
+
+/// On-disk schedule format (YAML, TOML, or JSON - parsed the same way as a
+/// single scenario file): a named registry of scenarios run on their own
+/// cron expression, for continuous game-day automation rather than a
+/// series of one-off `chaos run` invocations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleFile {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub schedules: Vec<ScheduleEntry>,
+}
+
+impl ScheduleFile {
+    /// Loads a schedule file in whichever of YAML/TOML/JSON its extension
+    /// indicates, mirroring [`crate::suite::SuiteFile::load`].
+    pub async fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+
+        let extension = path.extension().and_then(|s| s.to_str());
+        let file = match extension {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&contents)?,
+            Some("toml") => toml::from_str(&contents)?,
+            Some("json") => serde_json::from_str(&contents)?,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Unsupported file format. Use .yaml, .yml, .toml, or .json"
+                ))
+            }
+        };
+
+        Ok(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_entry_defaults_to_enabled() {
+        let toml = r#"
+name = "nightly-drills"
+
+[[schedules]]
+name = "checkout-outage"
+scenario_file = "checkout.yaml"
+cron = "0 0 3 * * *"
+"#;
+
+        let file: ScheduleFile = toml::from_str(toml).unwrap();
+        assert!(file.schedules[0].enabled);
+    }
+
+    #[test]
+    fn test_valid_cron_expression_parses() {
+        let entry = ScheduleEntry {
+            name: "nightly".to_string(),
+            scenario_file: PathBuf::from("x.yaml"),
+            cron: "0 0 3 * * *".to_string(),
+            enabled: true,
+            seed: None,
+        };
+
+        assert!(entry.parsed_cron().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_cron_expression_is_rejected() {
+        let entry = ScheduleEntry {
+            name: "bad".to_string(),
+            scenario_file: PathBuf::from("x.yaml"),
+            cron: "not a cron expression".to_string(),
+            enabled: true,
+            seed: None,
+        };
+
+        assert!(entry.parsed_cron().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_scenario_applies_seed_override() {
+        let dir = std::env::temp_dir().join(format!("chaos_schedule_test_{}", std::process::id()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let scenario_path = dir.join("scenario.yaml");
+        tokio::fs::write(
+            &scenario_path,
+            "name: probe\nduration: 1s\nphases:\n  - name: phase1\n    duration: 1s\n    injections: []\n",
+        )
+        .await
+        .unwrap();
+
+        let entry = ScheduleEntry {
+            name: "probe".to_string(),
+            scenario_file: PathBuf::from("scenario.yaml"),
+            cron: "0 0 3 * * *".to_string(),
+            enabled: true,
+            seed: Some(42),
+        };
+
+        let scenario = entry.load_scenario(&dir).await.unwrap();
+        assert_eq!(scenario.seed, Some(42));
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+}
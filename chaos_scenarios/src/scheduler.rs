@@ -1,5 +1,5 @@
 use crate::config::{Phase, Scenario};
-use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 use std::time::Duration;
 use tracing::info;
 
@@ -82,6 +82,8 @@ impl Scheduler {
             }
         }
 
+        apply_jitter(&mut phases, scenario.seed);
+
         info!(
             "Scheduled {} phases in {:?} mode",
             phases.len(),
@@ -106,6 +108,31 @@ impl Scheduler {
     }
 }
 
+/// Delays each jittered phase's start (and end, so its own duration is
+/// unaffected) by an amount in `[0, phase.jitter]`, seeded from `seed` and
+/// the phase's index for reproducibility - the same seed-mixing convention
+/// [`crate::runner`]'s injection selection uses, since there's no shared
+/// seed-composition helper to reuse instead. A jittered phase can end up
+/// overlapping slightly with whatever follows it.
+fn apply_jitter(phases: &mut [ScheduledPhase], seed: Option<u64>) {
+    for scheduled in phases.iter_mut() {
+        let Some(jitter) = scheduled.phase.jitter else {
+            continue;
+        };
+        if jitter.is_zero() {
+            continue;
+        }
+
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed ^ (scheduled.index as u64)),
+            None => StdRng::from_entropy(),
+        };
+        let offset = rng.gen_range(Duration::ZERO..=jitter);
+        scheduled.start_time += offset;
+        scheduled.end_time += offset;
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ScheduledPhase {
     pub phase: Phase,
@@ -198,6 +225,53 @@ mod tests {
         assert_eq!(phases[0].end_time, Duration::from_secs(15));
     }
 
+    #[test]
+    fn test_jitter_delays_start_and_end_by_a_reproducible_amount_within_bounds() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .seed(42)
+            .add_phase(
+                Phase::builder()
+                    .name("p1")
+                    .duration(Duration::from_secs(10))
+                    .jitter(Duration::from_secs(5))
+                    .build(),
+            )
+            .build();
+
+        let mut scheduler = Scheduler::sequential();
+        let first = scheduler.schedule_phases(&scenario);
+        let mut scheduler = Scheduler::sequential();
+        let second = scheduler.schedule_phases(&scenario);
+
+        assert_eq!(first[0].start_time, second[0].start_time);
+        assert!(first[0].start_time <= Duration::from_secs(5));
+        assert_eq!(
+            first[0].end_time - first[0].start_time,
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_zero_jitter_leaves_start_time_unchanged() {
+        let scenario = Scenario::builder()
+            .name("test")
+            .seed(42)
+            .add_phase(
+                Phase::builder()
+                    .name("p1")
+                    .duration(Duration::from_secs(10))
+                    .jitter(Duration::ZERO)
+                    .build(),
+            )
+            .build();
+
+        let mut scheduler = Scheduler::sequential();
+        let phases = scheduler.schedule_phases(&scenario);
+
+        assert_eq!(phases[0].start_time, Duration::ZERO);
+    }
+
     #[test]
     fn test_scheduled_phase_status() {
         let phase = ScheduledPhase {
@@ -0,0 +1,299 @@
+use crate::{
+    config::Scenario,
+    parser::parse_scenario_from_file,
+    runner::{ScenarioResult, ScenarioRunner},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// One entry in a [`SuiteFile`]: a scenario file to run as part of the
+/// battery, plus the verdict criterion for that run. The same scenario file
+/// can appear in more than one entry with a different `seed`, so a battery
+/// can exercise one scenario's variance as well as many distinct ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiteEntry {
+    pub name: String,
+    /// Path to the scenario file, relative to the suite file's own
+    /// directory (the same convention `ScenarioPackage` uses for its
+    /// assets relative to the package root).
+    pub scenario_file: PathBuf,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Minimum success rate required for this entry to count as a pass.
+    #[serde(default = "default_minimum_success_rate")]
+    pub minimum_success_rate: f64,
+}
+
+fn default_minimum_success_rate() -> f64 {
+    1.0
+}
+
+/// How a [`SuiteFile`]'s scenarios are scheduled relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SuiteMode {
+    /// Run one scenario to completion before starting the next.
+    #[default]
+    Sequential,
+    /// Run every scenario concurrently, each against its own `Executor`.
+    Parallel,
+}
+
+/// On-disk suite format (YAML, TOML, or JSON - parsed the same way as a
+/// single scenario file): a named battery of scenarios run together to
+/// produce one release-qualification verdict, rather than one scenario's
+/// result judged in isolation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiteFile {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub mode: SuiteMode,
+    pub scenarios: Vec<SuiteEntry>,
+}
+
+/// Outcome of running a single [`SuiteEntry`]. `result` is `Err` when the
+/// scenario failed to load or run at all, as distinct from loading fine but
+/// falling short of `minimum_success_rate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiteEntryResult {
+    pub name: String,
+    pub scenario_file: PathBuf,
+    pub result: Result<ScenarioResult, String>,
+    pub passed: bool,
+}
+
+/// Combined report for an entire [`SuiteFile`] run: every entry's result
+/// plus the overall pass/fail a release-qualification gate can key off of.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiteResult {
+    pub suite_name: String,
+    pub entries: Vec<SuiteEntryResult>,
+    pub passed: bool,
+}
+
+impl SuiteFile {
+    /// Loads a suite file in whichever of YAML/TOML/JSON its extension
+    /// indicates, mirroring [`crate::parser::parse_scenario_from_file`].
+    pub async fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+
+        let extension = path.extension().and_then(|s| s.to_str());
+        let suite = match extension {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&contents)?,
+            Some("toml") => toml::from_str(&contents)?,
+            Some("json") => serde_json::from_str(&contents)?,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Unsupported file format. Use .yaml, .yml, .toml, or .json"
+                ))
+            }
+        };
+
+        Ok(suite)
+    }
+
+    /// Runs every scenario in the suite according to `self.mode`, resolving
+    /// each entry's `scenario_file` relative to `base_dir`. An individual
+    /// scenario failing to load or run doesn't abort the suite - it's
+    /// recorded as a failed entry so the rest of the battery still produces
+    /// a verdict.
+    pub async fn run(&self, base_dir: impl AsRef<Path>) -> SuiteResult {
+        let base_dir = base_dir.as_ref();
+
+        let entries = match self.mode {
+            SuiteMode::Sequential => {
+                let mut results = Vec::with_capacity(self.scenarios.len());
+                for entry in &self.scenarios {
+                    results.push(run_entry(entry.clone(), base_dir.to_path_buf()).await);
+                }
+                results
+            }
+            SuiteMode::Parallel => {
+                let mut set = tokio::task::JoinSet::new();
+                for entry in self.scenarios.clone() {
+                    set.spawn(run_entry(entry, base_dir.to_path_buf()));
+                }
+
+                let mut results = Vec::with_capacity(self.scenarios.len());
+                while let Some(joined) = set.join_next().await {
+                    match joined {
+                        Ok(result) => results.push(result),
+                        Err(e) => results.push(SuiteEntryResult {
+                            name: "<panicked>".to_string(),
+                            scenario_file: PathBuf::new(),
+                            result: Err(format!("Suite entry task panicked: {}", e)),
+                            passed: false,
+                        }),
+                    }
+                }
+                results
+            }
+        };
+
+        let passed = !entries.is_empty() && entries.iter().all(|e| e.passed);
+
+        SuiteResult {
+            suite_name: self.name.clone(),
+            entries,
+            passed,
+        }
+    }
+}
+
+/// Runs each `(axis values, scenario)` combination from a `matrix:`
+/// expansion (see [`crate::parser::load_scenario_matrix`]) in order,
+/// producing the same combined verdict [`SuiteFile::run`] gives a battery
+/// of scenario files - there's just no on-disk `scenario_file` per
+/// combination, so entries are labeled by their axis values instead.
+pub async fn run_matrix(combinations: Vec<(HashMap<String, String>, Scenario)>) -> SuiteResult {
+    let mut entries = Vec::with_capacity(combinations.len());
+
+    for (axis_values, scenario) in combinations {
+        let result = ScenarioRunner::with_defaults().run(&scenario).await.map_err(|e| e.to_string());
+        let passed = result.as_ref().map(|r| r.success_rate() >= 1.0).unwrap_or(false);
+        entries.push(SuiteEntryResult {
+            name: label_for_combination(&axis_values),
+            scenario_file: PathBuf::new(),
+            result,
+            passed,
+        });
+    }
+
+    let passed = !entries.is_empty() && entries.iter().all(|e| e.passed);
+
+    SuiteResult {
+        suite_name: "matrix".to_string(),
+        entries,
+        passed,
+    }
+}
+
+fn label_for_combination(axis_values: &HashMap<String, String>) -> String {
+    if axis_values.is_empty() {
+        return "default".to_string();
+    }
+
+    let mut pairs: Vec<_> = axis_values.iter().collect();
+    pairs.sort_by(|a, b| a.0.cmp(b.0));
+    pairs.into_iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(", ")
+}
+
+async fn run_entry(entry: SuiteEntry, base_dir: PathBuf) -> SuiteEntryResult {
+    let outcome = run_entry_inner(&entry, &base_dir).await;
+
+    match outcome {
+        Ok(result) => {
+            let passed = result.success_rate() >= entry.minimum_success_rate;
+            SuiteEntryResult {
+                name: entry.name,
+                scenario_file: entry.scenario_file,
+                result: Ok(result),
+                passed,
+            }
+        }
+        Err(e) => SuiteEntryResult {
+            name: entry.name,
+            scenario_file: entry.scenario_file,
+            result: Err(e),
+            passed: false,
+        },
+    }
+}
+
+async fn run_entry_inner(entry: &SuiteEntry, base_dir: &Path) -> Result<ScenarioResult, String> {
+    let scenario_path = base_dir.join(&entry.scenario_file);
+
+    let mut scenario: Scenario = parse_scenario_from_file(&scenario_path)
+        .await
+        .map_err(|e| format!("Failed to load '{}': {}", scenario_path.display(), e))?;
+
+    if let Some(seed) = entry.seed {
+        scenario.seed = Some(seed);
+    }
+
+    ScenarioRunner::with_defaults()
+        .run(&scenario)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suite_defaults_to_sequential_mode() {
+        let toml = r#"
+name = "release-qualification"
+
+[[scenarios]]
+name = "checkout-outage"
+scenario_file = "checkout.yaml"
+"#;
+
+        let suite: SuiteFile = toml::from_str(toml).unwrap();
+        assert_eq!(suite.mode, SuiteMode::Sequential);
+        assert_eq!(suite.scenarios[0].minimum_success_rate, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_suite_with_no_scenarios_does_not_pass() {
+        let suite = SuiteFile {
+            name: "empty".to_string(),
+            description: None,
+            mode: SuiteMode::Sequential,
+            scenarios: Vec::new(),
+        };
+
+        let result = suite.run(".").await;
+        assert!(!result.passed);
+        assert!(result.entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_matrix_labels_entries_by_axis_values() {
+        let scenario = Scenario::builder()
+            .name("sweep")
+            .add_phase(
+                crate::config::Phase::builder()
+                    .name("phase1")
+                    .duration(std::time::Duration::from_secs(1))
+                    .build(),
+            )
+            .build();
+
+        let mut axis_values = HashMap::new();
+        axis_values.insert("latency".to_string(), "50ms".to_string());
+
+        let result = run_matrix(vec![(axis_values, scenario)]).await;
+        assert_eq!(result.entries.len(), 1);
+        assert_eq!(result.entries[0].name, "latency=50ms");
+        assert!(result.passed);
+    }
+
+    #[tokio::test]
+    async fn test_suite_entry_with_missing_scenario_file_fails_without_aborting_suite() {
+        let suite = SuiteFile {
+            name: "release-qualification".to_string(),
+            description: None,
+            mode: SuiteMode::Sequential,
+            scenarios: vec![SuiteEntry {
+                name: "missing".to_string(),
+                scenario_file: PathBuf::from("does-not-exist.yaml"),
+                seed: None,
+                minimum_success_rate: 1.0,
+            }],
+        };
+
+        let result = suite.run(".").await;
+        assert!(!result.passed);
+        assert_eq!(result.entries.len(), 1);
+        assert!(!result.entries[0].passed);
+        assert!(result.entries[0].result.is_err());
+    }
+}